@@ -1,9 +1,68 @@
 use std::env;
 
+use crate::compilation::*;
+
+const IMPORT_DIRECTORY_ARGUMENT: &str = "-import_dir";
+const TARGET_ARGUMENT: &str = "-target";
+const LINK_ARGUMENT: &str = "-link";
+
+pub const USAGE: &str = "usage: rust-hephaestus [-target windows|linux] [-import_dir <directory>]... [-link] <file>";
+
+#[derive(Debug, PartialEq)]
+pub enum ArgumentError {
+    UnknownTarget(String)
+}
+
+pub fn unknown_target_error(target: &str) -> ArgumentError {
+    ArgumentError::UnknownTarget(target.to_string())
+}
+
 pub fn get_file_to_compile_from_invocation_arguments() -> Option<String>{
-    let args: Vec<String> = env::args().collect();
-    if let Some(file_name) = args.get(1) {
-        return Some(file_name.clone())
+    get_file_to_compile_from_arguments(&get_invocation_arguments())
+}
+
+pub fn get_module_paths_from_invocation_arguments() -> Vec<String> {
+    get_module_paths_from_arguments(&get_invocation_arguments())
+}
+
+pub fn get_target_from_invocation_arguments() -> Result<CompilationTarget, ArgumentError> {
+    get_target_from_arguments(&get_invocation_arguments())
+}
+
+pub fn get_link_from_invocation_arguments() -> bool {
+    get_link_from_arguments(&get_invocation_arguments())
+}
+
+fn get_invocation_arguments() -> Vec<String> {
+    env::args().skip(1).collect()
+}
+
+pub fn get_file_to_compile_from_arguments(args: &[String]) -> Option<String> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == IMPORT_DIRECTORY_ARGUMENT || arg == TARGET_ARGUMENT {
+            args.next();
+        } else if arg != LINK_ARGUMENT {
+            return Some(arg.clone());
+        }
     }
     None
-}
\ No newline at end of file
+}
+
+pub fn get_module_paths_from_arguments(args: &[String]) -> Vec<String> {
+    args.windows(2)
+        .filter(|pair| pair[0] == IMPORT_DIRECTORY_ARGUMENT)
+        .map(|pair| pair[1].clone())
+        .collect()
+}
+
+pub fn get_target_from_arguments(args: &[String]) -> Result<CompilationTarget, ArgumentError> {
+    match args.windows(2).rev().find(|pair| pair[0] == TARGET_ARGUMENT) {
+        Some(pair) => try_get_compilation_target(&pair[1]).ok_or_else(|| unknown_target_error(&pair[1])),
+        None => Ok(CompilationTarget::default())
+    }
+}
+
+pub fn get_link_from_arguments(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == LINK_ARGUMENT)
+}
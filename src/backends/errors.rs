@@ -6,6 +6,11 @@ pub enum BackendError {
     UnimplementedInstruction,
     UnimplementedFeature(String),
     RegisterNotAvailable(usize),
+    UndefinedSymbol(String, String),
+    DuplicateSymbol(String, String),
+    EntryPointNotFound(String),
+    RelocationOutOfRange(String),
+    LabelNotFound(u32),
 }
 
 pub type BackendErrorResult = Result<(), BackendError>;
@@ -25,3 +30,23 @@ pub fn todo_error(text: &str) -> BackendError {
 pub fn register_not_available_error(r: usize) -> BackendError {
     BackendError::RegisterNotAvailable(r)
 }
+
+pub fn undefined_symbol_error(name: &str, referenced_by: &str) -> BackendError {
+    BackendError::UndefinedSymbol(string(name), string(referenced_by))
+}
+
+pub fn duplicate_symbol_error(name: &str, defined_by: &str) -> BackendError {
+    BackendError::DuplicateSymbol(string(name), string(defined_by))
+}
+
+pub fn entry_point_not_found_error(name: &str) -> BackendError {
+    BackendError::EntryPointNotFound(string(name))
+}
+
+pub fn relocation_out_of_range_error(name: &str) -> BackendError {
+    BackendError::RelocationOutOfRange(string(name))
+}
+
+pub fn label_not_found_error(label: u32) -> BackendError {
+    BackendError::LabelNotFound(label)
+}
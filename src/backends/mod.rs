@@ -2,10 +2,14 @@ mod llvm;
 mod x64;
 mod errors;
 
+use std::mem;
+
 pub use llvm::*;
 pub use x64::*;
 pub use errors::*;
 
+pub use errors::todo_error;
+
 use crate::{
     acting::*,
     compilation::*,
@@ -16,7 +20,7 @@ use crate::{
 };
 
 pub trait BackendBuild : Send + Clone + 'static {
-    fn build_backend(&mut self, ir: IntermediateRepresentation, has_prior_errors: bool) -> BackendErrorResult;
+    fn build_backend(&mut self, file_name: &str, irs: IntermediateRepresentations, has_prior_errors: bool) -> BackendErrorResult;
 }
 
 
@@ -24,13 +28,17 @@ pub struct BackendActor<TBackend: BackendBuild> {
     compiler: CompilationActorHandle,
     error_reporter: CompilationActorHandle,
     backend: TBackend,
+    irs: IntermediateRepresentations,
+    has_prior_errors: bool
 }
 
 pub fn create_backend_actor<TBackend: BackendBuild>(compiler: CompilationActorHandle, error_reporter: CompilationActorHandle, backend: TBackend) -> BackendActor<TBackend> {
     BackendActor {
         compiler,
         error_reporter,
-        backend
+        backend,
+        irs: vec!(),
+        has_prior_errors: false
     }
 }
 
@@ -38,7 +46,9 @@ impl<TBackend: BackendBuild> Actor<CompilationMessage> for BackendActor<TBackend
     fn receive(&mut self, message: CompilationMessage, _ctx: &CompilationMessageContext) -> AfterReceiveAction {
         match message {
             CompilationMessage::BuildBackend { code, has_prior_errors } =>
-                build_backend_from_ir(&mut self.backend, code, &self.compiler, &self.error_reporter,  has_prior_errors),
+                add_ir_to_backend(self, code, has_prior_errors),
+            CompilationMessage::EmitBackend { file_name, has_prior_errors } =>
+                emit_backend_from_irs(self, &file_name, has_prior_errors),
             _ => continue_listening_after_receive()
         }
     }
@@ -48,17 +58,19 @@ impl<TBackend: BackendBuild> Actor<CompilationMessage> for BackendActor<TBackend
     }
 }
 
-fn build_backend_from_ir<TBackend: BackendBuild>(
-    backend: &mut TBackend,
-    ir: IntermediateRepresentation,
-    compiler: &CompilationActorHandle,
-    error_reporter: &CompilationActorHandle,
-    has_prior_errors: bool
-) -> AfterReceiveAction {
+fn add_ir_to_backend<TBackend: BackendBuild>(actor: &mut BackendActor<TBackend>, ir: IntermediateRepresentation, has_prior_errors: bool) -> AfterReceiveAction {
     let id = ir.id;
-    let result = backend.build_backend(ir, has_prior_errors);
-    report_errors(error_reporter, compiler.clone(), create_errors_for_backend_error_result(result));
-    send_message_to_actor(compiler, create_backend_built_event(id));
+    actor.irs.push(ir);
+    actor.has_prior_errors |= has_prior_errors;
+    send_message_to_actor(&actor.compiler, create_backend_built_event(id));
+    continue_listening_after_receive()
+}
+
+fn emit_backend_from_irs<TBackend: BackendBuild>(actor: &mut BackendActor<TBackend>, file_name: &str, has_prior_errors: bool) -> AfterReceiveAction {
+    let irs = mem::take(&mut actor.irs);
+    let result = actor.backend.build_backend(file_name, irs, actor.has_prior_errors || has_prior_errors);
+    report_errors(&actor.error_reporter, actor.compiler.clone(), create_errors_for_backend_error_result(result));
+    send_message_to_actor(&actor.compiler, create_backend_emitted_event());
     shutdown_after_receive()
 }
 
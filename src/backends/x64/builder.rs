@@ -1,31 +1,358 @@
+use std::collections::HashMap;
+
 use super::Coff;
 
 use crate::{
+    compilation::*,
     intermediate_representation::*,
     backends::*
 };
 
 #[derive(Clone)]
-pub struct X64Backend;
+pub struct X64Backend {
+    target: CompilationTarget,
+    link: bool
+}
 
 impl BackendBuild for X64Backend {
-    fn build_backend(&mut self, ir: IntermediateRepresentation, has_prior_errors: bool) -> BackendErrorResult {
+    fn build_backend(&mut self, file_name: &str, irs: IntermediateRepresentations, has_prior_errors: bool) -> BackendErrorResult {
         if has_prior_errors {
             return Ok(());
         }
-        let mut coff = create_coff();
-        let file_name = build_x64_object(&mut coff, ir)?;   
-        write_coff_to_file(&coff, &mut create_coff_file(&file_name).unwrap()).unwrap();
+        match self.target {
+            CompilationTarget::Windows => {
+                let coff = build_x64_coff_object(file_name, &irs)?;
+                write_coff_to_file(&coff, &mut create_coff_file(&get_object_file_name(file_name, "obj")).unwrap()).unwrap();
+                if self.link {
+                    let pe = link_pe_executable(&[coff], &get_pe_import_libraries(&irs), PE_ENTRY_POINT)?;
+                    write_pe_to_file(&pe, &mut create_pe_file(&get_executable_file_name(file_name, self.target)).unwrap()).unwrap();
+                }
+            },
+            CompilationTarget::Linux => {
+                let elf = build_x64_elf_object(file_name, &irs)?;
+                write_elf_to_file(&elf, &mut create_elf_file(&get_object_file_name(file_name, "o")).unwrap()).unwrap();
+                if self.link {
+                    let executable = link_elf_executable(&[elf], ELF_ENTRY_POINT)?;
+                    write_elf_executable_to_file(&executable, &mut create_elf_executable_file(&get_executable_file_name(file_name, self.target)).unwrap()).unwrap();
+                }
+            }
+        }
         Ok(())
     }
 }
 
-pub fn create_x64_backend() -> X64Backend {
-    X64Backend
+pub fn create_x64_backend(target: CompilationTarget, link: bool) -> X64Backend {
+    X64Backend { target, link }
+}
+
+pub fn build_x64_coff_object(file_name: &str, irs: &[IntermediateRepresentation]) -> Result<Coff, BackendError> {
+    let mut coff = create_coff();
+    let symbols = build_x64_object(&mut coff, irs)?;
+
+    add_debug_file_name_symbols(&mut coff, file_name);
+    add_data_section_header_symbols(&mut coff);
+    add_text_section_header_symbols(&mut coff);
+    add_bss_section_header_symbols(&mut coff);
+    add_read_only_data_section_header_symbols(&mut coff);
+    add_absolute_static_symbol(&mut coff, ".absolut", 0);
+
+    for symbol in symbols.iter().rev() {
+        match symbol {
+            ByteCodeSymbol::DataSectionItem { name, value } => add_data_section_static_symbol(&mut coff, name, *value),
+            ByteCodeSymbol::ExternalDataSectionItem { name, value } => add_data_section_external_symbol(&mut coff, name, *value),
+            ByteCodeSymbol::ExternalBssSectionItem { name, value } => add_bss_section_external_symbol(&mut coff, name, *value),
+            ByteCodeSymbol::ForeignExternal { name } => add_foreign_external_symbol(&mut coff, name),
+            ByteCodeSymbol::AbsoluteExternal32 { name, value } => add_absolute_external_symbol_32(&mut coff, name, *value),
+            ByteCodeSymbol::AbsoluteExternal64 { name, value } => add_absolute_external_symbol_32(&mut coff, name, get_coff_absolute_symbol_value(name, *value)?),
+            ByteCodeSymbol::ExternalCodeLabel { name, position } => add_text_section_external_symbol(&mut coff, name, *position),
+        }
+    }
+
+    Ok(coff)
+}
+
+fn get_coff_absolute_symbol_value(name: &str, value: u64) -> Result<u32, BackendError> {
+    i32::try_from(value as i64)
+        .map(|value| value as u32)
+        .map_err(|_| relocation_out_of_range_error(name))
 }
 
-fn build_x64_object(coff: &mut Coff, ir: IntermediateRepresentation) -> Result<String, BackendError> {
-    for op in ir.byte_code  {
+pub fn build_x64_elf_object(file_name: &str, irs: &[IntermediateRepresentation]) -> Result<Elf, BackendError> {
+    let mut coff = create_coff();
+    let symbols = build_x64_object(&mut coff, irs)?;
+
+    let mut elf = create_elf();
+    set_elf_text_section(&mut elf, get_text_section(&coff));
+    set_elf_data_section(&mut elf, get_data_section(&coff));
+    set_elf_rodata_section(&mut elf, get_read_only_data_section(&coff));
+    set_elf_bss_section_size(&mut elf, get_bss_section_size(&coff));
+
+    add_elf_file_symbol(&mut elf, file_name);
+    add_elf_section_symbol(&mut elf, ELF_SECTION_TEXT);
+    let data_section_symbol = add_elf_section_symbol(&mut elf, ELF_SECTION_DATA);
+    let rodata_section_symbol = add_elf_section_symbol(&mut elf, ELF_SECTION_RODATA);
+    add_elf_section_symbol(&mut elf, ELF_SECTION_BSS);
+
+    let mut elf_symbols = vec!();
+    for symbol in &symbols {
+        let elf_symbol = match symbol {
+            ByteCodeSymbol::DataSectionItem { name, value } => add_elf_data_section_static_symbol(&mut elf, name, *value),
+            ByteCodeSymbol::ExternalDataSectionItem { name, value } => add_elf_data_section_external_symbol(&mut elf, name, *value),
+            ByteCodeSymbol::ExternalBssSectionItem { name, value } => add_elf_bss_section_external_symbol(&mut elf, name, *value),
+            ByteCodeSymbol::ForeignExternal { name } => add_elf_foreign_external_symbol(&mut elf, name),
+            ByteCodeSymbol::AbsoluteExternal32 { name, value } => add_elf_absolute_external_symbol_32(&mut elf, name, *value),
+            ByteCodeSymbol::AbsoluteExternal64 { name, value } => add_elf_absolute_external_symbol_64(&mut elf, name, *value),
+            ByteCodeSymbol::ExternalCodeLabel { name, position } => add_elf_text_section_external_symbol(&mut elf, name, *position),
+        };
+        elf_symbols.push(elf_symbol);
+    }
+
+    let relocation_symbols = elf_relocation_symbols(data_section_symbol, rodata_section_symbol, elf_symbols);
+    for entry in get_text_section_relocation_entries(&coff) {
+        let relocation = convert_coff_relocation_to_elf(get_text_section(&coff), entry, &relocation_symbols)?;
+        add_elf_text_section_relocation(&mut elf, relocation);
+    }
+    for entry in get_data_section_relocation_entries(&coff) {
+        let relocation = convert_coff_relocation_to_elf(get_data_section(&coff), entry, &relocation_symbols)?;
+        add_elf_data_section_relocation(&mut elf, relocation);
+    }
+
+    Ok(elf)
+}
+
+struct ElfRelocationSymbols {
+    data_section_symbol: ElfSymbolIndex,
+    rodata_section_symbol: ElfSymbolIndex,
+    symbols: Vec<ElfSymbolIndex>
+}
+
+fn elf_relocation_symbols(data_section_symbol: ElfSymbolIndex, rodata_section_symbol: ElfSymbolIndex, symbols: Vec<ElfSymbolIndex>) -> ElfRelocationSymbols {
+    ElfRelocationSymbols { data_section_symbol, rodata_section_symbol, symbols }
+}
+
+fn convert_coff_relocation_to_elf(section: &[u8], entry: &CoffRelocationEntry, relocation_symbols: &ElfRelocationSymbols) -> Result<ElfRelocation, BackendError> {
+    let (offset, coff_symbol_index, relocation_type) = get_relocation_entry_parts(entry);
+    let symbol = match coff_symbol_index {
+        0x02 => relocation_symbols.data_section_symbol,
+        0x08 => relocation_symbols.rodata_section_symbol,
+        _ => {
+            let number_of_symbols = relocation_symbols.symbols.len();
+            relocation_symbols.symbols[convert_coff_to_byte_code_symbol_index(number_of_symbols, coff_symbol_index)]
+        }
+    };
+
+    let start = offset as usize;
+    match relocation_type {
+        IMAGE_REL_AMD64_REL32 => {
+            let addend = read_i32_from_section(section, start) as i64 - 4;
+            if start > 0 && section[start - 1] == OP_CALL {
+                Ok(elf_relocation(offset, symbol, R_X86_64_PLT32, addend))
+            } else {
+                Ok(elf_relocation(offset, symbol, R_X86_64_PC32, addend))
+            }
+        },
+        IMAGE_REL_AMD64_ADDR32 => 
+            Ok(elf_relocation(offset, symbol, R_X86_64_32S, read_i32_from_section(section, start) as i64)),
+        IMAGE_REL_AMD64_ADDR64 => {
+            let addend = i64::from_le_bytes(section[start..start + 8].try_into().unwrap());
+            Ok(elf_relocation(offset, symbol, R_X86_64_64, addend))
+        },
+        _ => Err(unsupported_instruction_error())
+    }
+}
+
+fn read_i32_from_section(section: &[u8], start: usize) -> i32 {
+    i32::from_le_bytes(section[start..start + 4].try_into().unwrap())
+}
+
+pub const PE_ENTRY_POINT: &str = "main";
+pub const ELF_ENTRY_POINT: &str = "_start";
+
+pub fn get_pe_import_libraries(irs: &[IntermediateRepresentation]) -> Vec<PeImportLibrary> {
+    let mut library_names = HashMap::new();
+    for ir in irs {
+        if let Some(library) = ir.foreign_libraries.first() {
+            library_names.insert(ir.top_level_symbol.as_str(), get_pe_import_library_name(library));
+        }
+    }
+
+    let mut libraries: Vec<PeImportLibrary> = vec!();
+    for ir in irs {
+        if let Some(library_name) = ir.foreign_library_identifier.as_ref().and_then(|identifier| library_names.get(identifier.as_str())) {
+            match libraries.iter_mut().find(|library| &library.name == library_name) {
+                Some(library) => library.functions.push(ir.top_level_symbol.clone()),
+                None => libraries.push(pe_import_library(library_name.clone(), vec!(ir.top_level_symbol.clone())))
+            }
+        }
+    }
+
+    libraries.sort_by(|a, b| a.name.cmp(&b.name));
+    for library in &mut libraries {
+        library.functions.sort();
+        library.functions.dedup();
+    }
+    libraries
+}
+
+fn get_pe_import_library_name(library: &str) -> String {
+    if library.contains('.') {
+        return string(library);
+    }
+    format!("{}.dll", library)
+}
+
+pub fn get_object_file_name(file_name: &str, extension: &str) -> String {
+    format!("{}.{}", file_name.replace(".hep", ""), extension)
+}
+
+pub fn get_executable_file_name(file_name: &str, target: CompilationTarget) -> String {
+    let file_root = file_name.replace(".hep", "");
+    match target {
+        CompilationTarget::Windows => get_object_file_name(file_name, "exe"),
+        CompilationTarget::Linux if file_root == file_name => get_object_file_name(file_name, "out"),
+        CompilationTarget::Linux => file_root
+    }
+}
+
+fn build_x64_object(coff: &mut Coff, irs: &[IntermediateRepresentation]) -> Result<ByteCodeSymbols, BackendError> {
+    let symbol_table = create_combined_symbol_table(irs)?;
+    let number_of_symbols = symbol_table.owners.len();
+
+    let mut unit_bases = vec!();
+    let mut bss_size = 0;
+    for (ir, symbol_indexes) in irs.iter().zip(&symbol_table.unit_symbol_indexes) {
+        let bases = start_unit_sections(coff, bss_size);
+        bss_size = bases.bss + ir.bss.size;
+        build_x64_unit(coff, ir, &unit_symbols(number_of_symbols, symbol_indexes, bases))?;
+        unit_bases.push(bases);
+    }
+
+    set_bss_section_size(coff, bss_size);
+
+    Ok(get_combined_symbols(irs, &symbol_table, &unit_bases))
+}
+
+struct CombinedSymbolTable {
+    owners: Vec<(usize, usize)>,
+    unit_symbol_indexes: Vec<Vec<usize>>
+}
+
+fn create_combined_symbol_table(irs: &[IntermediateRepresentation]) -> Result<CombinedSymbolTable, BackendError> {
+    let mut owners: Vec<(usize, usize)> = vec!();
+    let mut named_indexes: HashMap<&str, usize> = HashMap::new();
+    let mut unit_symbol_indexes = vec!();
+
+    for (unit_index, ir) in irs.iter().enumerate() {
+        let mut symbol_indexes = vec!();
+        for (symbol_index, symbol) in ir.symbols.iter().enumerate() {
+            let name = match symbol {
+                ByteCodeSymbol::DataSectionItem { .. } => None,
+                _ => Some(get_symbol_name(symbol))
+            };
+
+            let combined_index = match name.and_then(|name| named_indexes.get(name).copied()) {
+                Some(combined_index) => {
+                    let (owner_unit, owner_symbol) = owners[combined_index];
+                    if is_foreign_external_symbol(&irs[owner_unit].symbols[owner_symbol]) {
+                        owners[combined_index] = (unit_index, symbol_index);
+                    } else if !is_foreign_external_symbol(symbol) {
+                        return Err(duplicate_symbol_error(get_symbol_name(symbol), &ir.filename));
+                    }
+                    combined_index
+                },
+                None => {
+                    owners.push((unit_index, symbol_index));
+                    if let Some(name) = name {
+                        named_indexes.insert(name, owners.len() - 1);
+                    }
+                    owners.len() - 1
+                }
+            };
+            symbol_indexes.push(combined_index);
+        }
+        unit_symbol_indexes.push(symbol_indexes);
+    }
+
+    Ok(CombinedSymbolTable { owners, unit_symbol_indexes })
+}
+
+fn get_symbol_name(symbol: &ByteCodeSymbol) -> &str {
+    match symbol {
+        ByteCodeSymbol::DataSectionItem { name, .. } |
+        ByteCodeSymbol::ExternalDataSectionItem { name, .. } |
+        ByteCodeSymbol::ExternalBssSectionItem { name, .. } |
+        ByteCodeSymbol::ForeignExternal { name } |
+        ByteCodeSymbol::AbsoluteExternal32 { name, .. } |
+        ByteCodeSymbol::AbsoluteExternal64 { name, .. } |
+        ByteCodeSymbol::ExternalCodeLabel { name, .. } => name
+    }
+}
+
+fn is_foreign_external_symbol(symbol: &ByteCodeSymbol) -> bool {
+    matches!(symbol, ByteCodeSymbol::ForeignExternal { .. })
+}
+
+#[derive(Copy, Clone)]
+struct UnitBases {
+    text: u32,
+    data: u32,
+    read_only_data: u32,
+    bss: u32
+}
+
+const UNIT_SECTION_ALIGNMENT: u32 = 16;
+
+fn start_unit_sections(coff: &mut Coff, bss_size: u32) -> UnitBases {
+    while !(get_data_section(coff).len() as u32).is_multiple_of(UNIT_SECTION_ALIGNMENT) {
+        add_byte_to_data_section(coff, &0);
+    }
+    while !(get_read_only_data_section(coff).len() as u32).is_multiple_of(UNIT_SECTION_ALIGNMENT) {
+        add_bytes_to_read_only_data_section(coff, &[0]);
+    }
+    UnitBases {
+        text: get_text_section(coff).len() as u32,
+        data: get_data_section(coff).len() as u32,
+        read_only_data: get_read_only_data_section(coff).len() as u32,
+        bss: bss_size.next_multiple_of(UNIT_SECTION_ALIGNMENT)
+    }
+}
+
+fn get_combined_symbols(irs: &[IntermediateRepresentation], symbol_table: &CombinedSymbolTable, unit_bases: &[UnitBases]) -> ByteCodeSymbols {
+    symbol_table.owners
+        .iter()
+        .map(|(unit_index, symbol_index)| rebase_symbol(&irs[*unit_index].symbols[*symbol_index], unit_bases[*unit_index]))
+        .collect()
+}
+
+fn rebase_symbol(symbol: &ByteCodeSymbol, bases: UnitBases) -> ByteCodeSymbol {
+    match symbol {
+        ByteCodeSymbol::DataSectionItem { name, value } => data_section_item(name.clone(), value + bases.data),
+        ByteCodeSymbol::ExternalDataSectionItem { name, value } => external_data_section_item(name.clone(), value + bases.data),
+        ByteCodeSymbol::ExternalBssSectionItem { name, value } => external_bss_section_item(name.clone(), value + bases.bss),
+        ByteCodeSymbol::ExternalCodeLabel { name, position } => external_code_label(name.clone(), position + bases.text),
+        _ => symbol.clone()
+    }
+}
+
+struct UnitSymbols<'a> {
+    number_of_symbols: usize,
+    symbol_indexes: &'a [usize],
+    bases: UnitBases
+}
+
+fn unit_symbols(number_of_symbols: usize, symbol_indexes: &[usize], bases: UnitBases) -> UnitSymbols<'_> {
+    UnitSymbols { number_of_symbols, symbol_indexes, bases }
+}
+
+fn get_unit_coff_symbol_index(unit: &UnitSymbols, symbol_index: SymbolIndex) -> u32 {
+    convert_byte_code_to_coff_symbol_index(unit.number_of_symbols, unit.symbol_indexes[*symbol_index as usize])
+}
+
+fn build_x64_unit(coff: &mut Coff, ir: &IntermediateRepresentation, unit: &UnitSymbols) -> BackendErrorResult {
+    let calling_convention = ir.calling_convention;
+    let mut labels = HashMap::new();
+    let mut jumps = vec!();
+    for op in ir.byte_code.iter().copied() {
         match op {
             ByteCodeInstruction::Unsupported => return Err(unsupported_instruction_error()),
             ByteCodeInstruction::Unimplemented => return Err(unimplemented_instruction_error()),
@@ -33,96 +360,227 @@ fn build_x64_object(coff: &mut Coff, ir: IntermediateRepresentation) -> Result<S
                 add_call_relocatable_addr_op(
                     coff, 
                     relocatable_value(
-                        convert_byte_code_to_coff_symbol_index(ir.symbols.len(), symbol_index), 
+                        get_unit_coff_symbol_index(unit, symbol_index), 
                     0x0)
                 ),
             ByteCodeInstruction::PushReg64(register) => 
-                add_push_reg_op(coff, get_register(register)?),
+                add_push_reg_op(coff, get_register(calling_convention, register)?),
             ByteCodeInstruction::PopReg64(register) =>
-                add_pop_reg_op(coff, get_register(register)?),    
+                add_pop_reg_op(coff, get_register(calling_convention, register)?),    
             ByteCodeInstruction::MoveSymbolToReg32 { symbol_index, to } => 
                 add_mov_dword_relocatable_value_to_reg_op(
                     coff,
-                    relocatable_value(convert_byte_code_to_coff_symbol_index(ir.symbols.len(), symbol_index), 0x0), 
-                    get_register(to)?
+                    relocatable_value(get_unit_coff_symbol_index(unit, symbol_index), 0x0), 
+                    get_register(calling_convention, to)?
                 ),      
             ByteCodeInstruction::MoveValueToReg32 { value, to } => 
-                add_mov_dword_value_to_reg_op(coff, value, get_register(to)?),      
+                add_mov_dword_value_to_reg_op(coff, value, get_register(calling_convention, to)?),      
             ByteCodeInstruction::MoveValueToReg64 { value, to }  =>
-                add_mov_qword_value_to_reg_op(coff, value, get_register(to)?),  
+                add_mov_qword_value_to_reg_op(coff, value, get_register(calling_convention, to)?),  
             ByteCodeInstruction::MoveRegToReg64 { from, to } => 
-                add_mov_from_qword_reg_to_reg_op(coff, get_register(from)?, get_register(to)?),
+                add_mov_from_qword_reg_to_reg_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
             ByteCodeInstruction::MoveValueToRegPlusOffset32 { value, to, offset } => 
-                add_mov_dword_value_into_reg_plus_offset_pointer_op(coff, value, get_register(to)?, *offset),
+                add_mov_dword_value_into_reg_plus_offset_pointer_op(coff, value, get_register(calling_convention, to)?, *offset),
             ByteCodeInstruction::MoveValueToRegPlusOffset64 { value, to, offset } => 
-                add_mov_qword_value_into_reg_plus_offset_pointer_op(coff, value, get_register(to)?, *offset),
+                add_mov_qword_value_into_reg_plus_offset_pointer_op(coff, value, get_register(calling_convention, to)?, *offset),
             ByteCodeInstruction::MoveRegToRegPlusOffset32 { from, to, offset } => 
-                add_mov_reg_to_reg_plus_offset_dword_pointer_op(coff, get_register(from)?, get_register(to)?, *offset),
+                add_mov_reg_to_reg_plus_offset_dword_pointer_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?, *offset),
             ByteCodeInstruction::MoveRegToRegPlusOffset64 { from, to, offset } => 
-                add_mov_reg_to_reg_plus_offset_qword_pointer_op(coff, get_register(from)?, get_register(to)?, *offset),
+                add_mov_reg_to_reg_plus_offset_qword_pointer_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?, *offset),
             ByteCodeInstruction::MoveRegPlusOffsetToReg32 { from, offset, to } => 
-                add_mov_dword_reg_plus_offset_pointer_to_reg_op(coff, get_register(from)?, *offset, get_register(to)?),
+                add_mov_dword_reg_plus_offset_pointer_to_reg_op(coff, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
             ByteCodeInstruction::MoveRegPlusOffsetToReg64 { from, offset, to } => 
-                add_mov_qword_reg_plus_offset_pointer_to_reg_op(coff, get_register(from)?, *offset, get_register(to)?),
+                add_mov_qword_reg_plus_offset_pointer_to_reg_op(coff, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
             ByteCodeInstruction::SubValueFromReg8 { value, from } => 
-                add_sub_byte_value_from_reg_op(coff, value, get_register(from)?),
+                add_sub_byte_value_from_reg_op(coff, value, get_register(calling_convention, from)?),
             ByteCodeInstruction::AddValueToReg8 { value, to } => 
-                add_add_byte_value_to_reg_op(coff, value, get_register(to)?),
+                add_add_byte_value_to_reg_op(coff, value, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SubValueFromReg32 { value, from } => 
+                add_sub_dword_value_from_reg_op(coff, value, get_register(calling_convention, from)?),
+            ByteCodeInstruction::AddValueToReg32 { value, to } => 
+                add_add_dword_value_to_reg_op(coff, value, get_register(calling_convention, to)?),
             ByteCodeInstruction::ZeroReg64(register) =>
-                add_xor_qword_reg_into_reg_op(coff, get_register(register)?, get_register(register)?),
+                add_xor_qword_reg_into_reg_op(coff, get_register(calling_convention, register)?, get_register(calling_convention, register)?),
             ByteCodeInstruction::Return => add_ret_op(coff),
             ByteCodeInstruction::LoadDataSectionAddressToReg64 { data_section_offset, to } => 
                 add_lea_reg_plus_relocatable_offset_pointer_to_reg_op(
                     coff, 
                     REG_IP, 
-                    relocatable_value(0x02, *data_section_offset), 
-                    get_register(to)?
+                    relocatable_value(0x02, *data_section_offset + unit.bases.data), 
+                    get_register(calling_convention, to)?
+                ),
+            ByteCodeInstruction::LoadReadOnlyDataAddressToReg64 { read_only_data_offset, to } => 
+                add_lea_reg_plus_relocatable_offset_pointer_to_reg_op(
+                    coff, 
+                    REG_IP, 
+                    relocatable_value(0x08, *read_only_data_offset + unit.bases.read_only_data), 
+                    get_register(calling_convention, to)?
+                ),
+            ByteCodeInstruction::LoadSymbolAddressToReg64 { symbol_index, to } => 
+                add_lea_reg_plus_relocatable_offset_pointer_to_reg_op(
+                    coff, 
+                    REG_IP, 
+                    relocatable_value(get_unit_coff_symbol_index(unit, symbol_index), 0x0), 
+                    get_register(calling_convention, to)?
                 ),
             ByteCodeInstruction::LoadAddressInRegPlusOffsetToReg64 { from, offset, to } =>
                 add_lea_reg_plus_offset_pointer_to_reg_op(
                     coff, 
-                    get_register(from)?, 
+                    get_register(calling_convention, from)?, 
                     *offset, 
-                    get_register(to)?
-                )
+                    get_register(calling_convention, to)?
+                ),
+            ByteCodeInstruction::MoveRegToRegPlusOffset8 { from, to, offset } =>
+                add_mov_byte_reg_to_reg_plus_offset_pointer_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?, *offset),
+            ByteCodeInstruction::MoveDataSectionToFloatReg32 { data_section_offset, to } =>
+                add_sse_mov_relocatable_pointer_to_reg_op(coff, ScalarFloat::Single, relocatable_value(0x02, *data_section_offset + unit.bases.data), get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveDataSectionToFloatReg64 { data_section_offset, to } =>
+                add_sse_mov_relocatable_pointer_to_reg_op(coff, ScalarFloat::Double, relocatable_value(0x02, *data_section_offset + unit.bases.data), get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveRegPlusOffsetToFloatReg32 { from, offset, to } =>
+                add_sse_mov_reg_plus_offset_pointer_to_reg_op(coff, ScalarFloat::Single, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveRegPlusOffsetToFloatReg64 { from, offset, to } =>
+                add_sse_mov_reg_plus_offset_pointer_to_reg_op(coff, ScalarFloat::Double, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveFloatRegToRegPlusOffset32 { from, to, offset } =>
+                add_sse_mov_reg_to_reg_plus_offset_pointer_op(coff, ScalarFloat::Single, get_register(calling_convention, from)?, get_register(calling_convention, to)?, *offset),
+            ByteCodeInstruction::MoveFloatRegToRegPlusOffset64 { from, to, offset } =>
+                add_sse_mov_reg_to_reg_plus_offset_pointer_op(coff, ScalarFloat::Double, get_register(calling_convention, from)?, get_register(calling_convention, to)?, *offset),
+            ByteCodeInstruction::AddFloatRegToFloatReg32 { from, to } =>
+                add_sse_add_reg_to_reg_op(coff, ScalarFloat::Single, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::AddFloatRegToFloatReg64 { from, to } =>
+                add_sse_add_reg_to_reg_op(coff, ScalarFloat::Double, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SubFloatRegFromFloatReg32 { from, to } =>
+                add_sse_sub_reg_from_reg_op(coff, ScalarFloat::Single, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SubFloatRegFromFloatReg64 { from, to } =>
+                add_sse_sub_reg_from_reg_op(coff, ScalarFloat::Double, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MulFloatRegToFloatReg32 { from, to } =>
+                add_sse_mul_reg_to_reg_op(coff, ScalarFloat::Single, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MulFloatRegToFloatReg64 { from, to } =>
+                add_sse_mul_reg_to_reg_op(coff, ScalarFloat::Double, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::CompareFloatRegToFloatReg32 { lhs, rhs } =>
+                add_sse_compare_reg_to_reg_op(coff, ScalarFloat::Single, get_register(calling_convention, lhs)?, get_register(calling_convention, rhs)?),
+            ByteCodeInstruction::CompareFloatRegToFloatReg64 { lhs, rhs } =>
+                add_sse_compare_reg_to_reg_op(coff, ScalarFloat::Double, get_register(calling_convention, lhs)?, get_register(calling_convention, rhs)?),
+            ByteCodeInstruction::SetReg8OnCondition { condition, to } =>
+                add_set_byte_reg_on_condition_op(coff, get_condition(condition), get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertIntReg32ToFloatReg32 { from, to } =>
+                add_sse_convert_int_reg_to_float_reg_op(coff, ScalarFloat::Single, false, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertIntReg32ToFloatReg64 { from, to } =>
+                add_sse_convert_int_reg_to_float_reg_op(coff, ScalarFloat::Double, false, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertIntReg64ToFloatReg32 { from, to } =>
+                add_sse_convert_int_reg_to_float_reg_op(coff, ScalarFloat::Single, true, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertIntReg64ToFloatReg64 { from, to } =>
+                add_sse_convert_int_reg_to_float_reg_op(coff, ScalarFloat::Double, true, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertFloatReg32ToIntReg32 { from, to } =>
+                add_sse_convert_float_reg_to_int_reg_op(coff, ScalarFloat::Single, false, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertFloatReg64ToIntReg32 { from, to } =>
+                add_sse_convert_float_reg_to_int_reg_op(coff, ScalarFloat::Double, false, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertFloatReg32ToIntReg64 { from, to } =>
+                add_sse_convert_float_reg_to_int_reg_op(coff, ScalarFloat::Single, true, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ConvertFloatReg64ToIntReg64 { from, to } =>
+                add_sse_convert_float_reg_to_int_reg_op(coff, ScalarFloat::Double, true, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveValueToRegPlusOffset8 { value, to, offset } =>
+                add_mov_byte_value_into_reg_plus_offset_pointer_op(coff, value, get_register(calling_convention, to)?, *offset),
+            ByteCodeInstruction::MoveValueToRegPlusOffset16 { value, to, offset } =>
+                add_mov_word_value_into_reg_plus_offset_pointer_op(coff, value, get_register(calling_convention, to)?, *offset),
+            ByteCodeInstruction::MoveRegToRegPlusOffset16 { from, to, offset } =>
+                add_mov_word_reg_to_reg_plus_offset_pointer_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?, *offset),
+            ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend8 { from, offset, to } =>
+                add_movsx_reg_plus_offset_pointer_to_reg_op(coff, 1, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend16 { from, offset, to } =>
+                add_movsx_reg_plus_offset_pointer_to_reg_op(coff, 2, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend32 { from, offset, to } =>
+                add_movsx_reg_plus_offset_pointer_to_reg_op(coff, 4, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend8 { from, offset, to } =>
+                add_movzx_reg_plus_offset_pointer_to_reg_op(coff, 1, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend16 { from, offset, to } =>
+                add_movzx_reg_plus_offset_pointer_to_reg_op(coff, 2, get_register(calling_convention, from)?, *offset, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SignExtendReg8ToReg64 { from, to } =>
+                add_movsx_reg_to_reg_op(coff, 1, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SignExtendReg16ToReg64 { from, to } =>
+                add_movsx_reg_to_reg_op(coff, 2, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SignExtendReg32ToReg64 { from, to } =>
+                add_movsx_reg_to_reg_op(coff, 4, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ZeroExtendReg8ToReg64 { from, to } =>
+                add_movzx_reg_to_reg_op(coff, 1, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ZeroExtendReg16ToReg64 { from, to } =>
+                add_movzx_reg_to_reg_op(coff, 2, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::ZeroExtendReg32ToReg64 { from, to } =>
+                add_movzx_reg_to_reg_op(coff, 4, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::CompareRegToReg64 { lhs, rhs } =>
+                add_cmp_qword_reg_to_reg_op(coff, get_register(calling_convention, lhs)?, get_register(calling_convention, rhs)?),
+            ByteCodeInstruction::AddRegToReg64 { from, to } =>
+                add_add_qword_reg_to_reg_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::SubRegFromReg64 { from, to } =>
+                add_sub_qword_reg_from_reg_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::MulRegToReg64 { from, to } =>
+                add_imul_qword_reg_to_reg_op(coff, get_register(calling_convention, from)?, get_register(calling_convention, to)?),
+            ByteCodeInstruction::TrapOnCondition(condition) =>
+                add_trap_on_condition_op(coff, get_condition(condition)),
+            ByteCodeInstruction::Label(label) => {
+                labels.insert(label, get_text_section(coff).len() as u32);
+            },
+            ByteCodeInstruction::Jump(label) =>
+                jumps.push((add_jump_op(coff), label)),
+            ByteCodeInstruction::JumpOnCondition { condition, label } =>
+                jumps.push((add_jump_on_condition_op(coff, get_condition(condition)), label)),
+            ByteCodeInstruction::SystemCall => add_syscall_op(coff),
         }
     }
 
-    for data_item in ir.data.items {
+    set_jump_displacements(coff, &labels, &jumps)?;
+
+    for data_item in &ir.data.items {
         match data_item {
-            ByteCodeDataItem::String { value } => add_string_to_data_section(coff, &value),
-            ByteCodeDataItem::QuadWord { value } => add_quad_word_to_data_section(coff, &value),
-            ByteCodeDataItem::Pointer { value } => add_pointer_to_data_section_and_make_relocation(coff, &value),
+            ByteCodeDataItem::String { value } => add_string_to_data_section(coff, value),
+            ByteCodeDataItem::Byte { value } => add_byte_to_data_section(coff, value),
+            ByteCodeDataItem::Word { value } => add_word_to_data_section(coff, value),
+            ByteCodeDataItem::DoubleWord { value } => add_double_word_to_data_section(coff, value),
+            ByteCodeDataItem::QuadWord { value } => add_quad_word_to_data_section(coff, value),
+            ByteCodeDataItem::Pointer { value } => add_pointer_to_data_section_and_make_relocation(coff, &(value + unit.bases.data as u64)),
+            ByteCodeDataItem::SymbolPointer { symbol_index } => 
+                add_symbol_pointer_to_data_section_and_make_relocation(coff, get_unit_coff_symbol_index(unit, *symbol_index)),
+            ByteCodeDataItem::ReadOnlyPointer { value } =>
+                add_read_only_data_pointer_to_data_section_and_make_relocation(coff, &(value + unit.bases.read_only_data as u64)),
         };
     }
 
-    add_debug_file_name_symbols(coff, &ir.filename);
-    add_data_section_header_symbols(coff);
-    add_text_section_header_symbols(coff);
-    add_absolute_static_symbol(coff, ".absolut", 0);
-    
-    for symbol_index in (0..ir.symbols.len()).rev() {
-        match &ir.symbols[symbol_index] {
-            ByteCodeSymbol::DataSectionItem { name, value } => add_data_section_static_symbol(coff, name, *value),
-            ByteCodeSymbol::ForeignExternal { name } => add_foreign_external_symbol(coff, name),
-            ByteCodeSymbol::AbsoluteExternal32 { name, value } => add_absolute_external_symbol_32(coff, name, *value),
-            ByteCodeSymbol::AbsoluteExternal64 { .. } => return Err(todo_error("64 bit absolute external symbol insertion")),
-            ByteCodeSymbol::ExternalCodeLabel { name, position } => add_text_section_external_symbol(coff, name, *position),
-        }
+    for data_item in &ir.read_only_data.items {
+        match data_item {
+            ByteCodeDataItem::String { value } => add_bytes_to_read_only_data_section(coff, value),
+            ByteCodeDataItem::Byte { value } => add_bytes_to_read_only_data_section(coff, &[*value]),
+            ByteCodeDataItem::Word { value } => add_bytes_to_read_only_data_section(coff, &value.to_le_bytes()),
+            ByteCodeDataItem::DoubleWord { value } => add_bytes_to_read_only_data_section(coff, &value.to_le_bytes()),
+            ByteCodeDataItem::QuadWord { value } => add_bytes_to_read_only_data_section(coff, &value.to_le_bytes()),
+            _ => return Err(unsupported_instruction_error())
+        };
     }
 
-    let file_root = ir.filename.replace(".hep", "");
-    Ok(format!("{}-{}.obj", file_root, ir.top_level_symbol))
+    Ok(())
+}
+
+fn set_jump_displacements(coff: &mut Coff, labels: &HashMap<ByteCodeLabel, u32>, jumps: &[(u32, ByteCodeLabel)]) -> BackendErrorResult {
+    for (rel32_offset, label) in jumps {
+        if let Some(label_offset) = labels.get(label) {
+            let displacement = *label_offset as i32 - (*rel32_offset + 4) as i32;
+            set_double_word_in_text_section(coff, *rel32_offset, displacement as u32);
+        } else {
+            return Err(label_not_found_error(**label));
+        }
+    }
+    Ok(())
+}
 
+fn convert_byte_code_to_coff_symbol_index(number_of_symbols: usize, symbol_index: usize) -> u32 {
+    (10 + number_of_symbols - symbol_index) as u32
 }
 
-fn convert_byte_code_to_coff_symbol_index(number_of_symbols: usize, symbol_index: SymbolIndex) -> u32 {
-    (6 + number_of_symbols) as u32 - *symbol_index
+fn convert_coff_to_byte_code_symbol_index(number_of_symbols: usize, coff_symbol_index: u32) -> usize {
+    10 + number_of_symbols - coff_symbol_index as usize
 }
 
 type ResgisterResult = Result<u8, BackendError>;
 
-fn get_register(register: ByteCodeRegister) -> ResgisterResult {
+fn get_register(calling_convention: CallingConvention, register: ByteCodeRegister) -> ResgisterResult {
     match register {
         ByteCodeRegister::Standard(number) => match number {
             0 => Ok(REG_AX),
@@ -136,26 +594,55 @@ fn get_register(register: ByteCodeRegister) -> ResgisterResult {
             8 => Ok(REG_R13),
             9 => Ok(REG_R14),
             10 => Ok(REG_R15),
+            11 => Ok(REG_SI),
+            12 => Ok(REG_DI),
             r => Err(register_not_available_error(r))
         },
-        ByteCodeRegister::CallArg(number) => match number {
-            0 => Ok(REG_CX),
-            1 => Ok(REG_DX),
-            2 => Ok(REG_R8),
-            3 => Ok(REG_R9),
-            4 => Ok(REG_R10),
-            5 => Ok(REG_R11),
-            6 => Ok(REG_R12),
-            7 => Ok(REG_R13),
-            8 => Ok(REG_R14),
-            9 => Ok(REG_R15),
-            r => Err(register_not_available_error(r))
+        ByteCodeRegister::CallArg(number) => match get_call_arg_standard_register(calling_convention, number) {
+            Some(register) => get_register(calling_convention, register),
+            None => Err(register_not_available_error(number))
         },
         ByteCodeRegister::CallReturnArg(number) => match number {
             0 => Ok(REG_AX),
             _ => Err(todo_error("more call return arg registers"))
         },
         ByteCodeRegister::StackPointer => Ok(REG_SP),
-        ByteCodeRegister::BasePointer => Ok(REG_BP)
+        ByteCodeRegister::BasePointer => Ok(REG_BP),
+        ByteCodeRegister::FloatStandard(number) => match number {
+            0 => Ok(REG_XMM4),
+            1 => Ok(REG_XMM5),
+            r => Err(register_not_available_error(r))
+        },
+        ByteCodeRegister::FloatCallArg(number) if is_float_call_arg_available(calling_convention, number) => match number {
+            0 => Ok(REG_XMM0),
+            1 => Ok(REG_XMM1),
+            2 => Ok(REG_XMM2),
+            3 => Ok(REG_XMM3),
+            4 => Ok(REG_XMM4),
+            5 => Ok(REG_XMM5),
+            6 => Ok(REG_XMM6),
+            7 => Ok(REG_XMM7),
+            r => Err(register_not_available_error(r))
+        },
+        ByteCodeRegister::FloatCallArg(number) => Err(register_not_available_error(number)),
+        ByteCodeRegister::FloatCallReturnArg(number) => match number {
+            0 => Ok(REG_XMM0),
+            r => Err(register_not_available_error(r))
+        }
+    }
+}
+
+fn get_condition(condition: ByteCodeCondition) -> u8 {
+    match condition {
+        ByteCodeCondition::Equal => CONDITION_EQUAL,
+        ByteCodeCondition::NotEqual => CONDITION_NOT_EQUAL,
+        ByteCodeCondition::Below => CONDITION_BELOW,
+        ByteCodeCondition::BelowOrEqual => CONDITION_BELOW_OR_EQUAL,
+        ByteCodeCondition::Above => CONDITION_ABOVE,
+        ByteCodeCondition::AboveOrEqual => CONDITION_ABOVE_OR_EQUAL,
+        ByteCodeCondition::Less => CONDITION_LESS,
+        ByteCodeCondition::LessOrEqual => CONDITION_LESS_OR_EQUAL,
+        ByteCodeCondition::Greater => CONDITION_GREATER,
+        ByteCodeCondition::GreaterOrEqual => CONDITION_GREATER_OR_EQUAL
     }
 }
\ No newline at end of file
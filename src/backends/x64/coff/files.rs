@@ -12,6 +12,8 @@ pub fn write_coff_to_file(coff: &Coff, file: &mut File) -> io::Result<()> {
     file.write_all(any_as_u8_slice(&coff.header))?;
     file.write_all(any_as_u8_slice(&coff.data_section_header))?;
     file.write_all(any_as_u8_slice(&coff.text_section_header))?;
+    file.write_all(any_as_u8_slice(&coff.bss_section_header))?;
+    file.write_all(any_as_u8_slice(&coff.read_only_data_section_header))?;
     file.write_all(&coff.data_section)?;
     for relocation in &coff.data_section_relocations {
         file.write_all(any_as_u8_slice(relocation))?;
@@ -20,6 +22,7 @@ pub fn write_coff_to_file(coff: &Coff, file: &mut File) -> io::Result<()> {
     for relocation in &coff.text_section_relocations {
         file.write_all(any_as_u8_slice(relocation))?;
     }
+    file.write_all(&coff.read_only_data_section)?;
     for symbol in &coff.symbols {
         file.write_all(any_as_u8_slice(symbol))?;
     }
@@ -3,7 +3,7 @@ use crate::backends::*;
 use crate::utilities::*;
 
 pub fn initial_base_dynamic_data_pointer() -> u32 {
-    (size_of::<CoffHeader>() + (size_of::<CoffSectionHeader>() * 2)) as u32
+    (size_of::<CoffHeader>() + (size_of::<CoffSectionHeader>() * 4)) as u32
 }
 
 pub fn header(
@@ -15,6 +15,7 @@ use crate::utilities::*;
 const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
 
 const IMAGE_SCN_CNT_INITIALISED_DATA: u32 = 0x00000040;
+const IMAGE_SCN_CNT_UNINITIALISED_DATA: u32 = 0x00000080;
 const IMAGE_SCN_CNT_CODE: u32 = 0x00000020;
 const IMAGE_SCN_ALIGN_4BYTES: u32 = 0x00300000;
 const IMAGE_SCN_ALIGN_16BYTES: u32 = 0x00500000;
@@ -26,10 +27,13 @@ pub struct Coff {
     header: CoffHeader,
     data_section_header: CoffSectionHeader,
     text_section_header: CoffSectionHeader,
+    bss_section_header: CoffSectionHeader,
+    read_only_data_section_header: CoffSectionHeader,
     data_section: Vec<u8>,
     data_section_relocations: Vec<CoffRelocationEntry>,
     text_section: Vec<u8>,
     text_section_relocations: Vec<CoffRelocationEntry>,
+    read_only_data_section: Vec<u8>,
     symbols: Vec<CoffSymbol>,    
     strings_table_length: u32,
     strings: Vec<u8>
@@ -125,7 +129,7 @@ pub fn create_coff() -> Coff {
     Coff {
         header : header( 
             IMAGE_FILE_MACHINE_AMD64,
-            2,
+            4,
             get_current_timestamp(), 
             initial_base_dynamic_data_pointer(),
             0,
@@ -156,10 +160,35 @@ pub fn create_coff() -> Coff {
             0,
             IMAGE_SCN_CNT_CODE | IMAGE_SCN_ALIGN_16BYTES | IMAGE_SCN_MEM_EXECUTE | IMAGE_SCN_MEM_READ,
         ),
+        bss_section_header: section_header(
+            ".bss",
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            IMAGE_SCN_CNT_UNINITIALISED_DATA | IMAGE_SCN_ALIGN_16BYTES | IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_WRITE
+        ),
+        read_only_data_section_header: section_header(
+            ".rdata",
+            0,
+            0,
+            0,
+            initial_base_dynamic_data_pointer(),
+            0,
+            0,
+            0,
+            0,
+            IMAGE_SCN_CNT_INITIALISED_DATA | IMAGE_SCN_ALIGN_16BYTES | IMAGE_SCN_MEM_READ
+        ),
         data_section: vec!(),
         data_section_relocations: vec!(),
         text_section: vec!(),
         text_section_relocations: vec!(),
+        read_only_data_section: vec!(),
         symbols: vec!(),
         strings_table_length: 0x4,
         strings: vec!()
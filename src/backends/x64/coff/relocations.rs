@@ -31,6 +31,7 @@ pub fn relocatable_value(symbol_index: u32, initial_value_to_use: u32) -> Reloca
 pub fn add_text_section_relocation_entry(coff: &mut Coff, entry: CoffRelocationEntry) {
     coff.text_section_relocations.push(entry);
     coff.text_section_header.number_of_relocations += 1;    
+    coff.read_only_data_section_header.pointer_to_section += size_of::<CoffRelocationEntry>() as u32;
     coff.header.pointer_to_symbol_table += size_of::<CoffRelocationEntry>() as u32;
     set_current_timestamp(coff);
 }
@@ -40,6 +41,7 @@ pub fn add_data_section_relocation_entry(coff: &mut Coff, entry: CoffRelocationE
     coff.data_section_header.number_of_relocations += 1;
     coff.text_section_header.pointer_to_section += size_of::<CoffRelocationEntry>() as u32;
     coff.text_section_header.pointer_to_relocations += size_of::<CoffRelocationEntry>() as u32;
+    coff.read_only_data_section_header.pointer_to_section += size_of::<CoffRelocationEntry>() as u32;
     coff.header.pointer_to_symbol_table += size_of::<CoffRelocationEntry>() as u32;
     set_current_timestamp(coff);
 }
@@ -54,4 +56,15 @@ pub fn add_relocatable_entry_and_text_section_inital_entry(coff: &mut Coff, relo
         )
     );
     add_entries_to_text_section(coff, u32_to_bytes(&relocatable_value.initial_value_to_use));
-}
\ No newline at end of file
+}
+pub fn get_text_section_relocation_entries(coff: &Coff) -> &[CoffRelocationEntry] {
+    &coff.text_section_relocations
+}
+
+pub fn get_data_section_relocation_entries(coff: &Coff) -> &[CoffRelocationEntry] {
+    &coff.data_section_relocations
+}
+
+pub fn get_relocation_entry_parts(entry: &CoffRelocationEntry) -> (u32, u32, u16) {
+    (entry.pointer_to_reference, entry.symbol_index, entry.relocation_type)
+}
@@ -7,6 +7,7 @@ fn advance_data_section(coff: &mut Coff, amount: u32) {
     coff.data_section_header.pointer_to_relocations += amount;
     coff.text_section_header.pointer_to_section += amount;
     coff.text_section_header.pointer_to_relocations += amount;
+    coff.read_only_data_section_header.pointer_to_section += amount;
     coff.header.pointer_to_symbol_table += amount;
     set_current_timestamp(coff);
 }
@@ -19,6 +20,14 @@ pub fn add_string_to_data_section(coff: &mut Coff, to_add: &ByteString) -> u32 {
     pointer
 }
 
+pub fn add_double_word_to_data_section(coff: &mut Coff, to_add: &u32) -> u32 {
+    let pointer = coff.data_section_header.size_of_section; 
+    let mut bytes = u32_to_bytes(to_add);
+    advance_data_section(coff, bytes.len() as u32);
+    coff.data_section.append(&mut bytes);
+    pointer
+}
+
 pub fn add_quad_word_to_data_section(coff: &mut Coff, to_add: &u64) -> u32 {
     let pointer = coff.data_section_header.size_of_section; 
     let mut bytes = u64_to_bytes(to_add);
@@ -33,14 +42,56 @@ pub fn add_pointer_to_data_section_and_make_relocation(coff: &mut Coff, to_add:
     pointer
 }
 
+pub fn add_symbol_pointer_to_data_section_and_make_relocation(coff: &mut Coff, symbol_index: u32) -> u32 {
+    let pointer = add_quad_word_to_data_section(coff, &0);
+    add_data_section_relocation_entry(coff, relocation_entry(pointer, symbol_index, IMAGE_REL_AMD64_ADDR64));
+    pointer
+}
+
+pub fn add_read_only_data_pointer_to_data_section_and_make_relocation(coff: &mut Coff, to_add: &u64) -> u32 {
+    let pointer = add_quad_word_to_data_section(coff, to_add);
+    add_data_section_relocation_entry(coff, relocation_entry(pointer, 8, IMAGE_REL_AMD64_ADDR64));
+    pointer
+}
+
 fn add_relocation_for_data_pointer(coff: &mut Coff, pointer: u32) {
     add_data_section_relocation_entry(coff, relocation_entry(pointer, 2, IMAGE_REL_AMD64_ADDR64));
 }
 
+pub fn add_byte_to_data_section(coff: &mut Coff, to_add: &u8) -> u32 {
+    let pointer = coff.data_section_header.size_of_section; 
+    advance_data_section(coff, 1);
+    coff.data_section.push(*to_add);
+    pointer
+}
+
+pub fn add_word_to_data_section(coff: &mut Coff, to_add: &u16) -> u32 {
+    let pointer = coff.data_section_header.size_of_section; 
+    let mut bytes = to_add.to_le_bytes().to_vec();
+    advance_data_section(coff, bytes.len() as u32);
+    coff.data_section.append(&mut bytes);
+    pointer
+}
+
+pub fn add_bytes_to_read_only_data_section(coff: &mut Coff, to_add: &[u8]) -> u32 {
+    let pointer = coff.read_only_data_section_header.size_of_section;
+    coff.read_only_data_section_header.size_of_section += to_add.len() as u32;
+    coff.header.pointer_to_symbol_table += to_add.len() as u32;
+    coff.read_only_data_section.extend_from_slice(to_add);
+    set_current_timestamp(coff);
+    pointer
+}
+
+pub fn set_bss_section_size(coff: &mut Coff, size: u32) {
+    coff.bss_section_header.size_of_section = size;
+    set_current_timestamp(coff);
+}
+
 pub fn add_entry_to_text_section(coff: &mut Coff, entry: u8) {
     coff.text_section.push(entry);
     coff.text_section_header.size_of_section += 1;
     coff.text_section_header.pointer_to_relocations += 1;
+    coff.read_only_data_section_header.pointer_to_section += 1;
     coff.header.pointer_to_symbol_table += 1;
     set_current_timestamp(coff);
 }
@@ -49,4 +100,24 @@ pub fn add_entries_to_text_section(coff: &mut Coff, entries: Vec<u8>) {
     for entry in entries {
         add_entry_to_text_section(coff, entry);
     }
-}
\ No newline at end of file
+}
+pub fn set_double_word_in_text_section(coff: &mut Coff, offset: u32, value: u32) {
+    let offset = offset as usize;
+    coff.text_section[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+pub fn get_text_section(coff: &Coff) -> &[u8] {
+    &coff.text_section
+}
+
+pub fn get_data_section(coff: &Coff) -> &[u8] {
+    &coff.data_section
+}
+
+pub fn get_read_only_data_section(coff: &Coff) -> &[u8] {
+    &coff.read_only_data_section
+}
+
+pub fn get_bss_section_size(coff: &Coff) -> u32 {
+    coff.bss_section_header.size_of_section
+}
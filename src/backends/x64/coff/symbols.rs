@@ -3,9 +3,14 @@ use crate::utilities::*;
 
 const IMAGE_SYM_DEBUG: u16 = 0xFFFE;
 const IMAGE_SYM_CLASS_FILE: u8 = 0x67;
-const IMAGE_SYM_CLASS_EXTERNAL: u8 = 0x02;
+pub const IMAGE_SYM_CLASS_EXTERNAL: u8 = 0x02;
 const IMAGE_SYM_CLASS_STATIC: u8 = 0x03;
-const IMAGE_SYM_ABSOLUTE: u16 = 0xFFFF;
+pub const IMAGE_SYM_ABSOLUTE: u16 = 0xFFFF;
+pub const IMAGE_SYM_UNDEFINED: u16 = 0;
+pub const COFF_SECTION_DATA: u16 = 1;
+pub const COFF_SECTION_TEXT: u16 = 2;
+pub const COFF_SECTION_BSS: u16 = 3;
+pub const COFF_SECTION_READ_ONLY_DATA: u16 = 4;
 
 fn short_named_symbol(
     name: &str,
@@ -129,13 +134,23 @@ fn add_section_symbols(coff: &mut Coff, section_name: &str, section_number: u16,
 pub fn add_data_section_header_symbols(coff: &mut Coff) {
     let section_size = coff.data_section_header.size_of_section;
     let number_of_relocations = coff.data_section_header.number_of_relocations;
-    add_section_symbols(coff, ".data", 1, section_size, number_of_relocations);
+    add_section_symbols(coff, ".data", COFF_SECTION_DATA, section_size, number_of_relocations);
 }
 
 pub fn add_text_section_header_symbols(coff: &mut Coff) {
     let section_size = coff.text_section_header.size_of_section;
     let number_of_relocations = coff.text_section_header.number_of_relocations;
-    add_section_symbols(coff, ".text", 2, section_size, number_of_relocations);
+    add_section_symbols(coff, ".text", COFF_SECTION_TEXT, section_size, number_of_relocations);
+}
+
+pub fn add_bss_section_header_symbols(coff: &mut Coff) {
+    let section_size = coff.bss_section_header.size_of_section;
+    add_section_symbols(coff, ".bss", COFF_SECTION_BSS, section_size, 0);
+}
+
+pub fn add_read_only_data_section_header_symbols(coff: &mut Coff) {
+    let section_size = coff.read_only_data_section_header.size_of_section;
+    add_section_symbols(coff, ".rdata", COFF_SECTION_READ_ONLY_DATA, section_size, 0);
 }
 
 pub fn add_absolute_static_symbol(coff: &mut Coff, name: &str, value: u32) {
@@ -147,15 +162,23 @@ pub fn add_absolute_external_symbol_32(coff: &mut Coff, name: &str, value: u32)
 }
 
 pub fn add_data_section_static_symbol(coff: &mut Coff, name: &str, value: u32) {
-    add_static_symbol(coff, name, value, 1);
+    add_static_symbol(coff, name, value, COFF_SECTION_DATA);
+}
+
+pub fn add_data_section_external_symbol(coff: &mut Coff, name: &str, value: u32) {
+    add_external_symbol(coff, name, value, COFF_SECTION_DATA);
+}
+
+pub fn add_bss_section_external_symbol(coff: &mut Coff, name: &str, value: u32) {
+    add_external_symbol(coff, name, value, COFF_SECTION_BSS);
 }
 
 pub fn add_foreign_external_symbol(coff: &mut Coff, name: &str) {
-    add_external_symbol(coff, name, 0, 0);
+    add_external_symbol(coff, name, 0, IMAGE_SYM_UNDEFINED);
 }
 
 pub fn add_text_section_external_symbol(coff: &mut Coff, name: &str, value: u32) {
-    add_external_symbol(coff, name, value, 2);
+    add_external_symbol(coff, name, value, COFF_SECTION_TEXT);
 }
 
 fn add_static_symbol(coff: &mut Coff, name: &str, value: u32, section_number: u16) {
@@ -164,4 +187,69 @@ fn add_static_symbol(coff: &mut Coff, name: &str, value: u32, section_number: u1
 
 fn add_external_symbol(coff: &mut Coff, name: &str, value: u32, section_number: u16) {
     add_named_symbol(coff, name, value, section_number, 0, IMAGE_SYM_CLASS_EXTERNAL, 0);
-}
\ No newline at end of file
+}
+
+pub struct CoffSymbolDefinition {
+    pub name: String,
+    pub value: u32,
+    pub section_number: u16,
+    pub storage_class: u8
+}
+
+pub fn get_coff_symbol_definition(coff: &Coff, index: u32) -> CoffSymbolDefinition {
+    let symbol = unsafe { coff.symbols[index as usize].short_named };
+    let name = if symbol.name[..4] == [0; 4] {
+        let long_named = unsafe { coff.symbols[index as usize].long_named };
+        get_string(coff, long_named.pointer_to_string_table)
+    } else {
+        let length = symbol.name.iter().position(|byte| *byte == 0).unwrap_or(symbol.name.len());
+        String::from_utf8_lossy(&symbol.name[..length]).to_string()
+    };
+
+    CoffSymbolDefinition {
+        name,
+        value: symbol.value,
+        section_number: symbol.section_number,
+        storage_class: symbol.storage_class
+    }
+}
+
+pub fn get_coff_symbol_definitions(coff: &Coff) -> Vec<(u32, CoffSymbolDefinition)> {
+    let mut definitions = vec!();
+    let mut index = 0;
+    while (index as usize) < coff.symbols.len() {
+        let number_of_auxillary_symbols = unsafe { coff.symbols[index as usize].short_named.number_of_auxillary_symbols as u32 };
+        definitions.push((index, get_coff_symbol_definition(coff, index)));
+        index += 1 + number_of_auxillary_symbols;
+    }
+    definitions
+}
+
+pub fn get_coff_external_symbol_containing(coff: &Coff, section_number: u16, offset: u32) -> Option<String> {
+    get_coff_symbol_definitions(coff)
+        .into_iter()
+        .map(|(_, definition)| definition)
+        .filter(|definition| definition.storage_class == IMAGE_SYM_CLASS_EXTERNAL && definition.section_number == section_number && definition.value <= offset)
+        .max_by_key(|definition| definition.value)
+        .map(|definition| definition.name)
+}
+
+pub fn get_coff_file_name(coff: &Coff) -> String {
+    let file_symbol = get_coff_symbol_definitions(coff)
+        .into_iter()
+        .find(|(_, definition)| definition.storage_class == IMAGE_SYM_CLASS_FILE);
+    match file_symbol {
+        Some((index, _)) => {
+            let name = unsafe { coff.symbols[index as usize + 1].name };
+            let length = name.0.iter().position(|byte| *byte == 0).unwrap_or(name.0.len());
+            String::from_utf8_lossy(&name.0[..length]).to_string()
+        },
+        None => empty_string()
+    }
+}
+
+fn get_string(coff: &Coff, pointer: u32) -> String {
+    let start = (pointer - 4) as usize;
+    let length = coff.strings[start..].iter().position(|byte| *byte == 0).unwrap_or(coff.strings.len() - start);
+    String::from_utf8_lossy(&coff.strings[start..start + length]).to_string()
+}
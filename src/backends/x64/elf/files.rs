@@ -0,0 +1,171 @@
+use std::*;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::mem::size_of;
+use crate::utilities::*;
+
+use super::*;
+
+const SECTION_NAMES: [&str; ELF_NUMBER_OF_SECTIONS as usize] = [
+    "", ".text", ".rela.text", ".data", ".rela.data", ".rodata", ".bss", ".symtab", ".strtab", ".shstrtab"
+];
+
+const EXECUTABLE_SECTION_NAMES: [&str; 6] = ["", ".text", ".rodata", ".data", ".bss", ".shstrtab"];
+
+pub fn create_elf_file(name: &str) -> io::Result<File> {
+    File::create(name)
+}
+
+pub fn write_elf_to_file(elf: &Elf, file: &mut File) -> io::Result<()> {
+    file.write_all(&elf_to_bytes(elf))?;
+    file.flush()?;
+    Ok(())
+}
+
+pub fn elf_to_bytes(elf: &Elf) -> Vec<u8> {
+    let (section_names, name_pointers) = get_section_names(&SECTION_NAMES);
+    let mut section_headers = [
+        elf_null_section_header(),
+        elf_text_section_header(name_pointers[ELF_SECTION_TEXT as usize]),
+        elf_relocations_section_header(name_pointers[ELF_SECTION_RELA_TEXT as usize], ELF_SECTION_TEXT),
+        elf_data_section_header(name_pointers[ELF_SECTION_DATA as usize]),
+        elf_relocations_section_header(name_pointers[ELF_SECTION_RELA_DATA as usize], ELF_SECTION_DATA),
+        elf_rodata_section_header(name_pointers[ELF_SECTION_RODATA as usize]),
+        elf_bss_section_header(name_pointers[ELF_SECTION_BSS as usize]),
+        elf_symbols_section_header(name_pointers[ELF_SECTION_SYMTAB as usize], elf.local_symbols.len() as u32),
+        elf_strings_section_header(name_pointers[ELF_SECTION_STRTAB as usize]),
+        elf_strings_section_header(name_pointers[ELF_SECTION_SHSTRTAB as usize])
+    ];
+
+    let mut bytes = vec!(0; size_of::<ElfHeader>());
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_TEXT as usize], &elf.text_section);
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_RELA_TEXT as usize], &relocations_to_bytes(elf, &elf.text_section_relocations));
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_DATA as usize], &elf.data_section);
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_RELA_DATA as usize], &relocations_to_bytes(elf, &elf.data_section_relocations));
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_RODATA as usize], &elf.rodata_section);
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_BSS as usize], &[]);
+    section_headers[ELF_SECTION_BSS as usize].size = elf.bss_section_size;
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_SYMTAB as usize], &symbols_to_bytes(elf));
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_STRTAB as usize], &elf.strings);
+    append_section(&mut bytes, &mut section_headers[ELF_SECTION_SHSTRTAB as usize], &section_names);
+
+    align_bytes(&mut bytes, 8);
+    let section_header_offset = bytes.len() as u64;
+    for section_header in &section_headers {
+        bytes.extend_from_slice(any_as_u8_slice(section_header));
+    }
+
+    let header = elf_header(section_header_offset);
+    bytes[..size_of::<ElfHeader>()].copy_from_slice(any_as_u8_slice(&header));
+    bytes
+}
+
+pub fn create_elf_executable_file(name: &str) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o755);
+    options.open(name)
+}
+
+pub fn write_elf_executable_to_file(executable: &ElfExecutable, file: &mut File) -> io::Result<()> {
+    file.write_all(&elf_executable_to_bytes(executable))?;
+    file.flush()?;
+    Ok(())
+}
+
+pub fn elf_executable_to_bytes(executable: &ElfExecutable) -> Vec<u8> {
+    let mut program_headers = vec!(elf_text_program_header(executable.text_address, executable.text_section.len() as u64));
+    if !executable.rodata_section.is_empty() {
+        program_headers.push(elf_rodata_program_header(executable.rodata_address, executable.rodata_section.len() as u64));
+    }
+    let data_memory_size = executable.bss_address + executable.bss_section_size - executable.data_address;
+    if data_memory_size > 0 {
+        program_headers.push(elf_data_program_header(executable.data_address, executable.data_section.len() as u64, data_memory_size));
+    }
+    program_headers.push(elf_stack_program_header());
+
+    let mut bytes = vec!(0; size_of::<ElfHeader>());
+    for program_header in &program_headers {
+        bytes.extend_from_slice(any_as_u8_slice(program_header));
+    }
+    append_executable_section(&mut bytes, executable.text_address, &executable.text_section);
+    append_executable_section(&mut bytes, executable.rodata_address, &executable.rodata_section);
+    append_executable_section(&mut bytes, executable.data_address, &executable.data_section);
+
+    let (section_names, name_pointers) = get_section_names(&EXECUTABLE_SECTION_NAMES);
+    let section_names_offset = bytes.len() as u64;
+    bytes.extend_from_slice(&section_names);
+
+    let mut section_names_header = elf_strings_section_header(name_pointers[5]);
+    section_names_header.offset = section_names_offset;
+    section_names_header.size = section_names.len() as u64;
+    let mut section_headers = vec!(
+        elf_null_section_header(),
+        elf_executable_section_header(elf_text_section_header(name_pointers[1]), executable.text_address, executable.text_section.len() as u64)
+    );
+    if !executable.rodata_section.is_empty() {
+        section_headers.push(elf_executable_section_header(elf_rodata_section_header(name_pointers[2]), executable.rodata_address, executable.rodata_section.len() as u64));
+    }
+    section_headers.push(elf_executable_section_header(elf_data_section_header(name_pointers[3]), executable.data_address, executable.data_section.len() as u64));
+    section_headers.push(elf_executable_section_header(elf_bss_section_header(name_pointers[4]), executable.bss_address, executable.bss_section_size));
+    section_headers.push(section_names_header);
+
+    align_bytes(&mut bytes, 8);
+    let section_header_offset = bytes.len() as u64;
+    for section_header in &section_headers {
+        bytes.extend_from_slice(any_as_u8_slice(section_header));
+    }
+
+    let header = elf_executable_header(executable.entry, program_headers.len() as u16, section_header_offset, section_headers.len() as u16);
+    bytes[..size_of::<ElfHeader>()].copy_from_slice(any_as_u8_slice(&header));
+    bytes
+}
+
+fn append_executable_section(bytes: &mut Vec<u8>, address: u64, contents: &[u8]) {
+    if !contents.is_empty() {
+        bytes.resize((address - ELF_EXECUTABLE_BASE_ADDRESS) as usize, 0);
+        bytes.extend_from_slice(contents);
+    }
+}
+
+fn get_section_names(names: &[&str]) -> (Vec<u8>, Vec<u32>) {
+    let mut section_names = vec!();
+    let mut name_pointers = vec!();
+    for name in names {
+        name_pointers.push(section_names.len() as u32);
+        section_names.append(&mut string_to_bytes_zero_terminated(name));
+    }
+    (section_names, name_pointers)
+}
+
+fn append_section(bytes: &mut Vec<u8>, section_header: &mut ElfSectionHeader, contents: &[u8]) {
+    let alignment = section_header.address_alignment;
+    align_bytes(bytes, alignment);
+    section_header.offset = bytes.len() as u64;
+    section_header.size = contents.len() as u64;
+    bytes.extend_from_slice(contents);
+}
+
+fn align_bytes(bytes: &mut Vec<u8>, alignment: u64) {
+    if alignment > 1 {
+        let alignment = alignment as usize;
+        bytes.resize(bytes.len().div_ceil(alignment) * alignment, 0);
+    }
+}
+
+fn relocations_to_bytes(elf: &Elf, relocations: &[ElfRelocation]) -> Vec<u8> {
+    let mut bytes = vec!();
+    for relocation in relocations {
+        bytes.extend_from_slice(any_as_u8_slice(&relocation_entry_for_elf(elf, relocation)));
+    }
+    bytes
+}
+
+fn symbols_to_bytes(elf: &Elf) -> Vec<u8> {
+    let mut bytes = vec!();
+    for symbol in elf.local_symbols.iter().chain(elf.global_symbols.iter()) {
+        bytes.extend_from_slice(any_as_u8_slice(symbol));
+    }
+    bytes
+}
@@ -0,0 +1,146 @@
+use std::mem::size_of;
+
+use super::*;
+
+pub fn elf_header(section_header_offset: u64) -> ElfHeader {
+    ElfHeader {
+        identification: [
+            0x7F, b'E', b'L', b'F',
+            ELF_CLASS_64,
+            ELF_DATA_LITTLE_ENDIAN,
+            ELF_VERSION_CURRENT,
+            ELF_OS_ABI_SYSTEM_V,
+            0, 0, 0, 0, 0, 0, 0, 0
+        ],
+        file_type: ELF_TYPE_RELOCATABLE,
+        machine: ELF_MACHINE_X86_64,
+        version: ELF_VERSION_CURRENT as u32,
+        entry: 0,
+        program_header_offset: 0,
+        section_header_offset,
+        flags: 0,
+        header_size: size_of::<ElfHeader>() as u16,
+        program_header_entry_size: 0,
+        number_of_program_headers: 0,
+        section_header_entry_size: size_of::<ElfSectionHeader>() as u16,
+        number_of_section_headers: ELF_NUMBER_OF_SECTIONS,
+        section_names_section_index: ELF_SECTION_SHSTRTAB
+    }
+}
+
+pub fn elf_executable_header(entry: u64, number_of_program_headers: u16, section_header_offset: u64, number_of_section_headers: u16) -> ElfHeader {
+    ElfHeader {
+        file_type: ELF_TYPE_EXECUTABLE,
+        entry,
+        program_header_offset: size_of::<ElfHeader>() as u64,
+        program_header_entry_size: size_of::<ElfProgramHeader>() as u16,
+        number_of_program_headers,
+        number_of_section_headers,
+        section_names_section_index: number_of_section_headers - 1,
+        ..elf_header(section_header_offset)
+    }
+}
+
+pub fn elf_load_program_header(flags: u32, virtual_address: u64, file_size: u64, memory_size: u64) -> ElfProgramHeader {
+    ElfProgramHeader {
+        segment_type: PT_LOAD,
+        flags,
+        offset: virtual_address - ELF_EXECUTABLE_BASE_ADDRESS,
+        virtual_address,
+        physical_address: virtual_address,
+        file_size,
+        memory_size,
+        alignment: ELF_PAGE_SIZE
+    }
+}
+
+pub fn elf_stack_program_header() -> ElfProgramHeader {
+    ElfProgramHeader {
+        segment_type: PT_GNU_STACK,
+        flags: PF_R | PF_W,
+        offset: 0,
+        virtual_address: 0,
+        physical_address: 0,
+        file_size: 0,
+        memory_size: 0,
+        alignment: 16
+    }
+}
+
+pub fn elf_text_program_header(virtual_address: u64, size: u64) -> ElfProgramHeader {
+    elf_load_program_header(PF_R | PF_X, virtual_address, size, size)
+}
+
+pub fn elf_rodata_program_header(virtual_address: u64, size: u64) -> ElfProgramHeader {
+    elf_load_program_header(PF_R, virtual_address, size, size)
+}
+
+pub fn elf_data_program_header(virtual_address: u64, file_size: u64, memory_size: u64) -> ElfProgramHeader {
+    elf_load_program_header(PF_R | PF_W, virtual_address, file_size, memory_size)
+}
+
+pub fn elf_null_section_header() -> ElfSectionHeader {
+    elf_section_header(0, 0, 0, 0)
+}
+
+fn elf_section_header(name: u32, section_type: u32, flags: u64, address_alignment: u64) -> ElfSectionHeader {
+    ElfSectionHeader {
+        name,
+        section_type,
+        flags,
+        address: 0,
+        offset: 0,
+        size: 0,
+        link: 0,
+        info: 0,
+        address_alignment,
+        entry_size: 0
+    }
+}
+
+pub fn elf_text_section_header(name: u32) -> ElfSectionHeader {
+    elf_section_header(name, SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR, 16)
+}
+
+pub fn elf_data_section_header(name: u32) -> ElfSectionHeader {
+    elf_section_header(name, SHT_PROGBITS, SHF_ALLOC | SHF_WRITE, 16)
+}
+
+pub fn elf_rodata_section_header(name: u32) -> ElfSectionHeader {
+    elf_section_header(name, SHT_PROGBITS, SHF_ALLOC, 16)
+}
+
+pub fn elf_bss_section_header(name: u32) -> ElfSectionHeader {
+    elf_section_header(name, SHT_NOBITS, SHF_ALLOC | SHF_WRITE, 16)
+}
+
+pub fn elf_relocations_section_header(name: u32, relocated_section: u16) -> ElfSectionHeader {
+    ElfSectionHeader {
+        link: ELF_SECTION_SYMTAB as u32,
+        info: relocated_section as u32,
+        entry_size: size_of::<ElfRelocationEntry>() as u64,
+        ..elf_section_header(name, SHT_RELA, SHF_INFO_LINK, 8)
+    }
+}
+
+pub fn elf_symbols_section_header(name: u32, first_global_symbol: u32) -> ElfSectionHeader {
+    ElfSectionHeader {
+        link: ELF_SECTION_STRTAB as u32,
+        info: first_global_symbol,
+        entry_size: size_of::<ElfSymbol>() as u64,
+        ..elf_section_header(name, SHT_SYMTAB, 0, 8)
+    }
+}
+
+pub fn elf_strings_section_header(name: u32) -> ElfSectionHeader {
+    elf_section_header(name, SHT_STRTAB, 0, 1)
+}
+
+pub fn elf_executable_section_header(section: ElfSectionHeader, address: u64, size: u64) -> ElfSectionHeader {
+    ElfSectionHeader {
+        address,
+        offset: address - ELF_EXECUTABLE_BASE_ADDRESS,
+        size,
+        ..section
+    }
+}
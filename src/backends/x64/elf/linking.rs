@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use crate::backends::*;
+
+use super::*;
+
+const ELF_OBJECT_SECTION_ALIGNMENT: u64 = 16;
+
+struct ElfObjectBases {
+    text: u64,
+    rodata: u64,
+    data: u64,
+    bss: u64
+}
+
+struct ElfLayout {
+    text: u64,
+    rodata: u64,
+    data: u64,
+    bss: u64,
+    object_bases: Vec<ElfObjectBases>
+}
+
+type ElfSymbolDefinitions = HashMap<String, (usize, ElfSymbolDefinition)>;
+
+pub fn link_elf_executable(objects: &[Elf], entry_point: &str) -> Result<ElfExecutable, BackendError> {
+    let definitions = get_elf_symbol_definitions(objects)?;
+
+    let mut text = vec!();
+    let mut rodata = vec!();
+    let mut data = vec!();
+    let mut bss_size: u64 = 0;
+    let mut object_bases = vec!();
+    for object in objects {
+        align_elf_section_contents(&mut text);
+        align_elf_section_contents(&mut rodata);
+        align_elf_section_contents(&mut data);
+        bss_size = bss_size.next_multiple_of(ELF_OBJECT_SECTION_ALIGNMENT);
+        object_bases.push(ElfObjectBases { text: text.len() as u64, rodata: rodata.len() as u64, data: data.len() as u64, bss: bss_size });
+        text.extend_from_slice(&object.text_section);
+        rodata.extend_from_slice(&object.rodata_section);
+        data.extend_from_slice(&object.data_section);
+        bss_size += object.bss_section_size;
+    }
+
+    let text_address = ELF_EXECUTABLE_BASE_ADDRESS + ELF_PAGE_SIZE;
+    let rodata_address = (text_address + text.len() as u64).next_multiple_of(ELF_PAGE_SIZE);
+    let data_address = (rodata_address + rodata.len() as u64).next_multiple_of(ELF_PAGE_SIZE);
+    let bss_address = (data_address + data.len() as u64).next_multiple_of(ELF_OBJECT_SECTION_ALIGNMENT);
+    let layout = ElfLayout { text: text_address, rodata: rodata_address, data: data_address, bss: bss_address, object_bases };
+
+    for (object_index, object) in objects.iter().enumerate() {
+        let bases = &layout.object_bases[object_index];
+        let text_base = bases.text as usize;
+        let data_base = bases.data as usize;
+        let text_section = &mut text[text_base..text_base + object.text_section.len()];
+        apply_elf_relocations(objects, object_index, text_section, ELF_SECTION_TEXT, &layout, &definitions)?;
+        let data_section = &mut data[data_base..data_base + object.data_section.len()];
+        apply_elf_relocations(objects, object_index, data_section, ELF_SECTION_DATA, &layout, &definitions)?;
+    }
+
+    let entry = match definitions.get(entry_point) {
+        Some((object_index, definition)) if definition.section_index == ELF_SECTION_TEXT =>
+            get_elf_defined_symbol_address(&layout, *object_index, definition),
+        _ => return Err(entry_point_not_found_error(entry_point))
+    };
+
+    Ok(ElfExecutable {
+        entry,
+        text_address,
+        text_section: text,
+        rodata_address,
+        rodata_section: rodata,
+        data_address,
+        data_section: data,
+        bss_address,
+        bss_section_size: bss_size
+    })
+}
+
+pub fn get_elf_executable_entry(executable: &ElfExecutable) -> u64 {
+    executable.entry
+}
+
+pub fn get_elf_executable_text_section(executable: &ElfExecutable) -> (u64, &[u8]) {
+    (executable.text_address, &executable.text_section)
+}
+
+pub fn get_elf_executable_rodata_section(executable: &ElfExecutable) -> (u64, &[u8]) {
+    (executable.rodata_address, &executable.rodata_section)
+}
+
+pub fn get_elf_executable_data_section(executable: &ElfExecutable) -> (u64, &[u8]) {
+    (executable.data_address, &executable.data_section)
+}
+
+fn align_elf_section_contents(contents: &mut Vec<u8>) {
+    contents.resize((contents.len() as u64).next_multiple_of(ELF_OBJECT_SECTION_ALIGNMENT) as usize, 0);
+}
+
+fn get_elf_symbol_definitions(objects: &[Elf]) -> Result<ElfSymbolDefinitions, BackendError> {
+    let mut definitions = HashMap::new();
+    for (object_index, object) in objects.iter().enumerate() {
+        for definition in get_elf_global_symbol_definitions(object) {
+            if definition.section_index == SHN_UNDEF {
+                continue;
+            }
+            if definitions.contains_key(&definition.name) {
+                return Err(duplicate_symbol_error(&definition.name, &get_elf_file_name(object)));
+            }
+            definitions.insert(definition.name.clone(), (object_index, definition));
+        }
+    }
+    Ok(definitions)
+}
+
+fn get_elf_defined_symbol_address(layout: &ElfLayout, object_index: usize, definition: &ElfSymbolDefinition) -> u64 {
+    let bases = &layout.object_bases[object_index];
+    match definition.section_index {
+        ELF_SECTION_TEXT => layout.text + bases.text + definition.value,
+        ELF_SECTION_RODATA => layout.rodata + bases.rodata + definition.value,
+        ELF_SECTION_DATA => layout.data + bases.data + definition.value,
+        ELF_SECTION_BSS => layout.bss + bases.bss + definition.value,
+        _ => definition.value
+    }
+}
+
+fn apply_elf_relocations(
+    objects: &[Elf],
+    object_index: usize,
+    section: &mut [u8],
+    section_index: u16,
+    layout: &ElfLayout,
+    definitions: &ElfSymbolDefinitions
+) -> BackendErrorResult {
+    let object = &objects[object_index];
+    let bases = &layout.object_bases[object_index];
+    let (section_address, relocations) = match section_index {
+        ELF_SECTION_TEXT => (layout.text + bases.text, &object.text_section_relocations),
+        _ => (layout.data + bases.data, &object.data_section_relocations)
+    };
+
+    for relocation in relocations {
+        let definition = get_elf_symbol_definition(object, relocation.symbol);
+        let symbol_address = if definition.section_index == SHN_UNDEF {
+            match definitions.get(&definition.name) {
+                Some((defining_object_index, defined)) => get_elf_defined_symbol_address(layout, *defining_object_index, defined),
+                None => {
+                    let referenced_by = get_elf_global_symbol_containing(object, section_index, relocation.offset).unwrap_or_else(|| get_elf_file_name(object));
+                    return Err(undefined_symbol_error(&definition.name, &referenced_by));
+                }
+            }
+        } else {
+            get_elf_defined_symbol_address(layout, object_index, &definition)
+        };
+
+        let value = symbol_address as i64 + relocation.addend;
+        let place = (section_address + relocation.offset) as i64;
+        let start = relocation.offset as usize;
+        match relocation.relocation_type {
+            R_X86_64_64 => section[start..start + 8].copy_from_slice(&value.to_le_bytes()),
+            R_X86_64_PC32 | R_X86_64_PLT32 => {
+                let value = i32::try_from(value - place).map_err(|_| relocation_out_of_range_error(&definition.name))?;
+                section[start..start + 4].copy_from_slice(&value.to_le_bytes());
+            },
+            R_X86_64_32S => {
+                let value = i32::try_from(value).map_err(|_| relocation_out_of_range_error(&definition.name))?;
+                section[start..start + 4].copy_from_slice(&value.to_le_bytes());
+            },
+            _ => return Err(unsupported_instruction_error())
+        }
+    }
+    Ok(())
+}
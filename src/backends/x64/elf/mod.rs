@@ -0,0 +1,172 @@
+mod headers;
+mod symbols;
+mod relocations;
+mod sections;
+mod files;
+mod linking;
+
+pub use headers::*;
+pub use symbols::*;
+pub use relocations::*;
+pub use sections::*;
+pub use files::*;
+pub use linking::*;
+
+const ELF_CLASS_64: u8 = 2;
+const ELF_DATA_LITTLE_ENDIAN: u8 = 1;
+const ELF_VERSION_CURRENT: u8 = 1;
+const ELF_OS_ABI_SYSTEM_V: u8 = 0;
+const ELF_TYPE_RELOCATABLE: u16 = 1;
+const ELF_TYPE_EXECUTABLE: u16 = 2;
+const ELF_MACHINE_X86_64: u16 = 62;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHT_NOBITS: u32 = 8;
+
+const SHF_WRITE: u64 = 0x1;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const PT_LOAD: u32 = 1;
+const PT_GNU_STACK: u32 = 0x6474E551;
+
+const PF_X: u32 = 0x1;
+const PF_W: u32 = 0x2;
+const PF_R: u32 = 0x4;
+
+pub const ELF_EXECUTABLE_BASE_ADDRESS: u64 = 0x400000;
+pub const ELF_PAGE_SIZE: u64 = 0x1000;
+
+pub const ELF_SECTION_TEXT: u16 = 1;
+pub const ELF_SECTION_RELA_TEXT: u16 = 2;
+pub const ELF_SECTION_DATA: u16 = 3;
+pub const ELF_SECTION_RELA_DATA: u16 = 4;
+pub const ELF_SECTION_RODATA: u16 = 5;
+pub const ELF_SECTION_BSS: u16 = 6;
+pub const ELF_SECTION_SYMTAB: u16 = 7;
+pub const ELF_SECTION_STRTAB: u16 = 8;
+pub const ELF_SECTION_SHSTRTAB: u16 = 9;
+const ELF_NUMBER_OF_SECTIONS: u16 = 10;
+
+pub struct Elf {
+    text_section: Vec<u8>,
+    text_section_relocations: Vec<ElfRelocation>,
+    data_section: Vec<u8>,
+    data_section_relocations: Vec<ElfRelocation>,
+    rodata_section: Vec<u8>,
+    bss_section_size: u64,
+    local_symbols: Vec<ElfSymbol>,
+    global_symbols: Vec<ElfSymbol>,
+    strings: Vec<u8>
+}
+
+pub struct ElfExecutable {
+    entry: u64,
+    text_address: u64,
+    text_section: Vec<u8>,
+    rodata_address: u64,
+    rodata_section: Vec<u8>,
+    data_address: u64,
+    data_section: Vec<u8>,
+    bss_address: u64,
+    bss_section_size: u64
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct ElfHeader {
+    identification: [u8; 16],
+    file_type: u16,
+    machine: u16,
+    version: u32,
+    entry: u64,
+    program_header_offset: u64,
+    section_header_offset: u64,
+    flags: u32,
+    header_size: u16,
+    program_header_entry_size: u16,
+    number_of_program_headers: u16,
+    section_header_entry_size: u16,
+    number_of_section_headers: u16,
+    section_names_section_index: u16
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct ElfSectionHeader {
+    name: u32,
+    section_type: u32,
+    flags: u64,
+    address: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    address_alignment: u64,
+    entry_size: u64
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct ElfProgramHeader {
+    segment_type: u32,
+    flags: u32,
+    offset: u64,
+    virtual_address: u64,
+    physical_address: u64,
+    file_size: u64,
+    memory_size: u64,
+    alignment: u64
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+#[derive(Copy, Clone)]
+pub struct ElfSymbol {
+    name: u32,
+    info: u8,
+    other: u8,
+    section_index: u16,
+    value: u64,
+    size: u64
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ElfSymbolIndex {
+    Local(u32),
+    Global(u32)
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ElfRelocation {
+    offset: u64,
+    symbol: ElfSymbolIndex,
+    relocation_type: u32,
+    addend: i64
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct ElfRelocationEntry {
+    offset: u64,
+    info: u64,
+    addend: i64
+}
+
+pub fn create_elf() -> Elf {
+    Elf {
+        text_section: vec!(),
+        text_section_relocations: vec!(),
+        data_section: vec!(),
+        data_section_relocations: vec!(),
+        rodata_section: vec!(),
+        bss_section_size: 0,
+        local_symbols: vec!(null_symbol()),
+        global_symbols: vec!(),
+        strings: vec!(0)
+    }
+}
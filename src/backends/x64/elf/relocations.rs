@@ -0,0 +1,42 @@
+use super::*;
+
+pub const R_X86_64_64: u32 = 1;
+pub const R_X86_64_PC32: u32 = 2;
+pub const R_X86_64_PLT32: u32 = 4;
+pub const R_X86_64_32S: u32 = 11;
+
+pub fn elf_relocation(offset: u32, symbol: ElfSymbolIndex, relocation_type: u32, addend: i64) -> ElfRelocation {
+    ElfRelocation {
+        offset: offset as u64,
+        symbol,
+        relocation_type,
+        addend
+    }
+}
+
+pub fn add_elf_text_section_relocation(elf: &mut Elf, relocation: ElfRelocation) {
+    clear_relocated_bytes(&mut elf.text_section, &relocation);
+    elf.text_section_relocations.push(relocation);
+}
+
+pub fn add_elf_data_section_relocation(elf: &mut Elf, relocation: ElfRelocation) {
+    clear_relocated_bytes(&mut elf.data_section, &relocation);
+    elf.data_section_relocations.push(relocation);
+}
+
+fn clear_relocated_bytes(section: &mut [u8], relocation: &ElfRelocation) {
+    let start = relocation.offset as usize;
+    let length = match relocation.relocation_type {
+        R_X86_64_64 => 8,
+        _ => 4
+    };
+    section[start..start + length].fill(0);
+}
+
+pub fn relocation_entry_for_elf(elf: &Elf, relocation: &ElfRelocation) -> ElfRelocationEntry {
+    ElfRelocationEntry {
+        offset: relocation.offset,
+        info: (get_elf_symbol_table_index(elf, relocation.symbol) as u64) << 32 | relocation.relocation_type as u64,
+        addend: relocation.addend
+    }
+}
@@ -0,0 +1,17 @@
+use super::*;
+
+pub fn set_elf_text_section(elf: &mut Elf, text: &[u8]) {
+    elf.text_section = text.to_vec();
+}
+
+pub fn set_elf_data_section(elf: &mut Elf, data: &[u8]) {
+    elf.data_section = data.to_vec();
+}
+
+pub fn set_elf_rodata_section(elf: &mut Elf, rodata: &[u8]) {
+    elf.rodata_section = rodata.to_vec();
+}
+
+pub fn set_elf_bss_section_size(elf: &mut Elf, size: u32) {
+    elf.bss_section_size = size as u64;
+}
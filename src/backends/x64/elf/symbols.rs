@@ -0,0 +1,142 @@
+use crate::utilities::*;
+
+use super::*;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_OBJECT: u8 = 1;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+const STT_FILE: u8 = 4;
+pub const SHN_UNDEF: u16 = 0;
+pub const SHN_ABS: u16 = 0xFFF1;
+
+fn symbol(name: u32, binding: u8, symbol_type: u8, section_index: u16, value: u64) -> ElfSymbol {
+    ElfSymbol {
+        name,
+        info: binding << 4 | symbol_type,
+        other: 0,
+        section_index,
+        value,
+        size: 0
+    }
+}
+
+pub fn null_symbol() -> ElfSymbol {
+    symbol(0, STB_LOCAL, STT_NOTYPE, SHN_UNDEF, 0)
+}
+
+fn add_string(elf: &mut Elf, entry: &str) -> u32 {
+    let pointer = elf.strings.len() as u32;
+    elf.strings.append(&mut string_to_bytes_zero_terminated(entry));
+    pointer
+}
+
+fn add_local_symbol(elf: &mut Elf, entry: ElfSymbol) -> ElfSymbolIndex {
+    elf.local_symbols.push(entry);
+    ElfSymbolIndex::Local(elf.local_symbols.len() as u32 - 1)
+}
+
+fn add_global_symbol(elf: &mut Elf, entry: ElfSymbol) -> ElfSymbolIndex {
+    elf.global_symbols.push(entry);
+    ElfSymbolIndex::Global(elf.global_symbols.len() as u32 - 1)
+}
+
+fn add_named_symbol(elf: &mut Elf, name: &str, binding: u8, symbol_type: u8, section_index: u16, value: u64) -> ElfSymbolIndex {
+    let name_pointer = add_string(elf, name);
+    let entry = symbol(name_pointer, binding, symbol_type, section_index, value);
+    if binding == STB_LOCAL {
+        add_local_symbol(elf, entry)
+    } else {
+        add_global_symbol(elf, entry)
+    }
+}
+
+pub fn add_elf_file_symbol(elf: &mut Elf, file_name: &str) -> ElfSymbolIndex {
+    add_named_symbol(elf, file_name, STB_LOCAL, STT_FILE, SHN_ABS, 0)
+}
+
+pub fn add_elf_section_symbol(elf: &mut Elf, section_index: u16) -> ElfSymbolIndex {
+    add_local_symbol(elf, symbol(0, STB_LOCAL, STT_SECTION, section_index, 0))
+}
+
+pub fn add_elf_data_section_static_symbol(elf: &mut Elf, name: &str, value: u32) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_LOCAL, STT_OBJECT, ELF_SECTION_DATA, value as u64)
+}
+
+pub fn add_elf_data_section_external_symbol(elf: &mut Elf, name: &str, value: u32) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_GLOBAL, STT_OBJECT, ELF_SECTION_DATA, value as u64)
+}
+
+pub fn add_elf_bss_section_external_symbol(elf: &mut Elf, name: &str, value: u32) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_GLOBAL, STT_OBJECT, ELF_SECTION_BSS, value as u64)
+}
+
+pub fn add_elf_text_section_external_symbol(elf: &mut Elf, name: &str, value: u32) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_GLOBAL, STT_FUNC, ELF_SECTION_TEXT, value as u64)
+}
+
+pub fn add_elf_foreign_external_symbol(elf: &mut Elf, name: &str) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_GLOBAL, STT_NOTYPE, SHN_UNDEF, 0)
+}
+
+pub fn add_elf_absolute_external_symbol_32(elf: &mut Elf, name: &str, value: u32) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_GLOBAL, STT_NOTYPE, SHN_ABS, value as i32 as i64 as u64)
+}
+
+pub fn add_elf_absolute_external_symbol_64(elf: &mut Elf, name: &str, value: u64) -> ElfSymbolIndex {
+    add_named_symbol(elf, name, STB_GLOBAL, STT_NOTYPE, SHN_ABS, value)
+}
+
+pub fn get_elf_symbol_table_index(elf: &Elf, symbol: ElfSymbolIndex) -> u32 {
+    match symbol {
+        ElfSymbolIndex::Local(index) => index,
+        ElfSymbolIndex::Global(index) => elf.local_symbols.len() as u32 + index
+    }
+}
+
+pub struct ElfSymbolDefinition {
+    pub name: String,
+    pub section_index: u16,
+    pub value: u64
+}
+
+pub fn get_elf_symbol_definition(elf: &Elf, symbol: ElfSymbolIndex) -> ElfSymbolDefinition {
+    let entry = match symbol {
+        ElfSymbolIndex::Local(index) => elf.local_symbols[index as usize],
+        ElfSymbolIndex::Global(index) => elf.global_symbols[index as usize]
+    };
+    ElfSymbolDefinition {
+        name: get_string(elf, entry.name),
+        section_index: entry.section_index,
+        value: entry.value
+    }
+}
+
+pub fn get_elf_global_symbol_definitions(elf: &Elf) -> Vec<ElfSymbolDefinition> {
+    (0..elf.global_symbols.len())
+        .map(|index| get_elf_symbol_definition(elf, ElfSymbolIndex::Global(index as u32)))
+        .collect()
+}
+
+pub fn get_elf_global_symbol_containing(elf: &Elf, section_index: u16, offset: u64) -> Option<String> {
+    get_elf_global_symbol_definitions(elf)
+        .into_iter()
+        .filter(|definition| definition.section_index == section_index && definition.value <= offset)
+        .max_by_key(|definition| definition.value)
+        .map(|definition| definition.name)
+}
+
+pub fn get_elf_file_name(elf: &Elf) -> String {
+    elf.local_symbols
+        .iter()
+        .find(|symbol| symbol.info & 0xF == STT_FILE)
+        .map_or_else(empty_string, |symbol| get_string(elf, symbol.name))
+}
+
+fn get_string(elf: &Elf, pointer: u32) -> String {
+    let start = pointer as usize;
+    let length = elf.strings[start..].iter().position(|byte| *byte == 0).unwrap_or(elf.strings.len() - start);
+    String::from_utf8_lossy(&elf.strings[start..start + length]).to_string()
+}
@@ -1,9 +1,13 @@
 mod coff;
+mod elf;
+mod pe;
 mod operations;
 mod registers;
 mod builder;
 
 pub use coff::*;
+pub use elf::*;
+pub use pe::*;
 pub use operations::*;
 pub use registers::*;
 pub use builder::*;
@@ -3,11 +3,13 @@ use crate::backends::*;
 use crate::utilities::*;
 
 const MOD_REGISTER_INDIRECT: u8 = 0x01;
+const MOD_REGISTER_INDIRECT_DWORD_OFFSET: u8 = 0x02;
 const MOD_REGISTER_DIRECT: u8 = 0x03;
 const REX_B: u8 = 0x41;
 const REX_R: u8 = 0x44;
 const REX_W: u8 = 0x48;
 const OP_ADD: u8 = 0x83;
+const OP_ADD_IMM32: u8 = 0x81;
 const OP_LEA: u8 = 0x8D;
 const OP_XOR: u8 = 0x31;
 const OP_PUSH: u8 = 0x50;
@@ -16,39 +18,146 @@ const OP_MOV_R_TO_RM: u8 = 0x89;
 const OP_MOV_RM_TO_R: u8 = 0x8B;
 const OP_MOV_IMM_TO_R: u8 = 0xB8;
 const OP_MOV_IMM_TO_RM: u8 = 0xC7;
-const OP_CALL: u8 = 0xE8;
+pub const OP_CALL: u8 = 0xE8;
 const OP_RET: u8 = 0xC3;
 const RSP_SIB_BYTE: u8 = 0x24;
+const OP_MOV_R8_TO_RM8: u8 = 0x88;
+const OP_TWO_BYTE_ESCAPE: u8 = 0x0F;
+const OP_SETCC: u8 = 0x90;
+const OP_SSE_MOV_RM_TO_R: u8 = 0x10;
+const OP_SSE_MOV_R_TO_RM: u8 = 0x11;
+const OP_SSE_CVTSI2S: u8 = 0x2A;
+const OP_SSE_CVTTS2SI: u8 = 0x2C;
+const OP_SSE_UCOMIS: u8 = 0x2E;
+const OP_SSE_ADD: u8 = 0x58;
+const OP_SSE_MUL: u8 = 0x59;
+const OP_SSE_SUB: u8 = 0x5C;
+const PREFIX_OPERAND_SIZE: u8 = 0x66;
+const PREFIX_SCALAR_DOUBLE: u8 = 0xF2;
+const PREFIX_SCALAR_SINGLE: u8 = 0xF3;
+const REX: u8 = 0x40;
+const MOD_REGISTER_RELATIVE: u8 = 0x00;
+const RM_RIP_RELATIVE: u8 = 0x05;
+const OP_MOV_IMM8_TO_RM8: u8 = 0xC6;
+const OP_MOVSXD: u8 = 0x63;
+const OP_MOVSX_RM8: u8 = 0xBE;
+const OP_MOVSX_RM16: u8 = 0xBF;
+const OP_MOVZX_RM8: u8 = 0xB6;
+const OP_MOVZX_RM16: u8 = 0xB7;
+const OP_CMP_R_TO_RM: u8 = 0x39;
+const OP_ADD_R_TO_RM: u8 = 0x01;
+const OP_SUB_R_FROM_RM: u8 = 0x29;
+const OP_IMUL_RM_TO_R: u8 = 0xAF;
+const OP_JCC_REL8: u8 = 0x70;
+const OP_JCC_REL32: u8 = 0x80;
+const OP_JMP_REL32: u8 = 0xE9;
+const OP_UD2: u8 = 0x0B;
+const OP_SYSCALL: u8 = 0x05;
+const UD2_SIZE: u8 = 0x02;
+
+pub const CONDITION_BELOW: u8 = 0x2;
+pub const CONDITION_ABOVE_OR_EQUAL: u8 = 0x3;
+pub const CONDITION_EQUAL: u8 = 0x4;
+pub const CONDITION_NOT_EQUAL: u8 = 0x5;
+pub const CONDITION_BELOW_OR_EQUAL: u8 = 0x6;
+pub const CONDITION_ABOVE: u8 = 0x7;
+pub const CONDITION_LESS: u8 = 0xC;
+pub const CONDITION_GREATER_OR_EQUAL: u8 = 0xD;
+pub const CONDITION_LESS_OR_EQUAL: u8 = 0xE;
+pub const CONDITION_GREATER: u8 = 0xF;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ScalarFloat {
+    Single,
+    Double
+}
+
+fn scalar_float_prefix(scalar: ScalarFloat) -> u8 {
+    match scalar {
+        ScalarFloat::Single => PREFIX_SCALAR_SINGLE,
+        ScalarFloat::Double => PREFIX_SCALAR_DOUBLE
+    }
+}
 
 const SECONDARY_ADD_OP_SUB: u8 = 0x5;
 const SECONDARY_OP_NONE: u8 = 0x0;
 
 fn mod_rm(mod_part: u8, reg_part: u8, r_m_part: u8) -> u8 {
-    mod_part << 6 | reg_part << 3 | r_m_part
+    mod_part << 6 | remove_register_high_bit(reg_part) << 3 | remove_register_high_bit(r_m_part)
+}
+
+fn get_address_offset_mod(address_offset: i32) -> u8 {
+    if i8::try_from(address_offset).is_ok() {
+        MOD_REGISTER_INDIRECT
+    } else {
+        MOD_REGISTER_INDIRECT_DWORD_OFFSET
+    }
+}
+
+fn add_address_offset_to_text_section(coff: &mut Coff, address_offset: i32) {
+    match i8::try_from(address_offset) {
+        Ok(byte_offset) => add_entry_to_text_section(coff, byte_offset as u8),
+        Err(_) => add_entries_to_text_section(coff, address_offset.to_le_bytes().to_vec())
+    }
 }
 
 pub fn add_push_reg_op(coff: &mut Coff, register: u8) {
-    add_entry_to_text_section(coff, OP_PUSH + register);
+    add_rex_entry_to_text_section_if_required(coff, false, false, register_has_high_bit(register));
+    add_entry_to_text_section(coff, OP_PUSH + remove_register_high_bit(register));
 }
 
 pub fn add_pop_reg_op(coff: &mut Coff, register: u8) {
-    add_entry_to_text_section(coff, OP_POP + register);
+    add_rex_entry_to_text_section_if_required(coff, false, false, register_has_high_bit(register));
+    add_entry_to_text_section(coff, OP_POP + remove_register_high_bit(register));
 }
 
 pub fn add_sub_byte_value_from_reg_op(coff: &mut Coff, value: u8, register: u8) {
-    add_entry_to_text_section(coff, REX_W);
+    add_rex_entry_to_text_section_if_required(coff, true, false, register_has_high_bit(register));
     add_entry_to_text_section(coff, OP_ADD);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_ADD_OP_SUB, register));
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_ADD_OP_SUB, remove_register_high_bit(register)));
     add_entry_to_text_section(coff, value);
 }
 
 pub fn add_add_byte_value_to_reg_op(coff: &mut Coff, value: u8, register: u8) {
-    add_entry_to_text_section(coff, REX_W);
+    add_rex_entry_to_text_section_if_required(coff, true, false, register_has_high_bit(register));
     add_entry_to_text_section(coff, OP_ADD);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_OP_NONE, register));
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_OP_NONE, remove_register_high_bit(register)));
     add_entry_to_text_section(coff, value);
 }
 
+pub fn add_sub_dword_value_from_reg_op(coff: &mut Coff, value: u32, register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, false, register_has_high_bit(register));
+    add_entry_to_text_section(coff, OP_ADD_IMM32);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_ADD_OP_SUB, remove_register_high_bit(register)));
+    add_entries_to_text_section(coff, u32_to_bytes(&value));
+}
+
+pub fn add_add_dword_value_to_reg_op(coff: &mut Coff, value: u32, register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, false, register_has_high_bit(register));
+    add_entry_to_text_section(coff, OP_ADD_IMM32);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_OP_NONE, remove_register_high_bit(register)));
+    add_entries_to_text_section(coff, u32_to_bytes(&value));
+}
+
+pub fn add_add_qword_reg_to_reg_op(coff: &mut Coff, from_register: u8, into_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(from_register), register_has_high_bit(into_register));
+    add_entry_to_text_section(coff, OP_ADD_R_TO_RM);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(from_register), remove_register_high_bit(into_register)));
+}
+
+pub fn add_sub_qword_reg_from_reg_op(coff: &mut Coff, from_register: u8, into_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(from_register), register_has_high_bit(into_register));
+    add_entry_to_text_section(coff, OP_SUB_R_FROM_RM);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(from_register), remove_register_high_bit(into_register)));
+}
+
+pub fn add_imul_qword_reg_to_reg_op(coff: &mut Coff, from_register: u8, into_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(into_register), register_has_high_bit(from_register));
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_IMUL_RM_TO_R);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(into_register), remove_register_high_bit(from_register)));
+}
+
 pub fn add_mov_dword_relocatable_value_to_reg_op(coff: &mut Coff, relocatable_value: RelocatableValue, register: u8) {
     add_entry_to_text_section(coff, OP_MOV_IMM_TO_R + register);
     add_relocatable_entry_and_text_section_inital_entry(coff, relocatable_value, IMAGE_REL_AMD64_ADDR32);
@@ -60,13 +169,15 @@ pub fn add_mov_dword_value_to_reg_op(coff: &mut Coff, value: u32, register: u8)
 }
 
 pub fn add_mov_qword_value_to_reg_op(coff: &mut Coff, value: u64, register: u8) {
-    add_entry_to_text_section(coff, OP_MOV_IMM_TO_R + register);
+    add_rex_entry_to_text_section_if_required(coff, true, false, register_has_high_bit(register));
+    add_entry_to_text_section(coff, OP_MOV_IMM_TO_R + remove_register_high_bit(register));
     add_entries_to_text_section(coff, u64_to_bytes(&value));
 }
 
 pub fn add_mov_from_qword_reg_to_reg_op(coff: &mut Coff, register_from: u8, register_to: u8) {
-    add_entry_to_text_section(coff, REX_W);
-    add_mov_from_dword_reg_to_reg_op(coff, register_from, register_to);
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(register_from), register_has_high_bit(register_to));
+    add_entry_to_text_section(coff, OP_MOV_R_TO_RM);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(register_from), remove_register_high_bit(register_to)));
 }
 
 pub fn add_mov_from_dword_reg_to_reg_op(coff: &mut Coff, register_from: u8, register_to: u8) {
@@ -74,54 +185,53 @@ pub fn add_mov_from_dword_reg_to_reg_op(coff: &mut Coff, register_from: u8, regi
     add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, register_from, register_to));
 }
 
-pub fn add_mov_dword_value_into_reg_plus_offset_pointer_op(coff: &mut Coff, value: u32, address_register: u8, address_offset: u8) {
-    add_entry_to_text_section(coff, REX_W);
+pub fn add_mov_dword_value_into_reg_plus_offset_pointer_op(coff: &mut Coff, value: u32, address_register: u8, address_offset: i32) {
     add_entry_to_text_section(coff,OP_MOV_IMM_TO_RM);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_INDIRECT, 0, address_register));
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), 0, address_register));
     add_sib_byte_entry_to_text_section_if_rsp_register(coff, address_register);
-    add_entry_to_text_section(coff, address_offset);
+    add_address_offset_to_text_section(coff, address_offset);
     add_entries_to_text_section(coff, u32_to_bytes(&value));
 }
 
-pub fn add_mov_qword_value_into_reg_plus_offset_pointer_op(coff: &mut Coff, value: u64, address_register: u8, address_offset: u8) {
+pub fn add_mov_qword_value_into_reg_plus_offset_pointer_op(coff: &mut Coff, value: u64, address_register: u8, address_offset: i32) {
     add_entry_to_text_section(coff, REX_W);
     add_entry_to_text_section(coff,OP_MOV_IMM_TO_RM);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_INDIRECT, 0, address_register));
-    add_entry_to_text_section(coff, address_offset);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), 0, address_register));
+    add_address_offset_to_text_section(coff, address_offset);
     //TODO:y does this have to be dword???
     add_entries_to_text_section(coff, u32_to_bytes(&(value as u32)));
 }
 
-pub fn add_mov_dword_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, address_register: u8, address_offset: u8, into_register: u8) {
+pub fn add_mov_dword_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, address_register: u8, address_offset: i32, into_register: u8) {
     if register_has_high_bit(into_register) {
         add_entry_to_text_section(coff, REX_R);    
     }
     add_entry_to_text_section(coff, OP_MOV_RM_TO_R);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_INDIRECT, remove_register_high_bit(into_register), address_register));
-    add_entry_to_text_section(coff, address_offset);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), remove_register_high_bit(into_register), address_register));
+    add_address_offset_to_text_section(coff, address_offset);
 }
 
-pub fn add_mov_qword_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, address_register: u8, address_offset: u8, into_register: u8) {
+pub fn add_mov_qword_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, address_register: u8, address_offset: i32, into_register: u8) {
     let rex = add_rex_r_for_high_bit_register(into_register, REX_W);
     add_entry_to_text_section(coff, rex);
     add_entry_to_text_section(coff, OP_MOV_RM_TO_R);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_INDIRECT, into_register, address_register));
-    add_entry_to_text_section(coff, address_offset);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), into_register, address_register));
+    add_address_offset_to_text_section(coff, address_offset);
 }
 
-pub fn add_mov_reg_to_reg_plus_offset_qword_pointer_op(coff: &mut Coff, from_register: u8, into_address_register: u8, into_address_offset: u8) {
+pub fn add_mov_reg_to_reg_plus_offset_qword_pointer_op(coff: &mut Coff, from_register: u8, into_address_register: u8, into_address_offset: i32) {
     let rex = add_rex_r_for_high_bit_register(from_register, REX_W);
     add_entry_to_text_section(coff, rex);
     add_entry_to_text_section(coff, OP_MOV_R_TO_RM);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_INDIRECT, from_register, into_address_register));
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(into_address_offset), from_register, into_address_register));
     add_sib_byte_entry_to_text_section_if_rsp_register(coff, into_address_register );
-    add_entry_to_text_section(coff, into_address_offset);
+    add_address_offset_to_text_section(coff, into_address_offset);
 }
 
-pub fn add_mov_reg_to_reg_plus_offset_dword_pointer_op(coff: &mut Coff, from_register: u8, into_address_register: u8, into_address_offset: u8) {
+pub fn add_mov_reg_to_reg_plus_offset_dword_pointer_op(coff: &mut Coff, from_register: u8, into_address_register: u8, into_address_offset: i32) {
     add_entry_to_text_section(coff, OP_MOV_R_TO_RM);
-    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_INDIRECT, from_register, into_address_register));
-    add_entry_to_text_section(coff, into_address_offset);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(into_address_offset), from_register, into_address_register));
+    add_address_offset_to_text_section(coff, into_address_offset);
 }
 
 pub fn add_call_relocatable_addr_op(coff: &mut Coff, relocatable_address: RelocatableValue) {
@@ -136,16 +246,15 @@ pub fn add_lea_reg_plus_relocatable_offset_pointer_to_reg_op(coff: &mut Coff, ad
     add_relocatable_entry_and_text_section_inital_entry(coff, relocatable_address_offset, IMAGE_REL_AMD64_REL32);
 }
 
-pub fn add_lea_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, address_register: u8, into_address_offset: u8, into_register: u8) {
+pub fn add_lea_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, address_register: u8, into_address_offset: i32, into_register: u8) {
     add_entry_to_text_section(coff, REX_W);
     add_entry_to_text_section(coff, OP_LEA);
-    add_entry_to_text_section(coff, mod_rm(0, into_register, address_register));
-    add_entry_to_text_section(coff, into_address_offset);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(into_address_offset), into_register, address_register));
+    add_address_offset_to_text_section(coff, into_address_offset);
 }
 
 pub fn add_xor_qword_reg_into_reg_op(coff: &mut Coff, register_from: u8, register_into: u8) {
-    let rex = add_rex_r_for_high_bit_register(register_from, REX_W | REX_B);
-    add_entry_to_text_section(coff, rex);
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(register_from), register_has_high_bit(register_into));
     add_entry_to_text_section(coff, OP_XOR);
     add_entry_to_text_section(
         coff, 
@@ -168,4 +277,203 @@ fn add_sib_byte_entry_to_text_section_if_rsp_register(coff: &mut Coff, register:
     if register == REG_SP {
         add_entry_to_text_section(coff, RSP_SIB_BYTE);
     }
-}
\ No newline at end of file
+}
+
+pub fn add_mov_byte_reg_to_reg_plus_offset_pointer_op(coff: &mut Coff, from_register: u8, into_address_register: u8, into_address_offset: i32) {
+    if register_has_high_bit(from_register) {
+        add_entry_to_text_section(coff, REX_R);
+    }
+    add_entry_to_text_section(coff, OP_MOV_R8_TO_RM8);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(into_address_offset), remove_register_high_bit(from_register), into_address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, into_address_register);
+    add_address_offset_to_text_section(coff, into_address_offset);
+}
+
+pub fn add_set_byte_reg_on_condition_op(coff: &mut Coff, condition: u8, register: u8) {
+    if register_has_high_bit(register) {
+        add_entry_to_text_section(coff, REX_B);
+    }
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_SETCC + condition);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, SECONDARY_OP_NONE, remove_register_high_bit(register)));
+}
+
+pub fn add_sse_mov_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, scalar: ScalarFloat, address_register: u8, address_offset: i32, into_register: u8) {
+    add_sse_op_prefix(coff, scalar, OP_SSE_MOV_RM_TO_R);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), into_register, address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, address_register);
+    add_address_offset_to_text_section(coff, address_offset);
+}
+
+pub fn add_sse_mov_reg_to_reg_plus_offset_pointer_op(coff: &mut Coff, scalar: ScalarFloat, from_register: u8, into_address_register: u8, into_address_offset: i32) {
+    add_sse_op_prefix(coff, scalar, OP_SSE_MOV_R_TO_RM);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(into_address_offset), from_register, into_address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, into_address_register);
+    add_address_offset_to_text_section(coff, into_address_offset);
+}
+
+pub fn add_sse_mov_relocatable_pointer_to_reg_op(coff: &mut Coff, scalar: ScalarFloat, relocatable_address: RelocatableValue, into_register: u8) {
+    add_sse_op_prefix(coff, scalar, OP_SSE_MOV_RM_TO_R);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_RELATIVE, into_register, RM_RIP_RELATIVE));
+    add_relocatable_entry_and_text_section_inital_entry(coff, relocatable_address, IMAGE_REL_AMD64_REL32);
+}
+
+pub fn add_sse_add_reg_to_reg_op(coff: &mut Coff, scalar: ScalarFloat, from_register: u8, into_register: u8) {
+    add_sse_reg_to_reg_op(coff, scalar, OP_SSE_ADD, from_register, into_register);
+}
+
+pub fn add_sse_sub_reg_from_reg_op(coff: &mut Coff, scalar: ScalarFloat, from_register: u8, into_register: u8) {
+    add_sse_reg_to_reg_op(coff, scalar, OP_SSE_SUB, from_register, into_register);
+}
+
+pub fn add_sse_mul_reg_to_reg_op(coff: &mut Coff, scalar: ScalarFloat, from_register: u8, into_register: u8) {
+    add_sse_reg_to_reg_op(coff, scalar, OP_SSE_MUL, from_register, into_register);
+}
+
+pub fn add_sse_compare_reg_to_reg_op(coff: &mut Coff, scalar: ScalarFloat, lhs_register: u8, rhs_register: u8) {
+    if scalar == ScalarFloat::Double {
+        add_entry_to_text_section(coff, PREFIX_OPERAND_SIZE);
+    }
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_SSE_UCOMIS);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, lhs_register, rhs_register));
+}
+
+pub fn add_sse_convert_int_reg_to_float_reg_op(coff: &mut Coff, scalar: ScalarFloat, is_quad_word: bool, from_register: u8, into_register: u8) {
+    add_entry_to_text_section(coff, scalar_float_prefix(scalar));
+    add_rex_entry_to_text_section_if_required(coff, is_quad_word, false, register_has_high_bit(from_register));
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_SSE_CVTSI2S);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, into_register, remove_register_high_bit(from_register)));
+}
+
+pub fn add_sse_convert_float_reg_to_int_reg_op(coff: &mut Coff, scalar: ScalarFloat, is_quad_word: bool, from_register: u8, into_register: u8) {
+    add_entry_to_text_section(coff, scalar_float_prefix(scalar));
+    add_rex_entry_to_text_section_if_required(coff, is_quad_word, register_has_high_bit(into_register), false);
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_SSE_CVTTS2SI);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(into_register), from_register));
+}
+
+pub fn add_mov_byte_value_into_reg_plus_offset_pointer_op(coff: &mut Coff, value: u8, address_register: u8, address_offset: i32) {
+    add_entry_to_text_section(coff, OP_MOV_IMM8_TO_RM8);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), SECONDARY_OP_NONE, address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, address_register);
+    add_address_offset_to_text_section(coff, address_offset);
+    add_entry_to_text_section(coff, value);
+}
+
+pub fn add_mov_word_value_into_reg_plus_offset_pointer_op(coff: &mut Coff, value: u16, address_register: u8, address_offset: i32) {
+    add_entry_to_text_section(coff, PREFIX_OPERAND_SIZE);
+    add_entry_to_text_section(coff, OP_MOV_IMM_TO_RM);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), SECONDARY_OP_NONE, address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, address_register);
+    add_address_offset_to_text_section(coff, address_offset);
+    add_entries_to_text_section(coff, value.to_le_bytes().to_vec());
+}
+
+pub fn add_mov_word_reg_to_reg_plus_offset_pointer_op(coff: &mut Coff, from_register: u8, into_address_register: u8, into_address_offset: i32) {
+    add_entry_to_text_section(coff, PREFIX_OPERAND_SIZE);
+    add_rex_entry_to_text_section_if_required(coff, false, register_has_high_bit(from_register), false);
+    add_entry_to_text_section(coff, OP_MOV_R_TO_RM);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(into_address_offset), remove_register_high_bit(from_register), into_address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, into_address_register);
+    add_address_offset_to_text_section(coff, into_address_offset);
+}
+
+pub fn add_movsx_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, from_size_in_bytes: u8, address_register: u8, address_offset: i32, into_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(into_register), false);
+    add_extend_op(coff, from_size_in_bytes, true);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), remove_register_high_bit(into_register), address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, address_register);
+    add_address_offset_to_text_section(coff, address_offset);
+}
+
+pub fn add_movzx_reg_plus_offset_pointer_to_reg_op(coff: &mut Coff, from_size_in_bytes: u8, address_register: u8, address_offset: i32, into_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(into_register), false);
+    add_extend_op(coff, from_size_in_bytes, false);
+    add_entry_to_text_section(coff, mod_rm(get_address_offset_mod(address_offset), remove_register_high_bit(into_register), address_register));
+    add_sib_byte_entry_to_text_section_if_rsp_register(coff, address_register);
+    add_address_offset_to_text_section(coff, address_offset);
+}
+
+pub fn add_movsx_reg_to_reg_op(coff: &mut Coff, from_size_in_bytes: u8, from_register: u8, into_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(into_register), register_has_high_bit(from_register));
+    add_extend_op(coff, from_size_in_bytes, true);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(into_register), remove_register_high_bit(from_register)));
+}
+
+pub fn add_movzx_reg_to_reg_op(coff: &mut Coff, from_size_in_bytes: u8, from_register: u8, into_register: u8) {
+    if from_size_in_bytes == 4 {
+        add_rex_entry_to_text_section_if_required(coff, false, register_has_high_bit(from_register), register_has_high_bit(into_register));
+        add_entry_to_text_section(coff, OP_MOV_R_TO_RM);
+        add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(from_register), remove_register_high_bit(into_register)));
+        return;
+    }
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(into_register), register_has_high_bit(from_register));
+    add_extend_op(coff, from_size_in_bytes, false);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(into_register), remove_register_high_bit(from_register)));
+}
+
+fn add_extend_op(coff: &mut Coff, from_size_in_bytes: u8, is_signed: bool) {
+    match (from_size_in_bytes, is_signed) {
+        (4, true) => add_entry_to_text_section(coff, OP_MOVSXD),
+        (2, true) => add_entries_to_text_section(coff, vec!(OP_TWO_BYTE_ESCAPE, OP_MOVSX_RM16)),
+        (2, false) => add_entries_to_text_section(coff, vec!(OP_TWO_BYTE_ESCAPE, OP_MOVZX_RM16)),
+        (_, true) => add_entries_to_text_section(coff, vec!(OP_TWO_BYTE_ESCAPE, OP_MOVSX_RM8)),
+        (_, false) => add_entries_to_text_section(coff, vec!(OP_TWO_BYTE_ESCAPE, OP_MOVZX_RM8))
+    }
+}
+
+pub fn add_cmp_qword_reg_to_reg_op(coff: &mut Coff, lhs_register: u8, rhs_register: u8) {
+    add_rex_entry_to_text_section_if_required(coff, true, register_has_high_bit(rhs_register), register_has_high_bit(lhs_register));
+    add_entry_to_text_section(coff, OP_CMP_R_TO_RM);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, remove_register_high_bit(rhs_register), remove_register_high_bit(lhs_register)));
+}
+
+pub fn add_trap_on_condition_op(coff: &mut Coff, condition: u8) {
+    add_entry_to_text_section(coff, OP_JCC_REL8 + (condition ^ 1));
+    add_entry_to_text_section(coff, UD2_SIZE);
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_UD2);
+}
+
+pub fn add_jump_op(coff: &mut Coff) -> u32 {
+    add_entry_to_text_section(coff, OP_JMP_REL32);
+    add_rel32_placeholder_to_text_section(coff)
+}
+
+pub fn add_jump_on_condition_op(coff: &mut Coff, condition: u8) -> u32 {
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_JCC_REL32 + condition);
+    add_rel32_placeholder_to_text_section(coff)
+}
+
+fn add_rel32_placeholder_to_text_section(coff: &mut Coff) -> u32 {
+    let offset = get_text_section(coff).len() as u32;
+    add_entries_to_text_section(coff, vec!(0; 4));
+    offset
+}
+
+pub fn add_syscall_op(coff: &mut Coff) {
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, OP_SYSCALL);
+}
+
+fn add_sse_reg_to_reg_op(coff: &mut Coff, scalar: ScalarFloat, op: u8, from_register: u8, into_register: u8) {
+    add_sse_op_prefix(coff, scalar, op);
+    add_entry_to_text_section(coff, mod_rm(MOD_REGISTER_DIRECT, into_register, from_register));
+}
+
+fn add_sse_op_prefix(coff: &mut Coff, scalar: ScalarFloat, op: u8) {
+    add_entry_to_text_section(coff, scalar_float_prefix(scalar));
+    add_entry_to_text_section(coff, OP_TWO_BYTE_ESCAPE);
+    add_entry_to_text_section(coff, op);
+}
+
+fn add_rex_entry_to_text_section_if_required(coff: &mut Coff, w: bool, r: bool, b: bool) {
+    let rex = REX | (w as u8) << 3 | (r as u8) << 2 | b as u8;
+    if rex != REX {
+        add_entry_to_text_section(coff, rex);
+    }
+}
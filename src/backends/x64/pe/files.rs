@@ -0,0 +1,41 @@
+use std::*;
+use std::fs::File;
+use std::io::Write;
+use crate::utilities::*;
+
+use super::*;
+
+pub fn create_pe_file(name: &str) -> io::Result<File> {
+    File::create(name)
+}
+
+pub fn write_pe_to_file(pe: &Pe, file: &mut File) -> io::Result<()> {
+    file.write_all(&pe_to_bytes(pe))?;
+    file.flush()?;
+    Ok(())
+}
+
+pub fn pe_to_bytes(pe: &Pe) -> Vec<u8> {
+    let mut bytes = vec!();
+    bytes.extend_from_slice(any_as_u8_slice(&pe_dos_header()));
+    bytes.extend_from_slice(&pe_signature());
+    bytes.extend_from_slice(any_as_u8_slice(&pe_file_header(pe.sections.len() as u16)));
+    bytes.extend_from_slice(any_as_u8_slice(&pe_optional_header(pe)));
+
+    let mut pointer_to_section = get_pe_size_of_headers(pe.sections.len());
+    for section in &pe.sections {
+        bytes.extend_from_slice(any_as_u8_slice(&pe_section_header(section, pointer_to_section)));
+        pointer_to_section += get_pe_section_raw_size(section);
+    }
+
+    for section in &pe.sections {
+        align_pe_bytes(&mut bytes);
+        bytes.extend_from_slice(&section.contents);
+    }
+    align_pe_bytes(&mut bytes);
+    bytes
+}
+
+fn align_pe_bytes(bytes: &mut Vec<u8>) {
+    bytes.resize(align_pe_value(bytes.len() as u32, PE_FILE_ALIGNMENT) as usize, 0);
+}
@@ -0,0 +1,125 @@
+use std::mem::size_of;
+
+use crate::backends::*;
+
+use super::*;
+
+const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+
+pub fn pe_dos_header() -> PeDosHeader {
+    PeDosHeader {
+        magic: [b'M', b'Z'],
+        unused: [0; 58],
+        new_header_offset: PE_HEADERS_OFFSET
+    }
+}
+
+pub fn pe_signature() -> [u8; 4] {
+    [b'P', b'E', 0, 0]
+}
+
+pub fn pe_file_header(number_of_sections: u16) -> CoffHeader {
+    header(
+        IMAGE_FILE_MACHINE_AMD64,
+        number_of_sections,
+        0,
+        0,
+        0,
+        size_of::<PeOptionalHeader>() as u16,
+        IMAGE_FILE_RELOCS_STRIPPED | IMAGE_FILE_EXECUTABLE_IMAGE | IMAGE_FILE_LARGE_ADDRESS_AWARE
+    )
+}
+
+pub fn get_pe_size_of_headers(number_of_sections: usize) -> u32 {
+    let size = PE_HEADERS_OFFSET as usize
+        + pe_signature().len()
+        + size_of::<CoffHeader>()
+        + size_of::<PeOptionalHeader>()
+        + size_of::<CoffSectionHeader>() * number_of_sections;
+    align_pe_value(size as u32, PE_FILE_ALIGNMENT)
+}
+
+pub fn pe_optional_header(pe: &Pe) -> PeOptionalHeader {
+    let size_of_code = sum_pe_section_sizes(pe, IMAGE_SCN_CNT_CODE);
+    let size_of_initialised_data = sum_pe_section_sizes(pe, IMAGE_SCN_CNT_INITIALISED_DATA);
+    let size_of_uninitialised_data = pe.sections
+        .iter()
+        .filter(|section| section.flags & IMAGE_SCN_CNT_UNINITIALISED_DATA != 0)
+        .map(|section| align_pe_value(section.virtual_size, PE_FILE_ALIGNMENT))
+        .sum();
+    let base_of_code = pe.sections
+        .iter()
+        .find(|section| section.flags & IMAGE_SCN_CNT_CODE != 0)
+        .map_or(0, |section| section.virtual_address);
+
+    PeOptionalHeader {
+        magic: IMAGE_NT_OPTIONAL_HDR64_MAGIC,
+        major_linker_version: 0,
+        minor_linker_version: 1,
+        size_of_code,
+        size_of_initialised_data,
+        size_of_uninitialised_data,
+        address_of_entry_point: pe.entry_point,
+        base_of_code,
+        image_base: PE_IMAGE_BASE,
+        section_alignment: PE_SECTION_ALIGNMENT,
+        file_alignment: PE_FILE_ALIGNMENT,
+        major_operating_system_version: 6,
+        minor_operating_system_version: 0,
+        major_image_version: 0,
+        minor_image_version: 0,
+        major_subsystem_version: 6,
+        minor_subsystem_version: 0,
+        win32_version_value: 0,
+        size_of_image: get_pe_size_of_image(pe),
+        size_of_headers: get_pe_size_of_headers(pe.sections.len()),
+        checksum: 0,
+        subsystem: IMAGE_SUBSYSTEM_WINDOWS_CUI,
+        dll_characteristics: IMAGE_DLLCHARACTERISTICS_NX_COMPAT | IMAGE_DLLCHARACTERISTICS_TERMINAL_SERVER_AWARE,
+        size_of_stack_reserve: 0x100000,
+        size_of_stack_commit: 0x1000,
+        size_of_heap_reserve: 0x100000,
+        size_of_heap_commit: 0x1000,
+        loader_flags: 0,
+        number_of_rva_and_sizes: IMAGE_NUMBEROF_DIRECTORY_ENTRIES as u32,
+        data_directories: pe.data_directories
+    }
+}
+
+pub fn pe_section_header(section: &PeSection, pointer_to_section: u32) -> CoffSectionHeader {
+    section_header(
+        section.name,
+        section.virtual_size,
+        section.virtual_address,
+        get_pe_section_raw_size(section),
+        if section.contents.is_empty() { 0 } else { pointer_to_section },
+        0,
+        0,
+        0,
+        0,
+        section.flags
+    )
+}
+
+pub fn get_pe_section_raw_size(section: &PeSection) -> u32 {
+    align_pe_value(section.contents.len() as u32, PE_FILE_ALIGNMENT)
+}
+
+fn sum_pe_section_sizes(pe: &Pe, flag: u32) -> u32 {
+    pe.sections
+        .iter()
+        .filter(|section| section.flags & flag != 0)
+        .map(get_pe_section_raw_size)
+        .sum()
+}
+
+fn get_pe_size_of_image(pe: &Pe) -> u32 {
+    match pe.sections.last() {
+        Some(section) => align_pe_value(section.virtual_address + section.virtual_size, PE_SECTION_ALIGNMENT),
+        None => align_pe_value(get_pe_size_of_headers(0), PE_SECTION_ALIGNMENT)
+    }
+}
+
+pub fn align_pe_value(value: u32, alignment: u32) -> u32 {
+    value.next_multiple_of(alignment)
+}
@@ -0,0 +1,90 @@
+use std::mem::size_of;
+
+use crate::utilities::*;
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeImportLibrary {
+    pub name: String,
+    pub functions: Vec<String>
+}
+
+pub fn pe_import_library(name: String, functions: Vec<String>) -> PeImportLibrary {
+    PeImportLibrary { name, functions }
+}
+
+pub struct PeImportTable {
+    pub contents: Vec<u8>,
+    pub import_directory: PeDataDirectory,
+    pub import_address_table_directory: PeDataDirectory,
+    pub function_slots: Vec<(String, u32)>
+}
+
+pub fn build_pe_import_table(libraries: &[PeImportLibrary], virtual_address: u32) -> PeImportTable {
+    let entry_size = size_of::<u64>() as u32;
+    let table_size: u32 = libraries
+        .iter()
+        .map(|library| (library.functions.len() as u32 + 1) * entry_size)
+        .sum();
+    let descriptors_offset = table_size;
+    let descriptors_size = (libraries.len() as u32 + 1) * size_of::<PeImportDescriptor>() as u32;
+    let lookup_tables_offset = descriptors_offset + descriptors_size;
+    let names_offset = lookup_tables_offset + table_size;
+
+    let mut names = vec!();
+    let mut function_name_offsets = vec!();
+    for library in libraries {
+        for function in &library.functions {
+            function_name_offsets.push(names_offset + names.len() as u32);
+            names.extend_from_slice(&[0, 0]);
+            append_pe_import_name(&mut names, function);
+        }
+    }
+    let mut library_name_offsets = vec!();
+    for library in libraries {
+        library_name_offsets.push(names_offset + names.len() as u32);
+        append_pe_import_name(&mut names, &library.name);
+    }
+
+    let mut address_tables = vec!();
+    let mut descriptors = vec!();
+    let mut function_slots = vec!();
+    let mut function_names = function_name_offsets.iter();
+    for (library, library_name_offset) in libraries.iter().zip(library_name_offsets) {
+        let table_offset = address_tables.len() as u32;
+        descriptors.extend_from_slice(any_as_u8_slice(&PeImportDescriptor {
+            import_lookup_table: virtual_address + lookup_tables_offset + table_offset,
+            time_date_stamp: 0,
+            forwarder_chain: 0,
+            name: virtual_address + library_name_offset,
+            import_address_table: virtual_address + table_offset
+        }));
+        for function in &library.functions {
+            function_slots.push((function.clone(), virtual_address + address_tables.len() as u32));
+            let hint_name = (virtual_address + function_names.next().unwrap()) as u64;
+            address_tables.append(&mut u64_to_bytes(&hint_name));
+        }
+        address_tables.append(&mut u64_to_bytes(&0));
+    }
+    descriptors.resize(descriptors_size as usize, 0);
+
+    let mut contents = address_tables.clone();
+    contents.append(&mut descriptors);
+    contents.append(&mut address_tables);
+    contents.append(&mut names);
+
+    PeImportTable {
+        contents,
+        import_directory: PeDataDirectory { virtual_address: virtual_address + descriptors_offset, size: descriptors_size },
+        import_address_table_directory: PeDataDirectory { virtual_address, size: table_size },
+        function_slots
+    }
+}
+
+fn append_pe_import_name(names: &mut Vec<u8>, name: &str) {
+    names.append(&mut string_to_bytes_zero_terminated(name));
+    if !names.len().is_multiple_of(2) {
+        names.push(0);
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use crate::backends::*;
+
+use super::*;
+
+const PE_OBJECT_SECTION_ALIGNMENT: u32 = 16;
+const PE_IMPORT_THUNK_SIZE: u32 = 8;
+const OP_JMP_INDIRECT: [u8; 2] = [0xFF, 0x25];
+const OP_INT3: u8 = 0xCC;
+
+struct PeObjectBases {
+    text: u32,
+    read_only_data: u32,
+    data: u32,
+    bss: u32
+}
+
+struct PeLayout {
+    text: u32,
+    read_only_data: u32,
+    data: u32,
+    bss: u32,
+    object_bases: Vec<PeObjectBases>
+}
+
+pub fn link_pe_executable(objects: &[Coff], import_libraries: &[PeImportLibrary], entry_point: &str) -> Result<Pe, BackendError> {
+    let definitions = get_pe_symbol_definitions(objects)?;
+    let imports = get_referenced_pe_imports(objects, &definitions, import_libraries);
+    let number_of_imported_functions: usize = imports.iter().map(|library| library.functions.len()).sum();
+
+    let mut text = vec!();
+    let mut read_only_data = vec!();
+    let mut data = vec!();
+    let mut bss_size = 0;
+    let mut object_bases = vec!();
+    for object in objects {
+        align_pe_section_contents(&mut text, OP_INT3);
+        align_pe_section_contents(&mut read_only_data, 0);
+        align_pe_section_contents(&mut data, 0);
+        bss_size = align_pe_value(bss_size, PE_OBJECT_SECTION_ALIGNMENT);
+        object_bases.push(PeObjectBases { text: text.len() as u32, read_only_data: read_only_data.len() as u32, data: data.len() as u32, bss: bss_size });
+        text.extend_from_slice(get_text_section(object));
+        read_only_data.extend_from_slice(get_read_only_data_section(object));
+        data.extend_from_slice(get_data_section(object));
+        bss_size += get_bss_section_size(object);
+    }
+    align_pe_section_contents(&mut text, OP_INT3);
+    let thunks_offset = text.len() as u32;
+    let text_size = thunks_offset + number_of_imported_functions as u32 * PE_IMPORT_THUNK_SIZE;
+
+    let number_of_sections = 1 + !imports.is_empty() as usize + !read_only_data.is_empty() as usize + !data.is_empty() as usize + (bss_size > 0) as usize;
+    let text_address = align_pe_value(get_pe_size_of_headers(number_of_sections), PE_SECTION_ALIGNMENT);
+    let imports_address = align_pe_value(text_address + text_size, PE_SECTION_ALIGNMENT);
+    let import_table = build_pe_import_table(&imports, imports_address);
+    let read_only_data_address = if imports.is_empty() {
+        imports_address
+    } else {
+        align_pe_value(imports_address + import_table.contents.len() as u32, PE_SECTION_ALIGNMENT)
+    };
+    let data_address = align_pe_value(read_only_data_address + read_only_data.len() as u32, PE_SECTION_ALIGNMENT);
+    let bss_address = align_pe_value(data_address + data.len() as u32, PE_SECTION_ALIGNMENT);
+    let layout = PeLayout { text: text_address, read_only_data: read_only_data_address, data: data_address, bss: bss_address, object_bases };
+
+    let mut addresses = HashMap::new();
+    for (name, (object_index, definition)) in &definitions {
+        addresses.insert(name.clone(), get_pe_defined_symbol_address(&layout, *object_index, definition));
+    }
+    for (index, (name, slot)) in import_table.function_slots.iter().enumerate() {
+        let thunk_address = text_address + thunks_offset + index as u32 * PE_IMPORT_THUNK_SIZE;
+        add_pe_import_thunk(&mut text, slot - (thunk_address + 6));
+        addresses.insert(name.clone(), PE_IMAGE_BASE + thunk_address as u64);
+    }
+
+    for (object_index, object) in objects.iter().enumerate() {
+        let bases = &layout.object_bases[object_index];
+        let text_base = bases.text as usize;
+        let data_base = bases.data as usize;
+        let text_section = &mut text[text_base..text_base + get_text_section(object).len()];
+        apply_pe_relocations(object, object_index, text_section, COFF_SECTION_TEXT, get_text_section_relocation_entries(object), &layout, &addresses)?;
+        let data_section = &mut data[data_base..data_base + get_data_section(object).len()];
+        apply_pe_relocations(object, object_index, data_section, COFF_SECTION_DATA, get_data_section_relocation_entries(object), &layout, &addresses)?;
+    }
+
+    let mut pe = create_pe();
+    pe.entry_point = match definitions.get(entry_point) {
+        Some((_, definition)) if definition.section_number == COFF_SECTION_TEXT =>
+            (addresses[entry_point] - PE_IMAGE_BASE) as u32,
+        _ => return Err(entry_point_not_found_error(entry_point))
+    };
+
+    add_pe_section(&mut pe, ".text", text_address, text, IMAGE_SCN_CNT_CODE | IMAGE_SCN_MEM_EXECUTE | IMAGE_SCN_MEM_READ);
+    if !imports.is_empty() {
+        pe.data_directories[IMAGE_DIRECTORY_ENTRY_IMPORT] = import_table.import_directory;
+        pe.data_directories[IMAGE_DIRECTORY_ENTRY_IAT] = import_table.import_address_table_directory;
+        add_pe_section(&mut pe, ".idata", imports_address, import_table.contents, IMAGE_SCN_CNT_INITIALISED_DATA | IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_WRITE);
+    }
+    if !read_only_data.is_empty() {
+        add_pe_section(&mut pe, ".rdata", read_only_data_address, read_only_data, IMAGE_SCN_CNT_INITIALISED_DATA | IMAGE_SCN_MEM_READ);
+    }
+    if !data.is_empty() {
+        add_pe_section(&mut pe, ".data", data_address, data, IMAGE_SCN_CNT_INITIALISED_DATA | IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_WRITE);
+    }
+    if bss_size > 0 {
+        pe.sections.push(PeSection {
+            name: ".bss",
+            virtual_address: bss_address,
+            virtual_size: bss_size,
+            contents: vec!(),
+            flags: IMAGE_SCN_CNT_UNINITIALISED_DATA | IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_WRITE
+        });
+    }
+
+    Ok(pe)
+}
+
+pub fn get_pe_entry_point(pe: &Pe) -> u32 {
+    pe.entry_point
+}
+
+pub fn get_pe_section_contents<'a>(pe: &'a Pe, name: &str) -> Option<&'a [u8]> {
+    pe.sections
+        .iter()
+        .find(|section| section.name == name)
+        .map(|section| section.contents.as_slice())
+}
+
+pub fn get_pe_section_virtual_address(pe: &Pe, name: &str) -> Option<u32> {
+    pe.sections
+        .iter()
+        .find(|section| section.name == name)
+        .map(|section| section.virtual_address)
+}
+
+pub fn get_pe_data_directory(pe: &Pe, index: usize) -> PeDataDirectory {
+    pe.data_directories[index]
+}
+
+fn add_pe_section(pe: &mut Pe, name: &'static str, virtual_address: u32, contents: Vec<u8>, flags: u32) {
+    pe.sections.push(PeSection { name, virtual_address, virtual_size: contents.len() as u32, contents, flags });
+}
+
+fn align_pe_section_contents(contents: &mut Vec<u8>, padding: u8) {
+    contents.resize(align_pe_value(contents.len() as u32, PE_OBJECT_SECTION_ALIGNMENT) as usize, padding);
+}
+
+fn add_pe_import_thunk(text: &mut Vec<u8>, displacement: u32) {
+    text.extend_from_slice(&OP_JMP_INDIRECT);
+    text.extend_from_slice(&displacement.to_le_bytes());
+    text.resize(text.len() + (PE_IMPORT_THUNK_SIZE as usize - 6), OP_INT3);
+}
+
+type PeSymbolDefinitions = HashMap<String, (usize, CoffSymbolDefinition)>;
+
+fn get_pe_symbol_definitions(objects: &[Coff]) -> Result<PeSymbolDefinitions, BackendError> {
+    let mut definitions = HashMap::new();
+    for (object_index, object) in objects.iter().enumerate() {
+        for definition in get_coff_external_symbols(object) {
+            if definition.section_number == IMAGE_SYM_UNDEFINED {
+                continue;
+            }
+            if definitions.contains_key(&definition.name) {
+                return Err(duplicate_symbol_error(&definition.name, &get_coff_file_name(object)));
+            }
+            definitions.insert(definition.name.clone(), (object_index, definition));
+        }
+    }
+    Ok(definitions)
+}
+
+fn get_referenced_pe_imports(objects: &[Coff], definitions: &PeSymbolDefinitions, import_libraries: &[PeImportLibrary]) -> Vec<PeImportLibrary> {
+    let mut referenced = vec!();
+    for object in objects {
+        for definition in get_coff_external_symbols(object) {
+            if definition.section_number != IMAGE_SYM_UNDEFINED || definitions.contains_key(&definition.name) || referenced.contains(&definition.name) {
+                continue;
+            }
+            if import_libraries.iter().any(|library| library.functions.contains(&definition.name)) {
+                referenced.push(definition.name);
+            }
+        }
+    }
+
+    import_libraries
+        .iter()
+        .map(|library| pe_import_library(
+            library.name.clone(),
+            library.functions.iter().filter(|function| referenced.contains(function)).cloned().collect()
+        ))
+        .filter(|library| !library.functions.is_empty())
+        .collect()
+}
+
+fn get_coff_external_symbols(object: &Coff) -> Vec<CoffSymbolDefinition> {
+    get_coff_symbol_definitions(object)
+        .into_iter()
+        .map(|(_, definition)| definition)
+        .filter(|definition| definition.storage_class == IMAGE_SYM_CLASS_EXTERNAL)
+        .collect()
+}
+
+fn get_pe_defined_symbol_address(layout: &PeLayout, object_index: usize, definition: &CoffSymbolDefinition) -> u64 {
+    let bases = &layout.object_bases[object_index];
+    match definition.section_number {
+        COFF_SECTION_TEXT => PE_IMAGE_BASE + (layout.text + bases.text + definition.value) as u64,
+        COFF_SECTION_DATA => PE_IMAGE_BASE + (layout.data + bases.data + definition.value) as u64,
+        COFF_SECTION_READ_ONLY_DATA => PE_IMAGE_BASE + (layout.read_only_data + bases.read_only_data + definition.value) as u64,
+        COFF_SECTION_BSS => PE_IMAGE_BASE + (layout.bss + bases.bss + definition.value) as u64,
+        _ => definition.value as u64
+    }
+}
+
+fn apply_pe_relocations(
+    object: &Coff,
+    object_index: usize,
+    section: &mut [u8],
+    section_number: u16,
+    relocations: &[CoffRelocationEntry],
+    layout: &PeLayout,
+    addresses: &HashMap<String, u64>
+) -> BackendErrorResult {
+    let bases = &layout.object_bases[object_index];
+    let section_address = match section_number {
+        COFF_SECTION_TEXT => layout.text + bases.text,
+        _ => layout.data + bases.data
+    };
+
+    for entry in relocations {
+        let (offset, symbol_index, relocation_type) = get_relocation_entry_parts(entry);
+        let definition = get_coff_symbol_definition(object, symbol_index);
+        let symbol_address = if definition.section_number == IMAGE_SYM_UNDEFINED {
+            match addresses.get(&definition.name) {
+                Some(address) => *address,
+                None => {
+                    let referenced_by = get_coff_external_symbol_containing(object, section_number, offset).unwrap_or_else(|| get_coff_file_name(object));
+                    return Err(undefined_symbol_error(&definition.name, &referenced_by));
+                }
+            }
+        } else {
+            get_pe_defined_symbol_address(layout, object_index, &definition)
+        };
+
+        let place = PE_IMAGE_BASE + (section_address + offset) as u64;
+        apply_pe_relocation(section, offset as usize, relocation_type, symbol_address, place, &definition.name)?;
+    }
+    Ok(())
+}
+
+fn apply_pe_relocation(section: &mut [u8], start: usize, relocation_type: u16, symbol_address: u64, place: u64, name: &str) -> BackendErrorResult {
+    match relocation_type {
+        IMAGE_REL_AMD64_ADDR64 => {
+            let addend = i64::from_le_bytes(section[start..start + 8].try_into().unwrap());
+            let value = (symbol_address as i64).wrapping_add(addend);
+            section[start..start + 8].copy_from_slice(&value.to_le_bytes());
+        },
+        IMAGE_REL_AMD64_ADDR32 => {
+            let addend = i32::from_le_bytes(section[start..start + 4].try_into().unwrap()) as i64;
+            let value = u32::try_from(symbol_address as i64 + addend).map_err(|_| relocation_out_of_range_error(name))?;
+            section[start..start + 4].copy_from_slice(&value.to_le_bytes());
+        },
+        IMAGE_REL_AMD64_REL32 => {
+            let addend = i32::from_le_bytes(section[start..start + 4].try_into().unwrap()) as i64;
+            let value = i32::try_from(symbol_address as i64 + addend - (place as i64 + 4)).map_err(|_| relocation_out_of_range_error(name))?;
+            section[start..start + 4].copy_from_slice(&value.to_le_bytes());
+        },
+        _ => return Err(unsupported_instruction_error())
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+mod headers;
+mod imports;
+mod linking;
+mod files;
+
+pub use headers::*;
+pub use imports::*;
+pub use linking::*;
+pub use files::*;
+
+const IMAGE_NT_OPTIONAL_HDR64_MAGIC: u16 = 0x20B;
+const IMAGE_SUBSYSTEM_WINDOWS_CUI: u16 = 3;
+const IMAGE_NUMBEROF_DIRECTORY_ENTRIES: usize = 16;
+const IMAGE_DIRECTORY_ENTRY_IMPORT: usize = 1;
+const IMAGE_DIRECTORY_ENTRY_IAT: usize = 12;
+
+const IMAGE_FILE_RELOCS_STRIPPED: u16 = 0x0001;
+const IMAGE_FILE_EXECUTABLE_IMAGE: u16 = 0x0002;
+const IMAGE_FILE_LARGE_ADDRESS_AWARE: u16 = 0x0020;
+
+const IMAGE_DLLCHARACTERISTICS_NX_COMPAT: u16 = 0x0100;
+const IMAGE_DLLCHARACTERISTICS_TERMINAL_SERVER_AWARE: u16 = 0x8000;
+
+const IMAGE_SCN_CNT_CODE: u32 = 0x00000020;
+const IMAGE_SCN_CNT_INITIALISED_DATA: u32 = 0x00000040;
+const IMAGE_SCN_CNT_UNINITIALISED_DATA: u32 = 0x00000080;
+const IMAGE_SCN_MEM_EXECUTE: u32 = 0x20000000;
+const IMAGE_SCN_MEM_READ: u32 = 0x40000000;
+const IMAGE_SCN_MEM_WRITE: u32 = 0x80000000;
+
+pub const PE_IMAGE_BASE: u64 = 0x400000;
+pub const PE_SECTION_ALIGNMENT: u32 = 0x1000;
+pub const PE_FILE_ALIGNMENT: u32 = 0x200;
+pub const PE_HEADERS_OFFSET: u32 = 0x40;
+
+pub struct Pe {
+    entry_point: u32,
+    sections: Vec<PeSection>,
+    data_directories: [PeDataDirectory; IMAGE_NUMBEROF_DIRECTORY_ENTRIES]
+}
+
+pub struct PeSection {
+    name: &'static str,
+    virtual_address: u32,
+    virtual_size: u32,
+    contents: Vec<u8>,
+    flags: u32
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct PeDosHeader {
+    magic: [u8; 2],
+    unused: [u8; 58],
+    new_header_offset: u32
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct PeOptionalHeader {
+    magic: u16,
+    major_linker_version: u8,
+    minor_linker_version: u8,
+    size_of_code: u32,
+    size_of_initialised_data: u32,
+    size_of_uninitialised_data: u32,
+    address_of_entry_point: u32,
+    base_of_code: u32,
+    image_base: u64,
+    section_alignment: u32,
+    file_alignment: u32,
+    major_operating_system_version: u16,
+    minor_operating_system_version: u16,
+    major_image_version: u16,
+    minor_image_version: u16,
+    major_subsystem_version: u16,
+    minor_subsystem_version: u16,
+    win32_version_value: u32,
+    size_of_image: u32,
+    size_of_headers: u32,
+    checksum: u32,
+    subsystem: u16,
+    dll_characteristics: u16,
+    size_of_stack_reserve: u64,
+    size_of_stack_commit: u64,
+    size_of_heap_reserve: u64,
+    size_of_heap_commit: u64,
+    loader_flags: u32,
+    number_of_rva_and_sizes: u32,
+    data_directories: [PeDataDirectory; IMAGE_NUMBEROF_DIRECTORY_ENTRIES]
+}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct PeDataDirectory {
+    pub virtual_address: u32,
+    pub size: u32
+}
+
+#[repr(C, packed)]
+#[allow(dead_code)]
+pub struct PeImportDescriptor {
+    import_lookup_table: u32,
+    time_date_stamp: u32,
+    forwarder_chain: u32,
+    name: u32,
+    import_address_table: u32
+}
+
+pub fn create_pe() -> Pe {
+    Pe {
+        entry_point: 0,
+        sections: vec!(),
+        data_directories: [PeDataDirectory::default(); IMAGE_NUMBEROF_DIRECTORY_ENTRIES]
+    }
+}
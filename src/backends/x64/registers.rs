@@ -4,6 +4,8 @@ pub const REG_DX: u8 = 0x02;
 pub const REG_SP: u8 = 0x04;
 pub const REG_BP: u8 = 0x05;
 pub const REG_IP: u8 = 0x05;
+pub const REG_SI: u8 = 0x06;
+pub const REG_DI: u8 = 0x07;
 pub const REG_R8: u8 = 0x08; 
 pub const REG_R9: u8 = 0x09;   
 pub const REG_R10: u8 = 0x0A;   
@@ -20,3 +22,12 @@ pub fn register_has_high_bit(register: u8) -> bool {
 pub fn remove_register_high_bit(register: u8) -> u8 {
     register & 0x7
 }
+
+pub const REG_XMM0: u8 = 0x00;
+pub const REG_XMM1: u8 = 0x01;
+pub const REG_XMM2: u8 = 0x02;
+pub const REG_XMM3: u8 = 0x03;
+pub const REG_XMM4: u8 = 0x04;
+pub const REG_XMM5: u8 = 0x05;
+pub const REG_XMM6: u8 = 0x06;
+pub const REG_XMM7: u8 = 0x07;
@@ -48,7 +48,7 @@ pub fn dequeue<T>(queue: &mut Queue<T>) -> Option<T> {
     }
 }
 
-fn is_queue_empty<T>(queue: &Queue<T>) -> bool {
+pub fn is_queue_empty<T>(queue: &Queue<T>) -> bool {
     match queue.end {
         None => true,
         _ => false,
@@ -13,16 +13,29 @@ pub fn build_backend<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap:
     backend: TBackend,
     unit: CompilationUnit,
     code: IntermediateRepresentation) {
-    start_compilation_phase(&mut compiler.statistics, backend_build_compilation_phase(unit.id));
+    build_backend_with_id(compiler, ctx, backend, unit.id, code);
+}
 
-    let byte_code_runner = start_backend_actor(
-        create_self_handle(ctx), 
-        compiler.error_reporter.clone(),
-        backend,
-        ctx
-    );
-    
-    send_build_backend_command_to_actor(byte_code_runner, code, compiler.errors_have_occurred);
+pub fn build_backend_with_id<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext,
+    backend: TBackend,
+    id: CompilationUnitId,
+    code: IntermediateRepresentation) {
+    start_compilation_phase(&mut compiler.statistics, backend_build_compilation_phase(id));
+
+    let backend_builder = get_or_start_backend_actor(compiler, backend, ctx);
+    send_build_backend_command_to_actor(backend_builder, code, compiler.errors_have_occurred);
+}
+
+fn get_or_start_backend_actor<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    backend: TBackend,
+    ctx: &CompilationMessageContext
+) -> CompilationActorHandle {
+    compiler.backend_builder
+        .get_or_insert_with(|| start_backend_actor(create_self_handle(ctx), compiler.error_reporter.clone(), backend, ctx))
+        .clone()
 }
 
 fn start_backend_actor<TBackend: BackendBuild>(
@@ -38,13 +51,28 @@ fn start_backend_actor<TBackend: BackendBuild>(
     byte_code_runner
 }
 
-fn send_build_backend_command_to_actor(byte_code_runner: CompilationActorHandle, code: IntermediateRepresentation, has_prior_errors: bool) {
+fn send_build_backend_command_to_actor(backend_builder: CompilationActorHandle, code: IntermediateRepresentation, has_prior_errors: bool) {
     send_message_to_actor(
-        &byte_code_runner, 
+        &backend_builder, 
         create_build_backend_command(code,  has_prior_errors)
     );
 }
 
+pub fn emit_backend<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>
+) -> bool {
+    match compiler.backend_builder.take() {
+        Some(backend_builder) => {
+            send_message_to_actor(
+                &backend_builder,
+                create_emit_backend_command(compiler.file_name.clone(), compiler.errors_have_occurred)
+            );
+            true
+        },
+        None => false
+    }
+}
+
 pub fn handle_backend_built<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
     compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
     id: CompilationUnitId,
@@ -52,4 +80,11 @@ pub fn handle_backend_built<TReader: FileRead, TBackend: BackendBuild, TMessageW
 ) -> AfterReceiveAction {    
     end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, backend_build_compilation_phase(id), ctx);
     continue_listening_after_receive()
-}
\ No newline at end of file
+}
+pub fn handle_backend_emitted<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext
+) -> AfterReceiveAction {
+    flush_error_reports(&compiler.error_reporter, create_self_handle(ctx));
+    continue_listening_after_receive()
+}
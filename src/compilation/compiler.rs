@@ -4,6 +4,7 @@ use crate::{
     acting::*,
     file_system::*,
     backends::*,
+    interpretation::*,
     errors::*, string_type_name,
 };
 
@@ -12,24 +13,46 @@ pub fn compile<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireT
     reader: TReader,
     backend: TBackend,
     message_wire_tap: TMessageWireTap
+) {
+    compile_with_module_paths(file_name, vec!(), reader, backend, message_wire_tap);
+}
+
+pub fn compile_with_module_paths<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    file_name: String,
+    module_paths: Vec<String>,
+    reader: TReader,
+    backend: TBackend,
+    message_wire_tap: TMessageWireTap
+) {
+    compile_for_target(file_name, module_paths, windows_target(), reader, backend, message_wire_tap);
+}
+
+pub fn compile_for_target<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    file_name: String,
+    module_paths: Vec<String>,
+    target: CompilationTarget,
+    reader: TReader,
+    backend: TBackend,
+    message_wire_tap: TMessageWireTap
 ) {
     let type_repository = create_type_repository_actor();
     let error_reporter = create_error_reporter_actor(reader.clone());
     let (type_repository_handle, ..) = start_singleton_actor(type_repository);
     let (error_reporter_handle, ..) = start_singleton_actor(error_reporter);
+    let (interpreter_handle, ..) = start_singleton_actor(create_interpreter_actor(error_reporter_handle.clone()));
     let (compiler_handle, compiler_shutdown_notifier) = start_singleton_actor(
-        create_compiler_actor(type_repository_handle, error_reporter_handle, reader, backend, message_wire_tap)
+        create_compiler_actor(type_repository_handle, error_reporter_handle, interpreter_handle, reader, backend, message_wire_tap, create_modules(module_paths))
     );
 
-    start_compilation(compiler_handle, file_name);
+    start_compilation(compiler_handle, file_name, target);
 
     await_shutdown(&compiler_shutdown_notifier);
 }
 
-fn start_compilation(compiler_handle: ActorHandle<CompilationMessage>, file_name: String) {
+fn start_compilation(compiler_handle: ActorHandle<CompilationMessage>, file_name: String, target: CompilationTarget) {
     send_message_to_actor(
         &compiler_handle, 
-        create_compile_command(file_name)
+        create_compile_command(file_name, target)
     );
 }
 
@@ -37,27 +60,47 @@ pub struct CompilerActor<TReader: FileRead, TBackend: BackendBuild, TMessageWire
     pub statistics: Statistics,
     pub type_repository: CompilationActorHandle,
     pub error_reporter: CompilationActorHandle,
+    pub interpreter: CompilationActorHandle,
     pub reader: TReader,
     pub backend: TBackend,
     pub message_wire_tap: TMessageWireTap,
-    pub errors_have_occurred: bool
+    pub errors_have_occurred: bool,
+    pub emitted_type_infos: EmittedTypeInfos,
+    pub interception: Interception,
+    pub modules: Modules,
+    pub loads: Loads,
+    pub preload: Preload,
+    pub target: CompilationTarget,
+    pub file_name: String,
+    pub backend_builder: Option<CompilationActorHandle>
 }
 
 fn create_compiler_actor<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
     type_repository: CompilationActorHandle,
     error_reporter: CompilationActorHandle,
+    interpreter: CompilationActorHandle,
     reader: TReader,
     backend: TBackend, 
-    message_wire_tap: TMessageWireTap
+    message_wire_tap: TMessageWireTap,
+    modules: Modules
 ) -> CompilerActor<TReader, TBackend, TMessageWireTap> {
     CompilerActor {
         statistics: create_statistics(),
         type_repository,
         error_reporter, 
+        interpreter,
         reader,
         backend,
         message_wire_tap,
-        errors_have_occurred: false
+        errors_have_occurred: false,
+        emitted_type_infos: EmittedTypeInfos::default(),
+        interception: Interception::default(),
+        modules,
+        loads: Loads::default(),
+        preload: Preload::default(),
+        target: CompilationTarget::default(),
+        file_name: String::default(),
+        backend_builder: None
     }
 }
 
@@ -65,22 +108,36 @@ impl <TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompila
     fn receive(&mut self, message: CompilationMessage, ctx: &CompilationMessageContext) -> AfterReceiveAction {
         self.message_wire_tap.tap(&message);
         match message {
-            CompilationMessage::Compile(file_name) =>
-                handle_compile(self, ctx, file_name ),
+            CompilationMessage::Compile { file_name, target } =>
+                handle_compile(self, ctx, file_name, target),
             CompilationMessage::FileParsed { units, file_name } =>
                 handle_file_parsed(self, file_name, units, ctx),
             CompilationMessage::UnitTyped { resolved_types, unit } => 
                 handle_unit_typed(self, unit, resolved_types, ctx),
             CompilationMessage::TypeFindRequested { awaiting_unit_id } => 
-                handle_type_find_requested(self, awaiting_unit_id),
-            CompilationMessage::UnitSized { unit } => 
-                handle_unit_sized(self, unit, ctx),
+                handle_type_find_requested(self, awaiting_unit_id, ctx),
+            CompilationMessage::TypeFindCompleted { awaiting_unit_id } => 
+                handle_type_find_completed(self, awaiting_unit_id),
+            CompilationMessage::AwaitedTypeRequestsConfirmed { check } =>
+                handle_awaited_type_requests_confirmed(self, check),
+            CompilationMessage::UnitSized { sized_types, unit } => 
+                handle_unit_sized(self, unit, sized_types, ctx),
             CompilationMessage::ByteCodeBuilt { code, unit  } => 
                 handle_byte_code_built(self, unit, code, ctx, self.backend.clone()),
+            CompilationMessage::ByteCodeExecuted { code, unit, requests } => 
+                handle_byte_code_executed(self, unit, code, requests, ctx),
+            CompilationMessage::InterceptedMessageDelivered { id, handler, requests, .. } => 
+                handle_intercepted_message_delivered(self, id, handler, requests, ctx),
             CompilationMessage::BackendBuilt { id, .. } => 
                 handle_backend_built(self, id, ctx),
+            CompilationMessage::BackendEmitted => 
+                handle_backend_emitted(self, ctx),
+            CompilationMessage::ErrorsFound => 
+                handle_errors_found(self),
             CompilationMessage::ErrorsReported(_) => 
                 handle_errors_reported(self),
+            CompilationMessage::AllUnitsCompiled =>
+                handle_all_units_compiled(self, ctx),
             CompilationMessage::CompilationComplete =>
                 handle_compilation_complete(self),
             _ => continue_listening_after_receive()
@@ -96,8 +153,21 @@ pub fn handle_compile<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap
     compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
     ctx: &CompilationMessageContext,
     file_name: String,
+    target: CompilationTarget
+) -> AfterReceiveAction {
+    compiler.target = target;
+    compiler.file_name = normalise_file_name(&file_name);
+    compile_preload(compiler, ctx);
+    continue_listening_after_receive()
+}
+
+pub fn handle_all_units_compiled<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext
 ) -> AfterReceiveAction {
-    parse_file(compiler, file_name, ctx);
+    if !intercept_completion(compiler, ctx) && !emit_backend(compiler) {
+        flush_error_reports(&compiler.error_reporter, create_self_handle(ctx));
+    }
     continue_listening_after_receive()
 }
 
@@ -106,6 +176,7 @@ pub fn handle_compilation_complete<TReader: FileRead, TBackend: BackendBuild, TM
 ) -> AfterReceiveAction {
     shut_down(&compiler.type_repository);
     shut_down(&compiler.error_reporter);
+    shut_down(&compiler.interpreter);
     shutdown_after_receive()
 }
 
@@ -4,17 +4,30 @@ use crate::{
     file_system::*,
     acting::*,
     backends::*,
+    interpretation::*,
     errors::*,
 };
 
+pub fn handle_errors_found<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage> (
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>
+) -> AfterReceiveAction {
+    compiler.errors_have_occurred = true;
+    continue_listening_after_receive()
+}
+
 pub fn handle_errors_reported<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage> (
     compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>
 ) -> AfterReceiveAction {
     compiler.errors_have_occurred = true;
     circuit_break_all_type_requests(&compiler.type_repository, compilation_error_type_request_circuit_break_reason(shutdown_requested_error_item()));
+    circuit_break_all_executions(&compiler.interpreter, compilation_error_execution_circuit_break_reason(shutdown_requested_error_item()));
     continue_listening_after_receive()
 }
 
+pub fn flush_error_reports(error_reporter: &CompilationActorHandle, compiler: CompilationActorHandle) {
+    send_message_to_actor(error_reporter, create_flush_error_reports_command(compiler));
+}
+
 pub fn report_errors(
     error_reporter: &CompilationActorHandle,
     compiler: CompilationActorHandle,
@@ -23,6 +36,8 @@ pub fn report_errors(
     if !are_any_compilation_errors(&errors) {
         return;
     }
+
+    send_message_to_actor(&compiler, create_errors_found_event());
     
     send_message_to_actor(
         error_reporter, 
@@ -0,0 +1,44 @@
+use crate::{
+    compilation::*,
+    parsing::*,
+    acting::*,
+    file_system::*,
+    backends::*,
+    strings::*,
+    utilities::*
+};
+
+pub fn is_immediate_insert_unit(unit: &CompilationUnit) -> bool {
+    matches!(
+        try_get_insert_directive_expr(unit.tree.item_ref()).map(|expr| expr.item_ref()),
+        Some(AbstractSyntaxNodeItem::Code { .. }) |
+        Some(AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(_))))
+    )
+}
+
+pub fn perform_insertion<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    ctx: &CompilationMessageContext
+) {
+    if let Some(expr) = try_get_insert_directive_expr(unit.tree.item_ref()) {
+        match expr.item_ref() {
+            AbstractSyntaxNodeItem::Code { units } =>
+                dispatch_units(compiler, clone_code_units_with_new_ids(units), ctx),
+            AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(source))) =>
+                perform_insertion_of_source(compiler, &unit.filename, source, ctx),
+            _ => {}
+        }
+    }
+}
+
+fn perform_insertion_of_source<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    filename: &str,
+    source: &ByteString,
+    ctx: &CompilationMessageContext
+) {
+    let (units, errors) = parse(string(filename), &byte_string_to_string(source));
+    report_errors(&compiler.error_reporter, create_self_handle(ctx), errors);
+    dispatch_units(compiler, units, ctx);
+}
@@ -0,0 +1,310 @@
+use std::{collections::HashSet, io::*};
+
+use crate::{
+    compilation::*,
+    parsing::*,
+    acting::*,
+    file_system::*,
+    intermediate_representation::*,
+    interpretation::*,
+    backends::*,
+    types::*,
+    strings::*,
+    utilities::*
+};
+
+pub const INTERCEPTED_NODE_KIND_OTHER: u64 = 0;
+pub const INTERCEPTED_NODE_KIND_PROCEDURE_HEADER: u64 = 1;
+pub const INTERCEPTED_NODE_KIND_PROCEDURE_BODY: u64 = 2;
+pub const INTERCEPTED_NODE_KIND_STRUCT: u64 = 3;
+pub const INTERCEPTED_NODE_KIND_CONSTANT: u64 = 4;
+pub const INTERCEPTED_NODE_KIND_GLOBAL_VARIABLE: u64 = 5;
+pub const INTERCEPTED_NODE_KIND_RUN: u64 = 6;
+pub const INTERCEPTED_NODE_KIND_ASSERT: u64 = 7;
+pub const INTERCEPTED_NODE_KIND_INSERT: u64 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterceptedMessageKind {
+    FileParsed = 0,
+    UnitTyped = 1,
+    CodeGenerated = 2,
+    Complete = 3
+}
+
+pub fn try_get_intercepted_message_kind(value: u64) -> Option<InterceptedMessageKind> {
+    match value {
+        0 => Some(InterceptedMessageKind::FileParsed),
+        1 => Some(InterceptedMessageKind::UnitTyped),
+        2 => Some(InterceptedMessageKind::CodeGenerated),
+        3 => Some(InterceptedMessageKind::Complete),
+        _ => None
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterceptedMessage {
+    pub kind: InterceptedMessageKind,
+    pub name: String,
+    pub node_kind: u64,
+    pub count: u64,
+    pub type_info: OptionalRuntimeTypePointer
+}
+
+pub fn file_parsed_intercepted_message(file_name: &str, units: &CompilationUnits) -> InterceptedMessage {
+    InterceptedMessage {
+        kind: InterceptedMessageKind::FileParsed,
+        name: string(file_name),
+        node_kind: INTERCEPTED_NODE_KIND_OTHER,
+        count: units.len() as u64,
+        type_info: None
+    }
+}
+
+pub fn unit_typed_intercepted_message(unit: &CompilationUnit, sized_types: &RuntimeTypePointers) -> InterceptedMessage {
+    InterceptedMessage {
+        kind: InterceptedMessageKind::UnitTyped,
+        name: get_intercepted_unit_name(unit),
+        node_kind: get_intercepted_node_kind(unit),
+        count: sized_types.len() as u64,
+        type_info: sized_types.first().cloned()
+    }
+}
+
+pub fn code_generated_intercepted_message(unit: &CompilationUnit, code: &IntermediateRepresentation) -> InterceptedMessage {
+    InterceptedMessage {
+        kind: InterceptedMessageKind::CodeGenerated,
+        name: code.top_level_symbol.clone(),
+        node_kind: get_intercepted_node_kind(unit),
+        count: code.byte_code.len() as u64,
+        type_info: None
+    }
+}
+
+pub fn complete_intercepted_message() -> InterceptedMessage {
+    InterceptedMessage {
+        kind: InterceptedMessageKind::Complete,
+        name: empty_string(),
+        node_kind: INTERCEPTED_NODE_KIND_OTHER,
+        count: 0,
+        type_info: None
+    }
+}
+
+fn get_intercepted_unit_name(unit: &CompilationUnit) -> String {
+    match unit.tree.item_ref() {
+        AbstractSyntaxNodeItem::ProcedureHeader { name, .. } |
+        AbstractSyntaxNodeItem::ProcedureBody { name, .. } |
+        AbstractSyntaxNodeItem::Struct { name, .. } |
+        AbstractSyntaxNodeItem::Constant { name, .. } |
+        AbstractSyntaxNodeItem::GlobalVariable { name, .. } => name.clone(),
+        _ => empty_string()
+    }
+}
+
+fn get_intercepted_node_kind(unit: &CompilationUnit) -> u64 {
+    match unit.tree.item_ref() {
+        AbstractSyntaxNodeItem::ProcedureHeader { .. } => INTERCEPTED_NODE_KIND_PROCEDURE_HEADER,
+        AbstractSyntaxNodeItem::ProcedureBody { .. } => INTERCEPTED_NODE_KIND_PROCEDURE_BODY,
+        AbstractSyntaxNodeItem::Struct { .. } => INTERCEPTED_NODE_KIND_STRUCT,
+        AbstractSyntaxNodeItem::Constant { .. } => INTERCEPTED_NODE_KIND_CONSTANT,
+        AbstractSyntaxNodeItem::GlobalVariable { .. } => INTERCEPTED_NODE_KIND_GLOBAL_VARIABLE,
+        AbstractSyntaxNodeItem::Run { .. } => INTERCEPTED_NODE_KIND_RUN,
+        AbstractSyntaxNodeItem::Assert { .. } => INTERCEPTED_NODE_KIND_ASSERT,
+        AbstractSyntaxNodeItem::Insert { .. } => INTERCEPTED_NODE_KIND_INSERT,
+        _ => INTERCEPTED_NODE_KIND_OTHER
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterceptHandler {
+    pub name: String,
+    pub filename: String,
+    pub position: SourceFilePosition
+}
+
+pub fn intercept_handler(name: String, filename: String, position: SourceFilePosition) -> InterceptHandler {
+    InterceptHandler {
+        name,
+        filename,
+        position
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InterceptedMessageDelivery {
+    pub id: CompilationUnitId,
+    pub handler: InterceptHandler,
+    pub message: InterceptedMessage,
+    pub code: IntermediateRepresentation
+}
+
+#[derive(Default)]
+pub struct Interception {
+    handlers: Vec<(InterceptedMessageKind, InterceptHandler)>,
+    history: Vec<InterceptedMessage>,
+    generated_units: HashSet<CompilationUnitId>,
+    complete_delivered: bool
+}
+
+pub fn intercept_message<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    message: InterceptedMessage,
+    ctx: &CompilationMessageContext
+) {
+    compiler.interception.history.push(message.clone());
+    for handler in find_intercept_handlers(&compiler.interception, message.kind) {
+        deliver_intercepted_message(compiler, handler, message.clone(), ctx);
+    }
+}
+
+pub fn intercept_unit_message<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    message: InterceptedMessage,
+    ctx: &CompilationMessageContext
+) {
+    if !compiler.interception.generated_units.contains(&unit.id) {
+        intercept_message(compiler, message, ctx);
+    }
+}
+
+pub fn intercept_completion<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext
+) -> bool {
+    if compiler.interception.complete_delivered {
+        return false;
+    }
+    compiler.interception.complete_delivered = true;
+
+    let handlers = find_intercept_handlers(&compiler.interception, InterceptedMessageKind::Complete);
+    for handler in handlers.iter().cloned() {
+        deliver_intercepted_message(compiler, handler, complete_intercepted_message(), ctx);
+    }
+    !handlers.is_empty()
+}
+
+pub fn perform_compiler_requests<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    filename: &str,
+    position: SourceFilePosition,
+    requests: CompilerRequests,
+    ctx: &CompilationMessageContext
+) {
+    for request in requests {
+        match request {
+            CompilerRequest::Intercept { kind, handler } =>
+                start_intercepting(compiler, kind, intercept_handler(handler, string(filename), position), ctx),
+            CompilerRequest::AddBuildString(source) =>
+                add_build_string_to_compilation(compiler, filename, &source, ctx),
+            CompilerRequest::WriteStandardOutput(output) =>
+                write_standard_output(&output)
+        }
+    }
+}
+
+fn write_standard_output(output: &ByteString) {
+    let mut standard_output = stdout();
+    if standard_output.write_all(output).is_ok() {
+        standard_output.flush().unwrap_or_default();
+    }
+}
+
+fn start_intercepting<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    kind: InterceptedMessageKind,
+    handler: InterceptHandler,
+    ctx: &CompilationMessageContext
+) {
+    compiler.interception.handlers.push((kind, handler.clone()));
+
+    let missed_messages: Vec<InterceptedMessage> = compiler.interception.history
+        .iter()
+        .filter(|message| message.kind == kind)
+        .cloned()
+        .collect();
+
+    for message in missed_messages {
+        deliver_intercepted_message(compiler, handler.clone(), message, ctx);
+    }
+}
+
+fn add_build_string_to_compilation<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    filename: &str,
+    source: &ByteString,
+    ctx: &CompilationMessageContext
+) {
+    let (units, errors) = parse(string(filename), &byte_string_to_string(source));
+    report_errors(&compiler.error_reporter, create_self_handle(ctx), errors);
+    compiler.interception.generated_units.extend(units.iter().map(|unit| unit.id));
+    dispatch_units(compiler, units, ctx);
+}
+
+fn find_intercept_handlers(interception: &Interception, kind: InterceptedMessageKind) -> Vec<InterceptHandler> {
+    interception.handlers
+        .iter()
+        .filter(|(handler_kind, _)| *handler_kind == kind)
+        .map(|(_, handler)| handler.clone())
+        .collect()
+}
+
+fn deliver_intercepted_message<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    handler: InterceptHandler,
+    message: InterceptedMessage,
+    ctx: &CompilationMessageContext
+) {
+    let id = create_compilation_unit_id();
+    let code = build_intercepted_message_intermediate_representation(
+        id,
+        handler.filename.clone(),
+        &handler.name,
+        &message,
+        get_target_calling_convention(compiler.target)
+    );
+
+    let backend = compiler.backend.clone();
+    emit_type_info_tables(compiler, ctx, backend, &code);
+
+    start_compilation_phase(&mut compiler.statistics, interception_compilation_phase(id));
+
+    send_message_to_actor(
+        &compiler.interpreter,
+        create_deliver_intercepted_message_command(
+            intercepted_message_delivery(id, handler, message, code),
+            create_self_handle(ctx),
+            compiler.errors_have_occurred
+        )
+    );
+
+    await_execution_in_statistics(&mut compiler.statistics, &compiler.type_repository, id, ctx);
+}
+
+fn intercepted_message_delivery(
+    id: CompilationUnitId,
+    handler: InterceptHandler,
+    message: InterceptedMessage,
+    code: IntermediateRepresentation
+) -> InterceptedMessageDelivery {
+    InterceptedMessageDelivery {
+        id,
+        handler,
+        message,
+        code
+    }
+}
+
+pub fn handle_intercepted_message_delivered<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    id: CompilationUnitId,
+    handler: InterceptHandler,
+    requests: CompilerRequests,
+    ctx: &CompilationMessageContext
+) -> AfterReceiveAction {
+    perform_compiler_requests(compiler, &handler.filename, handler.position, requests, ctx);
+
+    end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, interception_compilation_phase(id), ctx);
+
+    continue_listening_after_receive()
+}
@@ -17,16 +17,21 @@ pub fn perform_byte_code_creation<TReader: FileRead, TBackend: BackendBuild, TMe
 
     let intemediate_representation_handle = start_byte_code_creation_actor(
         ctx,
-        &compiler.error_reporter
+        &compiler.error_reporter,
+        get_target_calling_convention(compiler.target)
     );
 
     send_build_byte_code_command_to_actor(intemediate_representation_handle, unit, compiler.errors_have_occurred);
 }
 
-fn start_byte_code_creation_actor(ctx: &CompilationMessageContext, error_reporter: &CompilationActorHandle) -> CompilationActorHandle {
+fn start_byte_code_creation_actor(
+    ctx: &CompilationMessageContext,
+    error_reporter: &CompilationActorHandle,
+    calling_convention: CallingConvention
+) -> CompilationActorHandle {
     let (intemediate_representation_handle, ..) = start_actor(
         ctx, 
-        create_intemediate_representation_actor(create_self_handle(ctx), error_reporter.clone())
+        create_intemediate_representation_actor(create_self_handle(ctx), error_reporter.clone(), calling_convention)
     );
     intemediate_representation_handle
 }
@@ -50,9 +55,18 @@ pub fn handle_byte_code_built<TReader: FileRead, TBackend: BackendBuild, TMessag
     backend: TBackend
 ) -> AfterReceiveAction {
 
-    end_compilation_phase(&mut compiler.statistics,  &compiler.type_repository, byte_code_creation_compilation_phase(unit.id), ctx);
+    end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, byte_code_creation_compilation_phase(unit.id), ctx);
+
+    emit_type_info_tables(compiler, ctx, backend.clone(), &code);
+
+    intercept_unit_message(compiler, &unit, code_generated_intercepted_message(&unit, &code), ctx);
     
-    build_backend(compiler, ctx, backend, unit, code);
+    if is_compile_time_unit(&unit) {
+        perform_execution(compiler, unit, code, ctx);
+    } else {
+        add_byte_code_to_interpreter(&compiler.interpreter, code.clone());
+        build_backend(compiler, ctx, backend, unit, code);
+    }
 
     continue_listening_after_receive()
 }
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::{
+    parsing::*,
+    acting::*,
+    file_system::*,
+    intermediate_representation::*,
+    interpretation::*,
+    backends::*,
+    compilation::*
+};
+
+pub fn perform_execution<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>, 
+    unit: CompilationUnit,
+    code: IntermediateRepresentation,
+    ctx: &CompilationMessageContext
+) {
+    let unit_id = unit.id;
+    start_compilation_phase(&mut compiler.statistics, execution_compilation_phase(unit_id));
+
+    send_message_to_actor(
+        &compiler.interpreter, 
+        create_execute_byte_code_command(unit, code, create_self_handle(ctx), compiler.errors_have_occurred)
+    );
+
+    await_execution_in_statistics(&mut compiler.statistics, &compiler.type_repository, unit_id, ctx);
+}
+
+pub fn add_byte_code_to_interpreter(interpreter: &CompilationActorHandle, code: IntermediateRepresentation) {
+    send_message_to_actor(interpreter, create_add_byte_code_command(code));
+}
+
+pub fn circuit_break_all_executions(interpreter: &CompilationActorHandle, reason: ExecutionCircuitBreakReason) {
+    send_message_to_actor(interpreter, circuit_break_all_executions_command(reason));
+}
+
+pub fn handle_byte_code_executed<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: CompilationUnit,
+    code: IntermediateRepresentation,
+    requests: CompilerRequests,
+    ctx: &CompilationMessageContext
+) -> AfterReceiveAction {
+    if is_immediate_insert_unit(&unit) {
+        perform_insertion(compiler, &unit, ctx);
+    }
+
+    add_inserted_code_type(compiler, &unit);
+
+    perform_compiler_requests(compiler, &unit.filename, unit.tree.position, requests, ctx);
+
+    end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, execution_compilation_phase(unit.id), ctx);
+    
+    let backend = compiler.backend.clone();
+    build_backend(compiler, ctx, backend, unit, code);
+
+    continue_listening_after_receive()
+}
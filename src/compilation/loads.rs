@@ -0,0 +1,86 @@
+use std::collections::*;
+
+use crate::{
+    compilation::*,
+    parsing::*,
+    acting::*,
+    file_system::*,
+    backends::*,
+    errors::*,
+    utilities::*
+};
+
+#[derive(Default)]
+pub struct Loads {
+    parsed_files: HashSet<String>,
+    loaded_files: HashMap<String, Vec<String>>
+}
+
+pub fn add_parsed_file(loads: &mut Loads, file_name: &str) {
+    loads.parsed_files.insert(string(file_name));
+}
+
+pub fn is_parsed_file(loads: &Loads, file_name: &str) -> bool {
+    loads.parsed_files.contains(file_name)
+}
+
+pub fn load_file<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    file_name: &str,
+    ctx: &CompilationMessageContext
+) {
+    let loaded_file_name = resolve_file_name_relative_to_file(&unit.filename, file_name);
+
+    if let Some(mut chain) = find_load_chain(&compiler.loads, &loaded_file_name, &unit.filename) {
+        chain.push(loaded_file_name);
+        report_load_cycle(compiler, unit, chain, ctx);
+        return;
+    }
+
+    compiler.loads.loaded_files
+        .entry(unit.filename.clone())
+        .or_default()
+        .push(loaded_file_name.clone());
+
+    if compiler.loads.parsed_files.contains(&loaded_file_name) {
+        return;
+    }
+
+    add_loaded_file_to_loading_module(compiler, &unit.filename, &loaded_file_name);
+    parse_file(compiler, loaded_file_name, ctx);
+}
+
+fn find_load_chain(loads: &Loads, from_file_name: &str, to_file_name: &str) -> Option<Vec<String>> {
+    find_load_chain_from_file(loads, from_file_name, to_file_name, &mut HashSet::default())
+}
+
+fn find_load_chain_from_file(loads: &Loads, file_name: &str, to_file_name: &str, visited: &mut HashSet<String>) -> Option<Vec<String>> {
+    if file_name == to_file_name {
+        return Some(vec!(string(file_name)));
+    }
+
+    if !visited.insert(string(file_name)) {
+        return None;
+    }
+
+    for loaded_file_name in loads.loaded_files.get(file_name)? {
+        if let Some(mut chain) = find_load_chain_from_file(loads, loaded_file_name, to_file_name, visited) {
+            chain.insert(0, string(file_name));
+            return Some(chain);
+        }
+    }
+
+    None
+}
+
+fn report_load_cycle<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    chain: Vec<String>,
+    ctx: &CompilationMessageContext
+) {
+    let mut errors = create_compilation_errors(unit.filename.clone());
+    add_compilation_error(&mut errors, compilation_error(load_cycle_error(chain), unit.tree.position));
+    report_errors(&compiler.error_reporter, create_self_handle(ctx), errors);
+}
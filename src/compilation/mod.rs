@@ -3,22 +3,41 @@ mod parsing;
 mod typing;
 mod sizing;
 mod intermediate_representation;
+mod interpretation;
 mod backends;
+mod type_infos;
 mod errors;
 mod statistics;
+mod insertion;
+mod interception;
+mod modules;
+mod loads;
+mod targets;
+mod preload;
 
 pub use compiler::*;
 pub use parsing::*;
 pub use typing::*;
 pub use sizing::*;
 pub use intermediate_representation::*;
+pub use interpretation::*;
 pub use backends::*;
+pub use type_infos::*;
 pub use errors::*;
 pub use statistics::*;
+pub use insertion::*;
+pub use interception::*;
+pub use modules::*;
+pub use loads::*;
+pub use targets::*;
+pub use preload::*;
+
+pub use typing::perform_typing;
 
 use crate::{
     parsing::*,
     intermediate_representation::*,
+    interpretation::*,
     types::*,
     errors::*,
     typing::*,
@@ -27,26 +46,42 @@ use crate::{
 
 #[derive(Clone, Debug)]
 pub enum CompilationMessage {
-    Compile(String),
+    Compile { file_name: String, target: CompilationTarget },
     ParseFile(String),
     FileParsed { file_name: String, units: CompilationUnits },
     PerformTyping { unit: CompilationUnit, has_prior_errors: bool },
     UnitTyped { resolved_types: RuntimeTypePointers, unit: CompilationUnit },
     PerformSizing { unit: CompilationUnit, has_prior_errors: bool },
-    UnitSized { unit: CompilationUnit },
+    UnitSized { sized_types: RuntimeTypePointers, unit: CompilationUnit },
     FindType { criteria: FindTypeCriteria, respond_to: FindTypeCaller, compiler: CompilationActorHandle },
     TypeFindRequested { awaiting_unit_id: CompilationUnitId },
+    TypeFindCompleted { awaiting_unit_id: CompilationUnitId },
     TypeFound(RuntimeTypePointer),
     CircuitBreakTypeRequest(TypeRequestCircuitBreakReason),
     CircuitBreakAllTypeRequests(TypeRequestCircuitBreakReason),
-    AddResolvedType(RuntimeTypePointer),
+    ConfirmAwaitedTypeRequests { check: usize, compiler: CompilationActorHandle },
+    AwaitedTypeRequestsConfirmed { check: usize },
+    AddResolvedType { resolved_type: RuntimeTypePointer, declaration: TypeDeclaration },
+    AddModuleFile { filename: String, module: String },
+    AddModuleImport { importer_filename: String, module: String, alias: Option<String> },
     BuildByteCode { unit: CompilationUnit, has_prior_errors: bool },
     ByteCodeBuilt { unit: CompilationUnit, code: IntermediateRepresentation },
+    AddByteCode(IntermediateRepresentation),
+    ExecuteByteCode { unit: CompilationUnit, code: IntermediateRepresentation, compiler: CompilationActorHandle, has_prior_errors: bool },
+    ByteCodeExecuted { unit: CompilationUnit, code: IntermediateRepresentation, requests: CompilerRequests },
+    CircuitBreakAllExecutions(ExecutionCircuitBreakReason),
+    DeliverInterceptedMessage { delivery: InterceptedMessageDelivery, compiler: CompilationActorHandle, has_prior_errors: bool },
+    InterceptedMessageDelivered { id: CompilationUnitId, handler: InterceptHandler, message: InterceptedMessage, requests: CompilerRequests },
     BuildBackend { code: IntermediateRepresentation, has_prior_errors: bool },
     BackendBuilt { id: CompilationUnitId },
+    EmitBackend { file_name: String, has_prior_errors: bool },
+    BackendEmitted,
+    AllUnitsCompiled,
     CompilationComplete,
     ReportErrors { errors: CompilationErrors, compiler: CompilationActorHandle },
+    ErrorsFound,
     ErrorsReported(CompilationErrors),
+    FlushErrorReports { compiler: CompilationActorHandle },
     ShutDown,
 }
 
@@ -68,8 +103,8 @@ pub fn create_null_message_wire_tap() -> NullCompilationMessageWireTap {
 pub type CompilationActorHandle = ActorHandle<CompilationMessage>;
 pub type CompilationMessageContext = ActorContext<CompilationMessage>;
 
-fn create_compile_command(file_name: String) -> CompilationMessage {
-    CompilationMessage::Compile(file_name)
+fn create_compile_command(file_name: String, target: CompilationTarget) -> CompilationMessage {
+    CompilationMessage::Compile { file_name, target }
 }
 
 pub fn create_parse_file_command(file_name: String) -> CompilationMessage {
@@ -96,8 +131,8 @@ pub fn create_unit_typed_event(resolved_types: RuntimeTypePointers, unit: Compil
     CompilationMessage::UnitTyped { resolved_types, unit }
 }
 
-pub fn create_unit_sized_event(unit: CompilationUnit) -> CompilationMessage {
-    CompilationMessage::UnitSized { unit }
+pub fn create_unit_sized_event(sized_types: RuntimeTypePointers, unit: CompilationUnit) -> CompilationMessage {
+    CompilationMessage::UnitSized { sized_types, unit }
 }
 
 pub fn create_type_found_event(resolved_type: RuntimeTypePointer) -> CompilationMessage {
@@ -108,12 +143,32 @@ pub fn create_type_find_requested_event(awaiting_unit_id: CompilationUnitId) ->
     CompilationMessage::TypeFindRequested { awaiting_unit_id }
 }
 
+pub fn create_type_find_completed_event(awaiting_unit_id: CompilationUnitId) -> CompilationMessage {
+    CompilationMessage::TypeFindCompleted { awaiting_unit_id }
+}
+
 pub fn circuit_break_type_request(reason: TypeRequestCircuitBreakReason) -> CompilationMessage {
     CompilationMessage::CircuitBreakTypeRequest(reason)
 }
 
-pub fn create_add_resolved_type_command(resolved_type: RuntimeTypePointer) -> CompilationMessage {
-    CompilationMessage::AddResolvedType(resolved_type)
+pub fn create_add_resolved_type_command(resolved_type: RuntimeTypePointer, declaration: TypeDeclaration) -> CompilationMessage {
+    CompilationMessage::AddResolvedType { resolved_type, declaration }
+}
+
+pub fn create_add_module_file_command(filename: String, module: String) -> CompilationMessage {
+    CompilationMessage::AddModuleFile { filename, module }
+}
+
+pub fn create_add_module_import_command(importer_filename: String, module: String, alias: Option<String>) -> CompilationMessage {
+    CompilationMessage::AddModuleImport { importer_filename, module, alias }
+}
+
+pub fn create_confirm_awaited_type_requests_command(check: usize, compiler: CompilationActorHandle) -> CompilationMessage {
+    CompilationMessage::ConfirmAwaitedTypeRequests { check, compiler }
+}
+
+pub fn create_awaited_type_requests_confirmed_event(check: usize) -> CompilationMessage {
+    CompilationMessage::AwaitedTypeRequestsConfirmed { check }
 }
 
 pub fn circuit_break_all_type_requests_command(reason: TypeRequestCircuitBreakReason) -> CompilationMessage {
@@ -128,6 +183,35 @@ pub fn create_byte_code_built_event(unit: CompilationUnit, code: IntermediateRep
     CompilationMessage::ByteCodeBuilt { unit, code }
 }
 
+pub fn create_add_byte_code_command(code: IntermediateRepresentation) -> CompilationMessage {
+    CompilationMessage::AddByteCode(code)
+}
+
+pub fn create_execute_byte_code_command(unit: CompilationUnit, code: IntermediateRepresentation, compiler: CompilationActorHandle, has_prior_errors: bool) -> CompilationMessage {
+    CompilationMessage::ExecuteByteCode { unit, code, compiler, has_prior_errors }
+}
+
+pub fn create_byte_code_executed_event(unit: CompilationUnit, code: IntermediateRepresentation, requests: CompilerRequests) -> CompilationMessage {
+    CompilationMessage::ByteCodeExecuted { unit, code, requests }
+}
+
+pub fn circuit_break_all_executions_command(reason: ExecutionCircuitBreakReason) -> CompilationMessage {
+    CompilationMessage::CircuitBreakAllExecutions(reason)
+}
+
+pub fn create_deliver_intercepted_message_command(delivery: InterceptedMessageDelivery, compiler: CompilationActorHandle, has_prior_errors: bool) -> CompilationMessage {
+    CompilationMessage::DeliverInterceptedMessage { delivery, compiler, has_prior_errors }
+}
+
+pub fn create_intercepted_message_delivered_event(
+    id: CompilationUnitId,
+    handler: InterceptHandler,
+    message: InterceptedMessage,
+    requests: CompilerRequests
+) -> CompilationMessage {
+    CompilationMessage::InterceptedMessageDelivered { id, handler, message, requests }
+}
+
 pub fn create_build_backend_command(code: IntermediateRepresentation, has_prior_errors: bool) -> CompilationMessage {
     CompilationMessage::BuildBackend { code, has_prior_errors }
 }
@@ -136,6 +220,18 @@ pub fn create_backend_built_event(id: CompilationUnitId) -> CompilationMessage {
     CompilationMessage::BackendBuilt { id }
 }
 
+pub fn create_emit_backend_command(file_name: String, has_prior_errors: bool) -> CompilationMessage {
+    CompilationMessage::EmitBackend { file_name, has_prior_errors }
+}
+
+pub fn create_backend_emitted_event() -> CompilationMessage {
+    CompilationMessage::BackendEmitted
+}
+
+pub fn create_all_units_compiled_event() -> CompilationMessage {
+    CompilationMessage::AllUnitsCompiled
+}
+
 pub fn create_compilation_complete_event() -> CompilationMessage {
     CompilationMessage::CompilationComplete
 }
@@ -144,10 +240,18 @@ pub fn create_report_errors_command(errors: CompilationErrors, compiler: Compila
     CompilationMessage::ReportErrors { errors, compiler }
 }
 
+pub fn create_errors_found_event() -> CompilationMessage {
+    CompilationMessage::ErrorsFound
+}
+
 pub fn create_errors_reported_event(errors: CompilationErrors) -> CompilationMessage {
     CompilationMessage::ErrorsReported(errors)
 }
 
+pub fn create_flush_error_reports_command(compiler: CompilationActorHandle) -> CompilationMessage {
+    CompilationMessage::FlushErrorReports { compiler }
+}
+
 pub fn create_shutdown_command() -> CompilationMessage {
     CompilationMessage::ShutDown
 }
@@ -0,0 +1,137 @@
+use std::collections::*;
+use std::env;
+use std::path::*;
+
+use crate::{
+    compilation::*,
+    parsing::*,
+    acting::*,
+    file_system::*,
+    backends::*,
+    errors::*,
+    utilities::*
+};
+
+pub const MODULE_FILE_NAME: &str = "module.hep";
+pub const BUNDLED_MODULES_DIRECTORY: &str = "modules";
+pub const BUNDLED_MODULES_PATH_VARIABLE: &str = "HEPHAESTUS_MODULES";
+const BUILD_BUNDLED_MODULES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/modules");
+
+pub struct Modules {
+    search_paths: Vec<String>,
+    bundled_path: String,
+    imported: HashSet<String>,
+    module_files: HashMap<String, String>
+}
+
+pub fn create_modules(search_paths: Vec<String>) -> Modules {
+    Modules {
+        search_paths,
+        bundled_path: get_bundled_modules_path(),
+        imported: HashSet::default(),
+        module_files: HashMap::default()
+    }
+}
+
+pub fn get_bundled_modules_path() -> String {
+    find_bundled_modules_path(env::var(BUNDLED_MODULES_PATH_VARIABLE).ok(), env::current_exe().ok())
+}
+
+pub fn find_bundled_modules_path(configured_path: Option<String>, executable_path: Option<PathBuf>) -> String {
+    if let Some(path) = configured_path {
+        return normalise_file_name(&path);
+    }
+
+    let beside_executable = executable_path
+        .as_deref()
+        .and_then(Path::parent)
+        .map(|directory| directory.join(BUNDLED_MODULES_DIRECTORY))
+        .filter(|path| path.is_dir());
+
+    match beside_executable {
+        Some(path) => normalise_file_name(&path.to_string_lossy()),
+        None => normalise_file_name(BUILD_BUNDLED_MODULES_PATH)
+    }
+}
+
+pub fn get_modules_bundled_path(modules: &Modules) -> &str {
+    &modules.bundled_path
+}
+
+pub fn import_module<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    module: String,
+    alias: Option<String>,
+    ctx: &CompilationMessageContext
+) {
+    send_message_to_actor(
+        &compiler.type_repository,
+        create_add_module_import_command(unit.filename.clone(), module.clone(), alias)
+    );
+
+    if !compiler.modules.imported.insert(module.clone()) {
+        return;
+    }
+
+    match find_module_file(&compiler.reader, &compiler.modules, &module) {
+        Some(file_name) => {
+            add_module_file(compiler, file_name.clone(), module);
+            parse_file(compiler, file_name, ctx);
+        },
+        None => report_module_not_found(compiler, unit, module, ctx)
+    }
+}
+
+pub fn is_module_file(modules: &Modules, file_name: &str) -> bool {
+    modules.module_files.contains_key(file_name)
+}
+
+pub fn get_unit_symbol_qualifier(modules: &Modules, unit: &CompilationUnit) -> Option<String> {
+    match unit.visibility {
+        Visibility::File => Some(unit.filename.clone()),
+        Visibility::Export => modules.module_files.get(&unit.filename).cloned()
+    }
+}
+
+pub fn add_loaded_file_to_loading_module<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    loading_file_name: &str,
+    loaded_file_name: &str
+) {
+    if let Some(module) = compiler.modules.module_files.get(loading_file_name).cloned() {
+        add_module_file(compiler, string(loaded_file_name), module);
+    }
+}
+
+fn add_module_file<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    file_name: String,
+    module: String
+) {
+    compiler.modules.module_files.insert(file_name.clone(), module.clone());
+    send_message_to_actor(&compiler.type_repository, create_add_module_file_command(file_name, module));
+}
+
+fn find_module_file<TReader: FileRead>(reader: &TReader, modules: &Modules, module: &str) -> Option<String> {
+    modules.search_paths
+        .iter()
+        .chain(std::iter::once(&modules.bundled_path))
+        .map(|search_path| module_file_path(search_path, module))
+        .find(|file_name| reader.file_exists(file_name))
+}
+
+pub fn module_file_path(search_path: &str, module: &str) -> String {
+    normalise_file_name(&format!("{}/{}/{}", search_path, module, MODULE_FILE_NAME))
+}
+
+fn report_module_not_found<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    module: String,
+    ctx: &CompilationMessageContext
+) {
+    let mut errors = create_compilation_errors(unit.filename.clone());
+    add_compilation_error(&mut errors, compilation_error(module_not_found_error(module), unit.tree.position));
+    report_errors(&compiler.error_reporter, create_self_handle(ctx), errors);
+}
@@ -12,6 +12,7 @@ pub fn parse_file<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: Wi
     file_name: String,
     ctx: &CompilationMessageContext
 ) {
+    add_parsed_file(&mut compiler.loads, &file_name);
     start_compilation_phase(&mut compiler.statistics, parsing_compilation_phase(string(&file_name)));
 
     let parser_handle = start_parser_actor(ctx, &compiler.error_reporter, compiler.reader.clone());
@@ -40,14 +41,31 @@ pub fn handle_file_parsed<TReader: FileRead, TBackend: BackendBuild, TMessageWir
     units: CompilationUnits,
     ctx: &CompilationMessageContext
 ) -> AfterReceiveAction {
-    end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, parsing_compilation_phase(string(&file_name)), ctx);
+    intercept_message(compiler, file_parsed_intercepted_message(&file_name, &units), ctx);
 
-    for unit in units {
-        perform_typing(compiler, unit, ctx);
-    }
+    add_preload_units(&mut compiler.preload, &file_name, &units);
+    dispatch_units(compiler, units, ctx);
+
+    end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, parsing_compilation_phase(string(&file_name)), ctx);
 
     continue_listening_after_receive()
 }
 
-
-
+pub fn dispatch_units<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    units: CompilationUnits,
+    ctx: &CompilationMessageContext
+) {
+    for unit in units {
+        if let Some(loaded_file_name) = try_get_load_directive_filename(unit.tree.item_ref()) {
+            load_file(compiler, &unit, &loaded_file_name, ctx);
+        } else if let Some((module, alias)) = try_get_import_directive(unit.tree.item_ref()) {
+            import_module(compiler, &unit, module, alias, ctx);
+        } else if is_immediate_insert_unit(&unit) {
+            perform_insertion(compiler, &unit, ctx);
+        } else {
+            load_target_entry_point(compiler, &unit, ctx);
+            perform_typing(compiler, unit, ctx);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    compilation::*,
+    parsing::*,
+    types::*,
+    file_system::*,
+    backends::*,
+    utilities::*
+};
+
+#[derive(Default)]
+pub struct Preload {
+    units_to_size: usize
+}
+
+pub fn compile_preload<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext
+) {
+    parse_file(compiler, string(PRELOAD_FILE_NAME), ctx);
+}
+
+pub fn add_preload_units(preload: &mut Preload, file_name: &str, units: &CompilationUnits) {
+    if file_name == PRELOAD_FILE_NAME {
+        preload.units_to_size = units.len();
+    }
+}
+
+pub fn end_preload_unit_sizing<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    ctx: &CompilationMessageContext
+) {
+    if unit.filename != PRELOAD_FILE_NAME {
+        return;
+    }
+
+    compiler.preload.units_to_size -= 1;
+    if compiler.preload.units_to_size == 0 {
+        load_target_runtime(compiler, ctx);
+        parse_file(compiler, compiler.file_name.clone(), ctx);
+    }
+}
@@ -4,7 +4,9 @@ use crate::{
     sizing::*,
     acting::*,
     file_system::*,
-    backends::*
+    backends::*,
+    typing::*,
+    types::*
 };
 
 pub fn perform_sizing<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
@@ -45,12 +47,28 @@ fn send_perform_sizing_command_to_actor(
 pub fn handle_unit_sized<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
     compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>, 
     unit: CompilationUnit,
+    sized_types: RuntimeTypePointers,
     ctx: &CompilationMessageContext
 ) -> AfterReceiveAction {
     
+    intercept_unit_message(compiler, &unit, unit_typed_intercepted_message(&unit, &sized_types), ctx);
+
+    for sized_type in sized_types {
+        add_sized_type(&compiler.type_repository, sized_type, unit_type_declaration(&unit));
+    }
+
+    end_preload_unit_sizing(compiler, &unit, ctx);
+
     end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, sizing_compilation_phase(unit.id), ctx);
     
     perform_byte_code_creation(compiler, unit, ctx);
 
     continue_listening_after_receive()
-}
\ No newline at end of file
+}
+fn add_sized_type(
+    type_repository: &CompilationActorHandle,
+    sized_type: RuntimeTypePointer,
+    declaration: TypeDeclaration
+) {
+    send_message_to_actor(type_repository, create_add_resolved_type_command(sized_type, declaration));
+}
@@ -4,17 +4,21 @@ use log::*;
 use crate::{
     compilation::*,
     parsing::*,
+    interpretation::*,
     acting::*,
 };
 
 pub type FilesInPipe = HashMap<String, String>;
 pub type UnitsInPipe = HashMap<CompilationUnitId, CompilationUnitId>;
 pub type AwaitedUnitsInPipe = HashMap<CompilationUnitId, CompilationUnitId>;
+pub type AwaitedExecutionsInPipe = HashMap<CompilationUnitId, CompilationUnitId>;
 
 pub struct Statistics {
     files_in_pipe: FilesInPipe,
     units_in_pipe: UnitsInPipe,
-    awaited_units_in_pipe: AwaitedUnitsInPipe
+    awaited_units_in_pipe: AwaitedUnitsInPipe,
+    awaited_executions_in_pipe: AwaitedExecutionsInPipe,
+    circuit_break_checks: usize
 }
 
 pub fn create_statistics() -> Statistics {
@@ -22,6 +26,8 @@ pub fn create_statistics() -> Statistics {
         files_in_pipe: HashMap::default(),
         units_in_pipe: HashMap::default(),
         awaited_units_in_pipe: HashMap::default(),
+        awaited_executions_in_pipe: HashMap::default(),
+        circuit_break_checks: 0
     }
 }
 
@@ -31,6 +37,8 @@ pub enum CompilationPhase{
     Typing(CompilationUnitId),
     Sizing(CompilationUnitId),
     ByteCodeCreation(CompilationUnitId),
+    Execution(CompilationUnitId),
+    Interception(CompilationUnitId),
     BackendBuild(CompilationUnitId)
 }
 
@@ -50,6 +58,14 @@ pub fn byte_code_creation_compilation_phase(id: CompilationUnitId) -> Compilatio
     CompilationPhase::ByteCodeCreation(id)
 }
 
+pub fn execution_compilation_phase(id: CompilationUnitId) -> CompilationPhase {
+    CompilationPhase::Execution(id)
+}
+
+pub fn interception_compilation_phase(id: CompilationUnitId) -> CompilationPhase {
+    CompilationPhase::Interception(id)
+}
+
 pub fn backend_build_compilation_phase(id: CompilationUnitId) -> CompilationPhase {
     CompilationPhase::BackendBuild(id)
 }
@@ -60,15 +76,56 @@ pub fn start_compilation_phase(statistics: &mut Statistics, phase: CompilationPh
     log_statistics(statistics);
 }
 
-pub fn end_compilation_phase(statistics: &mut Statistics, type_repository: &CompilationActorHandle, phase: CompilationPhase, ctx: &CompilationMessageContext) {
+pub fn end_compilation_phase(
+    statistics: &mut Statistics,
+    type_repository: &CompilationActorHandle,
+    phase: CompilationPhase,
+    ctx: &CompilationMessageContext
+) {
     log_end_compilation_phase(&phase);
     perform_end_compilation_phase(statistics, type_repository, phase, ctx);
     log_statistics(statistics);
 }
 
-pub fn await_unit_in_statistics(statistics: &mut Statistics, type_repository: &CompilationActorHandle, awaited_unit_id: CompilationUnitId) {
+pub fn await_unit_in_statistics(
+    statistics: &mut Statistics,
+    type_repository: &CompilationActorHandle,
+    awaited_unit_id: CompilationUnitId,
+    ctx: &CompilationMessageContext
+) {
     add_awaited_unit_to_statistics(statistics, awaited_unit_id);
-    circuit_break_awaited_units_if_required(statistics, type_repository);
+    circuit_break_awaited_units_if_required(statistics, type_repository, ctx);
+}
+
+pub fn end_await_unit_in_statistics(statistics: &mut Statistics, awaited_unit_id: &CompilationUnitId) {
+    remove_awaited_unit_from_statistics(statistics, awaited_unit_id);
+}
+
+pub fn await_execution_in_statistics(
+    statistics: &mut Statistics,
+    type_repository: &CompilationActorHandle,
+    awaited_unit_id: CompilationUnitId,
+    ctx: &CompilationMessageContext
+) {
+    add_awaited_execution_to_statistics(statistics, awaited_unit_id);
+    circuit_break_awaited_units_if_required(statistics, type_repository, ctx);
+}
+
+pub fn circuit_break_confirmed_awaited_units(
+    statistics: &mut Statistics,
+    type_repository: &CompilationActorHandle,
+    interpreter: &CompilationActorHandle,
+    check: usize
+) {
+    if check != statistics.circuit_break_checks || !is_circuit_break_required(statistics) {
+        return;
+    }
+
+    if number_of_awaited_executions_in_pipe(statistics) > 0 {
+        circuit_break_awaited_executions(interpreter);
+    } else {
+        circuit_break_awaited_units(type_repository);
+    }
 }
 
 fn perform_start_compilation_phase(statistics: &mut Statistics, phase: CompilationPhase) {
@@ -77,51 +134,93 @@ fn perform_start_compilation_phase(statistics: &mut Statistics, phase: Compilati
             add_file_to_statistics(statistics, filename),
         CompilationPhase::Typing(id) => 
             add_unit_to_statistics(statistics, id),
+        CompilationPhase::Interception(id) => 
+            add_unit_to_statistics(statistics, id),
+        CompilationPhase::BackendBuild(id) => 
+            add_unit_to_statistics(statistics, id),
         _ => {},
     }
 }
 
-fn perform_end_compilation_phase(statistics: &mut Statistics, type_repository: &CompilationActorHandle, phase: CompilationPhase, ctx: &CompilationMessageContext) {
+fn perform_end_compilation_phase(
+    statistics: &mut Statistics,
+    type_repository: &CompilationActorHandle,
+    phase: CompilationPhase,
+    ctx: &CompilationMessageContext
+) {
     match phase {
         CompilationPhase::Parsing(filename) => {
             remove_file_from_statistics(statistics, &filename);
-            all_files_parsed(statistics);
+            circuit_break_awaited_units_if_required(statistics, type_repository, ctx);
+            check_for_statistics_completion(statistics, ctx);
         },
         CompilationPhase::Typing(id) =>
             remove_awaited_unit_from_statistics(statistics, &id),
-        CompilationPhase::BackendBuild(id) => {
-            remove_unit_from_statistics(statistics, &id);
-            circuit_break_awaited_units_if_required(statistics, type_repository);
-            check_for_statistics_completion(statistics, ctx);
+        CompilationPhase::Execution(id) =>
+            remove_awaited_execution_from_statistics(statistics, &id),
+        CompilationPhase::Interception(id) => {
+            remove_awaited_execution_from_statistics(statistics, &id);
+            end_unit_in_statistics(statistics, type_repository, id, ctx);
         },
+        CompilationPhase::BackendBuild(id) =>
+            end_unit_in_statistics(statistics, type_repository, id, ctx),
         _ => {},
     }
 }
 
+pub fn end_unit_in_statistics(
+    statistics: &mut Statistics,
+    type_repository: &CompilationActorHandle,
+    id: CompilationUnitId,
+    ctx: &CompilationMessageContext
+) {
+    remove_unit_from_statistics(statistics, &id);
+    circuit_break_awaited_units_if_required(statistics, type_repository, ctx);
+    check_for_statistics_completion(statistics, ctx);
+}
+
 fn check_for_statistics_completion(statistics: &mut Statistics, ctx: &CompilationMessageContext) {
-    if compilation_has_completed(statistics) {
+    if all_files_parsed(statistics) && compilation_has_completed(statistics) {
         notify_compiler_of_compilation_completion(ctx);
     }
 }
 
-fn circuit_break_awaited_units_if_required(statistics: &mut Statistics, type_repository: &CompilationActorHandle) {
-    if !all_files_parsed(statistics) {
+fn circuit_break_awaited_units_if_required(statistics: &mut Statistics, type_repository: &CompilationActorHandle, ctx: &CompilationMessageContext) {
+    if !is_circuit_break_required(statistics) {
         return;
     }
 
-    if compilation_has_completed(statistics) {
-        return;
+    statistics.circuit_break_checks += 1;
+    confirm_awaited_type_requests(type_repository, statistics.circuit_break_checks, ctx);
+}
+
+fn is_circuit_break_required(statistics: &mut Statistics) -> bool {
+    if !all_files_parsed(statistics) {
+        return false;
     }
 
-    if number_of_awaited_units_in_pipe(statistics) == number_of_units_in_pipe(statistics) {
-        circuit_break_awaited_units(type_repository);
+    if compilation_has_completed(statistics) {
+        return false;
     }
+
+    let number_of_awaited_units = number_of_awaited_units_in_pipe(statistics);
+    let number_of_awaited_executions = number_of_awaited_executions_in_pipe(statistics);
+    
+    number_of_awaited_units + number_of_awaited_executions == number_of_units_in_pipe(statistics)
+}
+
+fn confirm_awaited_type_requests(type_repository: &CompilationActorHandle, check: usize, ctx: &CompilationMessageContext) {
+    send_message_to_actor(type_repository, create_confirm_awaited_type_requests_command(check, create_self_handle(ctx)));
 }
 
 fn circuit_break_awaited_units(type_repository: &CompilationActorHandle) {
     circuit_break_all_type_requests(type_repository, types_not_found_type_request_circuit_break_reason());
 }
 
+fn circuit_break_awaited_executions(interpreter: &CompilationActorHandle) {
+    circuit_break_all_executions(interpreter, symbols_not_found_execution_circuit_break_reason());
+}
+
 fn add_file_to_statistics(statistics: &mut Statistics, filename: String) {
     statistics.files_in_pipe.insert(filename.clone(), filename);
 }
@@ -134,6 +233,14 @@ fn remove_awaited_unit_from_statistics(statistics: &mut Statistics, awaited_unit
     statistics.awaited_units_in_pipe.remove(awaited_unit_id);
 }
 
+fn add_awaited_execution_to_statistics(statistics: &mut Statistics, awaited_unit_id: CompilationUnitId) {
+    statistics.awaited_executions_in_pipe.insert(awaited_unit_id, awaited_unit_id);
+}
+
+fn remove_awaited_execution_from_statistics(statistics: &mut Statistics, awaited_unit_id: &CompilationUnitId) {
+    statistics.awaited_executions_in_pipe.remove(awaited_unit_id);
+}
+
 fn remove_file_from_statistics(statistics: &mut Statistics, filename: &str) {
     statistics.files_in_pipe.remove(filename);
 }
@@ -162,8 +269,12 @@ fn number_of_awaited_units_in_pipe(statistics: &Statistics) -> usize {
     statistics.awaited_units_in_pipe.len()
 }
 
+fn number_of_awaited_executions_in_pipe(statistics: &Statistics) -> usize {
+    statistics.awaited_executions_in_pipe.len()
+}
+
 fn notify_compiler_of_compilation_completion(ctx: &CompilationMessageContext) {
-    send_message_to_actor(&create_self_handle(ctx), create_compilation_complete_event());
+    send_message_to_actor(&create_self_handle(ctx), create_all_units_compiled_event());
 }
 
 fn log_start_compilation_phase(phase: &CompilationPhase) {
@@ -0,0 +1,84 @@
+use crate::{
+    compilation::*,
+    parsing::*,
+    file_system::*,
+    backends::*
+};
+
+pub const RUNTIME_SUPPORT_MODULE: &str = "Runtime_Support";
+pub const MAIN_PROCEDURE_NAME: &str = "main";
+
+const TARGET_NAME_WINDOWS: &str = "windows";
+const TARGET_NAME_LINUX: &str = "linux";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompilationTarget {
+    #[default]
+    Windows,
+    Linux
+}
+
+pub fn windows_target() -> CompilationTarget {
+    CompilationTarget::Windows
+}
+
+pub fn linux_target() -> CompilationTarget {
+    CompilationTarget::Linux
+}
+
+pub fn try_get_compilation_target(name: &str) -> Option<CompilationTarget> {
+    match name {
+        TARGET_NAME_WINDOWS => Some(windows_target()),
+        TARGET_NAME_LINUX => Some(linux_target()),
+        _ => None
+    }
+}
+
+pub fn get_target_runtime_file_name(bundled_modules_path: &str, target: CompilationTarget) -> Option<String> {
+    match target {
+        CompilationTarget::Windows => None,
+        CompilationTarget::Linux => Some(normalise_file_name(&format!("{}/{}/{}.hep", bundled_modules_path, RUNTIME_SUPPORT_MODULE, TARGET_NAME_LINUX)))
+    }
+}
+
+pub fn get_target_entry_file_name(bundled_modules_path: &str, target: CompilationTarget, main_returns_status: bool) -> Option<String> {
+    match (target, main_returns_status) {
+        (CompilationTarget::Windows, _) => None,
+        (CompilationTarget::Linux, true) => Some(normalise_file_name(&format!("{}/{}/{}_start.hep", bundled_modules_path, RUNTIME_SUPPORT_MODULE, TARGET_NAME_LINUX))),
+        (CompilationTarget::Linux, false) => Some(normalise_file_name(&format!("{}/{}/{}_start_void.hep", bundled_modules_path, RUNTIME_SUPPORT_MODULE, TARGET_NAME_LINUX)))
+    }
+}
+
+pub fn load_target_runtime<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext
+) {
+    if let Some(file_name) = get_target_runtime_file_name(get_modules_bundled_path(&compiler.modules), compiler.target) {
+        parse_file(compiler, file_name, ctx);
+    }
+}
+
+pub fn load_target_entry_point<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit,
+    ctx: &CompilationMessageContext
+) {
+    if is_module_file(&compiler.modules, &unit.filename) {
+        return;
+    }
+
+    if let Some(main_returns_status) = try_get_main_returns_status(unit.tree.item_ref()) {
+        if let Some(file_name) = get_target_entry_file_name(get_modules_bundled_path(&compiler.modules), compiler.target, main_returns_status) {
+            if !is_parsed_file(&compiler.loads, &file_name) {
+                parse_file(compiler, file_name, ctx);
+            }
+        }
+    }
+}
+
+fn try_get_main_returns_status(item: &AbstractSyntaxNodeItem) -> Option<bool> {
+    match item {
+        AbstractSyntaxNodeItem::ProcedureHeader { name, return_args, .. } if name == MAIN_PROCEDURE_NAME => Some(!return_args.is_empty()),
+        _ => None
+    }
+}
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use crate::{
+    parsing::*,
+    file_system::*,
+    intermediate_representation::*,
+    backends::*,
+    types::*,
+    compilation::*
+};
+
+pub type EmittedTypeInfos = HashSet<String>;
+
+pub fn emit_type_info_tables<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    ctx: &CompilationMessageContext,
+    backend: TBackend,
+    code: &IntermediateRepresentation
+) {
+    let mut required_type_infos = code.type_infos.clone();
+
+    while let Some(type_info_type) = required_type_infos.pop() {
+        if !compiler.emitted_type_infos.insert(type_info_symbol_name(&type_info_type)) {
+            continue;
+        }
+
+        let table = build_type_info_intermediate_representation(create_compilation_unit_id(), code.filename.clone(), &type_info_type);
+        required_type_infos.extend(table.type_infos.iter().cloned());
+
+        add_byte_code_to_interpreter(&compiler.interpreter, table.clone());
+        build_backend_with_id(compiler, ctx, backend.clone(), table.id, table);
+    }
+}
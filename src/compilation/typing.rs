@@ -34,6 +34,8 @@ pub fn perform_typing<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap
         compiler.type_repository.clone(),
         compiler.error_reporter.clone(),
         unit.id,
+        unit.filename.clone(),
+        get_unit_symbol_qualifier(&compiler.modules, &unit),
         ctx
     );
     
@@ -45,11 +47,13 @@ fn start_typing_actor(
     type_repository: CompilationActorHandle,
     error_reporter: CompilationActorHandle,
     unit_id: CompilationUnitId,
+    filename: String,
+    symbol_qualifier: Option<String>,
     ctx: &CompilationMessageContext
 ) -> CompilationActorHandle {
     let (typing_handle, ..) = start_actor(
         &ctx, 
-        create_typing_actor(compiler, type_repository, error_reporter, unit_id)
+        create_typing_actor(compiler, type_repository, error_reporter, unit_id, filename, symbol_qualifier)
     );
     typing_handle
 }
@@ -80,27 +84,64 @@ pub fn handle_unit_typed<TReader: FileRead, TBackend: BackendBuild, TMessageWire
 ) -> AfterReceiveAction {
     
     for resolved_type in resolved_types {
-        add_resolved_type(compiler.type_repository.clone(), resolved_type);
+        add_resolved_type(compiler.type_repository.clone(), resolved_type, unit_type_declaration(&unit));
     }
     
     end_compilation_phase(&mut compiler.statistics, &compiler.type_repository, typing_compilation_phase(unit.id), ctx);
 
-    perform_sizing(compiler, unit, ctx);
+    if is_immediate_insert_unit(&unit) {
+        perform_insertion(compiler, &unit, ctx);
+        end_unit_in_statistics(&mut compiler.statistics, &compiler.type_repository, unit.id, ctx);
+    } else if try_get_code_constant(unit.tree.item_ref()).is_some() {
+        end_unit_in_statistics(&mut compiler.statistics, &compiler.type_repository, unit.id, ctx);
+    } else {
+        perform_sizing(compiler, unit, ctx);
+    }
 
     continue_listening_after_receive()
 }
 
 pub fn handle_type_find_requested<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    awaiting_unit_id: CompilationUnitId,
+    ctx: &CompilationMessageContext
+) -> AfterReceiveAction {
+    await_unit_in_statistics(&mut compiler.statistics, &compiler.type_repository, awaiting_unit_id, ctx);
+    continue_listening_after_receive()
+}
+
+pub fn handle_type_find_completed<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
     compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
     awaiting_unit_id: CompilationUnitId
 ) -> AfterReceiveAction {
-    await_unit_in_statistics(&mut compiler.statistics, &compiler.type_repository, awaiting_unit_id);
+    end_await_unit_in_statistics(&mut compiler.statistics, &awaiting_unit_id);
+    continue_listening_after_receive()
+}
+
+pub fn handle_awaited_type_requests_confirmed<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &mut CompilerActor<TReader, TBackend, TMessageWireTap>,
+    check: usize
+) -> AfterReceiveAction {
+    circuit_break_confirmed_awaited_units(&mut compiler.statistics, &compiler.type_repository, &compiler.interpreter, check);
     continue_listening_after_receive()
 }
 
+pub fn add_inserted_code_type<TReader: FileRead, TBackend: BackendBuild, TMessageWireTap: WireTapCompilationMessage>(
+    compiler: &CompilerActor<TReader, TBackend, TMessageWireTap>,
+    unit: &CompilationUnit
+) {
+    if let Some((name, source)) = try_get_insert_directive_constant(unit.tree.item_ref()) {
+        if let AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(_))) = source.item_ref() {
+            let code_type = create_code_definition_type(unit.id, name, code_body(unit.filename.clone(), source.clone()));
+            add_resolved_type(compiler.type_repository.clone(), code_type, unit_type_declaration(unit));
+        }
+    }
+}
+
 fn add_resolved_type(
     type_repository: CompilationActorHandle,
-    resolved_type: RuntimeTypePointer
+    resolved_type: RuntimeTypePointer,
+    declaration: TypeDeclaration
 ) {
-    send_message_to_actor(&type_repository, create_add_resolved_type_command(resolved_type));
+    send_message_to_actor(&type_repository, create_add_resolved_type_command(resolved_type, declaration));
 }
@@ -4,8 +4,10 @@ use crate::compilation::*;
 use crate::parsing::*;
 use crate::typing::*;
 use crate::intermediate_representation::*;
+use crate::interpretation::*;
 use crate::utilities::*;
 use crate::file_system::*;
+use crate::arguments::*;
 
 pub struct ErrorReporterActor<T: FileRead> {
     file_reader: T
@@ -21,6 +23,7 @@ impl<T: FileRead> Actor<CompilationMessage> for ErrorReporterActor<T> {
     fn receive(&mut self, message: CompilationMessage, _ctx: &ActorContext<CompilationMessage>) -> AfterReceiveAction {
         match message {
             CompilationMessage::ReportErrors { errors, compiler} => report_errors(&self.file_reader, &errors, &compiler),
+            CompilationMessage::FlushErrorReports { compiler } => flush_error_reports(&compiler),
             CompilationMessage::ShutDown => shutdown_after_receive(),
             _ => continue_listening_after_receive()
         }
@@ -42,6 +45,11 @@ fn report_errors<T: FileRead>(file_reader: &T, errors: &CompilationErrors, compi
     continue_listening_after_receive()
 }
 
+fn flush_error_reports(compiler: &CompilationActorHandle) -> AfterReceiveAction {
+    send_message_to_actor(compiler, create_compilation_complete_event());
+    continue_listening_after_receive()
+}
+
 fn notify_compiler_errors_reported(compiler: &CompilationActorHandle, errors: CompilationErrors) {
     send_message_to_actor(compiler, create_errors_reported_event(errors));
 }
@@ -53,9 +61,16 @@ fn report_error<T: FileRead>(file_reader: &T, filename: &str, error: &Compilatio
         CompilationErrorItem::ParseError(parser_error) => report_parser_error(file_reader, filename, parser_error, error.position),
         CompilationErrorItem::TypeInferenceError(type_error) => report_type_inference_error(file_reader, filename, type_error, error.position),
         CompilationErrorItem::IntermediateRepresentationError(ir_error) => report_intermediate_representation_error(file_reader, filename, ir_error, error.position),
+        CompilationErrorItem::InterpretationError(interpretation_error) => report_interpretation_error(file_reader, filename, interpretation_error, error.position),
         CompilationErrorItem::ToDo { function, text } => report_todo_error(file_reader, filename, function, text, error.position),
+        CompilationErrorItem::ExpansionError { filename: macro_filename, error: macro_error } => {
+            report_error(file_reader, macro_filename, macro_error);
+            output_error(file_reader, filename, "in expansion of macro", error.position);
+        },
         CompilationErrorItem::ShutDownRequested => {},
         CompilationErrorItem::FileNotFound(filename) => report_file_not_found_error(filename),
+        CompilationErrorItem::LoadCycle(chain) => output_error(file_reader, filename, &format!("load cycle: {}", chain.join(" -> ")), error.position),
+        CompilationErrorItem::ModuleNotFound(module) => output_error(file_reader, filename, &format!("module {} not found", module), error.position),
         CompilationErrorItem::BackendError(backend_error) => report_backend_error(backend_error),
     }
 }
@@ -64,6 +79,7 @@ fn report_parser_error<T: FileRead>(file_reader: &T, filename: &str, error: &Par
     match error {
         ParseError::ExpectedFileName => output_error(file_reader, filename, "expected filename", position),
         ParseError::ExpectedLibraryName => output_error(file_reader, filename, "expected library name", position),
+        ParseError::ExpectedModuleName => output_error(file_reader, filename, "expected module name", position),
         ParseError::ExpectedForeignLibraryIdentifier => output_error(file_reader, filename, "expected foreign library identifier", position),
         ParseError::ExpectedIdentifier => output_error(file_reader, filename, "expected identifier", position),
         ParseError::ExpectedDeclarationName => output_error(file_reader, filename, "expected declaration name", position),
@@ -73,8 +89,10 @@ fn report_parser_error<T: FileRead>(file_reader: &T, filename: &str, error: &Par
         ParseError::ExpectedEnclosure(enclosure) => report_expected_enclosure_error(file_reader, filename, enclosure, position),
         ParseError::ExpectedOperator => output_error(file_reader, filename, "expected operator", position),
         ParseError::ExpectedType => output_error(file_reader, filename, "expected type", position),
+        ParseError::ExpectedCastMode => output_error(file_reader, filename, "expected cast mode", position),
         ParseError::ExpectedLineTerminator => output_error(file_reader, filename, "expected line terminator", position),
         ParseError::UnexpectedDirective => output_error(file_reader, filename, "unexpected directive", position),
+        ParseError::ExpectedAsmOperand => output_error(file_reader, filename, "expected asm operand", position),
         ParseError::TokenisationError(token_error) => report_token_error_error(file_reader, filename, token_error, position),
         ParseError::Unimplemented => output_error(file_reader, filename, "unimplemented", position),
     }
@@ -84,6 +102,7 @@ fn report_expected_enclosure_error<T: FileRead>(file_reader: &T, filename: &str,
     match enclosure {
         Enclosure::Brace(enclosure) => report_brace_error_error(file_reader, filename, enclosure, position),
         Enclosure::Parentheses(enclosure) => report_parentheses_error_error(file_reader, filename, enclosure, position),
+        Enclosure::Bracket(enclosure) => report_bracket_error_error(file_reader, filename, enclosure, position),
     }
 }
 
@@ -94,6 +113,13 @@ fn report_brace_error_error<T: FileRead>(file_reader: &T, filename: &str, enclos
     }
 }
 
+fn report_bracket_error_error<T: FileRead>(file_reader: &T, filename: &str, enclosure: &EnclosureType, position: SourceFilePosition) {
+    match enclosure {
+        EnclosureType::Open => output_error(file_reader, filename, "expected opening bracket", position),
+        EnclosureType::Close => output_error(file_reader, filename, "expected closing bracket", position),
+    }
+}
+
 fn report_parentheses_error_error<T: FileRead>(file_reader: &T, filename: &str, enclosure: &EnclosureType, position: SourceFilePosition) {
     match enclosure {
         EnclosureType::Open => output_error(file_reader, filename, "expected opening parentheses", position),
@@ -122,6 +148,24 @@ fn report_type_inference_error<T: FileRead>(file_reader: &T, filename: &str, err
         TypeInferenceError::LiteralShouldNotBeResolved => output_error(file_reader, filename, "literal should not resolved", position),
         TypeInferenceError::TypeSizeInBytesInvalidError(size) => output_error(file_reader, filename, &format!("type size {} in bytes invalid", size), position),
         TypeInferenceError::TypeCanNotBeFound => output_error(file_reader, filename, "type cannot be found", position),
+        TypeInferenceError::NotViableStructMember => output_error(file_reader, filename, "non viable struct member", position),
+        TypeInferenceError::NotAType => output_error(file_reader, filename, "expression is not a type", position),
+        TypeInferenceError::MemberNotFound(name) => output_error(file_reader, filename, &format!("member {} not found", name), position),
+        TypeInferenceError::IntrinsicArgsInvalid(name) => output_error(file_reader, filename, &format!("invalid arguments for {}", name), position),
+        TypeInferenceError::LossyImplicitConversion { from, to } => output_error(file_reader, filename, &format!("implicit conversion from {} to {} may lose data, use a cast", from, to), position),
+        TypeInferenceError::AssignmentToConstant(name) => output_error(file_reader, filename, &format!("cannot assign to constant {}", name), position),
+        TypeInferenceError::CallerLocalNotFound(name) => output_error(file_reader, filename, &format!("caller local {} not found for macro", name), position),
+        TypeInferenceError::ExpansionNotAStatement(name) => output_error(file_reader, filename, &format!("macro {} can only be expanded as a statement", name), position),
+        TypeInferenceError::ProcedureHasNoReturnValue(name) => output_error(file_reader, filename, &format!("procedure {} does not return a value", name), position),
+        TypeInferenceError::ConditionIsNotBool => output_error(file_reader, filename, "condition must be a bool", position),
+        TypeInferenceError::IndexIsNotAnInt => output_error(file_reader, filename, "index must be an int", position),
+        TypeInferenceError::NotIndexable => output_error(file_reader, filename, "only arrays and pointers to sized types can be indexed", position),
+        TypeInferenceError::ArrayLengthIsNotConstant => output_error(file_reader, filename, "array length must be a constant int expression", position),
+        TypeInferenceError::NotCode => output_error(file_reader, filename, "only code and strings can be inserted", position),
+        TypeInferenceError::TypeNotVisible { declaration_filename, declaration_position } => {
+            output_error(file_reader, filename, "type is declared but not visible here", position);
+            output_error(file_reader, declaration_filename, "declared here", *declaration_position);
+        },
     }
 }
 
@@ -136,7 +180,39 @@ fn report_intermediate_representation_error<T: FileRead>(file_reader: &T, filena
         IntermediateRepresentationError::ScopeNotKnown => output_error(file_reader, filename, "scope not known", position),
         IntermediateRepresentationError::NoAssignmentFound => output_error(file_reader, filename, "no assignment found", position),
         IntermediateRepresentationError::RegisterSizeNotResolved => output_error(file_reader, filename, "register size not resolved", position),
+        IntermediateRepresentationError::ExpectedOperator => output_error(file_reader, filename, "expected operator", position),
+        IntermediateRepresentationError::ExpectedCast => output_error(file_reader, filename, "expected cast", position),
+        IntermediateRepresentationError::AsmInstructionNotSupported(mnemonic) => output_error(file_reader, filename, &format!("asm instruction {} not supported with these operands", mnemonic), position),
+        IntermediateRepresentationError::AsmRegisterNotAvailable(name) => output_error(file_reader, filename, &format!("asm register {} not available", name), position),
+        IntermediateRepresentationError::GlobalVariableAssignmentValueNotSupported => output_error(file_reader, filename, "global variable assignment value not supported", position),
+    }
+}
+
+fn report_interpretation_error<T: FileRead>(file_reader: &T, filename: &str, error: &InterpretationError, position: SourceFilePosition) {
+    match error {
+        InterpretationError::SymbolNotFound(name) => output_error(file_reader, filename, &format!("symbol {} not available at compile time", name), position),
+        InterpretationError::UnsupportedInstruction => output_error(file_reader, filename, "unsupported instruction", position),
+        InterpretationError::UnimplementedInstruction => output_error(file_reader, filename, "unimplemented instruction", position),
+        InterpretationError::InvalidMemoryAccess(address) => output_error(file_reader, filename, &format!("invalid memory access at {:#x}", address), position),
+        InterpretationError::StackOverflow => output_error(file_reader, filename, "stack overflow", position),
+        InterpretationError::RegisterNotAvailable => output_error(file_reader, filename, "register not available", position),
+        InterpretationError::UnsupportedConstantExpression => output_error(file_reader, filename, "expression cannot be evaluated at compile time", position),
+        InterpretationError::AssertionFailed { message, operands } => output_error(file_reader, filename, &format_assertion_failed(message, operands), position),
+        InterpretationError::UnknownInterceptedMessageKind(kind) => output_error(file_reader, filename, &format!("unknown intercepted message kind {}", kind), position),
+        InterpretationError::NotAProcedure(address) => output_error(file_reader, filename, &format!("no procedure at {:#x}", address), position),
+        InterpretationError::UnsupportedFormatSpecifier(specifier) => output_error(file_reader, filename, &format!("unsupported format specifier %{}", specifier), position),
+        InterpretationError::UnsupportedSystemCall(number) => output_error(file_reader, filename, &format!("system call {} not supported", number), position),
+        InterpretationError::LabelNotFound(label) => output_error(file_reader, filename, &format!("label {} not found", label), position),
+        InterpretationError::Trap => output_error(file_reader, filename, "trapped on checked conversion", position),
+    }
+}
+
+fn format_assertion_failed(message: &str, operands: &[i64]) -> String {
+    let operands = operands.iter().map(|operand| operand.to_string()).collect::<Vec<String>>().join(", ");
+    if message.is_empty() {
+        return format!("assertion failed (evaluated: {})", operands);
     }
+    format!("assertion failed: {} (evaluated: {})", message, operands)
 }
 
 fn report_todo_error<T: FileRead>(file_reader: &T, filename: &str, function: &str, text: &str, position: SourceFilePosition) {
@@ -153,9 +229,21 @@ fn report_backend_error(backend_error: &BackendError) {
         BackendError::UnimplementedInstruction => println!("Unimplemented x64 instruction"),
         BackendError::UnimplementedFeature(feature) => println!("Unimplemented x64 feature: {}", feature),
         BackendError::RegisterNotAvailable(register) => println!("x64 register: {} not available", register),
+        BackendError::UndefinedSymbol(name, referenced_by) => println!("Unresolved external symbol: {} referenced in {}", name, referenced_by),
+        BackendError::DuplicateSymbol(name, defined_by) => println!("Symbol: {} in {} is already defined", name, defined_by),
+        BackendError::EntryPointNotFound(name) => println!("Entry point: {} not found", name),
+        BackendError::RelocationOutOfRange(name) => println!("Relocation against: {} is out of range", name),
+        BackendError::LabelNotFound(label) => println!("Jump to label: {} that is never placed", label),
     }
 }
 
+pub fn report_argument_error(error: ArgumentError) {
+    match error {
+        ArgumentError::UnknownTarget(target) => println!("Unknown target: {}", target),
+    }
+    println!("{}", USAGE);
+}
+
 fn output_error<T: FileRead>(file_reader: &T, filename: &str, text: &str, position: SourceFilePosition) {
     let source_line = get_source_line(file_reader, filename, position);
     println!("{}:", text);
@@ -171,11 +259,15 @@ fn get_source_line<'a, T: FileRead>(file_reader: &T, filename: &str, position: S
 pub enum CompilationErrorItem {
     None,
     FileNotFound(String),
+    ModuleNotFound(String),
+    LoadCycle(Vec<String>),
     ParseError(ParseError),
     TypeInferenceError(TypeInferenceError),
     IntermediateRepresentationError(IntermediateRepresentationError),
+    InterpretationError(InterpretationError),
     BackendError(BackendError),
     ToDo{ function: String, text: String },
+    ExpansionError { filename: String, error: Box<CompilationError> },
     ShutDownRequested
 }
 
@@ -187,6 +279,14 @@ pub fn file_not_found_error(filename: String) -> CompilationErrorItem {
     CompilationErrorItem::FileNotFound(filename)
 }
 
+pub fn module_not_found_error(module: String) -> CompilationErrorItem {
+    CompilationErrorItem::ModuleNotFound(module)
+}
+
+pub fn load_cycle_error(chain: Vec<String>) -> CompilationErrorItem {
+    CompilationErrorItem::LoadCycle(chain)
+}
+
 pub fn parser_error(error: ParseError) -> CompilationErrorItem {
     CompilationErrorItem::ParseError(error)
 }
@@ -199,6 +299,10 @@ pub fn intermediate_representation_error(error: IntermediateRepresentationError)
     CompilationErrorItem::IntermediateRepresentationError(error)
 }
 
+pub fn interpretation_error(error: InterpretationError) -> CompilationErrorItem {
+    CompilationErrorItem::InterpretationError(error)
+}
+
 pub fn todo_error(function: &str, text: &str) -> CompilationErrorItem {
     CompilationErrorItem::ToDo { function: string(function), text: string(text) }
 }
@@ -207,6 +311,10 @@ pub fn backend_error(error: BackendError) -> CompilationErrorItem {
     CompilationErrorItem::BackendError(error)
 }
 
+pub fn expansion_error(filename: String, error: CompilationError) -> CompilationErrorItem {
+    CompilationErrorItem::ExpansionError { filename, error: Box::new(error) }
+}
+
 pub fn shutdown_requested_error_item() -> CompilationErrorItem {
     CompilationErrorItem::ShutDownRequested
 }
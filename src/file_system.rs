@@ -1,10 +1,11 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf, Component};
 use std::fs::*;
 use std::io::{Result, BufReader, BufRead};
 
 pub trait FileRead: Send + Clone + 'static {
     fn read_file_to_string(&self, location: &str) -> Result<String>;
     fn read_line_from_file(&self, location: &str, line_number: usize) -> Result<String>;
+    fn file_exists(&self, location: &str) -> bool;
 }
 
 #[derive(Clone)]
@@ -22,6 +23,44 @@ impl FileRead for FileReader {
     fn read_line_from_file(&self, location: &str, line_number: usize) -> Result<String> {
         let file = File::open(PathBuf::from(location))?;
         let reader = BufReader::new(file);
-        reader.lines().nth(line_number - 1).unwrap()
+        match line_number.checked_sub(1).and_then(|index| reader.lines().nth(index)) {
+            Some(line) => line,
+            None => Ok(String::default())
+        }
     }
+
+    fn file_exists(&self, location: &str) -> bool {
+        PathBuf::from(location).is_file()
+    }
+}
+
+pub fn resolve_file_name_relative_to_file(relative_to_file_name: &str, file_name: &str) -> String {
+    let path = Path::new(file_name);
+    if path.is_absolute() {
+        return normalise_path(path);
+    }
+    let directory = Path::new(relative_to_file_name).parent().unwrap_or(Path::new(""));
+    normalise_path(&directory.join(path))
+}
+
+pub fn normalise_file_name(file_name: &str) -> String {
+    normalise_path(Path::new(file_name))
+}
+
+fn normalise_path(path: &Path) -> String {
+    let mut normalised = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {
+                if normalised.file_name().is_some() {
+                    normalised.pop();
+                } else {
+                    normalised.push(component);
+                }
+            },
+            _ => normalised.push(component)
+        }
+    }
+    normalised.to_string_lossy().into_owned()
 }
\ No newline at end of file
@@ -0,0 +1,34 @@
+use crate::{
+    parsing::*,
+    errors::*,
+    intermediate_representation::*,
+    utilities::*
+};
+
+pub fn build_bytecode_at_assert(ir: &mut IntermediateRepresentation, expr: &AbstractSyntaxNode, position: SourceFilePosition, errors: &mut CompilationErrors) {
+    ir.top_level_symbol = assert_symbol_name(position);
+    build_bytecode_at_assert_expression(ir, expr, errors);
+}
+
+pub fn assert_symbol_name(position: SourceFilePosition) -> String {
+    format!("__assert_{}", position.absolute)
+}
+
+fn build_bytecode_at_assert_expression(ir: &mut IntermediateRepresentation, expr: &AbstractSyntaxNode, errors: &mut CompilationErrors) {
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Global } =>
+            store_assert_identifier_as_foreign_symbol(ir, name),
+        AbstractSyntaxNodeItem::BinaryExpr { lhs, rhs, .. } => {
+            build_bytecode_at_assert_expression(ir, lhs, errors);
+            build_bytecode_at_assert_expression(ir, rhs, errors);
+        },
+        AbstractSyntaxNodeItem::Literal(_) => {},
+        _ => todo(errors, function!(), "Other assert expression types")
+    }
+}
+
+fn store_assert_identifier_as_foreign_symbol(ir: &mut IntermediateRepresentation, name: &str) {
+    if !ir.symbols.iter().any(|symbol| matches!(symbol, ByteCodeSymbol::ForeignExternal { name: existing } if existing == name)) {
+        add_symbol(&mut ir.symbols, foreign_external(string(name)));
+    }
+}
@@ -8,13 +8,19 @@ use crate::{
 
 pub struct IntemediateRepresentationActor {
     compiler: CompilationActorHandle,
-    error_reporter: CompilationActorHandle
+    error_reporter: CompilationActorHandle,
+    calling_convention: CallingConvention
 }
 
-pub fn create_intemediate_representation_actor(compiler: CompilationActorHandle, error_reporter: CompilationActorHandle) -> IntemediateRepresentationActor {
+pub fn create_intemediate_representation_actor(
+    compiler: CompilationActorHandle,
+    error_reporter: CompilationActorHandle,
+    calling_convention: CallingConvention
+) -> IntemediateRepresentationActor {
     IntemediateRepresentationActor {
         compiler,
-        error_reporter
+        error_reporter,
+        calling_convention
     }
 }
 
@@ -22,7 +28,7 @@ impl Actor<CompilationMessage> for IntemediateRepresentationActor {
     fn receive(&mut self, message: CompilationMessage, _ctx: &CompilationMessageContext) -> AfterReceiveAction {
         match message {
             CompilationMessage::BuildByteCode { unit, has_prior_errors } =>
-                build_bytecode(unit, &self.compiler, &self.error_reporter, has_prior_errors),
+                build_bytecode(unit, &self.compiler, &self.error_reporter, self.calling_convention, has_prior_errors),
             _ => continue_listening_after_receive()
         }
     }
@@ -32,8 +38,15 @@ impl Actor<CompilationMessage> for IntemediateRepresentationActor {
     }
 }
 
-fn build_bytecode(mut unit: CompilationUnit, compiler: &CompilationActorHandle, error_reporter: &CompilationActorHandle, has_prior_errors: bool) -> AfterReceiveAction {    
+fn build_bytecode(
+    mut unit: CompilationUnit,
+    compiler: &CompilationActorHandle,
+    error_reporter: &CompilationActorHandle,
+    calling_convention: CallingConvention,
+    has_prior_errors: bool
+) -> AfterReceiveAction {
     let mut ir = create_intermediate_representation(unit.id, unit.filename.clone());
+    ir.calling_convention = calling_convention;
     let mut errors = create_compilation_errors(unit.filename.clone());
     build_bytecode_at_root(&mut unit, &mut errors, &mut ir, has_prior_errors);
     report_errors(error_reporter, compiler.clone(), errors);
@@ -56,14 +69,21 @@ fn build_bytecode_at_root(unit: &mut CompilationUnit, errors: &mut CompilationEr
     }
 
     match unit.tree.item_ref() {
-        AbstractSyntaxNodeItem::ProcedureHeader { name, .. } =>
-            build_bytecode_at_procedure_header(ir, name),
+        AbstractSyntaxNodeItem::ProcedureHeader { name, body, .. } =>
+            build_bytecode_at_procedure_header(ir, name, body),
         AbstractSyntaxNodeItem::ProcedureBody { name, args, statements, .. } =>
             build_bytecode_at_procedure_body(ir, name, args, statements, errors),
         AbstractSyntaxNodeItem::Constant { name, value, ..} =>
             build_bytecode_at_top_root_const(ir, name, value, errors),
-        AbstractSyntaxNodeItem::Struct { name, ..} =>
-            todo(errors, function!(), &format!("struct bytecode coming soon {}", name)),
+        AbstractSyntaxNodeItem::GlobalVariable { name, value, variable_type } =>
+            build_bytecode_at_global_variable(ir, name, value, variable_type, errors),
+        AbstractSyntaxNodeItem::Run { expr } =>
+            build_bytecode_at_run(ir, expr, unit.tree.position, errors),
+        AbstractSyntaxNodeItem::Insert { expr } =>
+            build_bytecode_at_run(ir, expr, unit.tree.position, errors),
+        AbstractSyntaxNodeItem::Assert { expr, .. } =>
+            build_bytecode_at_assert(ir, expr, unit.tree.position, errors),
+        AbstractSyntaxNodeItem::Struct { .. } => {},
         AbstractSyntaxNodeItem::Error => {},
         item =>
             todo(errors, function!(), &format!("Other root bytecode: {:?}", item))
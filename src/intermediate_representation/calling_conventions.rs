@@ -0,0 +1,377 @@
+use crate::{
+    compilation::*,
+    intermediate_representation::*,
+    types::*
+};
+
+const WIN64_SHADOW_SPACE_SIZE: usize = 32;
+const WIN64_FLOAT_ARGUMENT_REGISTER_COUNT: usize = 4;
+const SYSTEM_V_FLOAT_ARGUMENT_REGISTER_COUNT: usize = 8;
+const STACK_ARGUMENT_SIZE: usize = 8;
+const RETURN_ADDRESS_AND_BASE_POINTER_SIZE: i32 = 16;
+const EIGHTBYTE_SIZE: usize = 8;
+const SYSTEM_V_MAX_REGISTER_STRUCT_SIZE: usize = 16;
+
+// integer argument registers as standard registers: rcx, rdx, r8, r9 on win64
+// and rdi, rsi, rdx, rcx, r8, r9 on system v
+const WIN64_INTEGER_ARGUMENT_REGISTERS: [usize; 4] = [1, 2, 3, 4];
+const SYSTEM_V_INTEGER_ARGUMENT_REGISTERS: [usize; 6] = [12, 11, 2, 1, 3, 4];
+
+const WIN64_CALLEE_SAVED_STANDARD_REGISTERS: [usize; 6] = [7, 8, 9, 10, 11, 12];
+const SYSTEM_V_CALLEE_SAVED_STANDARD_REGISTERS: [usize; 4] = [7, 8, 9, 10];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum CallingConvention {
+    #[default]
+    Win64,
+    SystemV
+}
+
+pub fn win64_calling_convention() -> CallingConvention {
+    CallingConvention::Win64
+}
+
+pub fn system_v_calling_convention() -> CallingConvention {
+    CallingConvention::SystemV
+}
+
+pub fn get_target_calling_convention(target: CompilationTarget) -> CallingConvention {
+    match target {
+        CompilationTarget::Windows => win64_calling_convention(),
+        CompilationTarget::Linux => system_v_calling_convention()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArgumentClass {
+    Integer,
+    Float
+}
+
+pub fn integer_argument_class() -> ArgumentClass {
+    ArgumentClass::Integer
+}
+
+pub fn float_argument_class() -> ArgumentClass {
+    ArgumentClass::Float
+}
+
+pub fn get_argument_class(argument_type: &RuntimeTypePointer) -> ArgumentClass {
+    match resolved_type_to_register_size(argument_type) {
+        Some(register_size) if is_float_register_size(register_size) => float_argument_class(),
+        _ => integer_argument_class()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArgumentLocation {
+    Register(ByteCodeRegister),
+    Stack(AddressOffset)
+}
+
+pub fn register_argument_location(register: ByteCodeRegister) -> ArgumentLocation {
+    ArgumentLocation::Register(register)
+}
+
+pub fn stack_argument_location(offset: AddressOffset) -> ArgumentLocation {
+    ArgumentLocation::Stack(offset)
+}
+
+pub type ArgumentLocations = Vec<ArgumentLocation>;
+
+pub fn get_argument_locations(calling_convention: CallingConvention, classes: &[ArgumentClass]) -> ArgumentLocations {
+    match calling_convention {
+        CallingConvention::Win64 => get_win64_argument_locations(classes),
+        CallingConvention::SystemV => get_system_v_argument_locations(classes)
+    }
+}
+
+fn get_win64_argument_locations(classes: &[ArgumentClass]) -> ArgumentLocations {
+    classes
+        .iter()
+        .enumerate()
+        .map(|(index, class)| {
+            if index < WIN64_INTEGER_ARGUMENT_REGISTERS.len() {
+                return register_argument_location(get_argument_register(*class, index));
+            }
+            let stack_index = index - WIN64_INTEGER_ARGUMENT_REGISTERS.len();
+            stack_argument_location(address_offset((WIN64_SHADOW_SPACE_SIZE + stack_index * STACK_ARGUMENT_SIZE) as i32))
+        })
+        .collect()
+}
+
+fn get_system_v_argument_locations(classes: &[ArgumentClass]) -> ArgumentLocations {
+    let parts: Vec<(Vec<ArgumentClass>, bool)> = classes
+        .iter()
+        .map(|class| (vec!(*class), false))
+        .collect();
+    get_system_v_argument_part_locations(&parts).concat()
+}
+
+fn get_system_v_argument_part_locations(parts: &[(Vec<ArgumentClass>, bool)]) -> Vec<ArgumentLocations> {
+    let mut integer_index = 0;
+    let mut float_index = 0;
+    let mut stack_index = 0;
+
+    let mut locations = vec!();
+    for (classes, is_in_memory) in parts {
+        let integer_count = classes.iter().filter(|class| **class == integer_argument_class()).count();
+        let float_count = classes.len() - integer_count;
+        let is_in_registers = !is_in_memory
+            && integer_index + integer_count <= get_argument_register_count(system_v_calling_convention(), integer_argument_class())
+            && float_index + float_count <= get_argument_register_count(system_v_calling_convention(), float_argument_class());
+
+        let mut argument_locations = vec!();
+        for class in classes {
+            if is_in_registers {
+                let register_index = match class {
+                    ArgumentClass::Integer => &mut integer_index,
+                    ArgumentClass::Float => &mut float_index
+                };
+                argument_locations.push(register_argument_location(get_argument_register(*class, *register_index)));
+                *register_index += 1;
+            } else {
+                argument_locations.push(stack_argument_location(address_offset((stack_index * STACK_ARGUMENT_SIZE) as i32)));
+                stack_index += 1;
+            }
+        }
+        locations.push(argument_locations);
+    }
+    locations
+}
+
+fn get_argument_register(class: ArgumentClass, number: usize) -> ByteCodeRegister {
+    match class {
+        ArgumentClass::Integer => call_arg_register(number),
+        ArgumentClass::Float => float_call_arg_register(number)
+    }
+}
+
+pub fn get_argument_register_count(calling_convention: CallingConvention, class: ArgumentClass) -> usize {
+    match (calling_convention, class) {
+        (CallingConvention::Win64, ArgumentClass::Integer) => WIN64_INTEGER_ARGUMENT_REGISTERS.len(),
+        (CallingConvention::Win64, ArgumentClass::Float) => WIN64_FLOAT_ARGUMENT_REGISTER_COUNT,
+        (CallingConvention::SystemV, ArgumentClass::Integer) => SYSTEM_V_INTEGER_ARGUMENT_REGISTERS.len(),
+        (CallingConvention::SystemV, ArgumentClass::Float) => SYSTEM_V_FLOAT_ARGUMENT_REGISTER_COUNT
+    }
+}
+
+pub fn get_call_arg_standard_register(calling_convention: CallingConvention, number: usize) -> Option<ByteCodeRegister> {
+    let registers: &[usize] = match calling_convention {
+        CallingConvention::Win64 => &WIN64_INTEGER_ARGUMENT_REGISTERS,
+        CallingConvention::SystemV => &SYSTEM_V_INTEGER_ARGUMENT_REGISTERS
+    };
+    registers.get(number).map(|register| standard_register(*register))
+}
+
+pub fn is_float_call_arg_available(calling_convention: CallingConvention, number: usize) -> bool {
+    number < get_argument_register_count(calling_convention, float_argument_class())
+}
+
+pub fn get_argument_location_register(location: ArgumentLocation) -> ByteCodeRegister {
+    match location {
+        ArgumentLocation::Register(register) => register,
+        ArgumentLocation::Stack(_) => standard_register(0)
+    }
+}
+
+pub fn get_argument_location_register_size(location: ArgumentLocation, register_size: RegisterSize) -> RegisterSize {
+    match (location, register_size) {
+        (ArgumentLocation::Stack(_), RegisterSize::Float) => register_size_32(),
+        (ArgumentLocation::Stack(_), RegisterSize::LargeFloat) => register_size_64(),
+        _ => register_size
+    }
+}
+
+pub fn get_shadow_space_size(calling_convention: CallingConvention) -> usize {
+    match calling_convention {
+        CallingConvention::Win64 => WIN64_SHADOW_SPACE_SIZE,
+        CallingConvention::SystemV => 0
+    }
+}
+
+pub fn get_call_stack_space_size(calling_convention: CallingConvention, locations: &[ArgumentLocation]) -> usize {
+    let stack_argument_count = locations
+        .iter()
+        .filter(|location| matches!(location, ArgumentLocation::Stack(_)))
+        .count();
+    get_shadow_space_size(calling_convention) + stack_argument_count * STACK_ARGUMENT_SIZE
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParameterHome {
+    CallerFrame(AddressOffset),
+    CalleeFrame
+}
+
+pub fn get_parameter_home(calling_convention: CallingConvention, index: usize, location: ArgumentLocation) -> ParameterHome {
+    match (calling_convention, location) {
+        (_, ArgumentLocation::Stack(offset)) =>
+            ParameterHome::CallerFrame(offset + RETURN_ADDRESS_AND_BASE_POINTER_SIZE),
+        (CallingConvention::Win64, ArgumentLocation::Register(_)) =>
+            ParameterHome::CallerFrame(address_offset(RETURN_ADDRESS_AND_BASE_POINTER_SIZE + (index * STACK_ARGUMENT_SIZE) as i32)),
+        (CallingConvention::SystemV, ArgumentLocation::Register(_)) =>
+            ParameterHome::CalleeFrame
+    }
+}
+
+pub fn is_callee_saved_register(calling_convention: CallingConvention, register: ByteCodeRegister) -> bool {
+    let standard_registers: &[usize] = match calling_convention {
+        CallingConvention::Win64 => &WIN64_CALLEE_SAVED_STANDARD_REGISTERS,
+        CallingConvention::SystemV => &SYSTEM_V_CALLEE_SAVED_STANDARD_REGISTERS
+    };
+
+    match register {
+        ByteCodeRegister::StackPointer | ByteCodeRegister::BasePointer => true,
+        ByteCodeRegister::Standard(number) => standard_registers.contains(&number),
+        _ => false
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructArgumentPassing {
+    Registers(Vec<ArgumentClass>),
+    Reference,
+    Memory
+}
+
+// win64 passes structs of 1, 2, 4 or 8 bytes in an integer register and anything else as a pointer
+// to a copy, system v copies structs over 16 bytes onto the stack and classifies each eightbyte
+// of the rest, which only goes in a float register when everything in it is a float
+pub fn classify_struct_argument(calling_convention: CallingConvention, struct_type: &RuntimeType) -> StructArgumentPassing {
+    let size = try_get_resolved_type_size(&struct_type.size).unwrap_or_default();
+
+    match calling_convention {
+        CallingConvention::Win64 => match size {
+            1 | 2 | 4 | 8 => StructArgumentPassing::Registers(vec!(integer_argument_class())),
+            _ => StructArgumentPassing::Reference
+        },
+        CallingConvention::SystemV => {
+            if size == 0 || size > SYSTEM_V_MAX_REGISTER_STRUCT_SIZE {
+                return StructArgumentPassing::Memory;
+            }
+
+            let mut fields = vec!();
+            add_struct_scalar_fields(&mut fields, struct_type, 0);
+
+            let classes = (0..size.div_ceil(EIGHTBYTE_SIZE))
+                .map(|eightbyte| {
+                    let is_float = fields
+                        .iter()
+                        .filter(|(offset, _)| *offset / EIGHTBYTE_SIZE == eightbyte)
+                        .all(|(_, is_float)| *is_float);
+                    if is_float { float_argument_class() } else { integer_argument_class() }
+                })
+                .collect();
+            StructArgumentPassing::Registers(classes)
+        }
+    }
+}
+
+fn add_struct_scalar_fields(fields: &mut Vec<(usize, bool)>, field_type: &RuntimeType, offset: usize) {
+    let members = get_type_info_members(field_type);
+    if members.is_empty() {
+        fields.push((offset, matches!(field_type.item, RuntimeTypeItem::Float)));
+        return;
+    }
+
+    for member in &members {
+        if let Some(member_offset) = get_member_offset(&members, &member.name) {
+            add_struct_scalar_fields(fields, &member.field_type, offset + member_offset);
+        }
+    }
+}
+
+pub fn is_struct_argument(argument_type: &RuntimeType) -> bool {
+    !matches!(argument_type.item, RuntimeTypeItem::Pointer { .. }) && !get_type_info_members(argument_type).is_empty()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassedArgument {
+    pub is_struct_value: bool,
+    pub classes: Vec<ArgumentClass>,
+    pub locations: ArgumentLocations
+}
+
+pub type PassedArguments = Vec<PassedArgument>;
+
+pub fn passed_argument(is_struct_value: bool, classes: Vec<ArgumentClass>, locations: ArgumentLocations) -> PassedArgument {
+    PassedArgument { is_struct_value, classes, locations }
+}
+
+pub fn get_passed_arguments(calling_convention: CallingConvention, argument_types: &[Option<RuntimeTypePointer>]) -> PassedArguments {
+    let parts: Vec<(Vec<ArgumentClass>, bool, bool)> = argument_types
+        .iter()
+        .map(|argument_type| get_argument_parts(calling_convention, argument_type.as_ref()))
+        .collect();
+
+    let locations = match calling_convention {
+        CallingConvention::Win64 => get_win64_argument_locations(&parts.iter().map(|(classes, ..)| classes[0]).collect::<Vec<ArgumentClass>>())
+            .into_iter()
+            .map(|location| vec!(location))
+            .collect(),
+        CallingConvention::SystemV => get_system_v_argument_part_locations(
+            &parts.iter().map(|(classes, _, is_in_memory)| (classes.clone(), *is_in_memory)).collect::<Vec<(Vec<ArgumentClass>, bool)>>()
+        )
+    };
+
+    parts
+        .into_iter()
+        .zip(locations)
+        .map(|((classes, is_struct_value, _), locations)| passed_argument(is_struct_value, classes, locations))
+        .collect()
+}
+
+fn get_argument_parts(calling_convention: CallingConvention, argument_type: Option<&RuntimeTypePointer>) -> (Vec<ArgumentClass>, bool, bool) {
+    match argument_type {
+        Some(argument_type) if is_struct_argument(argument_type) => match classify_struct_argument(calling_convention, argument_type) {
+            StructArgumentPassing::Registers(classes) => (classes, true, false),
+            StructArgumentPassing::Reference => (vec!(integer_argument_class()), false, false),
+            StructArgumentPassing::Memory => {
+                let size = try_get_resolved_type_size(&argument_type.size).unwrap_or_default();
+                (vec!(integer_argument_class(); size.div_ceil(EIGHTBYTE_SIZE)), true, true)
+            }
+        },
+        Some(argument_type) => (vec!(get_argument_class(argument_type)), false, false),
+        None => (vec!(integer_argument_class()), false, false)
+    }
+}
+
+pub fn get_passed_argument_locations(arguments: &[PassedArgument]) -> ArgumentLocations {
+    arguments
+        .iter()
+        .flat_map(|argument| argument.locations.clone())
+        .collect()
+}
+
+pub fn get_struct_part_register_size(struct_type: &RuntimeType, part: usize, class: ArgumentClass) -> RegisterSize {
+    let size = try_get_resolved_type_size(&struct_type.size).unwrap_or_default();
+    let part_size = size.saturating_sub(part * EIGHTBYTE_SIZE).min(EIGHTBYTE_SIZE);
+    match (class, part_size) {
+        (ArgumentClass::Float, 4) => register_size_float_32(),
+        (ArgumentClass::Float, _) => register_size_float_64(),
+        (ArgumentClass::Integer, 1) => register_size_8(),
+        (ArgumentClass::Integer, 2) => register_size_16(),
+        (ArgumentClass::Integer, 3 | 4) => register_size_32(),
+        (ArgumentClass::Integer, _) => register_size_64()
+    }
+}
+
+pub fn get_struct_part_offset(part: usize) -> i32 {
+    (part * EIGHTBYTE_SIZE) as i32
+}
+
+pub fn get_struct_parts_size(argument: &PassedArgument) -> usize {
+    argument.locations.len() * EIGHTBYTE_SIZE
+}
+
+pub fn struct_argument_pointer_register() -> ByteCodeRegister {
+    standard_register(0)
+}
+
+pub fn get_struct_argument_part_register(location: ArgumentLocation) -> ByteCodeRegister {
+    match location {
+        ArgumentLocation::Register(register) => register,
+        ArgumentLocation::Stack(_) => standard_register(5)
+    }
+}
@@ -18,6 +18,10 @@ pub fn build_bytecode_at_top_root_const(ir: &mut IntermediateRepresentation, nam
                 add_intermediate_representation_error(errors, literal_not_resolved_error(), const_position);
             }
         },        
+        AbstractSyntaxNodeItem::Insert { expr } =>
+            build_bytecode_at_run(ir, expr, const_position, errors),
+        AbstractSyntaxNodeItem::Run { expr } =>
+            build_bytecode_at_named_run(ir, name, expr, errors),
         _ => todo(errors, function!(), "other top level const types"),
     }
 }
@@ -12,6 +12,11 @@ pub enum IntermediateRepresentationError {
     ScopeNotKnown,
     NoAssignmentFound,
     RegisterSizeNotResolved,
+    ExpectedOperator,
+    ExpectedCast,
+    AsmInstructionNotSupported(String),
+    AsmRegisterNotAvailable(String),
+    GlobalVariableAssignmentValueNotSupported,
 }
 
 pub fn literal_not_resolved_error() -> IntermediateRepresentationError {
@@ -50,6 +55,26 @@ pub fn register_size_not_resolved_error() -> IntermediateRepresentationError {
     IntermediateRepresentationError::RegisterSizeNotResolved
 }
 
+pub fn expected_binary_operator_error() -> IntermediateRepresentationError {
+    IntermediateRepresentationError::ExpectedOperator
+}
+
+pub fn expected_cast_error() -> IntermediateRepresentationError {
+    IntermediateRepresentationError::ExpectedCast
+}
+
+pub fn asm_instruction_not_supported_error(mnemonic: String) -> IntermediateRepresentationError {
+    IntermediateRepresentationError::AsmInstructionNotSupported(mnemonic)
+}
+
+pub fn asm_register_not_available_error(name: String) -> IntermediateRepresentationError {
+    IntermediateRepresentationError::AsmRegisterNotAvailable(name)
+}
+
+pub fn global_variable_assignment_value_not_supported_error() -> IntermediateRepresentationError {
+    IntermediateRepresentationError::GlobalVariableAssignmentValueNotSupported
+}
+
 pub fn add_intermediate_representation_error(errors: &mut CompilationErrors, error: IntermediateRepresentationError, position: SourceFilePosition) {
     add_compilation_error(errors, compilation_error(intermediate_representation_error(error), position));
 }
@@ -0,0 +1,29 @@
+use crate::{
+    parsing::*,
+    intermediate_representation::*
+};
+
+pub fn try_get_float_literal_register_size(literal: &ResolvedLiteral) -> Option<RegisterSize> {
+    match literal {
+        ResolvedLiteral::Float32(_) => Some(register_size_float_32()),
+        ResolvedLiteral::Float64(_) => Some(register_size_float_64()),
+        _ => None
+    }
+}
+
+pub fn store_float_literal_in_data_section(ir: &mut IntermediateRepresentation, literal: &ResolvedLiteral) -> Option<DataSectionOffset> {
+    match literal {
+        ResolvedLiteral::Float32(value) => Some(add_data_item(&mut ir.data, double_word_data_item(value.to_bits()))),
+        ResolvedLiteral::Float64(value) => Some(add_data_item(&mut ir.data, quad_word_data_item(value.to_bits()))),
+        _ => None
+    }
+}
+
+pub fn build_bytecode_to_load_float_literal_to_reg(ir: &mut IntermediateRepresentation, literal: &ResolvedLiteral, to: ByteCodeRegister) {
+    if let (Some(register_size), Some(data_section_offset)) = (try_get_float_literal_register_size(literal), store_float_literal_in_data_section(ir, literal)) {
+        add_byte_code(
+            &mut ir.byte_code,
+            move_data_section_to_float_reg_instruction(register_size, data_section_offset, to)
+        );
+    }
+}
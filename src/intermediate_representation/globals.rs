@@ -0,0 +1,114 @@
+use crate::{
+    parsing::*,
+    errors::*,
+    intermediate_representation::*,
+    types::*
+};
+
+pub fn build_bytecode_at_global_variable(
+    ir: &mut IntermediateRepresentation,
+    name: &str,
+    value: &AbstractSyntaxNode,
+    variable_type: &ResolvableType,
+    errors: &mut CompilationErrors
+) {
+    ir.top_level_symbol = string(name);
+    let value_position = value.position;
+    match value.item_ref() {
+        AbstractSyntaxNodeItem::Null => 
+            build_bytecode_at_zero_initialised_global_variable(ir, name, variable_type, value_position, errors),
+        AbstractSyntaxNodeItem::Literal(literal) => {
+            if let Some(resolved_literal) = try_get_resolved_literal(literal) {
+                build_bytecode_at_literal_global_variable(ir, name, &resolved_literal, errors);
+            } else {
+                add_intermediate_representation_error(errors, literal_not_resolved_error(), value_position);
+            }
+        },
+        _ => todo(errors, function!(), "Other global variable initialisers")
+    }
+}
+
+fn build_bytecode_at_zero_initialised_global_variable(
+    ir: &mut IntermediateRepresentation,
+    name: &str,
+    variable_type: &ResolvableType,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(size) = try_get_type_size_from_resolvable_type(variable_type) {
+        let pointer = reserve_bss_space(&mut ir.bss, size as u32);
+        add_symbol(&mut ir.symbols, external_bss_section_item(string(name), pointer));
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), position);
+    }
+}
+
+fn build_bytecode_at_literal_global_variable(ir: &mut IntermediateRepresentation, name: &str, literal: &ResolvedLiteral, errors: &mut CompilationErrors) {
+    let data_item = match resolved_literal_to_instruction_value(literal) {
+        InstructionValue::Byte(value) => byte_data_item(value),
+        InstructionValue::Word(value) => word_data_item(value),
+        InstructionValue::DoubleWord(value) => double_word_data_item(value),
+        InstructionValue::QuadWord(value) => quad_word_data_item(value),
+        InstructionValue::Float(value) => double_word_data_item(value.to_bits()),
+        InstructionValue::LargeFloat(value) => quad_word_data_item(value.to_bits()),
+        InstructionValue::Unsupported => {
+            todo(errors, function!(), "String literal global variables");
+            return;
+        }
+    };
+    let pointer = add_data_item(&mut ir.data, data_item);
+    add_symbol(&mut ir.symbols, external_data_section_item(string(name), *pointer));
+}
+
+pub fn build_bytecode_to_load_global_variable_to_reg(ir: &mut IntermediateRepresentation, name: &str, register_size: RegisterSize, to: ByteCodeRegister) {
+    build_bytecode_to_load_global_variable_address(ir, name);
+    add_byte_code(
+        &mut ir.byte_code,
+        move_reg_plus_offset_to_reg_instruction(register_size, standard_register(0), address_offset(0), to)
+    );
+}
+
+pub fn build_bytecode_to_load_global_variable_to_reg_extended(
+    ir: &mut IntermediateRepresentation,
+    name: &str,
+    register_size: RegisterSize,
+    is_signed: bool,
+    to: ByteCodeRegister
+) {
+    build_bytecode_to_load_global_variable_address(ir, name);
+    add_byte_code(
+        &mut ir.byte_code,
+        move_reg_plus_offset_to_reg_extended_instruction(register_size, is_signed, standard_register(0), address_offset(0), to)
+    );
+}
+
+pub fn build_bytecode_to_store_reg_to_global_variable(ir: &mut IntermediateRepresentation, name: &str, register_size: RegisterSize, from: ByteCodeRegister) {
+    build_bytecode_to_load_global_variable_address(ir, name);
+    add_byte_code(
+        &mut ir.byte_code,
+        move_reg_to_reg_plus_offset_instruction(register_size, from, standard_register(0), address_offset(0))
+    );
+}
+
+pub fn build_bytecode_to_store_value_to_global_variable(ir: &mut IntermediateRepresentation, name: &str, value: InstructionValue) {
+    build_bytecode_to_load_global_variable_address(ir, name);
+    add_byte_code(
+        &mut ir.byte_code,
+        move_value_to_reg_plus_offset_instruction(value, standard_register(0), address_offset(0))
+    );
+}
+
+fn build_bytecode_to_load_global_variable_address(ir: &mut IntermediateRepresentation, name: &str) {
+    let symbol_index = add_symbol(&mut ir.symbols, foreign_external(string(name)));
+    add_byte_code(
+        &mut ir.byte_code,
+        load_symbol_address_to_reg(register_size_64(), symbol_index, standard_register(0))
+    );
+}
+
+pub fn global_variable_register(register_size: RegisterSize) -> ByteCodeRegister {
+    if is_float_register_size(register_size) {
+        return float_standard_register(0);
+    }
+    standard_register(1)
+}
@@ -0,0 +1,59 @@
+use crate::{
+    parsing::*,
+    compilation::*,
+    intermediate_representation::*,
+    strings::*
+};
+
+const COMPILER_MESSAGE_RECORD_SIZE: u64 = 48;
+
+pub fn build_intercepted_message_intermediate_representation(
+    id: CompilationUnitId,
+    filename: String,
+    handler: &str,
+    message: &InterceptedMessage,
+    calling_convention: CallingConvention
+) -> IntermediateRepresentation {
+    let name = intercept_symbol_name(handler);
+    let mut ir = create_intermediate_representation(id, filename);
+    ir.top_level_symbol = name.clone();
+    ir.calling_convention = calling_convention;
+
+    let locations = get_argument_locations(calling_convention, &[integer_argument_class()]);
+
+    store_procedure_name_as_external_symbol(&mut ir, &name);
+    build_bytecode_for_procedure_prologue(&mut ir);
+    reserve_call_stack_space(&mut ir, &locations);
+    build_bytecode_at_intercepted_message_argument(&mut ir, message, locations[0]);
+    call_external_function(&mut ir, handler);
+    release_call_stack_space(&mut ir, &locations);
+    build_bytecode_for_procedure_epilogue(&mut ir);
+    ir
+}
+
+pub fn intercept_symbol_name(handler: &str) -> String {
+    format!("__intercept_{}", handler)
+}
+
+fn build_bytecode_at_intercepted_message_argument(ir: &mut IntermediateRepresentation, message: &InterceptedMessage, location: ArgumentLocation) {
+    let message_pointer = store_intercepted_message_in_data_section(ir, message);
+    add_byte_code(&mut ir.byte_code, load_data_section_address_to_reg(register_size_64(), message_pointer, get_argument_location_register(location)));
+    build_bytecode_to_store_argument_if_on_stack(ir, location);
+}
+
+fn store_intercepted_message_in_data_section(ir: &mut IntermediateRepresentation, message: &InterceptedMessage) -> DataSectionOffset {
+    let name = to_byte_string(&message.name);
+    let type_info = match &message.type_info {
+        Some(type_info_type) => symbol_pointer_data_item(build_bytecode_at_type_info_reference(ir, type_info_type)),
+        None => quad_word_data_item(0)
+    };
+
+    let message_pointer = add_data_item(&mut ir.data, quad_word_data_item(message.kind as u64));
+    add_data_item(&mut ir.data, quad_word_data_item(name.len() as u64));
+    add_data_item(&mut ir.data, pointer_data_item(*message_pointer as u64 + COMPILER_MESSAGE_RECORD_SIZE));
+    add_data_item(&mut ir.data, quad_word_data_item(message.node_kind));
+    add_data_item(&mut ir.data, quad_word_data_item(message.count));
+    add_data_item(&mut ir.data, type_info);
+    add_data_item(&mut ir.data, string_data_item(name));
+    message_pointer
+}
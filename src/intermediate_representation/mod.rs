@@ -3,16 +3,30 @@
 mod builder;
 mod procedures;
 mod constants;
+mod globals;
 mod strings;
+mod floats;
+mod runs;
+mod asserts;
+mod type_infos;
+mod interceptions;
 mod errors;
+mod calling_conventions;
 
 use std::ops::{Deref, Add};
 
 pub use builder::*;
 pub use procedures::*;
 pub use constants::*;
+pub use globals::*;
 pub use strings::*;
+pub use floats::*;
+pub use runs::*;
+pub use asserts::*;
+pub use type_infos::*;
+pub use interceptions::*;
 pub use errors::*;
+pub use calling_conventions::*;
 
 use crate::{
     parsing::*,
@@ -35,9 +49,18 @@ pub struct IntermediateRepresentation {
     pub byte_code: ByteCodeInstructionStream,
     pub symbols: ByteCodeSymbols,
     pub data: ByteCodeData,
-    pub foreign_libraries: ForeignLibraryReferences
+    pub read_only_data: ByteCodeData,
+    pub bss: ByteCodeBss,
+    pub foreign_libraries: ForeignLibraryReferences,
+    pub foreign_library_identifier: Option<String>,
+    pub type_infos: RuntimeTypePointers,
+    pub calling_convention: CallingConvention,
+    pub saved_registers: ByteCodeRegisters,
+    pub label_count: u32
 }
 
+pub type IntermediateRepresentations = Vec<IntermediateRepresentation>;
+
 pub fn create_intermediate_representation(id: CompilationUnitId, filename: String) -> IntermediateRepresentation {
     IntermediateRepresentation {
         id,
@@ -46,19 +69,37 @@ pub fn create_intermediate_representation(id: CompilationUnitId, filename: Strin
         byte_code: vec!(),
         symbols: vec!(),
         data: ByteCodeData::default(),
-        foreign_libraries: vec!()
+        read_only_data: ByteCodeData::default(),
+        bss: ByteCodeBss::default(),
+        foreign_libraries: vec!(),
+        foreign_library_identifier: None,
+        type_infos: vec!(),
+        calling_convention: CallingConvention::default(),
+        saved_registers: vec!(),
+        label_count: 0
     }
 }
 
+pub fn create_label(ir: &mut IntermediateRepresentation) -> ByteCodeLabel {
+    let label = ByteCodeLabel(ir.label_count);
+    ir.label_count += 1;
+    label
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ByteCodeRegister {
     Standard(usize),
     CallArg(usize),
     CallReturnArg(usize),
     StackPointer,
-    BasePointer
+    BasePointer,
+    FloatStandard(usize),
+    FloatCallArg(usize),
+    FloatCallReturnArg(usize)
 }
 
+pub type ByteCodeRegisters = Vec<ByteCodeRegister>;
+
 pub fn standard_register(number: usize) -> ByteCodeRegister {
     ByteCodeRegister::Standard(number)
 }
@@ -79,6 +120,32 @@ pub fn stack_pointer_register() -> ByteCodeRegister {
     ByteCodeRegister::StackPointer
 }
 
+pub fn call_arg_register_for_size(register_size: RegisterSize, number: usize) -> ByteCodeRegister {
+    if is_float_register_size(register_size) {
+        return float_call_arg_register(number);
+    }
+    call_arg_register(number)
+}
+
+pub fn call_return_arg_register_for_size(register_size: RegisterSize, number: usize) -> ByteCodeRegister {
+    if is_float_register_size(register_size) {
+        return float_call_return_arg_register(number);
+    }
+    call_return_arg_register(number)
+}
+
+pub fn float_standard_register(number: usize) -> ByteCodeRegister {
+    ByteCodeRegister::FloatStandard(number)
+}
+
+pub fn float_call_arg_register(number: usize) -> ByteCodeRegister {
+    ByteCodeRegister::FloatCallArg(number)
+}
+
+pub fn float_call_return_arg_register(number: usize) -> ByteCodeRegister {
+    ByteCodeRegister::FloatCallReturnArg(number)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum InstructionValue {
     Byte(u8),
@@ -94,6 +161,10 @@ pub fn instruction_value_8(value: u8) -> InstructionValue {
     InstructionValue::Byte(value)
 }
 
+pub fn instruction_value_16(value: u16) -> InstructionValue {
+    InstructionValue::Word(value)
+}
+
 pub fn instruction_value_32(value: u32) -> InstructionValue {
     InstructionValue::DoubleWord(value)
 }
@@ -102,6 +173,14 @@ pub fn instruction_value_64(value: u64) -> InstructionValue {
     InstructionValue::QuadWord(value)
 }
 
+// values added to or subtracted from the stack pointer are sign extended so a byte only holds up to 127
+pub fn stack_size_instruction_value(size: usize) -> InstructionValue {
+    match i8::try_from(size) {
+        Ok(value) => instruction_value_8(value as u8),
+        Err(_) => instruction_value_32(size as u32)
+    }
+}
+
 impl From<&ResolvedLiteral> for InstructionValue {
     fn from(from: &ResolvedLiteral) -> Self {
         match from {
@@ -142,6 +221,26 @@ pub fn register_size_64() -> RegisterSize {
     RegisterSize::QuadWord
 }
 
+pub fn register_size_8() -> RegisterSize {
+    RegisterSize::Byte
+}
+
+pub fn register_size_16() -> RegisterSize {
+    RegisterSize::Word
+}
+
+pub fn register_size_float_32() -> RegisterSize {
+    RegisterSize::Float
+}
+
+pub fn register_size_float_64() -> RegisterSize {
+    RegisterSize::LargeFloat
+}
+
+pub fn is_float_register_size(register_size: RegisterSize) -> bool {
+    matches!(register_size, RegisterSize::Float | RegisterSize::LargeFloat)
+}
+
 impl From<BuiltInType> for RegisterSize {
     fn from(from: BuiltInType) -> Self {
         match from {
@@ -158,6 +257,12 @@ impl From<BuiltInType> for RegisterSize {
             BuiltInType::String => RegisterSize::QuadWord,
             BuiltInType::Boolean => RegisterSize::Byte,
             BuiltInType::Void => RegisterSize::QuadWord,
+            BuiltInType::TypeInfo => RegisterSize::QuadWord,
+            BuiltInType::TypeInfoMember => RegisterSize::QuadWord,
+            BuiltInType::Any => RegisterSize::QuadWord,
+            BuiltInType::Allocator => RegisterSize::QuadWord,
+            BuiltInType::SourceCodeLocation => RegisterSize::QuadWord,
+            BuiltInType::Context => RegisterSize::QuadWord,
         }
     }
 }
@@ -168,10 +273,12 @@ pub fn built_in_type_to_register_size(from: BuiltInType) -> RegisterSize {
 
 
 pub fn resolved_type_to_register_size(from: &RuntimeTypePointer) -> Option<RegisterSize> {
-    if let Some((built_in_arg_type, ..)) = try_get_built_in_type(&from.id) {
-        return Some(built_in_type_to_register_size(built_in_arg_type));
+    match (try_get_built_in_type(&from.id), &from.id) {
+        (Some((_, true)), _) => Some(register_size_64()),
+        (Some((built_in_arg_type, false)), _) => Some(built_in_type_to_register_size(built_in_arg_type)),
+        (None, RuntimeTypeId::UserDefined { is_pointer: true, .. }) => Some(register_size_64()),
+        (None, _) => None
     }
-    None
 }
 
 
@@ -183,6 +290,8 @@ pub enum ByteCodeInstruction {
     CallToSymbol(SymbolIndex),
     AddValueToReg8 { value: u8, to: ByteCodeRegister },
     SubValueFromReg8 { value: u8, from: ByteCodeRegister },
+    AddValueToReg32 { value: u32, to: ByteCodeRegister },
+    SubValueFromReg32 { value: u32, from: ByteCodeRegister },
     MoveSymbolToReg32 { symbol_index: SymbolIndex, to: ByteCodeRegister },
     MoveValueToReg32 { value: u32, to: ByteCodeRegister },
     MoveValueToReg64 { value: u64, to: ByteCodeRegister },
@@ -194,13 +303,102 @@ pub enum ByteCodeInstruction {
     MoveRegPlusOffsetToReg32 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
     MoveRegPlusOffsetToReg64 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
     LoadDataSectionAddressToReg64 { data_section_offset: DataSectionOffset, to: ByteCodeRegister },
+    LoadReadOnlyDataAddressToReg64 { read_only_data_offset: DataSectionOffset, to: ByteCodeRegister },
+    LoadSymbolAddressToReg64 { symbol_index: SymbolIndex, to: ByteCodeRegister },
     LoadAddressInRegPlusOffsetToReg64 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveRegToRegPlusOffset8 { from: ByteCodeRegister, to: ByteCodeRegister, offset: AddressOffset },
+    MoveDataSectionToFloatReg32 { data_section_offset: DataSectionOffset, to: ByteCodeRegister },
+    MoveDataSectionToFloatReg64 { data_section_offset: DataSectionOffset, to: ByteCodeRegister },
+    MoveRegPlusOffsetToFloatReg32 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveRegPlusOffsetToFloatReg64 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveFloatRegToRegPlusOffset32 { from: ByteCodeRegister, to: ByteCodeRegister, offset: AddressOffset },
+    MoveFloatRegToRegPlusOffset64 { from: ByteCodeRegister, to: ByteCodeRegister, offset: AddressOffset },
+    AddFloatRegToFloatReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    AddFloatRegToFloatReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    SubFloatRegFromFloatReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    SubFloatRegFromFloatReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    MulFloatRegToFloatReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    MulFloatRegToFloatReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    CompareFloatRegToFloatReg32 { lhs: ByteCodeRegister, rhs: ByteCodeRegister },
+    CompareFloatRegToFloatReg64 { lhs: ByteCodeRegister, rhs: ByteCodeRegister },
+    SetReg8OnCondition { condition: ByteCodeCondition, to: ByteCodeRegister },
+    ConvertIntReg32ToFloatReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertIntReg32ToFloatReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertIntReg64ToFloatReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertIntReg64ToFloatReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertFloatReg32ToIntReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertFloatReg64ToIntReg32 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertFloatReg32ToIntReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ConvertFloatReg64ToIntReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    MoveValueToRegPlusOffset8 { value: u8, to: ByteCodeRegister, offset: AddressOffset },
+    MoveValueToRegPlusOffset16 { value: u16, to: ByteCodeRegister, offset: AddressOffset },
+    MoveRegToRegPlusOffset16 { from: ByteCodeRegister, to: ByteCodeRegister, offset: AddressOffset },
+    MoveRegPlusOffsetToRegSignExtend8 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveRegPlusOffsetToRegSignExtend16 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveRegPlusOffsetToRegSignExtend32 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveRegPlusOffsetToRegZeroExtend8 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    MoveRegPlusOffsetToRegZeroExtend16 { from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister },
+    SignExtendReg8ToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    SignExtendReg16ToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    SignExtendReg32ToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ZeroExtendReg8ToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ZeroExtendReg16ToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    ZeroExtendReg32ToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    CompareRegToReg64 { lhs: ByteCodeRegister, rhs: ByteCodeRegister },
+    AddRegToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    SubRegFromReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    MulRegToReg64 { from: ByteCodeRegister, to: ByteCodeRegister },
+    TrapOnCondition(ByteCodeCondition),
+    Label(ByteCodeLabel),
+    Jump(ByteCodeLabel),
+    JumpOnCondition { condition: ByteCodeCondition, label: ByteCodeLabel },
     PushReg64(ByteCodeRegister),
     PopReg64(ByteCodeRegister),
     ZeroReg64(ByteCodeRegister),
+    SystemCall,
     Return
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ByteCodeCondition {
+    Equal,
+    NotEqual,
+    Below,
+    BelowOrEqual,
+    Above,
+    AboveOrEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual
+}
+
+pub fn float_comparison_condition(op: &Operator) -> Option<ByteCodeCondition> {
+    match op {
+        Operator::Equal => Some(ByteCodeCondition::Equal),
+        Operator::NotEqual => Some(ByteCodeCondition::NotEqual),
+        Operator::LessThan => Some(ByteCodeCondition::Below),
+        Operator::LessThanOrEqual => Some(ByteCodeCondition::BelowOrEqual),
+        Operator::GreaterThan => Some(ByteCodeCondition::Above),
+        Operator::GreaterThanOrEqual => Some(ByteCodeCondition::AboveOrEqual),
+        _ => None
+    }
+}
+
+pub fn int_comparison_condition(op: &Operator, is_signed: bool) -> Option<ByteCodeCondition> {
+    if !is_signed {
+        return float_comparison_condition(op);
+    }
+
+    match op {
+        Operator::LessThan => Some(ByteCodeCondition::Less),
+        Operator::LessThanOrEqual => Some(ByteCodeCondition::LessOrEqual),
+        Operator::GreaterThan => Some(ByteCodeCondition::Greater),
+        Operator::GreaterThanOrEqual => Some(ByteCodeCondition::GreaterOrEqual),
+        op => float_comparison_condition(op)
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SymbolIndex(u32);
@@ -217,31 +415,46 @@ pub fn symbol_index(value: u32) -> SymbolIndex {
     SymbolIndex(value)
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ByteCodeLabel(u32);
+
+impl Deref for ByteCodeLabel {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub fn byte_code_label(value: u32) -> ByteCodeLabel {
+    ByteCodeLabel(value)
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub struct AddressOffset(u8);
+pub struct AddressOffset(i32);
 
 impl Deref for AddressOffset {
-    type Target = u8;
+    type Target = i32;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl Add<u8> for AddressOffset {
+impl Add<i32> for AddressOffset {
     type Output = AddressOffset;
 
-    fn add(self, rhs: u8) -> Self::Output {
+    fn add(self, rhs: i32) -> Self::Output {
         address_offset(self.0 + rhs)
     }
 }
 
-pub fn address_offset(value: u8) -> AddressOffset {
+pub fn address_offset(value: i32) -> AddressOffset {
     AddressOffset(value)
 }
 
-pub fn negative_address_offset(value: u8) -> AddressOffset {
-    AddressOffset(-(value as i8) as u8)
+pub fn negative_address_offset(value: i32) -> AddressOffset {
+    AddressOffset(-value)
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -293,7 +506,7 @@ pub fn add_value_to_reg_instruction(value: InstructionValue, to: ByteCodeRegiste
         InstructionValue::LargeFloat(_) => ByteCodeInstruction::Unimplemented,
         InstructionValue::Byte(value) => ByteCodeInstruction::AddValueToReg8 { value, to },
         InstructionValue::Word(_) => ByteCodeInstruction::Unimplemented,
-        InstructionValue::DoubleWord(_) => ByteCodeInstruction::Unimplemented,
+        InstructionValue::DoubleWord(value) => ByteCodeInstruction::AddValueToReg32 { value, to },
         InstructionValue::QuadWord(_) =>  ByteCodeInstruction::Unimplemented,
     }
 }
@@ -305,7 +518,7 @@ pub fn sub_value_from_reg_instruction(value: InstructionValue, from: ByteCodeReg
         InstructionValue::LargeFloat(_) => ByteCodeInstruction::Unimplemented,
         InstructionValue::Byte(value) => ByteCodeInstruction::SubValueFromReg8 { value, from },
         InstructionValue::Word(_) => ByteCodeInstruction::Unimplemented,
-        InstructionValue::DoubleWord(_) => ByteCodeInstruction::Unimplemented,
+        InstructionValue::DoubleWord(value) => ByteCodeInstruction::SubValueFromReg32 { value, from },
         InstructionValue::QuadWord(_) =>  ByteCodeInstruction::Unimplemented
     }
 }
@@ -349,8 +562,8 @@ pub fn move_value_to_reg_plus_offset_instruction(value: InstructionValue, to: By
         InstructionValue::Unsupported => ByteCodeInstruction::Unimplemented,
         InstructionValue::Float(_) => ByteCodeInstruction::Unimplemented,
         InstructionValue::LargeFloat(_) => ByteCodeInstruction::Unimplemented,
-        InstructionValue::Byte(_) => ByteCodeInstruction::Unimplemented,
-        InstructionValue::Word(_) => ByteCodeInstruction::Unimplemented,
+        InstructionValue::Byte(value) => ByteCodeInstruction::MoveValueToRegPlusOffset8 { value, to, offset },
+        InstructionValue::Word(value) => ByteCodeInstruction::MoveValueToRegPlusOffset16 { value, to, offset },
         InstructionValue::DoubleWord(value) => ByteCodeInstruction::MoveValueToRegPlusOffset32 { value, to, offset },
         InstructionValue::QuadWord(value) => ByteCodeInstruction::MoveValueToRegPlusOffset64 { value, to, offset }
     }
@@ -358,10 +571,10 @@ pub fn move_value_to_reg_plus_offset_instruction(value: InstructionValue, to: By
 
 pub fn move_reg_to_reg_plus_offset_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister, offset: AddressOffset) -> ByteCodeInstruction {
     match register_size {
-        RegisterSize::Byte => ByteCodeInstruction::Unimplemented,
-        RegisterSize::Word => ByteCodeInstruction::Unimplemented,
-        RegisterSize::Float => ByteCodeInstruction::Unimplemented,
-        RegisterSize::LargeFloat => ByteCodeInstruction::Unimplemented,
+        RegisterSize::Byte => ByteCodeInstruction::MoveRegToRegPlusOffset8 { from, to, offset },
+        RegisterSize::Word => ByteCodeInstruction::MoveRegToRegPlusOffset16 { from, to, offset },
+        RegisterSize::Float => ByteCodeInstruction::MoveFloatRegToRegPlusOffset32 { from, to, offset },
+        RegisterSize::LargeFloat => ByteCodeInstruction::MoveFloatRegToRegPlusOffset64 { from, to, offset },
         RegisterSize::DoubleWord => ByteCodeInstruction::MoveRegToRegPlusOffset32 { from, to, offset },
         RegisterSize::QuadWord => ByteCodeInstruction::MoveRegToRegPlusOffset64 { from, to, offset },
     }
@@ -369,15 +582,92 @@ pub fn move_reg_to_reg_plus_offset_instruction(register_size: RegisterSize, from
 
 pub fn move_reg_plus_offset_to_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister) -> ByteCodeInstruction {
     match register_size {
-        RegisterSize::Byte => ByteCodeInstruction::Unimplemented,
-        RegisterSize::Word => ByteCodeInstruction::Unimplemented,
-        RegisterSize::Float => ByteCodeInstruction::Unimplemented,
-        RegisterSize::LargeFloat => ByteCodeInstruction::Unimplemented,
+        RegisterSize::Byte => ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend8 { from, offset, to },
+        RegisterSize::Word => ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend16 { from, offset, to },
+        RegisterSize::Float => ByteCodeInstruction::MoveRegPlusOffsetToFloatReg32 { from, offset, to },
+        RegisterSize::LargeFloat => ByteCodeInstruction::MoveRegPlusOffsetToFloatReg64 { from, offset, to },
         RegisterSize::DoubleWord => ByteCodeInstruction::MoveRegPlusOffsetToReg32 { from, offset, to },
         RegisterSize::QuadWord => ByteCodeInstruction::MoveRegPlusOffsetToReg64 { from, offset, to },
     }    
 }
 
+pub fn move_reg_plus_offset_to_reg_extended_instruction(register_size: RegisterSize, is_signed: bool, from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match (register_size, is_signed) {
+        (RegisterSize::Byte, true) => ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend8 { from, offset, to },
+        (RegisterSize::Word, true) => ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend16 { from, offset, to },
+        (RegisterSize::DoubleWord, true) => ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend32 { from, offset, to },
+        (RegisterSize::Byte, false) => ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend8 { from, offset, to },
+        (RegisterSize::Word, false) => ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend16 { from, offset, to },
+        (RegisterSize::DoubleWord, false) => ByteCodeInstruction::MoveRegPlusOffsetToReg32 { from, offset, to },
+        (RegisterSize::QuadWord, _) => ByteCodeInstruction::MoveRegPlusOffsetToReg64 { from, offset, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn extend_reg_to_reg_instruction(register_size: RegisterSize, is_signed: bool, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match (register_size, is_signed) {
+        (RegisterSize::Byte, true) => ByteCodeInstruction::SignExtendReg8ToReg64 { from, to },
+        (RegisterSize::Word, true) => ByteCodeInstruction::SignExtendReg16ToReg64 { from, to },
+        (RegisterSize::DoubleWord, true) => ByteCodeInstruction::SignExtendReg32ToReg64 { from, to },
+        (RegisterSize::Byte, false) => ByteCodeInstruction::ZeroExtendReg8ToReg64 { from, to },
+        (RegisterSize::Word, false) => ByteCodeInstruction::ZeroExtendReg16ToReg64 { from, to },
+        (RegisterSize::DoubleWord, false) => ByteCodeInstruction::ZeroExtendReg32ToReg64 { from, to },
+        (RegisterSize::QuadWord, _) => ByteCodeInstruction::MoveRegToReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn zero_reg_instruction(register_size: RegisterSize, register: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::ZeroReg64(register),
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn compare_reg_to_reg_instruction(register_size: RegisterSize, lhs: ByteCodeRegister, rhs: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::CompareRegToReg64 { lhs, rhs },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn add_reg_to_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::AddRegToReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn sub_reg_from_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::SubRegFromReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn mul_reg_to_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::MulRegToReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn trap_on_condition_instruction(condition: ByteCodeCondition) -> ByteCodeInstruction {
+    ByteCodeInstruction::TrapOnCondition(condition)
+}
+
+pub fn label_instruction(label: ByteCodeLabel) -> ByteCodeInstruction {
+    ByteCodeInstruction::Label(label)
+}
+
+pub fn jump_instruction(label: ByteCodeLabel) -> ByteCodeInstruction {
+    ByteCodeInstruction::Jump(label)
+}
+
+pub fn jump_on_condition_instruction(condition: ByteCodeCondition, label: ByteCodeLabel) -> ByteCodeInstruction {
+    ByteCodeInstruction::JumpOnCondition { condition, label }
+}
+
 pub fn load_data_section_address_to_reg(register_size: RegisterSize, data_section_offset: DataSectionOffset, to: ByteCodeRegister) -> ByteCodeInstruction {
     match register_size {
         RegisterSize::QuadWord => ByteCodeInstruction::LoadDataSectionAddressToReg64 { data_section_offset, to },
@@ -385,6 +675,20 @@ pub fn load_data_section_address_to_reg(register_size: RegisterSize, data_sectio
     }
 }
 
+pub fn load_read_only_data_address_to_reg(register_size: RegisterSize, read_only_data_offset: DataSectionOffset, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::LoadReadOnlyDataAddressToReg64 { read_only_data_offset, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn load_symbol_address_to_reg(register_size: RegisterSize, symbol_index: SymbolIndex, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::QuadWord => ByteCodeInstruction::LoadSymbolAddressToReg64 { symbol_index, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
 pub fn load_address_in_reg_plus_offset_to_reg(register_size: RegisterSize, from: ByteCodeRegister, offset: AddressOffset, to: ByteCodeRegister) -> ByteCodeInstruction {
     match register_size {
         RegisterSize::QuadWord => ByteCodeInstruction::LoadAddressInRegPlusOffsetToReg64 { from, offset, to },
@@ -392,6 +696,77 @@ pub fn load_address_in_reg_plus_offset_to_reg(register_size: RegisterSize, from:
     }
 }
 
+pub fn move_data_section_to_float_reg_instruction(register_size: RegisterSize, data_section_offset: DataSectionOffset, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::Float => ByteCodeInstruction::MoveDataSectionToFloatReg32 { data_section_offset, to },
+        RegisterSize::LargeFloat => ByteCodeInstruction::MoveDataSectionToFloatReg64 { data_section_offset, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn add_float_reg_to_float_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::Float => ByteCodeInstruction::AddFloatRegToFloatReg32 { from, to },
+        RegisterSize::LargeFloat => ByteCodeInstruction::AddFloatRegToFloatReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn sub_float_reg_from_float_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::Float => ByteCodeInstruction::SubFloatRegFromFloatReg32 { from, to },
+        RegisterSize::LargeFloat => ByteCodeInstruction::SubFloatRegFromFloatReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn mul_float_reg_to_float_reg_instruction(register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::Float => ByteCodeInstruction::MulFloatRegToFloatReg32 { from, to },
+        RegisterSize::LargeFloat => ByteCodeInstruction::MulFloatRegToFloatReg64 { from, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn compare_float_reg_to_float_reg_instruction(register_size: RegisterSize, lhs: ByteCodeRegister, rhs: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::Float => ByteCodeInstruction::CompareFloatRegToFloatReg32 { lhs, rhs },
+        RegisterSize::LargeFloat => ByteCodeInstruction::CompareFloatRegToFloatReg64 { lhs, rhs },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn set_reg_on_condition_instruction(register_size: RegisterSize, condition: ByteCodeCondition, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match register_size {
+        RegisterSize::Byte => ByteCodeInstruction::SetReg8OnCondition { condition, to },
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+pub fn convert_int_reg_to_float_reg_instruction(int_size: RegisterSize, float_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match (int_size, float_size) {
+        (RegisterSize::DoubleWord, RegisterSize::Float) => ByteCodeInstruction::ConvertIntReg32ToFloatReg32 { from, to },
+        (RegisterSize::DoubleWord, RegisterSize::LargeFloat) => ByteCodeInstruction::ConvertIntReg32ToFloatReg64 { from, to },
+        (RegisterSize::QuadWord, RegisterSize::Float) => ByteCodeInstruction::ConvertIntReg64ToFloatReg32 { from, to },
+        (RegisterSize::QuadWord, RegisterSize::LargeFloat) => ByteCodeInstruction::ConvertIntReg64ToFloatReg64 { from, to },
+        _ => ByteCodeInstruction::Unimplemented
+    }
+}
+
+pub fn convert_float_reg_to_int_reg_instruction(float_size: RegisterSize, int_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match (float_size, int_size) {
+        (RegisterSize::Float, RegisterSize::DoubleWord) => ByteCodeInstruction::ConvertFloatReg32ToIntReg32 { from, to },
+        (RegisterSize::LargeFloat, RegisterSize::DoubleWord) => ByteCodeInstruction::ConvertFloatReg64ToIntReg32 { from, to },
+        (RegisterSize::Float, RegisterSize::QuadWord) => ByteCodeInstruction::ConvertFloatReg32ToIntReg64 { from, to },
+        (RegisterSize::LargeFloat, RegisterSize::QuadWord) => ByteCodeInstruction::ConvertFloatReg64ToIntReg64 { from, to },
+        _ => ByteCodeInstruction::Unimplemented
+    }
+}
+
+pub fn system_call_instruction() -> ByteCodeInstruction {
+    ByteCodeInstruction::SystemCall
+}
+
 pub fn ret_instruction() -> ByteCodeInstruction {
     ByteCodeInstruction::Return
 }
@@ -408,6 +783,8 @@ pub fn add_byte_codes(byte_code_stream: &mut Vec<ByteCodeInstruction>, mut instr
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ByteCodeSymbol {
     DataSectionItem { name: String, value: u32 },
+    ExternalDataSectionItem { name: String, value: u32 },
+    ExternalBssSectionItem { name: String, value: u32 },
     ForeignExternal { name: String },
     AbsoluteExternal32 { name: String, value: u32 },
     AbsoluteExternal64 { name: String, value: u64 },
@@ -420,6 +797,14 @@ pub fn data_section_item(name: String, value: u32) -> ByteCodeSymbol{
     ByteCodeSymbol::DataSectionItem { name, value }
 }
 
+pub fn external_data_section_item(name: String, value: u32) -> ByteCodeSymbol{
+    ByteCodeSymbol::ExternalDataSectionItem { name, value }
+}
+
+pub fn external_bss_section_item(name: String, value: u32) -> ByteCodeSymbol{
+    ByteCodeSymbol::ExternalBssSectionItem { name, value }
+}
+
 pub fn foreign_external(name: String) -> ByteCodeSymbol{
     ByteCodeSymbol::ForeignExternal { name }
 }
@@ -449,6 +834,11 @@ pub fn data_section_item_name(data_item_pointer: DataSectionOffset) -> String {
 pub enum ByteCodeDataItem {
     String { value: ByteString },
     Pointer { value: u64 },
+    SymbolPointer { symbol_index: SymbolIndex },
+    ReadOnlyPointer { value: u64 },
+    Byte { value: u8 },
+    Word { value: u16 },
+    DoubleWord { value: u32 },
     QuadWord { value: u64 }
 }
 
@@ -463,6 +853,18 @@ pub struct ByteCodeData {
 pub fn string_data_item(value: ByteString) -> ByteCodeDataItem{
     ByteCodeDataItem::String { value }
 }
+pub fn byte_data_item(value: u8) -> ByteCodeDataItem{
+    ByteCodeDataItem::Byte { value }
+}
+
+pub fn word_data_item(value: u16) -> ByteCodeDataItem{
+    ByteCodeDataItem::Word { value }
+}
+
+pub fn double_word_data_item(value: u32) -> ByteCodeDataItem{
+    ByteCodeDataItem::DoubleWord { value }
+}
+
 pub fn quad_word_data_item(value: u64) -> ByteCodeDataItem{
     ByteCodeDataItem::QuadWord { value }
 }
@@ -471,6 +873,14 @@ pub fn pointer_data_item(value: u64) -> ByteCodeDataItem{
     ByteCodeDataItem::Pointer { value }
 }
 
+pub fn symbol_pointer_data_item(symbol_index: SymbolIndex) -> ByteCodeDataItem{
+    ByteCodeDataItem::SymbolPointer { symbol_index }
+}
+
+pub fn read_only_pointer_data_item(value: u64) -> ByteCodeDataItem{
+    ByteCodeDataItem::ReadOnlyPointer { value }
+}
+
 pub fn add_data_item(data: &mut ByteCodeData, item: ByteCodeDataItem) -> DataSectionOffset {
     let pointer = data.size;
     data.size += get_byte_code_data_item_size(&item);
@@ -482,6 +892,22 @@ fn get_byte_code_data_item_size(item: &ByteCodeDataItem) -> u32 {
     match item {
         ByteCodeDataItem::String { value } => value.len() as u32,
         ByteCodeDataItem::Pointer { .. } => 8,
+        ByteCodeDataItem::SymbolPointer { .. } => 8,
+        ByteCodeDataItem::ReadOnlyPointer { .. } => 8,
+        ByteCodeDataItem::Byte { .. } => 1,
+        ByteCodeDataItem::Word { .. } => 2,
+        ByteCodeDataItem::DoubleWord { .. } => 4,
         ByteCodeDataItem::QuadWord { .. } => 8,
     }
 }
+
+#[derive(Default, Debug, Clone)]
+pub struct ByteCodeBss {
+    pub size: u32
+}
+
+pub fn reserve_bss_space(bss: &mut ByteCodeBss, size: u32) -> u32 {
+    let pointer = bss.size;
+    bss.size += size;
+    pointer
+}
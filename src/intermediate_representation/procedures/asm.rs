@@ -0,0 +1,226 @@
+use crate::{
+    parsing::*,
+    intermediate_representation::*,
+    errors::*
+};
+
+enum AsmOperand<'a> {
+    Register(ByteCodeRegister),
+    Local(&'a IntermediateRepresentationAssignment),
+    Immediate(i64)
+}
+
+type AsmOperands<'a> = Vec<AsmOperand<'a>>;
+
+pub fn build_bytecode_at_asm(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    instructions: &AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) {
+    let clobbered_registers: ByteCodeRegisters = get_asm_clobbered_registers(instructions)
+        .into_iter()
+        .filter(|register| !is_callee_saved_register(ir.calling_convention, *register))
+        .collect();
+
+    for register in &clobbered_registers {
+        add_byte_code(&mut ir.byte_code, push_reg_instruction(register_size_64(), *register));
+    }
+
+    for instruction in instructions {
+        if let AbstractSyntaxNodeItem::AsmInstruction { mnemonic, operands } = instruction.item_ref() {
+            if let Some(operands) = get_asm_operands(assignment_map, operands, errors) {
+                build_bytecode_at_asm_instruction(ir, mnemonic, &operands, instruction.position, errors);
+            }
+        }
+    }
+
+    for register in clobbered_registers.iter().rev() {
+        add_byte_code(&mut ir.byte_code, pop_reg_instruction(register_size_64(), *register));
+    }
+}
+
+fn build_bytecode_at_asm_instruction(
+    ir: &mut IntermediateRepresentation,
+    mnemonic: &str,
+    operands: &AsmOperands,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let instruction = match (mnemonic, operands.as_slice()) {
+        ("mov", [AsmOperand::Register(to), AsmOperand::Register(from)]) =>
+            Some(move_reg_to_reg_instruction(register_size_64(), *from, *to)),
+        ("mov", [AsmOperand::Register(to), AsmOperand::Immediate(value)]) =>
+            Some(move_value_to_reg_instruction(instruction_value_64(*value as u64), *to)),
+        ("mov", [AsmOperand::Register(to), AsmOperand::Local(from)]) =>
+            get_asm_local_register_size(from)
+                .map(|register_size| move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), from.offset, *to)),
+        ("mov", [AsmOperand::Local(to), AsmOperand::Register(from)]) =>
+            get_asm_local_register_size(to)
+                .map(|register_size| move_reg_to_reg_plus_offset_instruction(register_size, *from, base_pointer_register(), to.offset)),
+        ("mov", [AsmOperand::Local(to), AsmOperand::Immediate(value)]) =>
+            get_asm_local_register_size(to)
+                .map(|register_size| move_value_to_reg_plus_offset_instruction(sized_instruction_value(register_size, *value), base_pointer_register(), to.offset)),
+        ("add", [AsmOperand::Register(to), AsmOperand::Register(from)]) =>
+            Some(add_reg_to_reg_instruction(register_size_64(), *from, *to)),
+        ("add", [AsmOperand::Register(to), AsmOperand::Immediate(value)]) if is_byte_immediate(*value) =>
+            Some(add_value_to_reg_instruction(instruction_value_8(*value as u8), *to)),
+        ("sub", [AsmOperand::Register(to), AsmOperand::Register(from)]) =>
+            Some(sub_reg_from_reg_instruction(register_size_64(), *from, *to)),
+        ("sub", [AsmOperand::Register(to), AsmOperand::Immediate(value)]) if is_byte_immediate(*value) =>
+            Some(sub_value_from_reg_instruction(instruction_value_8(*value as u8), *to)),
+        ("push", [AsmOperand::Register(register)]) =>
+            Some(push_reg_instruction(register_size_64(), *register)),
+        ("pop", [AsmOperand::Register(register)]) =>
+            Some(pop_reg_instruction(register_size_64(), *register)),
+        ("syscall", []) =>
+            Some(system_call_instruction()),
+        _ => None
+    };
+
+    if let Some(instruction) = instruction {
+        add_byte_code(&mut ir.byte_code, instruction);
+    } else {
+        add_intermediate_representation_error(errors, asm_instruction_not_supported_error(string(mnemonic)), position);
+    }
+}
+
+fn get_asm_operands<'a>(
+    assignment_map: &'a AssignmentMap,
+    operands: &AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) -> Option<AsmOperands<'a>> {
+    let mut asm_operands = vec!();
+
+    for operand in operands {
+        let operand_position = operand.position;
+        let asm_operand = match operand.item_ref() {
+            AbstractSyntaxNodeItem::AsmRegister(name) => {
+                let register = get_asm_register(name);
+                if register.is_none() {
+                    add_intermediate_representation_error(errors, asm_register_not_available_error(name.clone()), operand_position);
+                }
+                register.map(AsmOperand::Register)
+            },
+            AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } =>
+                get_assignment(assignment_map, name, operand_position, errors).map(AsmOperand::Local),
+            AbstractSyntaxNodeItem::Literal(literal) => {
+                let value = try_get_resolved_literal(literal).and_then(|literal| try_get_asm_immediate(&literal));
+                if value.is_none() {
+                    add_intermediate_representation_error(errors, literal_not_resolved_error(), operand_position);
+                }
+                value.map(AsmOperand::Immediate)
+            },
+            _ => {
+                todo(errors, function!(), "Non local asm operands");
+                None
+            }
+        };
+        asm_operands.push(asm_operand?);
+    }
+
+    Some(asm_operands)
+}
+
+pub fn get_asm_callee_saved_registers(calling_convention: CallingConvention, statements: &AbstractSyntaxChildNodes) -> ByteCodeRegisters {
+    let mut registers = vec!();
+    add_asm_callee_saved_registers(&mut registers, calling_convention, statements);
+    registers
+}
+
+fn add_asm_callee_saved_registers(registers: &mut ByteCodeRegisters, calling_convention: CallingConvention, statements: &AbstractSyntaxChildNodes) {
+    for statement in statements {
+        if let AbstractSyntaxNodeItem::Asm { instructions } = statement.item_ref() {
+            let callee_saved: ByteCodeRegisters = get_asm_clobbered_registers(instructions)
+                .into_iter()
+                .filter(|register| is_callee_saved_register(calling_convention, *register))
+                .collect();
+            add_asm_clobbered_registers(registers, &callee_saved);
+        }
+
+        for body in get_branch_bodies(statement.item_ref()) {
+            add_asm_callee_saved_registers(registers, calling_convention, body);
+        }
+    }
+}
+
+fn get_asm_clobbered_registers(instructions: &AbstractSyntaxChildNodes) -> ByteCodeRegisters {
+    let mut registers = vec!();
+
+    for instruction in instructions {
+        if let AbstractSyntaxNodeItem::AsmInstruction { mnemonic, operands } = instruction.item_ref() {
+            if mnemonic == "push" {
+                continue;
+            }
+            if mnemonic == "syscall" {
+                add_asm_clobbered_registers(&mut registers, &get_system_call_clobbered_registers());
+                continue;
+            }
+            if let Some(AbstractSyntaxNodeItem::AsmRegister(name)) = operands.first().map(|operand| operand.item_ref()) {
+                if let Some(register) = get_asm_register(name) {
+                    add_asm_clobbered_registers(&mut registers, &[register]);
+                }
+            }
+        }
+    }
+
+    registers
+}
+
+fn add_asm_clobbered_registers(registers: &mut Vec<ByteCodeRegister>, clobbered: &[ByteCodeRegister]) {
+    for register in clobbered {
+        if !registers.contains(register) {
+            registers.push(*register);
+        }
+    }
+}
+
+// the kernel returns in rax and uses rcx and r11 to return to the caller
+fn get_system_call_clobbered_registers() -> ByteCodeRegisters {
+    vec!(standard_register(0), standard_register(1), standard_register(6))
+}
+
+fn get_asm_register(name: &str) -> Option<ByteCodeRegister> {
+    match name {
+        "rax" => Some(standard_register(0)),
+        "rcx" => Some(standard_register(1)),
+        "rdx" => Some(standard_register(2)),
+        "r8" => Some(standard_register(3)),
+        "r9" => Some(standard_register(4)),
+        "r10" => Some(standard_register(5)),
+        "r11" => Some(standard_register(6)),
+        "r12" => Some(standard_register(7)),
+        "r13" => Some(standard_register(8)),
+        "r14" => Some(standard_register(9)),
+        "r15" => Some(standard_register(10)),
+        "rsi" => Some(standard_register(11)),
+        "rdi" => Some(standard_register(12)),
+        _ => None
+    }
+}
+
+fn get_asm_local_register_size(assignment: &IntermediateRepresentationAssignment) -> Option<RegisterSize> {
+    resolved_type_to_register_size(&assignment.resolved_type)
+        .filter(|register_size| !is_float_register_size(*register_size))
+}
+
+fn try_get_asm_immediate(literal: &ResolvedLiteral) -> Option<i64> {
+    match literal {
+        ResolvedLiteral::SignedInt64(value) => Some(*value),
+        ResolvedLiteral::UnsignedInt64(value) => Some(*value as i64),
+        _ => None
+    }
+}
+
+fn is_byte_immediate(value: i64) -> bool {
+    value >= i8::MIN as i64 && value <= i8::MAX as i64
+}
+
+fn sized_instruction_value(register_size: RegisterSize, value: i64) -> InstructionValue {
+    match register_size {
+        RegisterSize::Byte => instruction_value_8(value as u8),
+        RegisterSize::Word => instruction_value_16(value as u16),
+        RegisterSize::DoubleWord => instruction_value_32(value as u32),
+        _ => instruction_value_64(value as u64)
+    }
+}
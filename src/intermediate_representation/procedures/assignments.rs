@@ -2,11 +2,15 @@ use std::collections::HashMap;
 
 use crate::{
     parsing::*,
+    threading::*,
     types::*,
     intermediate_representation::*,
     errors::*
 };
 
+const PARAMETER_HOME_SIZE: isize = 8;
+const MAX_UNROLLED_ZERO_STORES: usize = 8;
+
 pub fn build_bytecode_at_variable_declaration(
     ir: &mut IntermediateRepresentation,
     assignment_map: &AssignmentMap,
@@ -22,14 +26,22 @@ pub fn build_bytecode_at_variable_declaration(
             build_bytecode_at_variable_assignment_to_procedure_call(ir, assignment_map, assignment_name, value_position, name, args, errors),
         AbstractSyntaxNodeItem::Literal(literal) => 
             build_bytecode_at_variable_assignment_to_literal(ir, assignment_map, assignment_name, literal, value_position, assignment_position, errors),
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::GlobalVariable } => 
+            build_bytecode_at_variable_assignment_to_global_variable(ir, assignment_map, assignment_name, assignment_position, name, errors),
         AbstractSyntaxNodeItem::Identifier { name, ..} => 
             build_bytecode_at_variable_assignment_to_identifier(ir, assignment_map, assignment_name, name),
         AbstractSyntaxNodeItem::Null =>  
             build_bytecode_at_variable_assignment_to_null(ir, assignment_map, assignment_name, assignment_position, errors),
-        AbstractSyntaxNodeItem::Cast { expr, .. } =>  
-            build_bytecode_at_variable_declaration(ir, assignment_map, assignment_name, assignment_position, expr, errors),
+        AbstractSyntaxNodeItem::Cast { .. } =>  
+            build_bytecode_at_variable_assignment_to_cast(ir, assignment_map, assignment_name, assignment_position, value, errors),
+        AbstractSyntaxNodeItem::BinaryExpr { .. } =>  
+            build_bytecode_at_variable_assignment_to_binary_expr(ir, assignment_map, assignment_name, assignment_position, value, errors),
+        AbstractSyntaxNodeItem::IndexExpr { .. } =>  
+            build_bytecode_at_variable_assignment_to_int_expr(ir, assignment_map, assignment_name, assignment_position, value, errors),
         AbstractSyntaxNodeItem::MemberExpr { instance, member, .. } =>  
             build_bytecode_at_variable_assignment_to_member_expr(ir, assignment_map, assignment_name, instance, member, errors),
+        AbstractSyntaxNodeItem::TypeInfo(type_info_type) =>  
+            build_bytecode_at_variable_assignment_to_type_info(ir, assignment_map, assignment_name, assignment_position, type_info_type, value_position, errors),
         item => todo(errors, function!(), &format!("implementation needed for {:?}", item))
     }
 }
@@ -48,6 +60,7 @@ fn build_bytecode_at_variable_assignment_to_literal(
         if let Some(resolved_literal) = try_get_resolved_literal(literal) {
             match resolved_literal {
                 ResolvedLiteral::String(value) => build_bytecode_at_variable_assignment_to_literal_string(ir, assignment_offset, value),
+                ResolvedLiteral::Float32(_) | ResolvedLiteral::Float64(_) => build_bytecode_at_variable_assignment_to_literal_float(ir, &resolved_literal, assignment_offset),
                 _ => build_bytecode_at_variable_assignment_to_literal_non_string(ir, resolved_literal, assignment_offset),  
             };
         } else {
@@ -67,13 +80,23 @@ fn build_bytecode_at_variable_assignment_to_literal_non_string(ir: &mut Intermed
     )
 }
 
+fn build_bytecode_at_variable_assignment_to_literal_float(ir: &mut IntermediateRepresentation, resolved_literal: &ResolvedLiteral, assignment_offset: AddressOffset) {
+    if let Some(register_size) = try_get_float_literal_register_size(resolved_literal) {
+        build_bytecode_to_load_float_literal_to_reg(ir, resolved_literal, float_standard_register(0));
+        add_byte_code(
+            &mut ir.byte_code, 
+            move_reg_to_reg_plus_offset_instruction(register_size, float_standard_register(0), base_pointer_register(), assignment_offset)
+        );
+    }
+}
+
 fn build_bytecode_at_variable_assignment_to_literal_string(
     ir: &mut IntermediateRepresentation,
     assignment_offset: AddressOffset,
     value: ByteString,
 ) {    
     store_string_count_member_value(ir, &value, assignment_offset);
-    let data_item_pointer = store_string_literal_in_data_section_and_add_symbol(ir, &value);
+    let data_item_pointer = store_string_literal_in_read_only_data(ir, &value);
     store_string_data_member_value(ir, data_item_pointer, assignment_offset);
 }
 
@@ -91,7 +114,7 @@ fn store_string_count_member_value(ir: &mut IntermediateRepresentation, value: &
 fn store_string_data_member_value(ir: &mut IntermediateRepresentation, data_item_pointer: DataSectionOffset, assignment_offset: AddressOffset) {
     add_byte_code(
         &mut ir.byte_code, 
-        load_data_section_address_to_reg(register_size_64(), data_item_pointer, call_arg_register(0))
+        load_read_only_data_address_to_reg(register_size_64(), data_item_pointer, call_arg_register(0))
     );
     add_byte_code(
         &mut ir.byte_code, 
@@ -105,11 +128,118 @@ fn store_string_data_member_value(ir: &mut IntermediateRepresentation, data_item
 }
 
 fn build_bytecode_at_variable_assignment_to_identifier(
-    _ir: &mut IntermediateRepresentation,
-    _assignment_map: &AssignmentMap,
-    _assignment_name: &str,
-    _name: &str
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    assignment_name: &str,
+    name: &str
 ) {
+    if let (Some(assignment), Some(from_assignment)) = (find_assignment(assignment_map, assignment_name), find_assignment(assignment_map, name)) {
+        if let Some(register_size) = resolved_type_to_register_size(&assignment.resolved_type) {
+            let register = if is_float_register_size(register_size) { float_standard_register(0) } else { standard_register(0) };
+            add_byte_codes(
+                &mut ir.byte_code, 
+                vec!(
+                    move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), from_assignment.offset, register),
+                    move_reg_to_reg_plus_offset_instruction(register_size, register, base_pointer_register(), assignment.offset)
+                )
+            );
+        }
+    }
+}
+
+fn build_bytecode_at_variable_assignment_to_global_variable(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    assignment_name: &str,
+    assignment_position: SourceFilePosition,
+    name: &str,
+    errors: &mut CompilationErrors
+) {
+    if let Some(assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
+        if let Some(register_size) = resolved_type_to_register_size(&assignment.resolved_type) {
+            let register = global_variable_register(register_size);
+            build_bytecode_to_load_global_variable_to_reg(ir, name, register_size, register);
+            add_byte_code(
+                &mut ir.byte_code, 
+                move_reg_to_reg_plus_offset_instruction(register_size, register, base_pointer_register(), assignment.offset)
+            );
+        } else {
+            todo(errors, function!(), "Non built in typed global variable assignment");
+        }
+    }
+}
+
+pub fn build_bytecode_at_assignment(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    target: &AbstractSyntaxNode,
+    value: &AbstractSyntaxNode,
+    assignment_type: &ResolvableType,
+    errors: &mut CompilationErrors
+) {
+    match target.item_ref() {
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } =>
+            build_bytecode_at_variable_declaration(ir, assignment_map, name, target.position, value, errors),
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::GlobalVariable } => match try_get_resolved_runtime_type_pointer(assignment_type) {
+            Some(variable_type) => build_bytecode_at_global_variable_assignment(ir, assignment_map, name, &variable_type, value, target.position, errors),
+            None => add_intermediate_representation_error(errors, type_not_resolved_error(), target.position)
+        },
+        AbstractSyntaxNodeItem::IndexExpr { instance, index, index_expression_type } => match try_get_resolved_runtime_type_pointer(index_expression_type) {
+            Some(element_type) => build_bytecode_to_store_int_index_expr(ir, assignment_map, instance, index, &element_type, value, errors),
+            None => add_intermediate_representation_error(errors, type_not_resolved_error(), target.position)
+        },
+        _ => todo(errors, function!(), "Other assignment targets")
+    }
+}
+
+fn build_bytecode_at_global_variable_assignment(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    name: &str,
+    variable_type: &RuntimeTypePointer,
+    value: &AbstractSyntaxNode,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let register_size = match resolved_type_to_register_size(variable_type) {
+        Some(register_size) => register_size,
+        None => return add_intermediate_representation_error(errors, register_size_not_resolved_error(), position)
+    };
+
+    let value_position = value.position;
+    let register = global_variable_register(register_size);
+
+    match value.item_ref() {
+        AbstractSyntaxNodeItem::Literal(literal) => match try_get_resolved_literal(literal) {
+            Some(ResolvedLiteral::String(_)) => todo(errors, function!(), "String literal global variable assignment"),
+            Some(resolved_literal @ (ResolvedLiteral::Float32(_) | ResolvedLiteral::Float64(_))) => {
+                build_bytecode_to_load_float_literal_to_reg(ir, &resolved_literal, register);
+                build_bytecode_to_store_reg_to_global_variable(ir, name, register_size, register);
+            },
+            Some(resolved_literal) =>
+                build_bytecode_to_store_value_to_global_variable(ir, name, resolved_literal_to_instruction_value(&resolved_literal)),
+            None => add_intermediate_representation_error(errors, literal_not_resolved_error(), value_position)
+        },
+        AbstractSyntaxNodeItem::Identifier { name: from_name, scope: Scope::Local } => {
+            if let Some(from_assignment) = get_assignment(assignment_map, from_name, value_position, errors) {
+                add_byte_code(
+                    &mut ir.byte_code,
+                    move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), from_assignment.offset, register)
+                );
+                build_bytecode_to_store_reg_to_global_variable(ir, name, register_size, register);
+            }
+        },
+        AbstractSyntaxNodeItem::Identifier { name: from_name, scope: Scope::GlobalVariable } => {
+            build_bytecode_to_load_global_variable_to_reg(ir, from_name, register_size, register);
+            build_bytecode_to_store_reg_to_global_variable(ir, name, register_size, register);
+        },
+        AbstractSyntaxNodeItem::BinaryExpr { .. } | AbstractSyntaxNodeItem::Cast { .. } | AbstractSyntaxNodeItem::IndexExpr { .. } if !is_float_register_size(register_size) => {
+            build_bytecode_to_load_int_expr_of_type(ir, assignment_map, value, variable_type, standard_register(0), errors);
+            add_byte_code(&mut ir.byte_code, move_reg_to_reg_instruction(register_size_64(), standard_register(0), register));
+            build_bytecode_to_store_reg_to_global_variable(ir, name, register_size, register);
+        },
+        _ => add_intermediate_representation_error(errors, global_variable_assignment_value_not_supported_error(), position)
+    }
 }
 
 fn build_bytecode_at_variable_assignment_to_null(
@@ -120,13 +250,70 @@ fn build_bytecode_at_variable_assignment_to_null(
     errors: &mut CompilationErrors
 ) {
     if let Some(assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
-        add_byte_code(
-            &mut ir.byte_code, 
-            move_value_to_reg_plus_offset_instruction(instruction_value_64(0), base_pointer_register(), assignment.offset)
+        let size = try_get_resolved_type_size(&assignment.resolved_type.size).unwrap_or(8).max(8);
+        build_bytecode_to_zero_fill(ir, assignment.offset, size);
+    }
+}
+
+fn build_bytecode_to_zero_fill(ir: &mut IntermediateRepresentation, offset: AddressOffset, size: usize) {
+    let quad_words = size / 8;
+    let mut filled = 0;
+
+    if quad_words > MAX_UNROLLED_ZERO_STORES {
+        let loop_label = create_label(ir);
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), offset, standard_register(0)),
+                move_value_to_reg_instruction(instruction_value_64(quad_words as u64), standard_register(1)),
+                zero_reg_instruction(register_size_64(), standard_register(2)),
+                label_instruction(loop_label),
+                move_value_to_reg_plus_offset_instruction(instruction_value_64(0), standard_register(0), address_offset(0)),
+                add_value_to_reg_instruction(instruction_value_8(8), standard_register(0)),
+                sub_value_from_reg_instruction(instruction_value_8(1), standard_register(1)),
+                compare_reg_to_reg_instruction(register_size_64(), standard_register(1), standard_register(2)),
+                jump_on_condition_instruction(ByteCodeCondition::NotEqual, loop_label)
+            )
         );
+        filled = quad_words * 8;
+    }
+
+    while filled < size {
+        let (value, width) = match size - filled {
+            1 => (instruction_value_8(0), 1),
+            2..=3 => (instruction_value_16(0), 2),
+            4..=7 => (instruction_value_32(0), 4),
+            _ => (instruction_value_64(0), 8)
+        };
+        add_byte_code(&mut ir.byte_code, move_value_to_reg_plus_offset_instruction(value, base_pointer_register(), offset + filled as i32));
+        filled += width;
     }
 }
 
+fn build_bytecode_at_variable_assignment_to_type_info(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    assignment_name: &str,
+    assignment_position: SourceFilePosition,
+    type_info_type: &ResolvableType,
+    type_info_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
+        if let Some(resolved_type) = try_get_resolved_runtime_type_pointer(type_info_type) {
+            let type_info_symbol = build_bytecode_at_type_info_reference(ir, &resolved_type);
+            add_byte_codes(
+                &mut ir.byte_code, 
+                vec!(
+                    load_symbol_address_to_reg(register_size_64(), type_info_symbol, call_arg_register(0)),
+                    move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), assignment.offset)
+                )
+            );
+        } else {
+            add_intermediate_representation_error(errors, type_not_resolved_error(), type_info_position);
+        }
+    }
+}
 
 fn build_bytecode_at_variable_assignment_to_member_expr(
     ir: &mut IntermediateRepresentation,
@@ -161,7 +348,7 @@ fn build_bytecode_at_variable_assignment_to_member_expr_member(
 ) {
     if let Some(name) = try_get_member_name(member.item_ref()) {
         if let Some(instance_member_offset) = try_get_instance_member_offset(instance_type, name) {
-            build_bytecode_to_move_instance_member_to_register(ir, address_offset(instance_member_offset as u8));
+            build_bytecode_to_move_instance_member_to_register(ir, address_offset(instance_member_offset as i32));
             if let Some(assignment) = get_assignment(
                 assignment_map,
                 assignment_name,
@@ -228,7 +415,7 @@ pub type Assignments = HashMap<String, IntermediateRepresentationAssignment>;
 
 pub struct IntermediateRepresentationAssignment {
     pub offset: AddressOffset,
-    resolved_type: RuntimeTypePointer
+    pub resolved_type: RuntimeTypePointer
 }
 
 pub type OptionalIntermediateRepresentationAssignment<'a> = Option<&'a IntermediateRepresentationAssignment>;
@@ -246,11 +433,17 @@ pub struct AssignmentMap {
     total_size: usize
 }
 
-pub fn get_assignment_map(args: &AbstractSyntaxChildNodes, statements: &AbstractSyntaxChildNodes, errors: &mut CompilationErrors) -> AssignmentMap {
+pub fn get_assignment_map(
+    calling_convention: CallingConvention,
+    args: &AbstractSyntaxChildNodes,
+    statements: &AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) -> AssignmentMap {
     let mut assignment_map = AssignmentMap::default();
+    let mut position = 0;
     
-    add_args_to_assignment_map(&mut assignment_map, args, errors);
-    add_statements_to_assignment_map(&mut assignment_map, statements, errors);
+    add_args_to_assignment_map(&mut assignment_map, calling_convention, args, &mut position, errors);
+    add_statements_to_assignment_map(&mut assignment_map, statements, &mut position, errors);
 
     assignment_map
 }
@@ -258,56 +451,192 @@ pub fn get_assignment_map(args: &AbstractSyntaxChildNodes, statements: &Abstract
 fn add_statements_to_assignment_map(
     assignment_map: &mut AssignmentMap,
     statements: &AbstractSyntaxChildNodes,
+    position: &mut isize,
     errors: &mut CompilationErrors
 ) {
-    let mut position = 0;
     for statement in statements {
         let statement_position = statement.position.clone();
         match statement.item_ref() {
-            AbstractSyntaxNodeItem::VariableDeclaration { name, variable_type, ..} => {
-                if let Some(size) = try_get_type_size_from_resolvable_type(variable_type) {
-                    position = position - size as isize;
-                    assignment_map.total_size += size;
-                    add_assignment_type_and_position_to_map(
-                        assignment_map,
-                        name,
-                        address_offset(position as u8),
-                        variable_type,
-                        statement_position,
-                        errors
-                    );
-                } else {
-                    add_intermediate_representation_error(errors, type_not_resolved_error(), statement_position);
+            AbstractSyntaxNodeItem::VariableDeclaration { name, variable_type, value, .. } => {
+                add_local_to_assignment_map(assignment_map, name, variable_type, position, statement_position, errors);
+                add_boxed_args_to_assignment_map(assignment_map, value, position, errors);
+            },
+            AbstractSyntaxNodeItem::ProcedureCall { .. } =>
+                add_boxed_args_to_assignment_map(assignment_map, statement, position, errors),
+            item => {
+                for body in get_branch_bodies(item) {
+                    add_statements_to_assignment_map(assignment_map, body, position, errors);
                 }
             }
-        _ => {}
         }
     }
 }
 
+fn add_boxed_args_to_assignment_map(
+    assignment_map: &mut AssignmentMap,
+    call: &AbstractSyntaxNode,
+    position: &mut isize,
+    errors: &mut CompilationErrors
+) {
+    if let AbstractSyntaxNodeItem::ProcedureCall { args, .. } = call.item_ref() {
+        for arg in args {
+            if let AbstractSyntaxNodeItem::Argument { expr, arg_type } = arg.item_ref() {
+                match expr.item_ref() {
+                    AbstractSyntaxNodeItem::Boxed { boxed_type, .. } => {
+                        let any_type = resolved_resolvable_type(create_shareable(any_runtime_type()));
+                        add_local_to_assignment_map(assignment_map, &boxed_value_assignment_name(expr.position), boxed_type, position, expr.position, errors);
+                        add_local_to_assignment_map(assignment_map, &boxed_any_assignment_name(expr.position), &any_type, position, expr.position, errors);
+                    },
+                    AbstractSyntaxNodeItem::VariadicArgs { args } =>
+                        add_variadic_args_to_assignment_map(assignment_map, args, arg_type, position, expr.position, errors),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn add_variadic_args_to_assignment_map(
+    assignment_map: &mut AssignmentMap,
+    args: &AbstractSyntaxChildNodes,
+    view_type: &ResolvableType,
+    position: &mut isize,
+    variadic_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let element_type = match try_get_variadic_arg_element_type(view_type) {
+        Some(element_type) => element_type,
+        None => return add_intermediate_representation_error(errors, type_not_resolved_error(), variadic_position)
+    };
+
+    if let Some(element_size) = try_get_resolved_type_size(&element_type.size) {
+        *position -= (element_size * args.len()) as isize;
+        assignment_map.total_size += element_size * args.len();
+        add_assignment_to_map(assignment_map, variadic_args_assignment_name(variadic_position), assignment(address_offset(*position as i32), element_type));
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), variadic_position);
+    }
+
+    add_local_to_assignment_map(assignment_map, &variadic_view_assignment_name(variadic_position), view_type, position, variadic_position, errors);
+
+    for arg in args {
+        if let AbstractSyntaxNodeItem::Argument { expr, .. } = arg.item_ref() {
+            if let AbstractSyntaxNodeItem::Boxed { boxed_type, .. } = expr.item_ref() {
+                add_local_to_assignment_map(assignment_map, &boxed_value_assignment_name(expr.position), boxed_type, position, expr.position, errors);
+            }
+        }
+    }
+}
+
+fn add_local_to_assignment_map(
+    assignment_map: &mut AssignmentMap,
+    name: &str,
+    local_type: &ResolvableType,
+    position: &mut isize,
+    local_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(size) = try_get_type_size_from_resolvable_type(local_type) {
+        *position -= size as isize;
+        assignment_map.total_size += size;
+        add_assignment_type_and_position_to_map(
+            assignment_map,
+            name,
+            address_offset(*position as i32),
+            local_type,
+            local_position,
+            errors
+        );
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), local_position);
+    }
+}
+
 fn add_args_to_assignment_map(
     assignment_map: &mut AssignmentMap,
+    calling_convention: CallingConvention,
     args: &AbstractSyntaxChildNodes,
+    position: &mut isize,
     errors: &mut CompilationErrors
 ) {
-    let mut position = 16;
-    for statement in args {
+    let arguments = get_procedure_passed_arguments(calling_convention, args);
+    for (arg_index, (statement, argument)) in args.iter().zip(&arguments).enumerate() {
         let statement_position = statement.position.clone();
         match statement.item_ref() {
-            AbstractSyntaxNodeItem::MemberDeclaration { name, member_type } => {
+            AbstractSyntaxNodeItem::MemberDeclaration { name, member_type } if !argument.is_struct_value => {
+                let offset = match get_parameter_home(calling_convention, arg_index, argument.locations[0]) {
+                    ParameterHome::CallerFrame(offset) => offset,
+                    ParameterHome::CalleeFrame => {
+                        *position -= PARAMETER_HOME_SIZE;
+                        assignment_map.total_size += PARAMETER_HOME_SIZE as usize;
+                        address_offset(*position as i32)
+                    }
+                };
                 add_assignment_type_and_position_to_map(
                     assignment_map,
                     name,
-                    address_offset(position),
+                    offset,
                     member_type,
                     statement_position,
                     errors
                 );
-                position = position + 8;
             }
         _ => {}
         }
     }
+
+    for (statement, argument) in args.iter().zip(&arguments) {
+        if let (AbstractSyntaxNodeItem::MemberDeclaration { name, member_type }, true) = (statement.item_ref(), argument.is_struct_value) {
+            add_struct_argument_to_assignment_map(assignment_map, name, member_type, argument, position, statement.position, errors);
+        }
+    }
+}
+
+fn add_struct_argument_to_assignment_map(
+    assignment_map: &mut AssignmentMap,
+    name: &str,
+    member_type: &ResolvableType,
+    argument: &PassedArgument,
+    position: &mut isize,
+    statement_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    *position -= PARAMETER_HOME_SIZE;
+    assignment_map.total_size += PARAMETER_HOME_SIZE as usize;
+    add_assignment_type_and_position_to_map(assignment_map, name, address_offset(*position as i32), member_type, statement_position, errors);
+
+    if matches!(argument.locations.first(), Some(ArgumentLocation::Register(_))) {
+        let size = get_struct_parts_size(argument);
+        *position -= size as isize;
+        assignment_map.total_size += size;
+        add_assignment_type_and_position_to_map(
+            assignment_map,
+            &struct_argument_value_assignment_name(statement_position),
+            address_offset(*position as i32),
+            member_type,
+            statement_position,
+            errors
+        );
+    }
+}
+
+pub fn struct_argument_value_assignment_name(position: SourceFilePosition) -> String {
+    format!("__struct_argument_value_{}", position.absolute)
+}
+
+pub fn get_procedure_passed_arguments(calling_convention: CallingConvention, args: &AbstractSyntaxChildNodes) -> PassedArguments {
+    let argument_types: Vec<Option<RuntimeTypePointer>> = args
+        .iter()
+        .map(get_procedure_argument_type)
+        .collect();
+    get_passed_arguments(calling_convention, &argument_types)
+}
+
+fn get_procedure_argument_type(arg: &AbstractSyntaxNode) -> Option<RuntimeTypePointer> {
+    if let AbstractSyntaxNodeItem::MemberDeclaration { member_type, .. } = arg.item_ref() {
+        return try_get_resolved_runtime_type_pointer(member_type);
+    }
+    None
 }
 
 fn add_assignment_type_and_position_to_map(
@@ -329,8 +658,12 @@ fn add_assignment_to_map(assignment_map: &mut AssignmentMap, name: String, assig
     assignment_map.assignments.insert(name, assignment);
 }
 
-pub fn get_full_assignment_storage_size(assignment_map: &AssignmentMap) -> u8 {
-    assignment_map.total_size as u8
+pub fn get_full_assignment_storage_size(assignment_map: &AssignmentMap) -> usize {
+    assignment_map.total_size
+}
+
+pub fn find_assignment<'a>(assignment_map: &'a AssignmentMap, assignment_name: &str) -> OptionalIntermediateRepresentationAssignment<'a> {
+    assignment_map.assignments.get(assignment_name)
 }
 
 pub fn get_assignment<'a>(
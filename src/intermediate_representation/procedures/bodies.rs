@@ -1,6 +1,7 @@
 use crate::{
     parsing::*,
     intermediate_representation::*,
+    types::*,
     errors::*
 };
 
@@ -12,29 +13,87 @@ pub fn build_bytecode_at_procedure_body(
     errors: &mut CompilationErrors
 ) {
     ir.top_level_symbol = string(&name);
-    let assignment_map = get_assignment_map(args, statements, errors);
+    let assignment_map = get_assignment_map(ir.calling_convention, args, statements, errors);
     store_procedure_name_as_external_symbol(ir, name);
     build_bytecode_for_procedure_prologue(ir);
-    build_bytecode_for_procedure_argument_shadow_storage(args, ir);
+    build_bytecode_for_procedure_argument_home_storage(ir, &assignment_map, args);
     build_bytecode_for_procedure_assignments_storage_reservation(ir, &assignment_map);
+    build_bytecode_for_procedure_struct_argument_storage(ir, &assignment_map, args);
+    build_bytecode_for_procedure_saved_registers(ir, statements);
     build_bytecode_for_procedure_body_statements(ir, &assignment_map, statements, errors);
     build_bytecode_for_procedure_epilogue(ir);
 }
 
-fn build_bytecode_for_procedure_argument_shadow_storage(args: &AbstractSyntaxChildNodes, ir: &mut IntermediateRepresentation) {
-    for arg_index in 0..args.len() {
-        build_bytecode_at_procedure_argument_shadow_storage(ir, arg_index);
+// register arguments are stored to their homes before the locals are reserved, system v homes
+// are below the stack pointer at this point but still inside the red zone
+fn build_bytecode_for_procedure_argument_home_storage(ir: &mut IntermediateRepresentation, assignment_map: &AssignmentMap, args: &AbstractSyntaxChildNodes) {
+    let arguments = get_procedure_passed_arguments(ir.calling_convention, args);
+    for (arg, argument) in args.iter().zip(arguments).filter(|(_, argument)| !argument.is_struct_value) {
+        if let (ArgumentLocation::Register(register), Some(offset)) = (argument.locations[0], get_procedure_argument_home_offset(assignment_map, arg)) {
+            build_bytecode_at_procedure_argument_home_storage(ir, get_procedure_argument_home_storage_size(arg), register, offset);
+        }
     }
 }
 
-fn build_bytecode_at_procedure_argument_shadow_storage(ir: &mut IntermediateRepresentation, arg_index: usize) {
+fn build_bytecode_for_procedure_struct_argument_storage(ir: &mut IntermediateRepresentation, assignment_map: &AssignmentMap, args: &AbstractSyntaxChildNodes) {
+    let arguments = get_procedure_passed_arguments(ir.calling_convention, args);
+    for (arg_index, (arg, argument)) in args.iter().zip(arguments).enumerate().filter(|(_, (_, argument))| argument.is_struct_value) {
+        let value_offset = match argument.locations[0] {
+            ArgumentLocation::Register(_) => find_assignment(assignment_map, &struct_argument_value_assignment_name(arg.position)).map(|value| value.offset),
+            location => match get_parameter_home(ir.calling_convention, arg_index, location) {
+                ParameterHome::CallerFrame(offset) => Some(offset),
+                ParameterHome::CalleeFrame => None
+            }
+        };
+
+        if let (Some(value_offset), Some(offset)) = (value_offset, get_procedure_argument_home_offset(assignment_map, arg)) {
+            build_bytecode_at_procedure_struct_argument_storage(ir, &argument, value_offset, offset);
+        }
+    }
+}
+
+fn build_bytecode_at_procedure_struct_argument_storage(ir: &mut IntermediateRepresentation, argument: &PassedArgument, value_offset: AddressOffset, offset: AddressOffset) {
+    for (part, (class, location)) in argument.classes.iter().zip(&argument.locations).enumerate() {
+        if let ArgumentLocation::Register(register) = location {
+            let register_size = match class {
+                ArgumentClass::Integer => register_size_64(),
+                ArgumentClass::Float => register_size_float_64()
+            };
+            build_bytecode_at_procedure_argument_home_storage(ir, register_size, *register, value_offset + get_struct_part_offset(part));
+        }
+    }
+
+    let pointer = struct_argument_pointer_register();
+    add_byte_code(&mut ir.byte_code, load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), value_offset, pointer));
+    build_bytecode_at_procedure_argument_home_storage(ir, register_size_64(), pointer, offset);
+}
+
+fn get_procedure_argument_home_offset(assignment_map: &AssignmentMap, arg: &AbstractSyntaxNode) -> Option<AddressOffset> {
+    if let AbstractSyntaxNodeItem::MemberDeclaration { name, .. } = arg.item_ref() {
+        return find_assignment(assignment_map, name).map(|assignment| assignment.offset);
+    }
+    None
+}
+
+fn get_procedure_argument_home_storage_size(arg: &AbstractSyntaxNode) -> RegisterSize {
+    if let AbstractSyntaxNodeItem::MemberDeclaration { member_type, .. } = arg.item_ref() {
+        let register_size = try_get_resolved_runtime_type_pointer(member_type)
+            .and_then(|member_type| resolved_type_to_register_size(&member_type));
+        if let Some(register_size) = register_size.filter(|register_size| is_float_register_size(*register_size)) {
+            return register_size;
+        }
+    }
+    register_size_64()
+}
+
+fn build_bytecode_at_procedure_argument_home_storage(ir: &mut IntermediateRepresentation, register_size: RegisterSize, register: ByteCodeRegister, offset: AddressOffset) {
     add_byte_code(
         &mut ir.byte_code,
         move_reg_to_reg_plus_offset_instruction(
-            register_size_64(),
-            call_arg_register(arg_index), 
+            register_size,
+            register, 
             base_pointer_register(), 
-            address_offset((16 + (arg_index * 8)) as u8)
+            offset
         )
     );
 }
@@ -48,11 +107,19 @@ fn build_bytecode_for_procedure_assignments_storage_reservation(ir: &mut Interme
 
     add_byte_code(
         &mut ir.byte_code,
-        sub_value_from_reg_instruction(instruction_value_8(assignment_storage_size), stack_pointer_register())
+        sub_value_from_reg_instruction(stack_size_instruction_value(assignment_storage_size), stack_pointer_register())
     );
 }
 
-fn build_bytecode_for_procedure_body_statements(
+fn build_bytecode_for_procedure_saved_registers(ir: &mut IntermediateRepresentation, statements: &AbstractSyntaxChildNodes) {
+    ir.saved_registers = get_asm_callee_saved_registers(ir.calling_convention, statements);
+
+    for register in ir.saved_registers.clone() {
+        add_byte_code(&mut ir.byte_code, push_reg_instruction(register_size_64(), register));
+    }
+}
+
+pub fn build_bytecode_for_procedure_body_statements(
     ir: &mut IntermediateRepresentation,
     assignment_map: &AssignmentMap,
     statements: &AbstractSyntaxChildNodes,
@@ -72,17 +139,28 @@ fn build_bytecode_at_procedure_body_statement(
     match statement.item_ref() {
         AbstractSyntaxNodeItem::ProcedureCall { name, args, .. } => 
             build_bytecode_at_procedure_call(ir, assignment_map, name, args, errors),
+        AbstractSyntaxNodeItem::VariableDeclaration { uninitialised: true, .. } => {},
         AbstractSyntaxNodeItem::VariableDeclaration { name, value, .. } => 
             build_bytecode_at_variable_declaration(ir, assignment_map, name, statement.position.clone(), value, errors),
+        AbstractSyntaxNodeItem::Assignment { target, value, assignment_type } => 
+            build_bytecode_at_assignment(ir, assignment_map, target, value, assignment_type, errors),
+        AbstractSyntaxNodeItem::Return { args } => 
+            build_bytecode_at_return(ir, assignment_map, args, errors),
+        AbstractSyntaxNodeItem::Asm { instructions } => 
+            build_bytecode_at_asm(ir, assignment_map, instructions, errors),
+        AbstractSyntaxNodeItem::If { condition, body, else_body } => 
+            build_bytecode_at_if(ir, assignment_map, condition, body, else_body, errors),
+        AbstractSyntaxNodeItem::While { condition, body } => 
+            build_bytecode_at_while(ir, assignment_map, condition, body, errors),
         _ => todo(errors, function!(), "Other procedure body statement types")
     }
 }
 
-fn store_procedure_name_as_external_symbol(ir: &mut IntermediateRepresentation, name: &str) {
+pub fn store_procedure_name_as_external_symbol(ir: &mut IntermediateRepresentation, name: &str) {
     add_symbol(&mut ir.symbols, external_code_label(string(&name), 0));
 }
 
-fn build_bytecode_for_procedure_prologue(ir: &mut IntermediateRepresentation) {
+pub fn build_bytecode_for_procedure_prologue(ir: &mut IntermediateRepresentation) {
     add_byte_codes(
         &mut ir.byte_code,
         vec!(
@@ -92,7 +170,11 @@ fn build_bytecode_for_procedure_prologue(ir: &mut IntermediateRepresentation) {
     );
 }
 
-fn build_bytecode_for_procedure_epilogue(ir: &mut IntermediateRepresentation) {
+pub fn build_bytecode_for_procedure_epilogue(ir: &mut IntermediateRepresentation) {
+    for register in ir.saved_registers.clone().iter().rev() {
+        add_byte_code(&mut ir.byte_code, pop_reg_instruction(register_size_64(), *register));
+    }
+
     add_byte_codes(
         &mut ir.byte_code, 
         vec!(
@@ -0,0 +1,164 @@
+use crate::{
+    parsing::*,
+    types::*,
+    intermediate_representation::*,
+    errors::*,
+    utilities::*
+};
+
+pub fn boxed_value_assignment_name(position: SourceFilePosition) -> String {
+    format!("__boxed_value_{}", position.absolute)
+}
+
+pub fn boxed_any_assignment_name(position: SourceFilePosition) -> String {
+    format!("__boxed_any_{}", position.absolute)
+}
+
+pub fn build_bytecode_at_procedure_call_argument_boxed(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    expr: &AbstractSyntaxNode,
+    boxed_type: &ResolvableType,
+    location: ArgumentLocation,
+    boxed_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let boxed_type = match try_get_resolved_runtime_type_pointer(boxed_type) {
+        Some(boxed_type) => boxed_type,
+        None => return add_intermediate_representation_error(errors, type_not_resolved_error(), boxed_position)
+    };
+
+    let value_offset = get_assignment(assignment_map, &boxed_value_assignment_name(boxed_position), boxed_position, errors).map(|value| value.offset);
+    let any_offset = get_assignment(assignment_map, &boxed_any_assignment_name(boxed_position), boxed_position, errors).map(|any| any.offset);
+
+    if let (Some(value_offset), Some(any_offset)) = (value_offset, any_offset) {
+        let scratch = get_argument_location_register(location);
+        build_bytecode_to_spill_boxed_value(ir, assignment_map, expr, &boxed_type, value_offset, scratch, errors);
+        build_bytecode_to_store_any(ir, &boxed_type, value_offset, any_offset, scratch);
+        add_byte_code(
+            &mut ir.byte_code,
+            load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), any_offset, scratch)
+        );
+        build_bytecode_to_store_argument_if_on_stack(ir, location);
+    }
+}
+
+pub fn build_bytecode_to_spill_boxed_value(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    expr: &AbstractSyntaxNode,
+    boxed_type: &RuntimeTypePointer,
+    value_offset: AddressOffset,
+    scratch: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let expr_position = expr.position;
+
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::Literal(literal) => match try_get_resolved_literal(literal) {
+            Some(ResolvedLiteral::String(value)) =>
+                build_bytecode_to_spill_boxed_string_literal(ir, &value, value_offset, scratch),
+            Some(resolved_literal) =>
+                add_byte_code(
+                    &mut ir.byte_code,
+                    move_value_to_reg_plus_offset_instruction(resolved_literal_to_instruction_value(&resolved_literal), base_pointer_register(), value_offset)
+                ),
+            None => add_intermediate_representation_error(errors, literal_not_resolved_error(), expr_position)
+        },
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } => {
+            if let Some(assignment) = get_assignment(assignment_map, name, expr_position, errors) {
+                build_bytecode_to_spill_boxed_local(ir, boxed_type, assignment.offset, value_offset, scratch, expr_position, errors);
+            }
+        },
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Global } => {
+            let symbol_index = add_symbol(&mut ir.symbols, foreign_external(string(name)));
+            match try_get_built_in_type(&boxed_type.id) {
+                Some((built_in_type, _)) => {
+                    let register_size = built_in_type_to_register_size(built_in_type);
+                    add_byte_codes(
+                        &mut ir.byte_code,
+                        vec!(
+                            move_symbol_to_reg_instruction(register_size, symbol_index, scratch),
+                            move_reg_to_reg_plus_offset_instruction(register_size, scratch, base_pointer_register(), value_offset)
+                        )
+                    );
+                },
+                None => todo(errors, function!(), "Non built in typed global boxed value")
+            }
+        },
+        _ => todo(errors, function!(), "Other boxed value expression types")
+    }
+}
+
+fn build_bytecode_to_spill_boxed_string_literal(
+    ir: &mut IntermediateRepresentation,
+    value: &ByteString,
+    value_offset: AddressOffset,
+    scratch: ByteCodeRegister
+) {
+    let data_item_pointer = store_string_literal_in_read_only_data(ir, value);
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            move_value_to_reg_plus_offset_instruction(instruction_value_64(value.len() as u64), base_pointer_register(), value_offset),
+            load_read_only_data_address_to_reg(register_size_64(), data_item_pointer, scratch),
+            move_reg_to_reg_plus_offset_instruction(register_size_64(), scratch, base_pointer_register(), value_offset + 8)
+        )
+    );
+}
+
+fn build_bytecode_to_spill_boxed_local(
+    ir: &mut IntermediateRepresentation,
+    boxed_type: &RuntimeTypePointer,
+    local_offset: AddressOffset,
+    value_offset: AddressOffset,
+    scratch: ByteCodeRegister,
+    local_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    match &boxed_type.item {
+        RuntimeTypeItem::String { .. } => {
+            build_bytecode_to_copy_local(ir, register_size_64(), local_offset, value_offset, scratch);
+            build_bytecode_to_copy_local(ir, register_size_64(), local_offset + 8, value_offset + 8, scratch);
+        },
+        _ => match resolved_type_to_register_size(boxed_type) {
+            Some(register_size) => build_bytecode_to_copy_local(ir, register_size, local_offset, value_offset, scratch),
+            None => add_intermediate_representation_error(errors, register_size_not_resolved_error(), local_position)
+        }
+    }
+}
+
+pub fn build_bytecode_to_copy_local(
+    ir: &mut IntermediateRepresentation,
+    register_size: RegisterSize,
+    from_offset: AddressOffset,
+    to_offset: AddressOffset,
+    scratch: ByteCodeRegister
+) {
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), from_offset, scratch),
+            move_reg_to_reg_plus_offset_instruction(register_size, scratch, base_pointer_register(), to_offset)
+        )
+    );
+}
+
+pub fn build_bytecode_to_store_any(
+    ir: &mut IntermediateRepresentation,
+    boxed_type: &RuntimeTypePointer,
+    value_offset: AddressOffset,
+    any_offset: AddressOffset,
+    scratch: ByteCodeRegister
+) {
+    let type_info_symbol = build_bytecode_at_type_info_reference(ir, boxed_type);
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            load_symbol_address_to_reg(register_size_64(), type_info_symbol, scratch),
+            move_reg_to_reg_plus_offset_instruction(register_size_64(), scratch, base_pointer_register(), any_offset),
+            load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), value_offset, scratch),
+            move_reg_to_reg_plus_offset_instruction(register_size_64(), scratch, base_pointer_register(), any_offset + 8)
+        )
+    );
+}
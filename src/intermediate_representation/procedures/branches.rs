@@ -0,0 +1,64 @@
+use crate::{
+    parsing::*,
+    types::*,
+    threading::*,
+    intermediate_representation::*,
+    errors::*
+};
+
+pub fn build_bytecode_at_if(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    condition: &AbstractSyntaxNode,
+    body: &AbstractSyntaxChildNodes,
+    else_body: &AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) {
+    let else_label = create_label(ir);
+    build_bytecode_to_jump_unless_condition(ir, assignment_map, condition, else_label, errors);
+    build_bytecode_for_procedure_body_statements(ir, assignment_map, body, errors);
+
+    if else_body.is_empty() {
+        add_byte_code(&mut ir.byte_code, label_instruction(else_label));
+        return;
+    }
+
+    let end_label = create_label(ir);
+    add_byte_codes(&mut ir.byte_code, vec!(jump_instruction(end_label), label_instruction(else_label)));
+    build_bytecode_for_procedure_body_statements(ir, assignment_map, else_body, errors);
+    add_byte_code(&mut ir.byte_code, label_instruction(end_label));
+}
+
+pub fn build_bytecode_at_while(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    condition: &AbstractSyntaxNode,
+    body: &AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) {
+    let start_label = create_label(ir);
+    let end_label = create_label(ir);
+    add_byte_code(&mut ir.byte_code, label_instruction(start_label));
+    build_bytecode_to_jump_unless_condition(ir, assignment_map, condition, end_label, errors);
+    build_bytecode_for_procedure_body_statements(ir, assignment_map, body, errors);
+    add_byte_codes(&mut ir.byte_code, vec!(jump_instruction(start_label), label_instruction(end_label)));
+}
+
+fn build_bytecode_to_jump_unless_condition(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    condition: &AbstractSyntaxNode,
+    label: ByteCodeLabel,
+    errors: &mut CompilationErrors
+) {
+    let condition_type = create_shareable(bool_runtime_type());
+    build_bytecode_to_load_int_expr_of_type(ir, assignment_map, condition, &condition_type, standard_register(0), errors);
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            zero_reg_instruction(register_size_64(), standard_register(1)),
+            compare_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+            jump_on_condition_instruction(ByteCodeCondition::Equal, label)
+        )
+    );
+}
@@ -14,11 +14,13 @@ pub fn build_bytecode_at_variable_assignment_to_procedure_call(
     args: &AbstractSyntaxChildNodes,
     errors: &mut CompilationErrors
 ) {
-    reserve_shadow_stack_space(ir, args.len());
-    build_bytecode_at_procedure_call_arguments(args, assignment_map, ir, errors);
+    let arguments = get_procedure_call_passed_arguments(ir, args);
+    let locations = get_passed_argument_locations(&arguments);
+    reserve_call_stack_space(ir, &locations);
+    build_bytecode_at_procedure_call_arguments(args, &arguments, assignment_map, ir, errors);
     call_external_function(ir, call_name);
     move_procedure_call_return_value_into_storage(ir, assignment_map, assignment_name, assignment_position, errors);
-    release_shadow_stack_space(ir, args.len());
+    release_call_stack_space(ir, &locations);
 }
 
 pub fn build_bytecode_at_procedure_call(
@@ -28,20 +30,74 @@ pub fn build_bytecode_at_procedure_call(
     args: &AbstractSyntaxChildNodes,
     errors: &mut CompilationErrors
 ) {
-    reserve_shadow_stack_space(ir, args.len());
-    build_bytecode_at_procedure_call_arguments(args, assignment_map, ir, errors);
+    let arguments = get_procedure_call_passed_arguments(ir, args);
+    let locations = get_passed_argument_locations(&arguments);
+    reserve_call_stack_space(ir, &locations);
+    build_bytecode_at_procedure_call_arguments(args, &arguments, assignment_map, ir, errors);
     call_external_function(ir, name);
-    release_shadow_stack_space(ir, args.len());
+    release_call_stack_space(ir, &locations);
+}
+
+fn get_procedure_call_passed_arguments(ir: &IntermediateRepresentation, args: &AbstractSyntaxChildNodes) -> PassedArguments {
+    let argument_types: Vec<Option<RuntimeTypePointer>> = args
+        .iter()
+        .map(get_procedure_call_argument_type)
+        .collect();
+    get_passed_arguments(ir.calling_convention, &argument_types)
+}
+
+fn get_procedure_call_argument_type(arg: &AbstractSyntaxNode) -> Option<RuntimeTypePointer> {
+    if let AbstractSyntaxNodeItem::Argument { arg_type, .. } = arg.item_ref() {
+        return try_get_resolved_runtime_type_pointer(arg_type);
+    }
+    None
 }
 
 fn build_bytecode_at_procedure_call_arguments(
     args: &AbstractSyntaxChildNodes,
+    arguments: &[PassedArgument],
     assignment_map: &AssignmentMap,
     ir: &mut IntermediateRepresentation,
     errors: &mut CompilationErrors
 ) {
-    for arg_index in 0..args.len() {
-        build_bytecode_at_procedure_call_argument(ir, assignment_map, &args[arg_index], arg_index, errors);
+    for (arg, argument) in args.iter().zip(arguments) {
+        if argument.is_struct_value {
+            build_bytecode_at_procedure_call_struct_value_argument(ir, assignment_map, arg, argument, errors);
+        } else {
+            build_bytecode_at_procedure_call_argument(ir, assignment_map, arg, argument.locations[0], errors);
+        }
+    }
+}
+
+fn build_bytecode_at_procedure_call_struct_value_argument(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    arg: &AbstractSyntaxNode,
+    argument: &PassedArgument,
+    errors: &mut CompilationErrors
+) {
+    let pointer = struct_argument_pointer_register();
+    build_bytecode_at_procedure_call_argument(ir, assignment_map, arg, register_argument_location(pointer), errors);
+
+    if let Some(struct_type) = get_procedure_call_argument_type(arg) {
+        for (part, (class, location)) in argument.classes.iter().zip(&argument.locations).enumerate() {
+            let register_size = get_struct_part_register_size(&struct_type, part, *class);
+            add_byte_code(
+                &mut ir.byte_code,
+                move_reg_plus_offset_to_reg_instruction(
+                    get_argument_location_register_size(*location, register_size),
+                    pointer,
+                    address_offset(get_struct_part_offset(part)),
+                    get_struct_argument_part_register(*location)
+                )
+            );
+            if let ArgumentLocation::Stack(offset) = location {
+                add_byte_code(
+                    &mut ir.byte_code,
+                    move_reg_to_reg_plus_offset_instruction(register_size_64(), get_struct_argument_part_register(*location), stack_pointer_register(), *offset)
+                );
+            }
+        }
     }
 }
 
@@ -49,13 +105,13 @@ fn build_bytecode_at_procedure_call_argument(
     ir: &mut IntermediateRepresentation,
     assignment_map: &AssignmentMap,
     arg: &AbstractSyntaxNode,
-    arg_index: usize,
+    location: ArgumentLocation,
     errors: &mut CompilationErrors
 ) {
     let arg_position = arg.position.clone();
     match arg.item_ref() {
         AbstractSyntaxNodeItem::Argument { expr, arg_type, .. } =>
-            build_bytecode_at_procedure_call_argument_expression(ir, assignment_map, expr, arg_type, arg_index, errors),
+            build_bytecode_at_procedure_call_argument_expression(ir, assignment_map, expr, arg_type, location, errors),
         _ => add_intermediate_representation_error(errors, expected_argument_error(), arg_position)
     }    
 }
@@ -65,7 +121,7 @@ fn build_bytecode_at_procedure_call_argument_expression(
     assignment_map: &AssignmentMap,
     expr: &AbstractSyntaxNode,
     arg_type: &ResolvableType,
-    arg_index: usize,
+    location: ArgumentLocation,
     errors: &mut CompilationErrors
 ) {
     let expr_position = expr.position.clone();
@@ -73,13 +129,19 @@ fn build_bytecode_at_procedure_call_argument_expression(
     match expr.item_ref() {
         AbstractSyntaxNodeItem::Literal(literal) => {
             if let Some(resolved_literal) = try_get_resolved_literal(literal) {
-                build_bytecode_at_procedure_call_argument_literal(ir, &resolved_literal, arg_index, errors)
+                build_bytecode_at_procedure_call_argument_literal(ir, &resolved_literal, location)
             } else {
                 add_intermediate_representation_error(errors, literal_not_resolved_error(), expr_position);
             }
         },
         AbstractSyntaxNodeItem::Identifier { name, scope} =>
-            build_bytecode_at_procedure_call_argument_identifier(ir, assignment_map, name, scope, arg_type, arg_index, expr_position, errors),
+            build_bytecode_at_procedure_call_argument_identifier(ir, assignment_map, name, scope, arg_type, location, expr_position, errors),
+        AbstractSyntaxNodeItem::TypeInfo(type_info_type) =>
+            build_bytecode_at_procedure_call_argument_type_info(ir, type_info_type, location, expr_position, errors),
+        AbstractSyntaxNodeItem::Boxed { boxed_type, expr } =>
+            build_bytecode_at_procedure_call_argument_boxed(ir, assignment_map, expr, boxed_type, location, expr_position, errors),
+        AbstractSyntaxNodeItem::VariadicArgs { args } =>
+            build_bytecode_at_procedure_call_argument_variadic(ir, assignment_map, args, arg_type, location, expr_position, errors),
         _ => todo(errors, function!(), "Other procedure call arg expression types")
     }    
 }
@@ -87,37 +149,45 @@ fn build_bytecode_at_procedure_call_argument_expression(
 fn build_bytecode_at_procedure_call_argument_literal(
     ir: &mut IntermediateRepresentation,
     literal: &ResolvedLiteral,
-    arg_index: usize,
-    errors: &mut CompilationErrors
+    location: ArgumentLocation
 ) {
-    if arg_index > 3 {
-        todo(errors, function!(), "Move fourth or more argument to shadow space");
-    }
-    
-    match literal {
-        ResolvedLiteral::String(value) => build_bytecode_at_procedure_call_argument_string_literal(ir, value, arg_index),
-        _ => build_bytecode_at_procedure_call_argument_non_string_literal(ir, literal, arg_index)
+    let register = get_argument_location_register(location);
+
+    match (literal, location) {
+        (ResolvedLiteral::String(value), _) => build_bytecode_to_load_string_literal_to_reg(ir, value, register),
+        (ResolvedLiteral::Float32(_) | ResolvedLiteral::Float64(_), ArgumentLocation::Register(_)) => build_bytecode_to_load_float_literal_to_reg(ir, literal, register),
+        (ResolvedLiteral::Float32(value), ArgumentLocation::Stack(_)) => build_bytecode_at_procedure_call_argument_value(ir, instruction_value_32(value.to_bits()), register),
+        (ResolvedLiteral::Float64(value), ArgumentLocation::Stack(_)) => build_bytecode_at_procedure_call_argument_value(ir, instruction_value_64(value.to_bits()), register),
+        _ => build_bytecode_at_procedure_call_argument_value(ir, resolved_literal_to_instruction_value(literal), register)
     }
+
+    build_bytecode_to_store_argument_if_on_stack(ir, location);
 }
 
-fn build_bytecode_at_procedure_call_argument_non_string_literal(ir: &mut IntermediateRepresentation, literal: &ResolvedLiteral, arg_index: usize) {
+fn build_bytecode_at_procedure_call_argument_value(ir: &mut IntermediateRepresentation, value: InstructionValue, register: ByteCodeRegister) {
     add_byte_code(
         &mut ir.byte_code, 
-        move_value_to_reg_instruction(resolved_literal_to_instruction_value(literal), call_arg_register(arg_index))
+        move_value_to_reg_instruction(value, register)
     );
 }
 
-fn build_bytecode_at_procedure_call_argument_string_literal(ir: &mut IntermediateRepresentation, value: &Vec<u8>, arg_index: usize) {
-    let string_literal_data_item_pointer = store_string_literal_in_data_section_and_add_symbol(ir, value);
-    let string_data_item_pointer = store_string_in_data_section_and_add_symbol(ir, value.len(), string_literal_data_item_pointer);
-    add_byte_code(
-        &mut ir.byte_code, 
-        load_data_section_address_to_reg(
-            register_size_64(), 
-            string_data_item_pointer, 
-            call_arg_register(arg_index)
-        )
-    );
+fn build_bytecode_at_procedure_call_argument_type_info(
+    ir: &mut IntermediateRepresentation,
+    type_info_type: &ResolvableType,
+    location: ArgumentLocation,
+    arg_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(resolved_type) = try_get_resolved_runtime_type_pointer(type_info_type) {
+        let type_info_symbol = build_bytecode_at_type_info_reference(ir, &resolved_type);
+        add_byte_code(
+            &mut ir.byte_code, 
+            load_symbol_address_to_reg(register_size_64(), type_info_symbol, get_argument_location_register(location))
+        );
+        build_bytecode_to_store_argument_if_on_stack(ir, location);
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), arg_position);
+    }
 }
 
 fn build_bytecode_at_procedure_call_argument_identifier(
@@ -126,7 +196,7 @@ fn build_bytecode_at_procedure_call_argument_identifier(
     name: &str,
     scope: &Scope,
     arg_type: &ResolvableType,
-    arg_index: usize,
+    location: ArgumentLocation,
     arg_position: SourceFilePosition,
     errors: &mut CompilationErrors
 ) {
@@ -138,10 +208,12 @@ fn build_bytecode_at_procedure_call_argument_identifier(
             name, 
             arg_position,
             arg_type,
-            arg_index,
+            location,
             errors
         ),
-        Scope::Global => build_bytecode_at_procedure_call_argument_global_identifier(ir, name, arg_type, arg_index, arg_position, errors),
+        Scope::Global => build_bytecode_at_procedure_call_argument_global_identifier(ir, name, arg_type, location, arg_position, errors),
+        Scope::GlobalVariable => build_bytecode_at_procedure_call_argument_global_variable(ir, name, arg_type, location, arg_position, errors),
+        Scope::Procedure => build_bytecode_at_procedure_call_argument_procedure(ir, name, location)
     }
 }
 
@@ -151,14 +223,13 @@ fn build_bytecode_at_procedure_call_argument_local_identifier(
     identifier_name: &str,
     identifier_position: SourceFilePosition,
     arg_type: &ResolvableType,
-    arg_index: usize,
+    location: ArgumentLocation,
     errors: &mut CompilationErrors
 ) {
     if let Some(assignment) = get_assignment(assignment_map, identifier_name, identifier_position, errors) {       
         if let Some(arg_type) = try_get_resolved_runtime_type_pointer(arg_type) {
-            if let Some((built_in_arg_type, ..)) = try_get_built_in_type(&arg_type.id) {
-                let register_size = built_in_type_to_register_size(built_in_arg_type);
-                build_bytecode_for_move_variable_to_call_arg_location(ir, register_size, assignment.offset, arg_index);
+            if let Some(register_size) = resolved_type_to_register_size(&arg_type) {
+                build_bytecode_for_move_variable_to_call_arg_location(ir, register_size, assignment.offset, location);
             } else {
                 todo(errors, function!(), "Non built in typed identifier call arg");
             }
@@ -168,56 +239,77 @@ fn build_bytecode_at_procedure_call_argument_local_identifier(
     }
 }
 
-fn build_bytecode_for_move_variable_to_call_arg_location(ir: &mut IntermediateRepresentation, register_size: RegisterSize, offset: AddressOffset, arg_index: usize) {
-    build_bytecode_for_move_variable_to_call_arg_register_instruction(ir, register_size, offset, arg_index);
-    build_bytecode_for_move_call_arg_to_shadow_space_if_fourth_or_more(ir, register_size, arg_index);
-}
-
-fn build_bytecode_for_move_variable_to_call_arg_register_instruction(
-    ir: &mut IntermediateRepresentation,
-    register_size: RegisterSize,
-    offset: AddressOffset,
-    arg_index: usize
-) {
-    add_byte_code(&mut ir.byte_code, move_variable_to_call_arg_register_instruction(register_size, offset, arg_index))
+fn build_bytecode_for_move_variable_to_call_arg_location(ir: &mut IntermediateRepresentation, register_size: RegisterSize, offset: AddressOffset, location: ArgumentLocation) {
+    add_byte_code(
+        &mut ir.byte_code,
+        move_reg_plus_offset_to_reg_instruction(
+            get_argument_location_register_size(location, register_size),
+            base_pointer_register(),
+            offset,
+            get_argument_location_register(location)
+        )
+    );
+    build_bytecode_to_store_argument_if_on_stack(ir, location);
 }
 
-fn build_bytecode_for_move_call_arg_to_shadow_space_if_fourth_or_more(ir: &mut IntermediateRepresentation, register_size: RegisterSize, arg_index: usize) {
-    if arg_index > 3 {
+pub fn build_bytecode_to_store_argument_if_on_stack(ir: &mut IntermediateRepresentation, location: ArgumentLocation) {
+    if let ArgumentLocation::Stack(offset) = location {
         add_byte_code(
             &mut ir.byte_code, 
             move_reg_to_reg_plus_offset_instruction(
-                register_size,
-                call_arg_register(arg_index),
+                register_size_64(),
+                get_argument_location_register(location),
                 stack_pointer_register(), 
-                address_offset((arg_index * 8) as u8)
+                offset
             )
         );
     }
 }
 
-fn move_variable_to_call_arg_register_instruction(register_size: RegisterSize, offset: AddressOffset, arg_index: usize) -> ByteCodeInstruction {
-    move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), offset, call_arg_register(arg_index))
+fn build_bytecode_at_procedure_call_argument_global_variable(
+    ir: &mut IntermediateRepresentation,
+    identifier_name: &str,
+    arg_type: &ResolvableType,
+    location: ArgumentLocation,
+    arg_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(arg_type) = try_get_resolved_runtime_type_pointer(arg_type) {
+        if let Some(register_size) = resolved_type_to_register_size(&arg_type) {
+            let register_size = get_argument_location_register_size(location, register_size);
+            build_bytecode_to_load_global_variable_to_reg(ir, identifier_name, register_size, get_argument_location_register(location));
+            build_bytecode_to_store_argument_if_on_stack(ir, location);
+        } else {
+            todo(errors, function!(), "Non built in typed global variable call arg");
+        }
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), arg_position);
+    }
+}
+
+fn build_bytecode_at_procedure_call_argument_procedure(ir: &mut IntermediateRepresentation, procedure_name: &str, location: ArgumentLocation) {
+    let symbol_index = add_symbol(&mut ir.symbols, foreign_external(string(procedure_name)));
+    add_byte_code(
+        &mut ir.byte_code, 
+        load_symbol_address_to_reg(register_size_64(), symbol_index, get_argument_location_register(location))
+    );
+    build_bytecode_to_store_argument_if_on_stack(ir, location);
 }
 
 fn build_bytecode_at_procedure_call_argument_global_identifier(
     ir: &mut IntermediateRepresentation,
     identifier_name: &str,
     arg_type: &ResolvableType,
-    arg_index: usize,
+    location: ArgumentLocation,
     arg_position: SourceFilePosition,
     errors: &mut CompilationErrors
 ) {
-    if arg_index > 3 {
-        todo(errors, function!(), "Move fourth or more argument to shadow space");
-    }
-
     let symbol_index = add_symbol(&mut ir.symbols, foreign_external(string(identifier_name)));
 
     if let Some(arg_type) = try_get_resolved_runtime_type_pointer(arg_type) {
         if let Some((built_in_arg_type, _is_pointer)) = try_get_built_in_type(&arg_type.id) {
-            let register_size = built_in_type_to_register_size(built_in_arg_type);
-            build_bytecode_at_procedure_call_argument_global_identifier_bulit_in_type(ir, register_size, symbol_index, arg_index);
+            let register_size = get_argument_location_register_size(location, built_in_type_to_register_size(built_in_arg_type));
+            build_bytecode_at_procedure_call_argument_global_identifier_bulit_in_type(ir, register_size, symbol_index, location);
         } else {
             todo(errors, function!(), "Non built in typed identifier call arg");
         }
@@ -231,12 +323,25 @@ fn build_bytecode_at_procedure_call_argument_global_identifier_bulit_in_type(
     ir: &mut IntermediateRepresentation,
     register_size: RegisterSize,
     symbol_index: SymbolIndex,
-    arg_index: usize
+    location: ArgumentLocation
 ) {
-    add_byte_code(
-        &mut ir.byte_code, 
-        move_symbol_to_reg_instruction(register_size, symbol_index, call_arg_register(arg_index))
-    );
+    let register = get_argument_location_register(location);
+
+    if register_size == register_size_64() {
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                move_symbol_to_reg_instruction(register_size_32(), symbol_index, register),
+                extend_reg_to_reg_instruction(register_size_32(), true, register, register)
+            )
+        );
+    } else {
+        add_byte_code(
+            &mut ir.byte_code, 
+            move_symbol_to_reg_instruction(register_size, symbol_index, register)
+        );
+    }
+    build_bytecode_to_store_argument_if_on_stack(ir, location);
 }
 
 fn move_procedure_call_return_value_into_storage(
@@ -247,11 +352,15 @@ fn move_procedure_call_return_value_into_storage(
     errors: &mut CompilationErrors
 ) {
     if let Some(assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
+        let register_size = match resolved_type_to_register_size(&assignment.resolved_type) {
+            Some(register_size) if is_float_register_size(register_size) || register_size == register_size_64() => register_size,
+            _ => register_size_32()
+        };
         add_byte_code(
             &mut ir.byte_code, 
             move_reg_to_reg_plus_offset_instruction(
-                register_size_32(),
-                call_return_arg_register(0), 
+                register_size,
+                call_return_arg_register_for_size(register_size, 0), 
                 base_pointer_register(),
                 assignment.offset
             )
@@ -259,7 +368,7 @@ fn move_procedure_call_return_value_into_storage(
     }
 }
 
-fn call_external_function(ir: &mut IntermediateRepresentation, name: &str) {
+pub fn call_external_function(ir: &mut IntermediateRepresentation, name: &str) {
     let call_name_symbol_index = add_symbol(&mut ir.symbols, foreign_external(string(name)));
     add_byte_code(
         &mut ir.byte_code,
@@ -267,20 +376,28 @@ fn call_external_function(ir: &mut IntermediateRepresentation, name: &str) {
     );
 }
 
-fn reserve_shadow_stack_space(ir: &mut IntermediateRepresentation, arg_count: usize) {
+pub fn reserve_call_stack_space(ir: &mut IntermediateRepresentation, locations: &[ArgumentLocation]) {
+    let size = get_call_stack_space_size(ir.calling_convention, locations);
+
+    if size == 0 {
+        return;
+    }
+
     add_byte_code(
         &mut ir.byte_code,
-        sub_value_from_reg_instruction(instruction_value_8(get_shadow_space_size(arg_count)), stack_pointer_register())
+        sub_value_from_reg_instruction(stack_size_instruction_value(size), stack_pointer_register())
     );
 }
 
-fn release_shadow_stack_space(ir: &mut IntermediateRepresentation, arg_count: usize) {
+pub fn release_call_stack_space(ir: &mut IntermediateRepresentation, locations: &[ArgumentLocation]) {
+    let size = get_call_stack_space_size(ir.calling_convention, locations);
+
+    if size == 0 {
+        return;
+    }
+
     add_byte_code(
         &mut ir.byte_code,
-        add_value_to_reg_instruction(instruction_value_8(get_shadow_space_size(arg_count)), stack_pointer_register())
+        add_value_to_reg_instruction(stack_size_instruction_value(size), stack_pointer_register())
     );
 }
-
-fn get_shadow_space_size(arg_count: usize) -> u8 {
-    std::cmp::max(8 * arg_count as u8, 32)
-}
\ No newline at end of file
@@ -0,0 +1,640 @@
+use crate::{
+    parsing::*,
+    types::*,
+    intermediate_representation::*,
+    errors::*
+};
+
+pub fn build_bytecode_at_variable_assignment_to_binary_expr(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    assignment_name: &str,
+    assignment_position: SourceFilePosition,
+    expr: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let (op, lhs, rhs) = match expr.item_ref() {
+        AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, .. } => (op, lhs, rhs),
+        _ => return add_intermediate_representation_error(errors, expected_binary_operator_error(), expr.position)
+    };
+
+    let operand_size = try_get_float_operand_register_size(assignment_map, lhs)
+        .or_else(|| try_get_float_operand_register_size(assignment_map, rhs));
+
+    let register_size = match operand_size {
+        Some(register_size) => register_size,
+        None => return build_bytecode_at_variable_assignment_to_int_expr(ir, assignment_map, assignment_name, assignment_position, expr, errors)
+    };
+
+    let op = match try_get_operator(op.item_ref()) {
+        Some(op) => op,
+        None => return add_intermediate_representation_error(errors, expected_binary_operator_error(), op.position)
+    };
+
+    if let Some(assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
+        build_bytecode_to_load_float_operand(ir, assignment_map, lhs, register_size, float_standard_register(0), errors);
+        build_bytecode_to_load_float_operand(ir, assignment_map, rhs, register_size, float_standard_register(1), errors);
+
+        if let Some(condition) = float_comparison_condition(&op) {
+            add_byte_codes(
+                &mut ir.byte_code,
+                vec!(
+                    compare_float_reg_to_float_reg_instruction(register_size, float_standard_register(0), float_standard_register(1)),
+                    set_reg_on_condition_instruction(register_size_8(), condition, standard_register(0)),
+                    move_reg_to_reg_plus_offset_instruction(register_size_8(), standard_register(0), base_pointer_register(), assignment.offset)
+                )
+            );
+            return;
+        }
+
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                float_arithmetic_instruction(&op, register_size, float_standard_register(1), float_standard_register(0)),
+                move_reg_to_reg_plus_offset_instruction(register_size, float_standard_register(0), base_pointer_register(), assignment.offset)
+            )
+        );
+    }
+}
+
+pub fn build_bytecode_at_variable_assignment_to_int_expr(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    assignment_name: &str,
+    assignment_position: SourceFilePosition,
+    expr: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    if let Some(assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
+        if let Some(register_size) = resolved_type_to_register_size(&assignment.resolved_type) {
+            build_bytecode_to_load_int_expr_of_type(ir, assignment_map, expr, &assignment.resolved_type, standard_register(0), errors);
+            add_byte_code(
+                &mut ir.byte_code,
+                move_reg_to_reg_plus_offset_instruction(register_size, standard_register(0), base_pointer_register(), assignment.offset)
+            );
+        } else {
+            add_intermediate_representation_error(errors, register_size_not_resolved_error(), assignment_position);
+        }
+    }
+}
+
+pub fn build_bytecode_to_load_int_expr(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    expr: &AbstractSyntaxNode,
+    to: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    build_bytecode_to_load_int_expr_with_layout(ir, assignment_map, expr, None, to, errors);
+}
+
+pub fn build_bytecode_to_load_int_expr_of_type(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    expr: &AbstractSyntaxNode,
+    expr_type: &RuntimeTypePointer,
+    to: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let layout = try_get_int_operand_register_layout(expr_type);
+    build_bytecode_to_load_int_expr_with_layout(ir, assignment_map, expr, layout, to, errors);
+}
+
+fn build_bytecode_to_load_int_expr_with_layout(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    expr: &AbstractSyntaxNode,
+    layout: Option<(RegisterSize, bool)>,
+    to: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let expr_position = expr.position;
+
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::Literal(literal) => match try_get_resolved_literal(literal).and_then(|literal| try_get_int_literal_value(&literal)) {
+            Some(value) => add_byte_code(&mut ir.byte_code, move_value_to_reg_instruction(instruction_value_64(value), to)),
+            None => add_intermediate_representation_error(errors, literal_not_resolved_error(), expr_position)
+        },
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } => {
+            if let Some(assignment) = get_assignment(assignment_map, name, expr_position, errors) {
+                build_bytecode_to_load_local_int_operand(ir, assignment, to, expr_position, errors);
+            }
+        },
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Global } =>
+            build_bytecode_to_load_global_int_operand(ir, name, to),
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::GlobalVariable } => match layout {
+            Some((register_size, is_signed)) => build_bytecode_to_load_global_variable_to_reg_extended(ir, name, register_size, is_signed, to),
+            None => add_intermediate_representation_error(errors, register_size_not_resolved_error(), expr_position)
+        },
+        AbstractSyntaxNodeItem::Cast { cast_type, expr: cast_expr, checked } =>
+            build_bytecode_to_load_int_cast(ir, assignment_map, cast_type, cast_expr, *checked, to, errors),
+        AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, expression_type } => {
+            let layout = try_get_resolved_runtime_type_pointer(expression_type)
+                .and_then(|expression_type| try_get_int_operand_register_layout(&expression_type))
+                .or(layout);
+            build_bytecode_to_evaluate_int_binary_expr(ir, assignment_map, op, lhs, rhs, layout, errors);
+            if to != standard_register(0) {
+                add_byte_code(&mut ir.byte_code, move_reg_to_reg_instruction(register_size_64(), standard_register(0), to));
+            }
+        },
+        AbstractSyntaxNodeItem::IndexExpr { instance, index, index_expression_type } => match try_get_resolved_runtime_type_pointer(index_expression_type) {
+            Some(element_type) => build_bytecode_to_load_int_index_expr(ir, assignment_map, instance, index, &element_type, to, errors),
+            None => add_intermediate_representation_error(errors, type_not_resolved_error(), expr_position)
+        },
+        _ => todo(errors, function!(), "Other int operand expression types")
+    }
+}
+
+fn build_bytecode_to_load_local_int_operand(
+    ir: &mut IntermediateRepresentation,
+    assignment: &IntermediateRepresentationAssignment,
+    to: ByteCodeRegister,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some((register_size, is_signed)) = try_get_int_operand_register_layout(&assignment.resolved_type) {
+        add_byte_code(
+            &mut ir.byte_code,
+            move_reg_plus_offset_to_reg_extended_instruction(register_size, is_signed, base_pointer_register(), assignment.offset, to)
+        );
+    } else {
+        add_intermediate_representation_error(errors, register_size_not_resolved_error(), position);
+    }
+}
+
+fn build_bytecode_to_load_global_int_operand(ir: &mut IntermediateRepresentation, name: &str, to: ByteCodeRegister) {
+    let symbol_index = add_symbol(&mut ir.symbols, foreign_external(string(name)));
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            move_symbol_to_reg_instruction(register_size_32(), symbol_index, to),
+            extend_reg_to_reg_instruction(register_size_32(), true, to, to)
+        )
+    );
+}
+
+fn build_bytecode_to_load_int_cast(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    cast_type: &ResolvableType,
+    expr: &AbstractSyntaxNode,
+    checked: bool,
+    to: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let conversion = try_get_resolved_runtime_type_pointer(cast_type)
+        .and_then(|cast_type| try_get_local_cast_conversion(assignment_map, expr, &cast_type).map(|from_assignment| (from_assignment, cast_type)));
+
+    match conversion {
+        Some((from_assignment, to_type)) => match try_get_int_register_layout(&to_type) {
+            Some((to_size, to_signed)) if try_get_int_register_layout(&from_assignment.resolved_type).is_some() => {
+                build_bytecode_to_load_converted_local_int(ir, from_assignment, &to_type, checked, to, expr.position, errors);
+                add_byte_code(&mut ir.byte_code, extend_reg_to_reg_instruction(to_size, to_signed, to, to));
+            },
+            _ => todo(errors, function!(), "Float conversions in int expressions")
+        },
+        None => build_bytecode_to_load_int_expr(ir, assignment_map, expr, to, errors)
+    }
+}
+
+fn build_bytecode_to_evaluate_int_binary_expr(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    op: &AbstractSyntaxNode,
+    lhs: &AbstractSyntaxNode,
+    rhs: &AbstractSyntaxNode,
+    layout: Option<(RegisterSize, bool)>,
+    errors: &mut CompilationErrors
+) {
+    let op = match try_get_operator(op.item_ref()) {
+        Some(op) => op,
+        None => return add_intermediate_representation_error(errors, expected_binary_operator_error(), op.position)
+    };
+
+    let layout = layout.filter(|_| int_comparison_condition(&op, true).is_none());
+    let lhs_layout = try_get_int_operand_layout(assignment_map, rhs).or(layout);
+    let rhs_layout = try_get_int_operand_layout(assignment_map, lhs).or(layout);
+
+    build_bytecode_to_load_int_operands(ir, assignment_map, lhs, lhs_layout, rhs, rhs_layout, errors);
+
+    if let Some(element_size) = try_get_pointer_offset_scale(assignment_map, lhs, &op) {
+        build_bytecode_to_scale_int_offset(ir, element_size);
+    }
+
+    let is_signed = rhs_layout
+        .or(lhs_layout)
+        .is_none_or(|(_, is_signed)| is_signed);
+
+    if let Some(condition) = int_comparison_condition(&op, is_signed) {
+        return add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                compare_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+                set_reg_on_condition_instruction(register_size_8(), condition, standard_register(0)),
+                extend_reg_to_reg_instruction(register_size_8(), false, standard_register(0), standard_register(0))
+            )
+        );
+    }
+
+    add_byte_code(&mut ir.byte_code, int_arithmetic_instruction(&op, standard_register(1), standard_register(0)));
+}
+
+fn build_bytecode_to_load_int_operands(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    lhs: &AbstractSyntaxNode,
+    lhs_layout: Option<(RegisterSize, bool)>,
+    rhs: &AbstractSyntaxNode,
+    rhs_layout: Option<(RegisterSize, bool)>,
+    errors: &mut CompilationErrors
+) {
+    build_bytecode_to_load_int_expr_with_layout(ir, assignment_map, lhs, lhs_layout, standard_register(0), errors);
+
+    if needs_accumulator(rhs) {
+        add_byte_code(&mut ir.byte_code, push_reg_instruction(register_size_64(), standard_register(0)));
+        build_bytecode_to_load_int_expr_with_layout(ir, assignment_map, rhs, rhs_layout, standard_register(0), errors);
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                move_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+                pop_reg_instruction(register_size_64(), standard_register(0))
+            )
+        );
+    } else {
+        build_bytecode_to_load_int_expr_with_layout(ir, assignment_map, rhs, rhs_layout, standard_register(1), errors);
+    }
+}
+
+fn build_bytecode_to_scale_int_offset(ir: &mut IntermediateRepresentation, element_size: usize) {
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            move_value_to_reg_instruction(instruction_value_64(element_size as u64), standard_register(2)),
+            mul_reg_to_reg_instruction(register_size_64(), standard_register(2), standard_register(1))
+        )
+    );
+}
+
+fn build_bytecode_to_load_int_index_expr(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    instance: &AbstractSyntaxNode,
+    index: &AbstractSyntaxNode,
+    element_type: &RuntimeTypePointer,
+    to: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let (register_size, is_signed) = match try_get_int_operand_register_layout(element_type) {
+        Some(layout) => layout,
+        None => return todo(errors, function!(), "Other indexed element types")
+    };
+
+    build_bytecode_to_load_index_address(ir, assignment_map, instance, index, element_type, errors);
+    add_byte_code(&mut ir.byte_code, move_reg_plus_offset_to_reg_extended_instruction(register_size, is_signed, standard_register(0), address_offset(0), to));
+}
+
+pub fn build_bytecode_to_store_int_index_expr(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    instance: &AbstractSyntaxNode,
+    index: &AbstractSyntaxNode,
+    element_type: &RuntimeTypePointer,
+    value: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let register_size = match try_get_int_operand_register_layout(element_type) {
+        Some((register_size, _)) => register_size,
+        None => return todo(errors, function!(), "Other indexed element types")
+    };
+
+    build_bytecode_to_load_index_address(ir, assignment_map, instance, index, element_type, errors);
+    add_byte_code(&mut ir.byte_code, push_reg_instruction(register_size_64(), standard_register(0)));
+    build_bytecode_to_load_int_expr_of_type(ir, assignment_map, value, element_type, standard_register(0), errors);
+    add_byte_codes(
+        &mut ir.byte_code,
+        vec!(
+            move_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+            pop_reg_instruction(register_size_64(), standard_register(0)),
+            move_reg_to_reg_plus_offset_instruction(register_size, standard_register(1), standard_register(0), address_offset(0))
+        )
+    );
+}
+
+fn build_bytecode_to_load_index_address(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    instance: &AbstractSyntaxNode,
+    index: &AbstractSyntaxNode,
+    element_type: &RuntimeTypePointer,
+    errors: &mut CompilationErrors
+) {
+    if let Some(array) = try_get_local_array_assignment(assignment_map, instance) {
+        build_bytecode_to_load_int_expr(ir, assignment_map, index, standard_register(1), errors);
+        add_byte_code(&mut ir.byte_code, load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), array.offset, standard_register(0)));
+    } else {
+        build_bytecode_to_load_int_operands(ir, assignment_map, instance, Some((register_size_64(), false)), index, None, errors);
+    }
+
+    if let TypeSize::Resolved { size_in_bytes } = element_type.size {
+        if size_in_bytes > 1 {
+            build_bytecode_to_scale_int_offset(ir, size_in_bytes);
+        }
+    }
+
+    add_byte_code(&mut ir.byte_code, add_reg_to_reg_instruction(register_size_64(), standard_register(1), standard_register(0)));
+}
+
+fn try_get_local_array_assignment<'a>(assignment_map: &'a AssignmentMap, instance: &AbstractSyntaxNode) -> OptionalIntermediateRepresentationAssignment<'a> {
+    if let AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } = instance.item_ref() {
+        return find_assignment(assignment_map, name).filter(|assignment| is_array_runtime_type(&assignment.resolved_type));
+    }
+    None
+}
+
+fn int_arithmetic_instruction(op: &Operator, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match op {
+        Operator::Add => add_reg_to_reg_instruction(register_size_64(), from, to),
+        Operator::Subtract => sub_reg_from_reg_instruction(register_size_64(), from, to),
+        Operator::Multiply => mul_reg_to_reg_instruction(register_size_64(), from, to),
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+fn needs_accumulator(expr: &AbstractSyntaxNode) -> bool {
+    !matches!(
+        expr.item_ref(),
+        AbstractSyntaxNodeItem::Literal(_) | AbstractSyntaxNodeItem::Identifier { scope: Scope::Local | Scope::Global, .. }
+    )
+}
+
+fn try_get_pointer_offset_scale(assignment_map: &AssignmentMap, lhs: &AbstractSyntaxNode, op: &Operator) -> Option<usize> {
+    if !matches!(op, Operator::Add | Operator::Subtract) {
+        return None;
+    }
+
+    if let RuntimeTypeItem::Pointer { to_type } = try_get_int_operand_type(assignment_map, lhs)?.item.clone() {
+        if let TypeSize::Resolved { size_in_bytes } = to_type.size {
+            return Some(size_in_bytes).filter(|size| *size > 1);
+        }
+    }
+    None
+}
+
+fn try_get_int_operand_type(assignment_map: &AssignmentMap, operand: &AbstractSyntaxNode) -> OptionalRuntimeTypePointer {
+    match operand.item_ref() {
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } =>
+            find_assignment(assignment_map, name).map(|assignment| assignment.resolved_type.clone()),
+        AbstractSyntaxNodeItem::BinaryExpr { expression_type, .. } => try_get_resolved_runtime_type_pointer(expression_type),
+        AbstractSyntaxNodeItem::Cast { cast_type, .. } => try_get_resolved_runtime_type_pointer(cast_type),
+        AbstractSyntaxNodeItem::IndexExpr { index_expression_type, .. } => try_get_resolved_runtime_type_pointer(index_expression_type),
+        _ => None
+    }
+}
+
+fn try_get_int_operand_layout(assignment_map: &AssignmentMap, operand: &AbstractSyntaxNode) -> Option<(RegisterSize, bool)> {
+    if let AbstractSyntaxNodeItem::Literal(literal) = operand.item_ref() {
+        return try_get_resolved_literal(literal).and_then(|literal| try_get_int_literal_layout(&literal));
+    }
+
+    try_get_int_operand_type(assignment_map, operand)
+        .and_then(|operand_type| try_get_int_operand_register_layout(&operand_type))
+}
+
+fn try_get_int_operand_register_layout(operand_type: &RuntimeTypePointer) -> Option<(RegisterSize, bool)> {
+    match operand_type.item {
+        RuntimeTypeItem::Int { is_signed } => resolved_type_to_register_size(operand_type).map(|register_size| (register_size, is_signed)),
+        RuntimeTypeItem::Bool | RuntimeTypeItem::Pointer { .. } => resolved_type_to_register_size(operand_type).map(|register_size| (register_size, false)),
+        _ => None
+    }
+}
+
+fn try_get_int_literal_value(literal: &ResolvedLiteral) -> Option<u64> {
+    match literal {
+        ResolvedLiteral::UnsignedInt8(value) => Some(*value as u64),
+        ResolvedLiteral::SignedInt8(value) => Some(*value as i64 as u64),
+        ResolvedLiteral::UnsignedInt16(value) => Some(*value as u64),
+        ResolvedLiteral::SignedInt16(value) => Some(*value as i64 as u64),
+        ResolvedLiteral::UnsignedInt32(value) => Some(*value as u64),
+        ResolvedLiteral::SignedInt32(value) => Some(*value as i64 as u64),
+        ResolvedLiteral::UnsignedInt64(value) => Some(*value),
+        ResolvedLiteral::SignedInt64(value) => Some(*value as u64),
+        _ => None
+    }
+}
+
+fn try_get_int_literal_layout(literal: &ResolvedLiteral) -> Option<(RegisterSize, bool)> {
+    match literal {
+        ResolvedLiteral::UnsignedInt8(_) => Some((register_size_8(), false)),
+        ResolvedLiteral::SignedInt8(_) => Some((register_size_8(), true)),
+        ResolvedLiteral::UnsignedInt16(_) => Some((register_size_16(), false)),
+        ResolvedLiteral::SignedInt16(_) => Some((register_size_16(), true)),
+        ResolvedLiteral::UnsignedInt32(_) => Some((register_size_32(), false)),
+        ResolvedLiteral::SignedInt32(_) => Some((register_size_32(), true)),
+        ResolvedLiteral::UnsignedInt64(_) => Some((register_size_64(), false)),
+        ResolvedLiteral::SignedInt64(_) => Some((register_size_64(), true)),
+        _ => None
+    }
+}
+
+fn float_arithmetic_instruction(op: &Operator, register_size: RegisterSize, from: ByteCodeRegister, to: ByteCodeRegister) -> ByteCodeInstruction {
+    match op {
+        Operator::Add => add_float_reg_to_float_reg_instruction(register_size, from, to),
+        Operator::Subtract => sub_float_reg_from_float_reg_instruction(register_size, from, to),
+        Operator::Multiply => mul_float_reg_to_float_reg_instruction(register_size, from, to),
+        _ => ByteCodeInstruction::Unsupported
+    }
+}
+
+fn try_get_float_operand_register_size(assignment_map: &AssignmentMap, operand: &AbstractSyntaxNode) -> Option<RegisterSize> {
+    let register_size = match operand.item_ref() {
+        AbstractSyntaxNodeItem::Literal(literal) =>
+            try_get_resolved_literal(literal).and_then(|literal| try_get_float_literal_register_size(&literal)),
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } =>
+            try_get_local_register_size(assignment_map, name),
+        _ => None
+    };
+    register_size.filter(|register_size| is_float_register_size(*register_size))
+}
+
+fn try_get_local_register_size(assignment_map: &AssignmentMap, name: &str) -> Option<RegisterSize> {
+    find_assignment(assignment_map, name).and_then(|assignment| resolved_type_to_register_size(&assignment.resolved_type))
+}
+
+fn build_bytecode_to_load_float_operand(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    operand: &AbstractSyntaxNode,
+    register_size: RegisterSize,
+    to: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let operand_position = operand.position;
+
+    match operand.item_ref() {
+        AbstractSyntaxNodeItem::Literal(literal) => match try_get_resolved_literal(literal) {
+            Some(resolved_literal) if try_get_float_literal_register_size(&resolved_literal) == Some(register_size) =>
+                build_bytecode_to_load_float_literal_to_reg(ir, &resolved_literal, to),
+            Some(_) => todo(errors, function!(), "Mixed operand types in float expressions"),
+            None => add_intermediate_representation_error(errors, literal_not_resolved_error(), operand_position)
+        },
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } => {
+            if let Some(assignment) = get_assignment(assignment_map, name, operand_position, errors) {
+                add_byte_code(
+                    &mut ir.byte_code,
+                    move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), assignment.offset, to)
+                );
+            }
+        },
+        _ => todo(errors, function!(), "Other float operand expression types")
+    }
+}
+
+pub fn build_bytecode_at_variable_assignment_to_cast(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    assignment_name: &str,
+    assignment_position: SourceFilePosition,
+    cast: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let (cast_type, expr, checked) = match cast.item_ref() {
+        AbstractSyntaxNodeItem::Cast { cast_type, expr, checked } => (cast_type, expr, *checked),
+        _ => return add_intermediate_representation_error(errors, expected_cast_error(), cast.position)
+    };
+
+    let conversion = try_get_resolved_runtime_type_pointer(cast_type)
+        .and_then(|cast_type| try_get_local_cast_conversion(assignment_map, expr, &cast_type).map(|from_assignment| (from_assignment, cast_type)));
+
+    match conversion {
+        Some((from_assignment, to_type)) => {
+            if let Some(to_assignment) = get_assignment(assignment_map, assignment_name, assignment_position, errors) {
+                build_bytecode_at_variable_assignment_to_conversion(ir, from_assignment, to_assignment, &to_type, checked, expr.position, errors);
+            }
+        },
+        None =>
+            build_bytecode_at_variable_declaration(ir, assignment_map, assignment_name, assignment_position, expr, errors)
+    }
+}
+
+pub fn try_get_local_cast_conversion<'a>(assignment_map: &'a AssignmentMap, expr: &AbstractSyntaxNode, cast_type: &RuntimeTypePointer) -> OptionalIntermediateRepresentationAssignment<'a> {
+    if let AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } = expr.item_ref() {
+        let from_assignment = find_assignment(assignment_map, name)?;
+        return match (&from_assignment.resolved_type.item, &cast_type.item) {
+            (RuntimeTypeItem::Int { .. }, RuntimeTypeItem::Float) | (RuntimeTypeItem::Float, RuntimeTypeItem::Int { .. }) =>
+                Some(from_assignment),
+            (RuntimeTypeItem::Int { .. }, RuntimeTypeItem::Int { .. }) if from_assignment.resolved_type.id != cast_type.id =>
+                Some(from_assignment),
+            _ => None
+        };
+    }
+    None
+}
+
+fn build_bytecode_at_variable_assignment_to_conversion(
+    ir: &mut IntermediateRepresentation,
+    from_assignment: &IntermediateRepresentationAssignment,
+    to_assignment: &IntermediateRepresentationAssignment,
+    to_type: &RuntimeTypePointer,
+    checked: bool,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let from_type = &from_assignment.resolved_type;
+
+    let (from_size, to_size) = match (resolved_type_to_register_size(from_type), resolved_type_to_register_size(to_type)) {
+        (Some(from_size), Some(to_size)) => (from_size, to_size),
+        _ => return add_intermediate_representation_error(errors, register_size_not_resolved_error(), position)
+    };
+
+    if is_float_register_size(to_size) {
+        return add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                move_reg_plus_offset_to_reg_instruction(from_size, base_pointer_register(), from_assignment.offset, standard_register(0)),
+                convert_int_reg_to_float_reg_instruction(get_conversion_int_size(from_type, from_size), to_size, standard_register(0), float_standard_register(0)),
+                move_reg_to_reg_plus_offset_instruction(to_size, float_standard_register(0), base_pointer_register(), to_assignment.offset)
+            )
+        );
+    }
+
+    if is_float_register_size(from_size) {
+        return add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                move_reg_plus_offset_to_reg_instruction(from_size, base_pointer_register(), from_assignment.offset, float_standard_register(0)),
+                convert_float_reg_to_int_reg_instruction(from_size, to_size, float_standard_register(0), standard_register(0)),
+                move_reg_to_reg_plus_offset_instruction(to_size, standard_register(0), base_pointer_register(), to_assignment.offset)
+            )
+        );
+    }
+
+    build_bytecode_to_load_converted_local_int(ir, from_assignment, to_type, checked, standard_register(0), position, errors);
+    add_byte_code(
+        &mut ir.byte_code,
+        move_reg_to_reg_plus_offset_instruction(to_size, standard_register(0), base_pointer_register(), to_assignment.offset)
+    );
+}
+
+fn get_conversion_int_size(int_type: &RuntimeTypePointer, register_size: RegisterSize) -> RegisterSize {
+    match int_type.item {
+        RuntimeTypeItem::Int { is_signed: false } if register_size == register_size_32() => register_size_64(),
+        _ => register_size
+    }
+}
+
+pub fn build_bytecode_to_load_converted_local_int(
+    ir: &mut IntermediateRepresentation,
+    from_assignment: &IntermediateRepresentationAssignment,
+    to_type: &RuntimeTypePointer,
+    checked: bool,
+    to: ByteCodeRegister,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let from_type = &from_assignment.resolved_type;
+
+    let (from_size, from_signed, to_size, to_signed) = match (try_get_int_register_layout(from_type), try_get_int_register_layout(to_type)) {
+        (Some((from_size, from_signed)), Some((to_size, to_signed))) => (from_size, from_signed, to_size, to_signed),
+        _ => return add_intermediate_representation_error(errors, register_size_not_resolved_error(), position)
+    };
+
+    add_byte_code(
+        &mut ir.byte_code,
+        move_reg_plus_offset_to_reg_extended_instruction(from_size, from_signed, base_pointer_register(), from_assignment.offset, to)
+    );
+
+    if checked {
+        build_bytecode_to_check_int_conversion(ir, from_signed, to_size, to_signed, to);
+    }
+}
+
+fn try_get_int_register_layout(int_type: &RuntimeTypePointer) -> Option<(RegisterSize, bool)> {
+    if let RuntimeTypeItem::Int { is_signed } = int_type.item {
+        return resolved_type_to_register_size(int_type).map(|register_size| (register_size, is_signed));
+    }
+    None
+}
+
+fn build_bytecode_to_check_int_conversion(ir: &mut IntermediateRepresentation, from_signed: bool, to_size: RegisterSize, to_signed: bool, value: ByteCodeRegister) {
+    if from_signed != to_signed {
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                zero_reg_instruction(register_size_64(), standard_register(1)),
+                compare_reg_to_reg_instruction(register_size_64(), value, standard_register(1)),
+                trap_on_condition_instruction(ByteCodeCondition::Less)
+            )
+        );
+    }
+
+    if to_size != register_size_64() {
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                extend_reg_to_reg_instruction(to_size, to_signed, value, standard_register(1)),
+                compare_reg_to_reg_instruction(register_size_64(), value, standard_register(1)),
+                trap_on_condition_instruction(ByteCodeCondition::NotEqual)
+            )
+        );
+    }
+}
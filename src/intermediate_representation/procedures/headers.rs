@@ -1,9 +1,15 @@
 use crate::{
     intermediate_representation::*,
+    parsing::*,
     utilities::*
 
 };
 
-pub fn build_bytecode_at_procedure_header(ir: &mut IntermediateRepresentation, name: &str) {
+pub fn build_bytecode_at_procedure_header(ir: &mut IntermediateRepresentation, name: &str, body: &ProcedureBodyReference) {
     ir.top_level_symbol = string(name);
-}
\ No newline at end of file
+    if let ProcedureBodyReference::Foerign(library) = body {
+        if let AbstractSyntaxNodeItem::Identifier { name: library_name, .. } = library.item_ref() {
+            ir.foreign_library_identifier = Some(library_name.clone());
+        }
+    }
+}
@@ -3,9 +3,21 @@ mod bodies;
 mod calls;
 mod assignments;
 mod parsing;
+mod returns;
+mod boxing;
+mod variadics;
+mod expressions;
+mod asm;
+mod branches;
 
 pub use headers::*;
 pub use bodies::*;
 pub use calls::*;
 pub use assignments::*;
-pub use parsing::*;
\ No newline at end of file
+pub use parsing::*;
+pub use returns::*;
+pub use boxing::*;
+pub use variadics::*;
+pub use expressions::*;
+pub use asm::*;
+pub use branches::*;
\ No newline at end of file
@@ -0,0 +1,140 @@
+use crate::{
+    parsing::*,
+    intermediate_representation::*,
+    errors::*,
+    types::*
+};
+
+pub fn build_bytecode_at_return(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    args: &AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) {
+    if args.len() > 1 {
+        todo(errors, function!(), "Multiple return args");
+    }
+
+    if let Some(arg) = args.first() {
+        build_bytecode_at_return_arg(ir, assignment_map, arg, errors);
+    }
+
+    build_bytecode_for_procedure_epilogue(ir);
+}
+
+fn build_bytecode_at_return_arg(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    arg: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let arg_position = arg.position;
+    match arg.item_ref() {
+        AbstractSyntaxNodeItem::Argument { expr, arg_type, .. } =>
+            build_bytecode_at_return_arg_expression(ir, assignment_map, expr, arg_type, errors),
+        _ => add_intermediate_representation_error(errors, expected_argument_error(), arg_position)
+    }
+}
+
+fn build_bytecode_at_return_arg_expression(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    expr: &AbstractSyntaxNode,
+    arg_type: &ResolvableType,
+    errors: &mut CompilationErrors
+) {
+    let expr_position = expr.position;
+
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::Literal(literal) => {
+            if let Some(resolved_literal) = try_get_resolved_literal(literal) {
+                build_bytecode_at_return_literal(ir, &resolved_literal);
+            } else {
+                add_intermediate_representation_error(errors, literal_not_resolved_error(), expr_position);
+            }
+        },
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } =>
+            build_bytecode_at_return_local_identifier(ir, assignment_map, name, arg_type, expr_position, errors),
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::GlobalVariable } =>
+            build_bytecode_at_return_global_variable(ir, name, arg_type, expr_position, errors),
+        AbstractSyntaxNodeItem::Cast { cast_type, expr, checked } =>
+            build_bytecode_at_return_cast(ir, assignment_map, cast_type, expr, *checked, errors),
+        _ => todo(errors, function!(), "Other return arg expression types")
+    }
+}
+
+fn build_bytecode_at_return_cast(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    cast_type: &ResolvableType,
+    expr: &AbstractSyntaxNode,
+    checked: bool,
+    errors: &mut CompilationErrors
+) {
+    let expr_position = expr.position;
+
+    if let Some(cast_type) = try_get_resolved_runtime_type_pointer(cast_type) {
+        match (try_get_local_cast_conversion(assignment_map, expr, &cast_type), &cast_type.item) {
+            (Some(from_assignment), RuntimeTypeItem::Int { .. }) if matches!(from_assignment.resolved_type.item, RuntimeTypeItem::Int { .. }) =>
+                build_bytecode_to_load_converted_local_int(ir, from_assignment, &cast_type, checked, call_return_arg_register(0), expr_position, errors),
+            (None, _) => 
+                build_bytecode_at_return_arg_expression(ir, assignment_map, expr, &resolved_resolvable_type(cast_type), errors),
+            _ => todo(errors, function!(), "Float conversion return args")
+        }
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), expr_position);
+    }
+}
+
+fn build_bytecode_at_return_literal(ir: &mut IntermediateRepresentation, literal: &ResolvedLiteral) {
+    match literal {
+        ResolvedLiteral::String(value) => build_bytecode_to_load_string_literal_to_reg(ir, value, call_return_arg_register(0)),
+        ResolvedLiteral::Float32(_) | ResolvedLiteral::Float64(_) => build_bytecode_to_load_float_literal_to_reg(ir, literal, float_call_return_arg_register(0)),
+        _ => add_byte_code(
+            &mut ir.byte_code,
+            move_value_to_reg_instruction(resolved_literal_to_instruction_value(literal), call_return_arg_register(0))
+        )
+    }
+}
+
+fn build_bytecode_at_return_local_identifier(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    name: &str,
+    arg_type: &ResolvableType,
+    identifier_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(assignment) = get_assignment(assignment_map, name, identifier_position, errors) {
+        if let Some(arg_type) = try_get_resolved_runtime_type_pointer(arg_type) {
+            if let Some(register_size) = resolved_type_to_register_size(&arg_type) {
+                add_byte_code(
+                    &mut ir.byte_code,
+                    move_reg_plus_offset_to_reg_instruction(register_size, base_pointer_register(), assignment.offset, call_return_arg_register_for_size(register_size, 0))
+                );
+            } else {
+                todo(errors, function!(), "Non built in typed identifier return arg");
+            }
+        } else {
+            add_intermediate_representation_error(errors, type_not_resolved_error(), identifier_position);
+        }
+    }
+}
+
+fn build_bytecode_at_return_global_variable(
+    ir: &mut IntermediateRepresentation,
+    name: &str,
+    arg_type: &ResolvableType,
+    identifier_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let Some(arg_type) = try_get_resolved_runtime_type_pointer(arg_type) {
+        if let Some(register_size) = resolved_type_to_register_size(&arg_type) {
+            build_bytecode_to_load_global_variable_to_reg(ir, name, register_size, call_return_arg_register_for_size(register_size, 0));
+        } else {
+            todo(errors, function!(), "Non built in typed global variable return arg");
+        }
+    } else {
+        add_intermediate_representation_error(errors, type_not_resolved_error(), identifier_position);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    parsing::*,
+    types::*,
+    intermediate_representation::*,
+    errors::*,
+    threading::*
+};
+
+pub fn variadic_args_assignment_name(position: SourceFilePosition) -> String {
+    format!("__variadic_args_{}", position.absolute)
+}
+
+pub fn variadic_view_assignment_name(position: SourceFilePosition) -> String {
+    format!("__variadic_view_{}", position.absolute)
+}
+
+pub fn build_bytecode_at_procedure_call_argument_variadic(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    args: &AbstractSyntaxChildNodes,
+    arg_type: &ResolvableType,
+    location: ArgumentLocation,
+    variadic_position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    let element_type = match try_get_variadic_arg_element_type(arg_type) {
+        Some(element_type) => element_type,
+        None => return add_intermediate_representation_error(errors, type_not_resolved_error(), variadic_position)
+    };
+
+    let element_size = match try_get_resolved_type_size(&element_type.size) {
+        Some(element_size) => element_size,
+        None => return add_intermediate_representation_error(errors, type_not_resolved_error(), variadic_position)
+    };
+
+    let array_offset = get_assignment(assignment_map, &variadic_args_assignment_name(variadic_position), variadic_position, errors).map(|array| array.offset);
+    let view_offset = get_assignment(assignment_map, &variadic_view_assignment_name(variadic_position), variadic_position, errors).map(|view| view.offset);
+
+    if let (Some(array_offset), Some(view_offset)) = (array_offset, view_offset) {
+        let scratch = get_argument_location_register(location);
+        for (index, arg) in args.iter().enumerate() {
+            let element_offset = array_offset + (index * element_size) as i32;
+            build_bytecode_to_store_variadic_element(ir, assignment_map, arg, element_offset, scratch, errors);
+        }
+        add_byte_codes(
+            &mut ir.byte_code,
+            vec!(
+                move_value_to_reg_plus_offset_instruction(instruction_value_64(args.len() as u64), base_pointer_register(), view_offset),
+                load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), array_offset, scratch),
+                move_reg_to_reg_plus_offset_instruction(register_size_64(), scratch, base_pointer_register(), view_offset + 8),
+                load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), view_offset, scratch)
+            )
+        );
+        build_bytecode_to_store_argument_if_on_stack(ir, location);
+    }
+}
+
+pub fn try_get_variadic_arg_element_type(arg_type: &ResolvableType) -> Option<RuntimeTypePointer> {
+    try_get_resolved_runtime_type_pointer(arg_type)
+        .and_then(|view_type| try_get_array_view_element_type(&view_type).cloned())
+        .map(create_shareable)
+}
+
+fn build_bytecode_to_store_variadic_element(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    arg: &AbstractSyntaxNode,
+    element_offset: AddressOffset,
+    scratch: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let arg_position = arg.position;
+
+    match arg.item_ref() {
+        AbstractSyntaxNodeItem::Argument { expr, arg_type } => match expr.item_ref() {
+            AbstractSyntaxNodeItem::Boxed { .. } =>
+                build_bytecode_to_store_variadic_boxed_element(ir, assignment_map, expr, element_offset, scratch, errors),
+            AbstractSyntaxNodeItem::Literal(literal) => match try_get_resolved_literal(literal) {
+                Some(ResolvedLiteral::String(_)) => todo(errors, function!(), "String literal variadic arg"),
+                Some(resolved_literal) =>
+                    add_byte_code(
+                        &mut ir.byte_code,
+                        move_value_to_reg_plus_offset_instruction(resolved_literal_to_instruction_value(&resolved_literal), base_pointer_register(), element_offset)
+                    ),
+                None => add_intermediate_representation_error(errors, literal_not_resolved_error(), expr.position)
+            },
+            AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Local } => {
+                let register_size = try_get_resolved_runtime_type_pointer(arg_type).and_then(|arg_type| resolved_type_to_register_size(&arg_type));
+                if let (Some(assignment), Some(register_size)) = (get_assignment(assignment_map, name, expr.position, errors), register_size) {
+                    build_bytecode_to_copy_local(ir, register_size, assignment.offset, element_offset, scratch);
+                } else {
+                    todo(errors, function!(), "Non built in typed local variadic arg");
+                }
+            },
+            _ => todo(errors, function!(), "Other variadic arg expression types")
+        },
+        _ => add_intermediate_representation_error(errors, expected_argument_error(), arg_position)
+    }
+}
+
+fn build_bytecode_to_store_variadic_boxed_element(
+    ir: &mut IntermediateRepresentation,
+    assignment_map: &AssignmentMap,
+    boxed: &AbstractSyntaxNode,
+    element_offset: AddressOffset,
+    scratch: ByteCodeRegister,
+    errors: &mut CompilationErrors
+) {
+    let boxed_position = boxed.position;
+    let (boxed_type, expr) = match boxed.item_ref() {
+        AbstractSyntaxNodeItem::Boxed { boxed_type, expr } => match try_get_resolved_runtime_type_pointer(boxed_type) {
+            Some(boxed_type) => (boxed_type, expr),
+            None => return add_intermediate_representation_error(errors, type_not_resolved_error(), boxed_position)
+        },
+        _ => return add_intermediate_representation_error(errors, expected_argument_error(), boxed_position)
+    };
+
+    if let Some(value) = get_assignment(assignment_map, &boxed_value_assignment_name(boxed_position), boxed_position, errors) {
+        let value_offset = value.offset;
+        build_bytecode_to_spill_boxed_value(ir, assignment_map, expr, &boxed_type, value_offset, scratch, errors);
+        build_bytecode_to_store_any(ir, &boxed_type, value_offset, element_offset, scratch);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    parsing::*,
+    errors::*,
+    intermediate_representation::*,
+};
+
+pub fn build_bytecode_at_run(ir: &mut IntermediateRepresentation, expr: &AbstractSyntaxNode, position: SourceFilePosition, errors: &mut CompilationErrors) {
+    build_bytecode_at_named_run(ir, &run_symbol_name(position), expr, errors);
+}
+
+pub fn build_bytecode_at_named_run(ir: &mut IntermediateRepresentation, name: &str, expr: &AbstractSyntaxNode, errors: &mut CompilationErrors) {
+    ir.top_level_symbol = string(name);
+    store_procedure_name_as_external_symbol(ir, name);
+    build_bytecode_for_procedure_prologue(ir);
+    build_bytecode_at_run_expression(ir, expr, errors);
+    build_bytecode_for_procedure_epilogue(ir);
+}
+
+pub fn run_symbol_name(position: SourceFilePosition) -> String {
+    format!("__run_{}", position.absolute)
+}
+
+fn build_bytecode_at_run_expression(ir: &mut IntermediateRepresentation, expr: &AbstractSyntaxNode, errors: &mut CompilationErrors) {
+    let expr_position = expr.position;
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::ProcedureCall { name, args, .. } =>
+            build_bytecode_at_procedure_call(ir, &AssignmentMap::default(), name, args, errors),
+        AbstractSyntaxNodeItem::Literal(literal) => {
+            if let Some(resolved_literal) = try_get_resolved_literal(literal) {
+                build_bytecode_at_run_literal(ir, &resolved_literal, errors);
+            } else {
+                add_intermediate_representation_error(errors, literal_not_resolved_error(), expr_position);
+            }
+        },
+        _ => todo(errors, function!(), "Other run expression types")
+    }
+}
+
+fn build_bytecode_at_run_literal(ir: &mut IntermediateRepresentation, literal: &ResolvedLiteral, errors: &mut CompilationErrors) {
+    match literal {
+        ResolvedLiteral::String(_) => todo(errors, function!(), "String literal run expressions"),
+        _ => add_byte_code(
+            &mut ir.byte_code,
+            move_value_to_reg_instruction(resolved_literal_to_instruction_value(literal), call_return_arg_register(0))
+        )
+    }
+}
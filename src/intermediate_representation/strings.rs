@@ -1,14 +1,21 @@
 use crate::{intermediate_representation::*, strings::*};
 
-pub fn store_string_literal_in_data_section_and_add_symbol(ir: &mut IntermediateRepresentation, value: &ByteString) -> DataSectionOffset {
-    let literal_data_item_pointer = add_data_item(&mut ir.data, string_data_item(value.clone()));
-    add_symbol(&mut ir.symbols, data_section_item(data_section_item_name(literal_data_item_pointer), *literal_data_item_pointer));
-    literal_data_item_pointer
+pub fn store_string_literal_in_read_only_data(ir: &mut IntermediateRepresentation, value: &ByteString) -> DataSectionOffset {
+    add_data_item(&mut ir.read_only_data, string_data_item(value.clone()))
 }
 
 pub fn store_string_in_data_section_and_add_symbol(ir: &mut IntermediateRepresentation, string_length: usize, string_literal_data_item_pointer: DataSectionOffset) -> DataSectionOffset {
     let string_data_item_pointer = add_data_item(&mut ir.data, quad_word_data_item(string_length as u64));
     add_symbol(&mut ir.symbols, data_section_item(data_section_item_name(string_data_item_pointer), *string_data_item_pointer));
-    add_data_item(&mut ir.data, pointer_data_item(*string_literal_data_item_pointer as u64));
+    add_data_item(&mut ir.data, read_only_pointer_data_item(*string_literal_data_item_pointer as u64));
     string_data_item_pointer
-}
\ No newline at end of file
+}
+
+pub fn build_bytecode_to_load_string_literal_to_reg(ir: &mut IntermediateRepresentation, value: &ByteString, to: ByteCodeRegister) {
+    let string_literal_data_item_pointer = store_string_literal_in_read_only_data(ir, value);
+    let string_data_item_pointer = store_string_in_data_section_and_add_symbol(ir, value.len(), string_literal_data_item_pointer);
+    add_byte_code(
+        &mut ir.byte_code, 
+        load_data_section_address_to_reg(register_size_64(), string_data_item_pointer, to)
+    );
+}
@@ -0,0 +1,78 @@
+use crate::{
+    parsing::*,
+    types::*,
+    intermediate_representation::*,
+    strings::*
+};
+
+const TYPE_INFO_RECORD_SIZE: u64 = 56;
+const TYPE_INFO_MEMBER_RECORD_SIZE: u64 = 32;
+
+pub fn build_bytecode_at_type_info_reference(ir: &mut IntermediateRepresentation, type_info_type: &RuntimeTypePointer) -> SymbolIndex {
+    add_required_type_info(ir, type_info_type);
+    store_type_info_as_foreign_symbol(ir, type_info_type)
+}
+
+pub fn build_type_info_intermediate_representation(id: CompilationUnitId, filename: String, type_info_type: &RuntimeTypePointer) -> IntermediateRepresentation {
+    let mut ir = create_intermediate_representation(id, filename);
+    ir.top_level_symbol = type_info_symbol_name(type_info_type);
+
+    let members = get_type_info_members(type_info_type);
+    let members_offset = TYPE_INFO_RECORD_SIZE;
+    let names_offset = members_offset + TYPE_INFO_MEMBER_RECORD_SIZE * members.len() as u64;
+
+    let type_name = to_byte_string(&type_info_type.name);
+    let member_names: Vec<ByteString> = members.iter().map(|member| to_byte_string(&member.name)).collect();
+
+    let record_pointer = add_data_item(&mut ir.data, quad_word_data_item(get_type_info_kind(&type_info_type.item)));
+    add_data_item(&mut ir.data, quad_word_data_item(type_name.len() as u64));
+    add_data_item(&mut ir.data, pointer_data_item(names_offset));
+    add_data_item(&mut ir.data, quad_word_data_item(try_get_resolved_type_size(&type_info_type.size).unwrap_or(0) as u64));
+    add_data_item(&mut ir.data, quad_word_data_item(members.len() as u64));
+    add_data_item(&mut ir.data, members_pointer_data_item(&members, members_offset));
+    add_data_item(&mut ir.data, quad_word_data_item(get_type_info_signed(&type_info_type.item)));
+
+    let mut member_name_offset = names_offset + type_name.len() as u64;
+    for (member, member_name) in members.iter().zip(&member_names) {
+        let member_type_symbol = build_bytecode_at_type_info_reference(&mut ir, &member.field_type);
+        add_data_item(&mut ir.data, quad_word_data_item(member_name.len() as u64));
+        add_data_item(&mut ir.data, pointer_data_item(member_name_offset));
+        add_data_item(&mut ir.data, quad_word_data_item(get_member_offset(&members, &member.name).unwrap_or(0) as u64));
+        add_data_item(&mut ir.data, symbol_pointer_data_item(member_type_symbol));
+        member_name_offset += member_name.len() as u64;
+    }
+
+    add_data_item(&mut ir.data, string_data_item(type_name));
+    for member_name in member_names {
+        add_data_item(&mut ir.data, string_data_item(member_name));
+    }
+
+    add_symbol(&mut ir.symbols, external_data_section_item(ir.top_level_symbol.clone(), *record_pointer));
+    ir
+}
+
+fn members_pointer_data_item(members: &RuntimeTypeMembers, members_offset: u64) -> ByteCodeDataItem {
+    if members.is_empty() {
+        return quad_word_data_item(0);
+    }
+    pointer_data_item(members_offset)
+}
+
+fn add_required_type_info(ir: &mut IntermediateRepresentation, type_info_type: &RuntimeTypePointer) {
+    let name = type_info_symbol_name(type_info_type);
+    if !ir.type_infos.iter().any(|required| type_info_symbol_name(required) == name) {
+        ir.type_infos.push(type_info_type.clone());
+    }
+}
+
+fn store_type_info_as_foreign_symbol(ir: &mut IntermediateRepresentation, type_info_type: &RuntimeTypePointer) -> SymbolIndex {
+    let name = type_info_symbol_name(type_info_type);
+    let existing = ir.symbols
+        .iter()
+        .position(|symbol| matches!(symbol, ByteCodeSymbol::ForeignExternal { name: existing } if existing == &name));
+
+    if let Some(index) = existing {
+        return symbol_index(index as u32);
+    }
+    add_symbol(&mut ir.symbols, foreign_external(name))
+}
@@ -0,0 +1,282 @@
+use crate::{
+    acting::*,
+    compilation::*,
+    parsing::*,
+    intermediate_representation::*,
+    interpretation::*,
+    types::*,
+    errors::*,
+    utilities::*
+};
+
+#[derive(Clone, Debug)]
+pub enum ExecutionCircuitBreakReason {
+    CompilationError(CompilationErrorItem),
+    SymbolsNotFound
+}
+
+pub fn compilation_error_execution_circuit_break_reason(error: CompilationErrorItem) -> ExecutionCircuitBreakReason {
+    ExecutionCircuitBreakReason::CompilationError(error)
+}
+
+pub fn symbols_not_found_execution_circuit_break_reason() -> ExecutionCircuitBreakReason {
+    ExecutionCircuitBreakReason::SymbolsNotFound
+}
+
+struct ExecutionRequest {
+    unit: CompilationUnit,
+    code: IntermediateRepresentation,
+    respond_to: CompilationActorHandle
+}
+
+fn execution_request(unit: CompilationUnit, code: IntermediateRepresentation, respond_to: CompilationActorHandle) -> ExecutionRequest {
+    ExecutionRequest {
+        unit,
+        code,
+        respond_to
+    }
+}
+
+type ExecutionRequests = Vec<ExecutionRequest>;
+
+struct DeliveryRequest {
+    delivery: InterceptedMessageDelivery,
+    respond_to: CompilationActorHandle
+}
+
+fn delivery_request(delivery: InterceptedMessageDelivery, respond_to: CompilationActorHandle) -> DeliveryRequest {
+    DeliveryRequest {
+        delivery,
+        respond_to
+    }
+}
+
+type DeliveryRequests = Vec<DeliveryRequest>;
+
+pub struct InterpreterActor {
+    library: IntermediateRepresentations,
+    execution_requests: ExecutionRequests,
+    delivery_requests: DeliveryRequests,
+    error_reporter: CompilationActorHandle
+}
+
+pub fn create_interpreter_actor(error_reporter: CompilationActorHandle) -> InterpreterActor {
+    InterpreterActor {
+        library: vec!(),
+        execution_requests: vec!(),
+        delivery_requests: vec!(),
+        error_reporter
+    }
+}
+
+impl Actor<CompilationMessage> for InterpreterActor {
+    fn receive(&mut self, message: CompilationMessage, _ctx: &CompilationMessageContext) -> AfterReceiveAction {
+        match message {
+            CompilationMessage::AddByteCode(code) =>
+                handle_add_byte_code(self, code),
+            CompilationMessage::ExecuteByteCode { unit, code, compiler, has_prior_errors } =>
+                handle_execute_byte_code(self, unit, code, compiler, has_prior_errors),
+            CompilationMessage::DeliverInterceptedMessage { delivery, compiler, has_prior_errors } =>
+                handle_deliver_intercepted_message(self, delivery, compiler, has_prior_errors),
+            CompilationMessage::CircuitBreakAllExecutions(reason) =>
+                handle_circuit_break_all_executions(self, reason),
+            CompilationMessage::ShutDown => shutdown_after_receive(),
+            _ => continue_listening_after_receive()
+        }
+    }
+    
+    fn get_type_name(&self) -> String {
+        string_type_name::<InterpreterActor>()
+    }
+}
+
+fn handle_add_byte_code(interpreter: &mut InterpreterActor, code: IntermediateRepresentation) -> AfterReceiveAction {
+    interpreter.library.push(code);
+    service_execution_requests(interpreter);
+    continue_listening_after_receive()
+}
+
+fn handle_execute_byte_code(
+    interpreter: &mut InterpreterActor,
+    unit: CompilationUnit,
+    code: IntermediateRepresentation,
+    compiler: CompilationActorHandle,
+    has_prior_errors: bool
+) -> AfterReceiveAction {
+    if has_prior_errors {
+        notify_compiler_byte_code_executed(&compiler, unit, code, vec!());
+    } else {
+        interpreter.execution_requests.push(execution_request(unit, code, compiler));
+        service_execution_requests(interpreter);
+    }
+    continue_listening_after_receive()
+}
+
+fn handle_deliver_intercepted_message(
+    interpreter: &mut InterpreterActor,
+    delivery: InterceptedMessageDelivery,
+    compiler: CompilationActorHandle,
+    has_prior_errors: bool
+) -> AfterReceiveAction {
+    if has_prior_errors {
+        notify_compiler_intercepted_message_delivered(&compiler, delivery, vec!());
+    } else {
+        interpreter.delivery_requests.push(delivery_request(delivery, compiler));
+        service_execution_requests(interpreter);
+    }
+    continue_listening_after_receive()
+}
+
+fn handle_circuit_break_all_executions(interpreter: &mut InterpreterActor, reason: ExecutionCircuitBreakReason) -> AfterReceiveAction {
+    for request in interpreter.execution_requests.drain(..).collect::<ExecutionRequests>() {
+        release_execution_request(interpreter, request, &reason);
+    }
+    for request in interpreter.delivery_requests.drain(..).collect::<DeliveryRequests>() {
+        release_delivery_request(interpreter, request, &reason);
+    }
+    continue_listening_after_receive()
+}
+
+fn service_execution_requests(interpreter: &mut InterpreterActor) {
+    let mut index = 0;
+
+    while index < interpreter.execution_requests.len() {
+        if find_unavailable_symbol(&interpreter.execution_requests[index].code, &interpreter.library).is_none() {
+            let request = interpreter.execution_requests.remove(index);
+            execute_request(interpreter, request);
+        } else {
+            index += 1;
+        }
+    }
+
+    index = 0;
+
+    while index < interpreter.delivery_requests.len() {
+        if find_unavailable_symbol(&interpreter.delivery_requests[index].delivery.code, &interpreter.library).is_none() {
+            let request = interpreter.delivery_requests.remove(index);
+            deliver_request(interpreter, request);
+        } else {
+            index += 1;
+        }
+    }
+}
+
+fn execute_request(interpreter: &InterpreterActor, request: ExecutionRequest) {
+    let ExecutionRequest { mut unit, mut code, respond_to } = request;
+    let mut requests = vec!();
+
+    if is_assert_unit(&unit) {
+        match evaluate_assertion(&unit.tree, &interpreter.library) {
+            Ok(()) => clear_byte_code(&mut code),
+            Err(error) => fail_execution(interpreter, &respond_to, &unit, &mut code, interpretation_error(error))
+        }
+    } else if is_insert_unit(&unit) {
+        match interpret_byte_code_returning_string(&code, &interpreter.library) {
+            Ok((source, compiler_requests)) => {
+                clear_byte_code(&mut code);
+                set_insert_directive_source(&mut unit.tree, source);
+                requests = compiler_requests;
+            },
+            Err(error) => fail_execution(interpreter, &respond_to, &unit, &mut code, interpretation_error(error))
+        }
+    } else {
+        match interpret_byte_code(&code, &interpreter.library) {
+            Ok((result, compiler_requests)) => {
+                match try_get_run_directive_constant(unit.tree.item_ref()) {
+                    Some((name, constant_type)) => bake_constant_execution_result(&mut code, name, constant_type, result),
+                    None => bake_execution_result(&mut code, result)
+                }
+                requests = compiler_requests;
+            },
+            Err(error) => fail_execution(interpreter, &respond_to, &unit, &mut code, interpretation_error(error))
+        }
+    }
+
+    notify_compiler_byte_code_executed(&respond_to, unit, code, requests);
+}
+
+fn deliver_request(interpreter: &InterpreterActor, request: DeliveryRequest) {
+    let DeliveryRequest { delivery, respond_to } = request;
+
+    match interpret_byte_code(&delivery.code, &interpreter.library) {
+        Ok((_, requests)) => notify_compiler_intercepted_message_delivered(&respond_to, delivery, requests),
+        Err(error) => {
+            fail_delivery(interpreter, &respond_to, &delivery.handler, interpretation_error(error));
+            notify_compiler_intercepted_message_delivered(&respond_to, delivery, vec!());
+        }
+    }
+}
+
+fn release_execution_request(interpreter: &InterpreterActor, request: ExecutionRequest, reason: &ExecutionCircuitBreakReason) {
+    let ExecutionRequest { unit, mut code, respond_to } = request;
+
+    match reason {
+        ExecutionCircuitBreakReason::CompilationError(..) => clear_byte_code(&mut code),
+        ExecutionCircuitBreakReason::SymbolsNotFound => {
+            let name = find_unavailable_symbol(&code, &interpreter.library).unwrap_or(empty_string());
+            fail_execution(interpreter, &respond_to, &unit, &mut code, interpretation_error(symbol_not_found_error(name)));
+        }
+    };
+
+    notify_compiler_byte_code_executed(&respond_to, unit, code, vec!());
+}
+
+fn release_delivery_request(interpreter: &InterpreterActor, request: DeliveryRequest, reason: &ExecutionCircuitBreakReason) {
+    let DeliveryRequest { delivery, respond_to } = request;
+
+    if let ExecutionCircuitBreakReason::SymbolsNotFound = reason {
+        let name = find_unavailable_symbol(&delivery.code, &interpreter.library).unwrap_or(empty_string());
+        fail_delivery(interpreter, &respond_to, &delivery.handler, interpretation_error(symbol_not_found_error(name)));
+    }
+
+    notify_compiler_intercepted_message_delivered(&respond_to, delivery, vec!());
+}
+
+fn fail_execution(
+    interpreter: &InterpreterActor,
+    compiler: &CompilationActorHandle,
+    unit: &CompilationUnit,
+    code: &mut IntermediateRepresentation,
+    error: CompilationErrorItem
+) {
+    let mut errors = create_compilation_errors(unit.filename.clone());
+    add_compilation_error(&mut errors, compilation_error(error, unit.tree.position));
+    report_errors(&interpreter.error_reporter, compiler.clone(), errors);
+    clear_byte_code(code);
+}
+
+fn fail_delivery(interpreter: &InterpreterActor, compiler: &CompilationActorHandle, handler: &InterceptHandler, error: CompilationErrorItem) {
+    let mut errors = create_compilation_errors(handler.filename.clone());
+    add_compilation_error(&mut errors, compilation_error(error, handler.position));
+    report_errors(&interpreter.error_reporter, compiler.clone(), errors);
+}
+
+fn bake_execution_result(code: &mut IntermediateRepresentation, result: u64) {
+    clear_byte_code(code);
+    let result_pointer = add_data_item(&mut code.data, quad_word_data_item(result));
+    add_symbol(&mut code.symbols, data_section_item(code.top_level_symbol.clone(), *result_pointer));
+}
+
+fn bake_constant_execution_result(code: &mut IntermediateRepresentation, name: &str, constant_type: &ResolvableType, result: u64) {
+    clear_byte_code(code);
+    let symbol = match try_get_type_size_from_resolvable_type(constant_type) {
+        Some(4) => absolute_external_32(string(name), result as u32),
+        _ => absolute_external_64(string(name), result)
+    };
+    add_symbol(&mut code.symbols, symbol);
+}
+
+fn clear_byte_code(code: &mut IntermediateRepresentation) {
+    code.byte_code.clear();
+    code.symbols.clear();
+    code.data = ByteCodeData::default();
+}
+
+fn notify_compiler_byte_code_executed(compiler: &CompilationActorHandle, unit: CompilationUnit, code: IntermediateRepresentation, requests: CompilerRequests) {
+    send_message_to_actor(compiler, create_byte_code_executed_event(unit, code, requests));
+}
+
+fn notify_compiler_intercepted_message_delivered(compiler: &CompilationActorHandle, delivery: InterceptedMessageDelivery, requests: CompilerRequests) {
+    let InterceptedMessageDelivery { id, handler, message, .. } = delivery;
+    send_message_to_actor(compiler, create_intercepted_message_delivered_event(id, handler, message, requests));
+}
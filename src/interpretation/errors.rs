@@ -0,0 +1,80 @@
+use crate::errors::*;
+use crate::parsing::*;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum InterpretationError {
+    SymbolNotFound(String),
+    UnsupportedInstruction,
+    UnimplementedInstruction,
+    InvalidMemoryAccess(u64),
+    StackOverflow,
+    RegisterNotAvailable,
+    UnsupportedConstantExpression,
+    AssertionFailed { message: String, operands: Vec<i64> },
+    UnknownInterceptedMessageKind(u64),
+    NotAProcedure(u64),
+    UnsupportedFormatSpecifier(char),
+    UnsupportedSystemCall(u64),
+    LabelNotFound(u32),
+    Trap
+}
+
+pub fn symbol_not_found_error(name: String) -> InterpretationError {
+    InterpretationError::SymbolNotFound(name)
+}
+
+pub fn interpreter_unsupported_instruction_error() -> InterpretationError {
+    InterpretationError::UnsupportedInstruction
+}
+
+pub fn interpreter_unimplemented_instruction_error() -> InterpretationError {
+    InterpretationError::UnimplementedInstruction
+}
+
+pub fn invalid_memory_access_error(address: u64) -> InterpretationError {
+    InterpretationError::InvalidMemoryAccess(address)
+}
+
+pub fn stack_overflow_error() -> InterpretationError {
+    InterpretationError::StackOverflow
+}
+
+pub fn interpreter_register_not_available_error() -> InterpretationError {
+    InterpretationError::RegisterNotAvailable
+}
+
+pub fn unsupported_constant_expression_error() -> InterpretationError {
+    InterpretationError::UnsupportedConstantExpression
+}
+
+pub fn assertion_failed_error(message: String, operands: Vec<i64>) -> InterpretationError {
+    InterpretationError::AssertionFailed { message, operands }
+}
+
+pub fn unknown_intercepted_message_kind_error(kind: u64) -> InterpretationError {
+    InterpretationError::UnknownInterceptedMessageKind(kind)
+}
+
+pub fn not_a_procedure_error(address: u64) -> InterpretationError {
+    InterpretationError::NotAProcedure(address)
+}
+
+pub fn unsupported_format_specifier_error(specifier: char) -> InterpretationError {
+    InterpretationError::UnsupportedFormatSpecifier(specifier)
+}
+
+pub fn unsupported_system_call_error(number: u64) -> InterpretationError {
+    InterpretationError::UnsupportedSystemCall(number)
+}
+
+pub fn interpreter_label_not_found_error(label: u32) -> InterpretationError {
+    InterpretationError::LabelNotFound(label)
+}
+
+pub fn trap_error() -> InterpretationError {
+    InterpretationError::Trap
+}
+
+pub fn add_interpretation_error(errors: &mut CompilationErrors, error: InterpretationError, position: SourceFilePosition) {
+    add_compilation_error(errors, compilation_error(interpretation_error(error), position));
+}
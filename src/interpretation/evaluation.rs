@@ -0,0 +1,97 @@
+use crate::{
+    parsing::*,
+    intermediate_representation::*,
+    interpretation::*,
+    strings::*,
+    utilities::*
+};
+
+pub fn evaluate_assertion(assertion: &AbstractSyntaxNode, library: &IntermediateRepresentations) -> InterpretationResult<()> {
+    match assertion.item_ref() {
+        AbstractSyntaxNodeItem::Assert { expr, message } => evaluate_assertion_expression(expr, &get_assertion_message(message), library),
+        _ => Err(unsupported_constant_expression_error())
+    }
+}
+
+fn evaluate_assertion_expression(expr: &AbstractSyntaxNode, message: &str, library: &IntermediateRepresentations) -> InterpretationResult<()> {
+    if let AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, .. } = expr.item_ref() {
+        if let AbstractSyntaxNodeItem::Operator(op) = op.item_ref() {
+            if is_comparison_operator(op) {
+                let lhs = evaluate_constant_expression(lhs, library)?;
+                let rhs = evaluate_constant_expression(rhs, library)?;
+                if !compare_constants(op, lhs, rhs) {
+                    return Err(assertion_failed_error(string(message), vec!(lhs, rhs)));
+                }
+                return Ok(());
+            }
+        }
+    }
+
+    let value = evaluate_constant_expression(expr, library)?;
+    if value == 0 {
+        return Err(assertion_failed_error(string(message), vec!(value)));
+    }
+    Ok(())
+}
+
+fn get_assertion_message(message: &AbstractSyntaxNode) -> String {
+    match message.item_ref() {
+        AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(message))) => byte_string_to_string(message),
+        AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Resolved(ResolvedLiteral::String(message))) => byte_string_to_string(message),
+        _ => string("")
+    }
+}
+
+pub fn evaluate_constant_expression(expr: &AbstractSyntaxNode, library: &IntermediateRepresentations) -> InterpretationResult<i64> {
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Resolved(literal)) => evaluate_constant_literal(literal),
+        AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Global } =>
+            find_library_constant_value(library, name)
+                .map(|value| value as i64)
+                .ok_or(symbol_not_found_error(string(name))),
+        AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, .. } => {
+            let lhs = evaluate_constant_expression(lhs, library)?;
+            let rhs = evaluate_constant_expression(rhs, library)?;
+            match op.item_ref() {
+                AbstractSyntaxNodeItem::Operator(op) => Ok(evaluate_constant_operation(op, lhs, rhs)),
+                _ => Err(unsupported_constant_expression_error())
+            }
+        },
+        _ => Err(unsupported_constant_expression_error())
+    }
+}
+
+fn evaluate_constant_literal(literal: &ResolvedLiteral) -> InterpretationResult<i64> {
+    match literal {
+        ResolvedLiteral::UnsignedInt8(value) => Ok(*value as i64),
+        ResolvedLiteral::SignedInt8(value) => Ok(*value as i64),
+        ResolvedLiteral::UnsignedInt16(value) => Ok(*value as i64),
+        ResolvedLiteral::SignedInt16(value) => Ok(*value as i64),
+        ResolvedLiteral::UnsignedInt32(value) => Ok(*value as i64),
+        ResolvedLiteral::SignedInt32(value) => Ok(*value as i64),
+        ResolvedLiteral::UnsignedInt64(value) => Ok(*value as i64),
+        ResolvedLiteral::SignedInt64(value) => Ok(*value),
+        _ => Err(unsupported_constant_expression_error())
+    }
+}
+
+fn evaluate_constant_operation(op: &Operator, lhs: i64, rhs: i64) -> i64 {
+    match op {
+        Operator::Add => lhs.wrapping_add(rhs),
+        Operator::Subtract => lhs.wrapping_sub(rhs),
+        Operator::Multiply => lhs.wrapping_mul(rhs),
+        comparison => compare_constants(comparison, lhs, rhs) as i64
+    }
+}
+
+fn compare_constants(op: &Operator, lhs: i64, rhs: i64) -> bool {
+    match op {
+        Operator::Equal => lhs == rhs,
+        Operator::NotEqual => lhs != rhs,
+        Operator::LessThan => lhs < rhs,
+        Operator::LessThanOrEqual => lhs <= rhs,
+        Operator::GreaterThan => lhs > rhs,
+        Operator::GreaterThanOrEqual => lhs >= rhs,
+        _ => lhs != 0
+    }
+}
@@ -0,0 +1,54 @@
+use std::io::*;
+
+use crate::interpretation::*;
+
+pub type ForeignProcedure = fn(&mut Interpreter) -> InterpretationResult<u64>;
+
+const STD_OUTPUT_HANDLE: u64 = -11i64 as u64;
+const STD_ERROR_HANDLE: u64 = -12i64 as u64;
+
+pub fn find_foreign_procedure(name: &str) -> Option<ForeignProcedure> {
+    match name {
+        "GetStdHandle" => Some(get_std_handle),
+        "WriteFile" => Some(write_file),
+        "compiler_intercept" => Some(compiler_intercept),
+        "add_build_string" => Some(add_build_string),
+        "malloc" => Some(malloc),
+        "free" => Some(free),
+        "memcpy" => Some(memcpy),
+        "memset" => Some(memset),
+        "strcmp" => Some(strcmp),
+        "_scprintf" => Some(scprintf),
+        "_snprintf" => Some(snprintf),
+        _ => None
+    }
+}
+
+fn get_std_handle(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let handle_type = get_call_arg_value(interpreter, 0)?;
+    Ok(handle_type as u32 as i32 as u64)
+}
+
+fn write_file(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let handle = get_call_arg_value(interpreter, 0)?;
+    let to_write = get_call_arg_value(interpreter, 1)?;
+    let bytes_to_write = get_call_arg_value(interpreter, 2)? as u32 as u64;
+    let bytes_written = get_call_arg_value(interpreter, 3)?;
+
+    let bytes = read_memory_bytes(interpreter, to_write, bytes_to_write)?.to_vec();
+    
+    let written = match handle {
+        STD_OUTPUT_HANDLE => {
+            add_compiler_request(interpreter, write_standard_output_compiler_request(bytes));
+            true
+        },
+        STD_ERROR_HANDLE => stderr().write_all(&bytes).is_ok(),
+        _ => false
+    };
+
+    if bytes_written != 0 {
+        write_memory(interpreter, bytes_written, 4, if written { bytes_to_write } else { 0 })?;
+    }
+
+    Ok(written as u64)
+}
@@ -0,0 +1,840 @@
+use std::{collections::HashMap, cmp::Ordering, fs::File};
+
+use crate::{
+    intermediate_representation::*,
+    interpretation::*,
+    strings::*,
+    types::*
+};
+
+pub type InterpretationResult<T> = Result<T, InterpretationError>;
+
+const NULL_GUARD_SIZE: u64 = 0x1000;
+const STACK_SIZE: u64 = 0x10000;
+const RETURN_ADDRESS_MARKER: u64 = 0;
+
+const REGISTER_COUNT: usize = 23;
+const STACK_POINTER_SLOT: usize = 11;
+const BASE_POINTER_SLOT: usize = 12;
+const FLOAT_REGISTER_SLOT: usize = 13;
+const FLOAT_STANDARD_SLOT: usize = 17;
+const SOURCE_INDEX_SLOT: usize = 21;
+const DESTINATION_INDEX_SLOT: usize = 22;
+
+const FIRST_OPEN_FILE_DESCRIPTOR: u64 = 3;
+
+const FIRST_PROCEDURE_ADDRESS: u64 = 0x7000_0000_0000_0000;
+
+struct InterpreterFrame {
+    program: usize,
+    instruction: usize
+}
+
+fn interpreter_frame(program: usize) -> InterpreterFrame {
+    InterpreterFrame {
+        program,
+        instruction: 0
+    }
+}
+
+pub struct Interpreter<'a> {
+    programs: Vec<&'a IntermediateRepresentation>,
+    calling_convention: CallingConvention,
+    memory: Vec<u8>,
+    registers: [u64; REGISTER_COUNT],
+    data_section_addresses: HashMap<usize, u64>,
+    read_only_data_addresses: HashMap<usize, u64>,
+    bss_section_addresses: HashMap<usize, u64>,
+    frames: Vec<InterpreterFrame>,
+    comparison: Option<Ordering>,
+    signed_comparison: Option<Ordering>,
+    compiler_requests: CompilerRequests,
+    open_files: HashMap<u64, File>,
+    procedure_addresses: Vec<String>
+}
+
+fn create_interpreter<'a>(entry: &'a IntermediateRepresentation, library: &'a IntermediateRepresentations) -> Interpreter<'a> {
+    let mut programs = vec!(entry);
+    programs.extend(library.iter());
+
+    let mut interpreter = Interpreter {
+        programs,
+        calling_convention: entry.calling_convention,
+        memory: vec!(0; (NULL_GUARD_SIZE + STACK_SIZE) as usize),
+        registers: [0; REGISTER_COUNT],
+        data_section_addresses: HashMap::default(),
+        read_only_data_addresses: HashMap::default(),
+        bss_section_addresses: HashMap::default(),
+        frames: vec!(),
+        comparison: None,
+        signed_comparison: None,
+        compiler_requests: vec!(),
+        open_files: HashMap::default(),
+        procedure_addresses: vec!()
+    };
+
+    interpreter.registers[STACK_POINTER_SLOT] = NULL_GUARD_SIZE + STACK_SIZE;
+    interpreter
+}
+
+pub fn interpret_byte_code(entry: &IntermediateRepresentation, library: &IntermediateRepresentations) -> InterpretationResult<(u64, CompilerRequests)> {
+    let interpreter = run_byte_code(entry, library)?;
+    let result = get_register_value(&interpreter, call_return_arg_register(0))?;
+    Ok((result, interpreter.compiler_requests))
+}
+
+pub fn interpret_byte_code_returning_string(entry: &IntermediateRepresentation, library: &IntermediateRepresentations) -> InterpretationResult<(ByteString, CompilerRequests)> {
+    let interpreter = run_byte_code(entry, library)?;
+    let string_address = get_register_value(&interpreter, call_return_arg_register(0))?;
+    let result = read_string(&interpreter, string_address)?;
+    Ok((result, interpreter.compiler_requests))
+}
+
+pub fn add_compiler_request(interpreter: &mut Interpreter, request: CompilerRequest) {
+    interpreter.compiler_requests.push(request);
+}
+
+fn run_byte_code<'a>(entry: &'a IntermediateRepresentation, library: &'a IntermediateRepresentations) -> InterpretationResult<Interpreter<'a>> {
+    let mut interpreter = create_interpreter(entry, library);
+    call_program(&mut interpreter, 0)?;
+
+    while !interpreter.frames.is_empty() {
+        let instruction = fetch_next_instruction(&mut interpreter);
+        execute_instruction(&mut interpreter, instruction)?;
+    }
+
+    Ok(interpreter)
+}
+
+pub fn find_unavailable_symbol(entry: &IntermediateRepresentation, library: &IntermediateRepresentations) -> Option<String> {
+    let interpreter = create_interpreter(entry, library);
+    let mut visited = vec!(0);
+    let mut to_visit = vec!(0);
+
+    while let Some(program) = to_visit.pop() {
+        for symbol in &interpreter.programs[program].symbols {
+            if let ByteCodeSymbol::ForeignExternal { name } = symbol {
+                let referenced_program = find_procedure_program(&interpreter, name)
+                    .or_else(|| find_data_symbol(&interpreter, name).map(|(program, _)| program))
+                    .or_else(|| find_bss_symbol(&interpreter, name).map(|(program, _)| program));
+
+                if let Some(called_program) = referenced_program {
+                    if !visited.contains(&called_program) {
+                        visited.push(called_program);
+                        to_visit.push(called_program);
+                    }
+                } else if find_constant_value(&interpreter, name).is_none() && find_foreign_procedure(name).is_none() {
+                    return Some(name.clone());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn fetch_next_instruction(interpreter: &mut Interpreter) -> ByteCodeInstruction {
+    let frame = interpreter.frames.last_mut().unwrap();
+    let byte_code = &interpreter.programs[frame.program].byte_code;
+
+    if let Some(instruction) = byte_code.get(frame.instruction) {
+        frame.instruction += 1;
+        return *instruction;
+    }
+
+    ret_instruction()
+}
+
+fn execute_instruction(interpreter: &mut Interpreter, instruction: ByteCodeInstruction) -> InterpretationResult<()> {
+    match instruction {
+        ByteCodeInstruction::Unsupported => return Err(interpreter_unsupported_instruction_error()),
+        ByteCodeInstruction::Unimplemented => return Err(interpreter_unimplemented_instruction_error()),
+        ByteCodeInstruction::CallToSymbol(symbol_index) =>
+            call_symbol(interpreter, symbol_index)?,
+        ByteCodeInstruction::AddValueToReg8 { value, to } => {
+            let result = get_register_value(interpreter, to)?.wrapping_add(value as i8 as u64);
+            set_register_value(interpreter, to, result)?;
+        },
+        ByteCodeInstruction::SubValueFromReg8 { value, from } => {
+            let result = get_register_value(interpreter, from)?.wrapping_sub(value as i8 as u64);
+            set_register_value(interpreter, from, result)?;
+        },
+        ByteCodeInstruction::AddValueToReg32 { value, to } => {
+            let result = get_register_value(interpreter, to)?.wrapping_add(value as i32 as u64);
+            set_register_value(interpreter, to, result)?;
+        },
+        ByteCodeInstruction::SubValueFromReg32 { value, from } => {
+            let result = get_register_value(interpreter, from)?.wrapping_sub(value as i32 as u64);
+            set_register_value(interpreter, from, result)?;
+        },
+        ByteCodeInstruction::MoveSymbolToReg32 { symbol_index, to } => {
+            let value = get_symbol_value(interpreter, symbol_index)?;
+            set_register_value(interpreter, to, value as u32 as u64)?;
+        },
+        ByteCodeInstruction::MoveValueToReg32 { value, to } =>
+            set_register_value(interpreter, to, value as u64)?,
+        ByteCodeInstruction::MoveValueToReg64 { value, to } =>
+            set_register_value(interpreter, to, value)?,
+        ByteCodeInstruction::MoveRegToReg64 { from, to } => {
+            let value = get_register_value(interpreter, from)?;
+            set_register_value(interpreter, to, value)?;
+        },
+        ByteCodeInstruction::MoveValueToRegPlusOffset32 { value, to, offset } => {
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 4, value as u64)?;
+        },
+        ByteCodeInstruction::MoveValueToRegPlusOffset64 { value, to, offset } => {
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 8, value)?;
+        },
+        ByteCodeInstruction::MoveRegToRegPlusOffset32 { from, to, offset } => {
+            let value = get_register_value(interpreter, from)?;
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 4, value)?;
+        },
+        ByteCodeInstruction::MoveRegToRegPlusOffset64 { from, to, offset } => {
+            let value = get_register_value(interpreter, from)?;
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 8, value)?;
+        },
+        ByteCodeInstruction::MoveRegPlusOffsetToReg32 { from, offset, to } => {
+            let address = get_register_plus_offset_address(interpreter, from, offset)?;
+            let value = read_memory(interpreter, address, 4)?;
+            set_register_value(interpreter, to, value)?;
+        },
+        ByteCodeInstruction::MoveRegPlusOffsetToReg64 { from, offset, to } => {
+            let address = get_register_plus_offset_address(interpreter, from, offset)?;
+            let value = read_memory(interpreter, address, 8)?;
+            set_register_value(interpreter, to, value)?;
+        },
+        ByteCodeInstruction::LoadDataSectionAddressToReg64 { data_section_offset, to } => {
+            let program = interpreter.frames.last().unwrap().program;
+            let address = get_data_section_address(interpreter, program)? + *data_section_offset as u64;
+            set_register_value(interpreter, to, address)?;
+        },
+        ByteCodeInstruction::LoadReadOnlyDataAddressToReg64 { read_only_data_offset, to } => {
+            let program = interpreter.frames.last().unwrap().program;
+            let address = get_read_only_data_address(interpreter, program)? + *read_only_data_offset as u64;
+            set_register_value(interpreter, to, address)?;
+        },
+        ByteCodeInstruction::LoadSymbolAddressToReg64 { symbol_index, to } => {
+            let name = get_symbol_name(interpreter, symbol_index)?;
+            let address = get_symbol_address(interpreter, &name)?;
+            set_register_value(interpreter, to, address)?;
+        },
+        ByteCodeInstruction::LoadAddressInRegPlusOffsetToReg64 { from, offset, to } => {
+            let address = get_register_plus_offset_address(interpreter, from, offset)?;
+            set_register_value(interpreter, to, address)?;
+        },
+        ByteCodeInstruction::PushReg64(register) => {
+            let value = get_register_value(interpreter, register)?;
+            push(interpreter, value)?;
+        },
+        ByteCodeInstruction::PopReg64(register) => {
+            let value = pop(interpreter)?;
+            set_register_value(interpreter, register, value)?;
+        },
+        ByteCodeInstruction::ZeroReg64(register) =>
+            set_register_value(interpreter, register, 0)?,
+        ByteCodeInstruction::MoveRegToRegPlusOffset8 { from, to, offset } => {
+            let value = get_register_value(interpreter, from)?;
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 1, value)?;
+        },
+        ByteCodeInstruction::MoveDataSectionToFloatReg32 { data_section_offset, to } =>
+            move_data_section_to_register(interpreter, data_section_offset, 4, to)?,
+        ByteCodeInstruction::MoveDataSectionToFloatReg64 { data_section_offset, to } =>
+            move_data_section_to_register(interpreter, data_section_offset, 8, to)?,
+        ByteCodeInstruction::MoveRegPlusOffsetToFloatReg32 { from, offset, to } => {
+            let address = get_register_plus_offset_address(interpreter, from, offset)?;
+            let value = read_memory(interpreter, address, 4)?;
+            set_register_value(interpreter, to, value)?;
+        },
+        ByteCodeInstruction::MoveRegPlusOffsetToFloatReg64 { from, offset, to } => {
+            let address = get_register_plus_offset_address(interpreter, from, offset)?;
+            let value = read_memory(interpreter, address, 8)?;
+            set_register_value(interpreter, to, value)?;
+        },
+        ByteCodeInstruction::MoveFloatRegToRegPlusOffset32 { from, to, offset } => {
+            let value = get_register_value(interpreter, from)?;
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 4, value)?;
+        },
+        ByteCodeInstruction::MoveFloatRegToRegPlusOffset64 { from, to, offset } => {
+            let value = get_register_value(interpreter, from)?;
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 8, value)?;
+        },
+        ByteCodeInstruction::AddFloatRegToFloatReg32 { from, to } =>
+            execute_float_operation(interpreter, from, to, false, |to, from| to + from)?,
+        ByteCodeInstruction::AddFloatRegToFloatReg64 { from, to } =>
+            execute_float_operation(interpreter, from, to, true, |to, from| to + from)?,
+        ByteCodeInstruction::SubFloatRegFromFloatReg32 { from, to } =>
+            execute_float_operation(interpreter, from, to, false, |to, from| to - from)?,
+        ByteCodeInstruction::SubFloatRegFromFloatReg64 { from, to } =>
+            execute_float_operation(interpreter, from, to, true, |to, from| to - from)?,
+        ByteCodeInstruction::MulFloatRegToFloatReg32 { from, to } =>
+            execute_float_operation(interpreter, from, to, false, |to, from| to * from)?,
+        ByteCodeInstruction::MulFloatRegToFloatReg64 { from, to } =>
+            execute_float_operation(interpreter, from, to, true, |to, from| to * from)?,
+        ByteCodeInstruction::CompareFloatRegToFloatReg32 { lhs, rhs } => {
+            let lhs = get_float_register_value(interpreter, lhs, false)?;
+            let rhs = get_float_register_value(interpreter, rhs, false)?;
+            interpreter.comparison = lhs.partial_cmp(&rhs);
+            interpreter.signed_comparison = None;
+        },
+        ByteCodeInstruction::CompareFloatRegToFloatReg64 { lhs, rhs } => {
+            let lhs = get_float_register_value(interpreter, lhs, true)?;
+            let rhs = get_float_register_value(interpreter, rhs, true)?;
+            interpreter.comparison = lhs.partial_cmp(&rhs);
+            interpreter.signed_comparison = None;
+        },
+        ByteCodeInstruction::SetReg8OnCondition { condition, to } => {
+            let value = is_condition_met(interpreter.comparison, interpreter.signed_comparison, condition) as u64;
+            set_register_value(interpreter, to, value)?;
+        },
+        ByteCodeInstruction::ConvertIntReg32ToFloatReg32 { from, to } => {
+            let value = get_register_value(interpreter, from)? as u32 as i32;
+            set_float_register_value(interpreter, to, false, value as f64)?;
+        },
+        ByteCodeInstruction::ConvertIntReg32ToFloatReg64 { from, to } => {
+            let value = get_register_value(interpreter, from)? as u32 as i32;
+            set_float_register_value(interpreter, to, true, value as f64)?;
+        },
+        ByteCodeInstruction::ConvertIntReg64ToFloatReg32 { from, to } => {
+            let value = get_register_value(interpreter, from)? as i64;
+            set_float_register_value(interpreter, to, false, value as f64)?;
+        },
+        ByteCodeInstruction::ConvertIntReg64ToFloatReg64 { from, to } => {
+            let value = get_register_value(interpreter, from)? as i64;
+            set_float_register_value(interpreter, to, true, value as f64)?;
+        },
+        ByteCodeInstruction::ConvertFloatReg32ToIntReg32 { from, to } => {
+            let value = get_float_register_value(interpreter, from, false)?;
+            set_register_value(interpreter, to, truncate_float_to_int_32(value))?;
+        },
+        ByteCodeInstruction::ConvertFloatReg64ToIntReg32 { from, to } => {
+            let value = get_float_register_value(interpreter, from, true)?;
+            set_register_value(interpreter, to, truncate_float_to_int_32(value))?;
+        },
+        ByteCodeInstruction::ConvertFloatReg32ToIntReg64 { from, to } => {
+            let value = get_float_register_value(interpreter, from, false)?;
+            set_register_value(interpreter, to, truncate_float_to_int_64(value))?;
+        },
+        ByteCodeInstruction::ConvertFloatReg64ToIntReg64 { from, to } => {
+            let value = get_float_register_value(interpreter, from, true)?;
+            set_register_value(interpreter, to, truncate_float_to_int_64(value))?;
+        },
+        ByteCodeInstruction::MoveValueToRegPlusOffset8 { value, to, offset } => {
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 1, value as u64)?;
+        },
+        ByteCodeInstruction::MoveValueToRegPlusOffset16 { value, to, offset } => {
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 2, value as u64)?;
+        },
+        ByteCodeInstruction::MoveRegToRegPlusOffset16 { from, to, offset } => {
+            let value = get_register_value(interpreter, from)?;
+            let address = get_register_plus_offset_address(interpreter, to, offset)?;
+            write_memory(interpreter, address, 2, value)?;
+        },
+        ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend8 { from, offset, to } =>
+            move_reg_plus_offset_to_reg_extended(interpreter, from, offset, to, 1, true)?,
+        ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend16 { from, offset, to } =>
+            move_reg_plus_offset_to_reg_extended(interpreter, from, offset, to, 2, true)?,
+        ByteCodeInstruction::MoveRegPlusOffsetToRegSignExtend32 { from, offset, to } =>
+            move_reg_plus_offset_to_reg_extended(interpreter, from, offset, to, 4, true)?,
+        ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend8 { from, offset, to } =>
+            move_reg_plus_offset_to_reg_extended(interpreter, from, offset, to, 1, false)?,
+        ByteCodeInstruction::MoveRegPlusOffsetToRegZeroExtend16 { from, offset, to } =>
+            move_reg_plus_offset_to_reg_extended(interpreter, from, offset, to, 2, false)?,
+        ByteCodeInstruction::SignExtendReg8ToReg64 { from, to } =>
+            extend_reg_to_reg(interpreter, from, to, 1, true)?,
+        ByteCodeInstruction::SignExtendReg16ToReg64 { from, to } =>
+            extend_reg_to_reg(interpreter, from, to, 2, true)?,
+        ByteCodeInstruction::SignExtendReg32ToReg64 { from, to } =>
+            extend_reg_to_reg(interpreter, from, to, 4, true)?,
+        ByteCodeInstruction::ZeroExtendReg8ToReg64 { from, to } =>
+            extend_reg_to_reg(interpreter, from, to, 1, false)?,
+        ByteCodeInstruction::ZeroExtendReg16ToReg64 { from, to } =>
+            extend_reg_to_reg(interpreter, from, to, 2, false)?,
+        ByteCodeInstruction::ZeroExtendReg32ToReg64 { from, to } =>
+            extend_reg_to_reg(interpreter, from, to, 4, false)?,
+        ByteCodeInstruction::CompareRegToReg64 { lhs, rhs } => {
+            let lhs = get_register_value(interpreter, lhs)?;
+            let rhs = get_register_value(interpreter, rhs)?;
+            interpreter.comparison = Some(lhs.cmp(&rhs));
+            interpreter.signed_comparison = Some((lhs as i64).cmp(&(rhs as i64)));
+        },
+        ByteCodeInstruction::AddRegToReg64 { from, to } => {
+            let result = get_register_value(interpreter, to)?.wrapping_add(get_register_value(interpreter, from)?);
+            set_register_value(interpreter, to, result)?;
+        },
+        ByteCodeInstruction::SubRegFromReg64 { from, to } => {
+            let result = get_register_value(interpreter, to)?.wrapping_sub(get_register_value(interpreter, from)?);
+            set_register_value(interpreter, to, result)?;
+        },
+        ByteCodeInstruction::MulRegToReg64 { from, to } => {
+            let result = get_register_value(interpreter, to)?.wrapping_mul(get_register_value(interpreter, from)?);
+            set_register_value(interpreter, to, result)?;
+        },
+        ByteCodeInstruction::TrapOnCondition(condition) => {
+            if is_condition_met(interpreter.comparison, interpreter.signed_comparison, condition) {
+                return Err(trap_error());
+            }
+        },
+        ByteCodeInstruction::Label(_) => {},
+        ByteCodeInstruction::Jump(label) =>
+            jump_to_label(interpreter, label)?,
+        ByteCodeInstruction::JumpOnCondition { condition, label } => {
+            if is_condition_met(interpreter.comparison, interpreter.signed_comparison, condition) {
+                jump_to_label(interpreter, label)?;
+            }
+        },
+        ByteCodeInstruction::SystemCall => {
+            let result = execute_system_call(interpreter)?;
+            set_register_value(interpreter, standard_register(0), result)?;
+        },
+        ByteCodeInstruction::Return => {
+            pop(interpreter)?;
+            interpreter.frames.pop();
+        }
+    }
+    Ok(())
+}
+
+fn jump_to_label(interpreter: &mut Interpreter, label: ByteCodeLabel) -> InterpretationResult<()> {
+    let frame = interpreter.frames.last_mut().unwrap();
+    let byte_code = &interpreter.programs[frame.program].byte_code;
+
+    if let Some(index) = byte_code.iter().position(|instruction| *instruction == label_instruction(label)) {
+        frame.instruction = index + 1;
+        return Ok(());
+    }
+
+    Err(interpreter_label_not_found_error(*label))
+}
+
+fn move_data_section_to_register(interpreter: &mut Interpreter, data_section_offset: DataSectionOffset, size: u64, to: ByteCodeRegister) -> InterpretationResult<()> {
+    let program = interpreter.frames.last().unwrap().program;
+    let address = get_data_section_address(interpreter, program)? + *data_section_offset as u64;
+    let value = read_memory(interpreter, address, size)?;
+    set_register_value(interpreter, to, value)
+}
+
+fn get_float_register_value(interpreter: &Interpreter, register: ByteCodeRegister, is_double: bool) -> InterpretationResult<f64> {
+    let bits = get_register_value(interpreter, register)?;
+    if is_double {
+        return Ok(f64::from_bits(bits));
+    }
+    Ok(f32::from_bits(bits as u32) as f64)
+}
+
+fn set_float_register_value(interpreter: &mut Interpreter, register: ByteCodeRegister, is_double: bool, value: f64) -> InterpretationResult<()> {
+    if is_double {
+        return set_register_value(interpreter, register, value.to_bits());
+    }
+    set_register_value(interpreter, register, (value as f32).to_bits() as u64)
+}
+
+fn execute_float_operation(
+    interpreter: &mut Interpreter,
+    from: ByteCodeRegister,
+    to: ByteCodeRegister,
+    is_double: bool,
+    operation: fn(f64, f64) -> f64
+) -> InterpretationResult<()> {
+    let from_value = get_float_register_value(interpreter, from, is_double)?;
+    let to_value = get_float_register_value(interpreter, to, is_double)?;
+    set_float_register_value(interpreter, to, is_double, operation(to_value, from_value))
+}
+
+fn move_reg_plus_offset_to_reg_extended(
+    interpreter: &mut Interpreter,
+    from: ByteCodeRegister,
+    offset: AddressOffset,
+    to: ByteCodeRegister,
+    size: u64,
+    is_signed: bool
+) -> InterpretationResult<()> {
+    let address = get_register_plus_offset_address(interpreter, from, offset)?;
+    let value = read_memory(interpreter, address, size)?;
+    set_register_value(interpreter, to, extend_value(value, size, is_signed))
+}
+
+fn extend_reg_to_reg(interpreter: &mut Interpreter, from: ByteCodeRegister, to: ByteCodeRegister, size: u64, is_signed: bool) -> InterpretationResult<()> {
+    let value = get_register_value(interpreter, from)?;
+    set_register_value(interpreter, to, extend_value(value, size, is_signed))
+}
+
+fn extend_value(value: u64, size: u64, is_signed: bool) -> u64 {
+    let unused_bits = 64 - size * 8;
+    if is_signed {
+        return (((value << unused_bits) as i64) >> unused_bits) as u64;
+    }
+    (value << unused_bits) >> unused_bits
+}
+
+fn is_condition_met(comparison: Option<Ordering>, signed_comparison: Option<Ordering>, condition: ByteCodeCondition) -> bool {
+    match condition {
+        ByteCodeCondition::Less => return signed_comparison == Some(Ordering::Less),
+        ByteCodeCondition::LessOrEqual => return matches!(signed_comparison, Some(Ordering::Less | Ordering::Equal)),
+        ByteCodeCondition::Greater => return signed_comparison == Some(Ordering::Greater),
+        ByteCodeCondition::GreaterOrEqual => return matches!(signed_comparison, Some(Ordering::Greater | Ordering::Equal)),
+        _ => {}
+    }
+
+    match (comparison, condition) {
+        (None, condition) => matches!(condition, ByteCodeCondition::Equal | ByteCodeCondition::Below | ByteCodeCondition::BelowOrEqual),
+        (Some(ordering), ByteCodeCondition::Equal) => ordering == Ordering::Equal,
+        (Some(ordering), ByteCodeCondition::NotEqual) => ordering != Ordering::Equal,
+        (Some(ordering), ByteCodeCondition::Below) => ordering == Ordering::Less,
+        (Some(ordering), ByteCodeCondition::BelowOrEqual) => ordering != Ordering::Greater,
+        (Some(ordering), ByteCodeCondition::Above) => ordering == Ordering::Greater,
+        (Some(ordering), ByteCodeCondition::AboveOrEqual) => ordering != Ordering::Less,
+        (_, ByteCodeCondition::Less | ByteCodeCondition::LessOrEqual | ByteCodeCondition::Greater | ByteCodeCondition::GreaterOrEqual) => false
+    }
+}
+
+fn truncate_float_to_int_32(value: f64) -> u64 {
+    if value.is_nan() || value <= i32::MIN as f64 - 1.0 || value >= i32::MAX as f64 + 1.0 {
+        return i32::MIN as u32 as u64;
+    }
+    value as i32 as u32 as u64
+}
+
+fn truncate_float_to_int_64(value: f64) -> u64 {
+    if value.is_nan() || value < i64::MIN as f64 || value >= i64::MAX as f64 {
+        return i64::MIN as u64;
+    }
+    value as i64 as u64
+}
+
+fn call_symbol(interpreter: &mut Interpreter, symbol_index: SymbolIndex) -> InterpretationResult<()> {
+    let name = get_symbol_name(interpreter, symbol_index)?;
+
+    if let Some(program) = find_procedure_program(interpreter, &name) {
+        return call_program(interpreter, program);
+    }
+
+    if let Some(foreign_procedure) = find_foreign_procedure(&name) {
+        let result = foreign_procedure(interpreter)?;
+        return set_register_value(interpreter, call_return_arg_register(0), result);
+    }
+
+    Err(symbol_not_found_error(name))
+}
+
+fn call_program(interpreter: &mut Interpreter, program: usize) -> InterpretationResult<()> {
+    push(interpreter, RETURN_ADDRESS_MARKER)?;
+    interpreter.frames.push(interpreter_frame(program));
+    Ok(())
+}
+
+fn get_symbol_name(interpreter: &Interpreter, symbol_index: SymbolIndex) -> InterpretationResult<String> {
+    let program = interpreter.frames.last().unwrap().program;
+    get_program_symbol_name(interpreter, program, symbol_index)
+}
+
+fn get_program_symbol_name(interpreter: &Interpreter, program: usize, symbol_index: SymbolIndex) -> InterpretationResult<String> {
+    match interpreter.programs[program].symbols.get(*symbol_index as usize) {
+        Some(ByteCodeSymbol::ForeignExternal { name }) => Ok(name.clone()),
+        Some(ByteCodeSymbol::ExternalCodeLabel { name, .. }) => Ok(name.clone()),
+        Some(ByteCodeSymbol::DataSectionItem { name, .. }) => Ok(name.clone()),
+        Some(ByteCodeSymbol::ExternalDataSectionItem { name, .. }) => Ok(name.clone()),
+        Some(ByteCodeSymbol::ExternalBssSectionItem { name, .. }) => Ok(name.clone()),
+        Some(ByteCodeSymbol::AbsoluteExternal32 { name, .. }) => Ok(name.clone()),
+        Some(ByteCodeSymbol::AbsoluteExternal64 { name, .. }) => Ok(name.clone()),
+        None => Err(symbol_not_found_error(format!("#{}", *symbol_index)))
+    }
+}
+
+fn get_symbol_value(interpreter: &Interpreter, symbol_index: SymbolIndex) -> InterpretationResult<u64> {
+    let name = get_symbol_name(interpreter, symbol_index)?;
+    find_constant_value(interpreter, &name).ok_or(symbol_not_found_error(name))
+}
+
+fn find_procedure_program(interpreter: &Interpreter, name: &str) -> Option<usize> {
+    interpreter.programs
+        .iter()
+        .position(|program| program.symbols
+            .iter()
+            .any(|symbol| matches!(symbol, ByteCodeSymbol::ExternalCodeLabel { name: label, .. } if label == name))
+        )
+}
+
+fn find_data_symbol(interpreter: &Interpreter, name: &str) -> Option<(usize, u32)> {
+    for (program, code) in interpreter.programs.iter().enumerate() {
+        for symbol in &code.symbols {
+            if let ByteCodeSymbol::ExternalDataSectionItem { name: data, value } = symbol {
+                if data == name {
+                    return Some((program, *value));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_bss_symbol(interpreter: &Interpreter, name: &str) -> Option<(usize, u32)> {
+    for (program, code) in interpreter.programs.iter().enumerate() {
+        for symbol in &code.symbols {
+            if let ByteCodeSymbol::ExternalBssSectionItem { name: data, value } = symbol {
+                if data == name {
+                    return Some((program, *value));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn get_symbol_address(interpreter: &mut Interpreter, name: &str) -> InterpretationResult<u64> {
+    if find_procedure_program(interpreter, name).is_some() {
+        return Ok(get_procedure_address(interpreter, name));
+    }
+    get_data_symbol_address(interpreter, name)
+}
+
+fn get_procedure_address(interpreter: &mut Interpreter, name: &str) -> u64 {
+    let index = match interpreter.procedure_addresses.iter().position(|procedure| procedure == name) {
+        Some(index) => index,
+        None => {
+            interpreter.procedure_addresses.push(name.to_string());
+            interpreter.procedure_addresses.len() - 1
+        }
+    };
+    FIRST_PROCEDURE_ADDRESS + index as u64
+}
+
+pub fn get_procedure_name_at_address(interpreter: &Interpreter, address: u64) -> InterpretationResult<String> {
+    address
+        .checked_sub(FIRST_PROCEDURE_ADDRESS)
+        .and_then(|index| interpreter.procedure_addresses.get(index as usize))
+        .cloned()
+        .ok_or(not_a_procedure_error(address))
+}
+
+fn get_data_symbol_address(interpreter: &mut Interpreter, name: &str) -> InterpretationResult<u64> {
+    if let Some((program, value)) = find_bss_symbol(interpreter, name) {
+        return Ok(get_bss_section_address(interpreter, program) + value as u64);
+    }
+    let (program, value) = find_data_symbol(interpreter, name).ok_or(symbol_not_found_error(name.to_string()))?;
+    Ok(get_data_section_address(interpreter, program)? + value as u64)
+}
+
+fn find_constant_value(interpreter: &Interpreter, name: &str) -> Option<u64> {
+    find_constant_value_in_programs(interpreter.programs.iter().copied(), name)
+}
+
+pub fn find_library_constant_value(library: &IntermediateRepresentations, name: &str) -> Option<u64> {
+    find_constant_value_in_programs(library.iter(), name)
+}
+
+fn find_constant_value_in_programs<'a>(programs: impl Iterator<Item = &'a IntermediateRepresentation>, name: &str) -> Option<u64> {
+    for program in programs {
+        for symbol in &program.symbols {
+            match symbol {
+                ByteCodeSymbol::AbsoluteExternal32 { name: constant, value } if constant == name => return Some(*value as i32 as u64),
+                ByteCodeSymbol::AbsoluteExternal64 { name: constant, value } if constant == name => return Some(*value),
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+fn get_data_section_address(interpreter: &mut Interpreter, program: usize) -> InterpretationResult<u64> {
+    if let Some(address) = interpreter.data_section_addresses.get(&program) {
+        return Ok(*address);
+    }
+
+    let read_only_data_address = get_read_only_data_address(interpreter, program)?;
+    let address = interpreter.memory.len() as u64;
+    let mut symbol_pointers = vec!();
+    for item in &interpreter.programs[program].data.items {
+        match item {
+            ByteCodeDataItem::String { value } => interpreter.memory.extend_from_slice(value),
+            ByteCodeDataItem::Byte { value } => interpreter.memory.push(*value),
+            ByteCodeDataItem::Word { value } => interpreter.memory.extend_from_slice(&value.to_le_bytes()),
+            ByteCodeDataItem::DoubleWord { value } => interpreter.memory.extend_from_slice(&value.to_le_bytes()),
+            ByteCodeDataItem::QuadWord { value } => interpreter.memory.extend_from_slice(&value.to_le_bytes()),
+            ByteCodeDataItem::Pointer { value } => interpreter.memory.extend_from_slice(&(address + value).to_le_bytes()),
+            ByteCodeDataItem::ReadOnlyPointer { value } => interpreter.memory.extend_from_slice(&(read_only_data_address + value).to_le_bytes()),
+            ByteCodeDataItem::SymbolPointer { symbol_index } => {
+                symbol_pointers.push((interpreter.memory.len() as u64, *symbol_index));
+                interpreter.memory.extend_from_slice(&0u64.to_le_bytes());
+            }
+        }
+    }
+
+    interpreter.data_section_addresses.insert(program, address);
+
+    for (pointer_address, symbol_index) in symbol_pointers {
+        let name = get_program_symbol_name(interpreter, program, symbol_index)?;
+        let symbol_address = get_data_symbol_address(interpreter, &name)?;
+        write_memory(interpreter, pointer_address, 8, symbol_address)?;
+    }
+
+    Ok(address)
+}
+
+fn get_read_only_data_address(interpreter: &mut Interpreter, program: usize) -> InterpretationResult<u64> {
+    if let Some(address) = interpreter.read_only_data_addresses.get(&program) {
+        return Ok(*address);
+    }
+
+    let address = interpreter.memory.len() as u64;
+    for item in &interpreter.programs[program].read_only_data.items {
+        match item {
+            ByteCodeDataItem::String { value } => interpreter.memory.extend_from_slice(value),
+            ByteCodeDataItem::Byte { value } => interpreter.memory.push(*value),
+            ByteCodeDataItem::Word { value } => interpreter.memory.extend_from_slice(&value.to_le_bytes()),
+            ByteCodeDataItem::DoubleWord { value } => interpreter.memory.extend_from_slice(&value.to_le_bytes()),
+            ByteCodeDataItem::QuadWord { value } => interpreter.memory.extend_from_slice(&value.to_le_bytes()),
+            _ => return Err(interpreter_unsupported_instruction_error())
+        }
+    }
+
+    interpreter.read_only_data_addresses.insert(program, address);
+    Ok(address)
+}
+
+fn get_bss_section_address(interpreter: &mut Interpreter, program: usize) -> u64 {
+    if let Some(address) = interpreter.bss_section_addresses.get(&program) {
+        return *address;
+    }
+
+    let address = interpreter.memory.len() as u64;
+    let size = interpreter.programs[program].bss.size as usize;
+    interpreter.memory.resize(address as usize + size, 0);
+    interpreter.bss_section_addresses.insert(program, address);
+    address
+}
+
+fn get_register_slot(calling_convention: CallingConvention, register: ByteCodeRegister) -> InterpretationResult<usize> {
+    match register {
+        ByteCodeRegister::Standard(number) if number <= 10 => Ok(number),
+        ByteCodeRegister::Standard(11) => Ok(SOURCE_INDEX_SLOT),
+        ByteCodeRegister::Standard(12) => Ok(DESTINATION_INDEX_SLOT),
+        ByteCodeRegister::CallArg(number) => match get_call_arg_standard_register(calling_convention, number) {
+            Some(register) => get_register_slot(calling_convention, register),
+            None => Err(interpreter_register_not_available_error())
+        },
+        ByteCodeRegister::CallReturnArg(0) => Ok(0),
+        ByteCodeRegister::StackPointer => Ok(STACK_POINTER_SLOT),
+        ByteCodeRegister::BasePointer => Ok(BASE_POINTER_SLOT),
+        ByteCodeRegister::FloatCallArg(number) if is_float_call_arg_available(calling_convention, number) => Ok(FLOAT_REGISTER_SLOT + number),
+        ByteCodeRegister::FloatCallReturnArg(0) => Ok(FLOAT_REGISTER_SLOT),
+        ByteCodeRegister::FloatStandard(number) if number <= 1 => Ok(FLOAT_STANDARD_SLOT + number),
+        _ => Err(interpreter_register_not_available_error())
+    }
+}
+
+pub fn get_register_value(interpreter: &Interpreter, register: ByteCodeRegister) -> InterpretationResult<u64> {
+    Ok(interpreter.registers[get_register_slot(interpreter.calling_convention, register)?])
+}
+
+pub fn get_call_arg_value(interpreter: &Interpreter, number: usize) -> InterpretationResult<u64> {
+    let locations = get_argument_locations(interpreter.calling_convention, &vec!(integer_argument_class(); number + 1));
+    match locations[number] {
+        ArgumentLocation::Register(register) => get_register_value(interpreter, register),
+        ArgumentLocation::Stack(offset) => read_memory(interpreter, get_register_value(interpreter, stack_pointer_register())?.wrapping_add(*offset as i64 as u64), 8)
+    }
+}
+
+pub fn get_string_call_arg_value(interpreter: &Interpreter, number: usize) -> InterpretationResult<ByteString> {
+    match classify_struct_argument(interpreter.calling_convention, &string_runtime_type()) {
+        StructArgumentPassing::Registers(_) => {
+            let count = get_call_arg_value(interpreter, number)?;
+            let data_address = get_call_arg_value(interpreter, number + 1)?;
+            Ok(read_memory_bytes(interpreter, data_address, count)?.to_vec())
+        },
+        _ => read_string(interpreter, get_call_arg_value(interpreter, number)?)
+    }
+}
+
+fn set_register_value(interpreter: &mut Interpreter, register: ByteCodeRegister, value: u64) -> InterpretationResult<()> {
+    let slot = get_register_slot(interpreter.calling_convention, register)?;
+
+    if slot == STACK_POINTER_SLOT && value < NULL_GUARD_SIZE {
+        return Err(stack_overflow_error());
+    }
+
+    interpreter.registers[slot] = value;
+    Ok(())
+}
+
+fn get_register_plus_offset_address(interpreter: &Interpreter, register: ByteCodeRegister, offset: AddressOffset) -> InterpretationResult<u64> {
+    Ok(get_register_value(interpreter, register)?.wrapping_add(*offset as i64 as u64))
+}
+
+fn push(interpreter: &mut Interpreter, value: u64) -> InterpretationResult<()> {
+    let address = get_register_value(interpreter, stack_pointer_register())?.wrapping_sub(8);
+    set_register_value(interpreter, stack_pointer_register(), address)?;
+    write_memory(interpreter, address, 8, value)
+}
+
+fn pop(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let address = get_register_value(interpreter, stack_pointer_register())?;
+    let value = read_memory(interpreter, address, 8)?;
+    set_register_value(interpreter, stack_pointer_register(), address.wrapping_add(8))?;
+    Ok(value)
+}
+
+pub fn read_memory_bytes<'a>(interpreter: &'a Interpreter, address: u64, length: u64) -> InterpretationResult<&'a [u8]> {
+    if address < NULL_GUARD_SIZE || address.saturating_add(length) > interpreter.memory.len() as u64 {
+        return Err(invalid_memory_access_error(address));
+    }
+    Ok(&interpreter.memory[address as usize..(address + length) as usize])
+}
+
+pub fn read_string(interpreter: &Interpreter, string_address: u64) -> InterpretationResult<ByteString> {
+    let count = read_memory(interpreter, string_address, 8)?;
+    let data_address = read_memory(interpreter, string_address.wrapping_add(8), 8)?;
+    Ok(read_memory_bytes(interpreter, data_address, count)?.to_vec())
+}
+
+fn read_memory(interpreter: &Interpreter, address: u64, size: u64) -> InterpretationResult<u64> {
+    let mut bytes = [0; 8];
+    bytes[..size as usize].copy_from_slice(read_memory_bytes(interpreter, address, size)?);
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub fn write_memory(interpreter: &mut Interpreter, address: u64, size: u64, value: u64) -> InterpretationResult<()> {
+    read_memory_bytes(interpreter, address, size)?;
+    interpreter.memory[address as usize..(address + size) as usize].copy_from_slice(&value.to_le_bytes()[..size as usize]);
+    Ok(())
+}
+
+pub fn write_memory_bytes(interpreter: &mut Interpreter, address: u64, bytes: &[u8]) -> InterpretationResult<()> {
+    read_memory_bytes(interpreter, address, bytes.len() as u64)?;
+    interpreter.memory[address as usize..address as usize + bytes.len()].copy_from_slice(bytes);
+    Ok(())
+}
+
+pub fn allocate_memory(interpreter: &mut Interpreter, size: u64) -> u64 {
+    let address = interpreter.memory.len() as u64;
+    interpreter.memory.resize((address + size) as usize, 0);
+    address
+}
+
+pub fn exit_interpreter(interpreter: &mut Interpreter) {
+    interpreter.frames.clear();
+}
+
+pub fn add_open_file(interpreter: &mut Interpreter, file: File) -> u64 {
+    let descriptor = (FIRST_OPEN_FILE_DESCRIPTOR..)
+        .find(|descriptor| !interpreter.open_files.contains_key(descriptor))
+        .unwrap();
+    interpreter.open_files.insert(descriptor, file);
+    descriptor
+}
+
+pub fn get_open_file<'a>(interpreter: &'a mut Interpreter, descriptor: u64) -> Option<&'a mut File> {
+    interpreter.open_files.get_mut(&descriptor)
+}
+
+pub fn remove_open_file(interpreter: &mut Interpreter, descriptor: u64) -> Option<File> {
+    interpreter.open_files.remove(&descriptor)
+}
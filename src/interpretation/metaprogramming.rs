@@ -0,0 +1,45 @@
+use crate::{
+    compilation::*,
+    interpretation::*,
+    strings::*
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompilerRequest {
+    Intercept { kind: InterceptedMessageKind, handler: String },
+    AddBuildString(ByteString),
+    WriteStandardOutput(ByteString)
+}
+
+pub type CompilerRequests = Vec<CompilerRequest>;
+
+pub fn intercept_compiler_request(kind: InterceptedMessageKind, handler: String) -> CompilerRequest {
+    CompilerRequest::Intercept { kind, handler }
+}
+
+pub fn add_build_string_compiler_request(source: ByteString) -> CompilerRequest {
+    CompilerRequest::AddBuildString(source)
+}
+
+pub fn write_standard_output_compiler_request(output: ByteString) -> CompilerRequest {
+    CompilerRequest::WriteStandardOutput(output)
+}
+
+pub fn compiler_intercept(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let kind_value = get_call_arg_value(interpreter, 0)?;
+    let handler_address = get_call_arg_value(interpreter, 1)?;
+
+    let kind = try_get_intercepted_message_kind(kind_value)
+        .ok_or_else(|| unknown_intercepted_message_kind_error(kind_value))?;
+    let handler = get_procedure_name_at_address(interpreter, handler_address)?;
+
+    add_compiler_request(interpreter, intercept_compiler_request(kind, handler));
+    Ok(0)
+}
+
+pub fn add_build_string(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let source = get_string_call_arg_value(interpreter, 0)?;
+
+    add_compiler_request(interpreter, add_build_string_compiler_request(source));
+    Ok(0)
+}
@@ -0,0 +1,17 @@
+mod actor;
+mod machine;
+mod foreign;
+mod runtime;
+mod system_calls;
+mod errors;
+mod evaluation;
+mod metaprogramming;
+
+pub use actor::*;
+pub use machine::*;
+pub use foreign::*;
+pub use runtime::*;
+pub use system_calls::*;
+pub use errors::*;
+pub use evaluation::*;
+pub use metaprogramming::*;
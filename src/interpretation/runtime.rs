@@ -0,0 +1,122 @@
+use crate::{
+    interpretation::*,
+    strings::*
+};
+
+pub fn malloc(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let size = get_call_arg_value(interpreter, 0)?;
+    Ok(allocate_memory(interpreter, size))
+}
+
+pub fn free(_interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    Ok(0)
+}
+
+pub fn memcpy(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let destination = get_call_arg_value(interpreter, 0)?;
+    let source = get_call_arg_value(interpreter, 1)?;
+    let count = get_call_arg_value(interpreter, 2)?;
+
+    let bytes = read_memory_bytes(interpreter, source, count)?.to_vec();
+    write_memory_bytes(interpreter, destination, &bytes)?;
+    Ok(destination)
+}
+
+pub fn memset(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let destination = get_call_arg_value(interpreter, 0)?;
+    let value = get_call_arg_value(interpreter, 1)? as u8;
+    let count = get_call_arg_value(interpreter, 2)?;
+
+    write_memory_bytes(interpreter, destination, &vec!(value; count as usize))?;
+    Ok(destination)
+}
+
+pub fn strcmp(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let lhs = read_c_string(interpreter, get_call_arg_value(interpreter, 0)?)?;
+    let rhs = read_c_string(interpreter, get_call_arg_value(interpreter, 1)?)?;
+    Ok(lhs.cmp(&rhs) as i32 as u32 as u64)
+}
+
+pub fn scprintf(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let formatted = format_c_string(interpreter, 0)?;
+    Ok(formatted.len() as u64)
+}
+
+pub fn snprintf(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let buffer = get_call_arg_value(interpreter, 0)?;
+    let count = get_call_arg_value(interpreter, 1)?;
+    let mut formatted = format_c_string(interpreter, 2)?;
+
+    if formatted.len() as u64 > count {
+        formatted.truncate(count as usize);
+        write_memory_bytes(interpreter, buffer, &formatted)?;
+        return Ok(-1i32 as u32 as u64);
+    }
+
+    let written = formatted.len() as u64;
+    if written < count {
+        formatted.push(0);
+    }
+    write_memory_bytes(interpreter, buffer, &formatted)?;
+    Ok(written)
+}
+
+pub fn read_c_string(interpreter: &Interpreter, address: u64) -> InterpretationResult<ByteString> {
+    let mut result = vec!();
+    loop {
+        let byte = read_memory_bytes(interpreter, address + result.len() as u64, 1)?[0];
+        if byte == 0 {
+            return Ok(result);
+        }
+        result.push(byte);
+    }
+}
+
+fn format_c_string(interpreter: &Interpreter, format_arg_index: usize) -> InterpretationResult<ByteString> {
+    let format = read_c_string(interpreter, get_call_arg_value(interpreter, format_arg_index)?)?;
+    let mut next_arg_index = format_arg_index + 1;
+    let mut result = vec!();
+    let mut bytes = format.iter().peekable();
+
+    while let Some(byte) = bytes.next() {
+        if *byte != b'%' {
+            result.push(*byte);
+            continue;
+        }
+
+        let mut modifiers = vec!();
+        while let Some(modifier) = bytes.next_if(|byte| matches!(byte, b'l' | b'I' | b'6' | b'4')) {
+            modifiers.push(*modifier);
+        }
+        let is_wide = modifiers == b"ll" || modifiers == b"I64";
+
+        let specifier = match bytes.next() {
+            Some(specifier) => *specifier,
+            None => break
+        };
+
+        if specifier == b'%' {
+            result.push(b'%');
+            continue;
+        }
+
+        let arg = get_call_arg_value(interpreter, next_arg_index)?;
+        next_arg_index += 1;
+
+        match specifier {
+            b'd' | b'i' if is_wide => result.extend(format!("{}", arg as i64).bytes()),
+            b'd' | b'i' => result.extend(format!("{}", arg as u32 as i32).bytes()),
+            b'u' if is_wide => result.extend(format!("{}", arg).bytes()),
+            b'u' => result.extend(format!("{}", arg as u32).bytes()),
+            b'x' if is_wide => result.extend(format!("{:x}", arg).bytes()),
+            b'x' => result.extend(format!("{:x}", arg as u32).bytes()),
+            b'X' if is_wide => result.extend(format!("{:X}", arg).bytes()),
+            b'X' => result.extend(format!("{:X}", arg as u32).bytes()),
+            b'c' => result.push(arg as u8),
+            b's' => result.extend(read_c_string(interpreter, arg)?),
+            _ => return Err(unsupported_format_specifier_error(specifier as char))
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,157 @@
+use std::{fs::*, io::*};
+
+use crate::{
+    intermediate_representation::*,
+    interpretation::*
+};
+
+const SYSTEM_CALL_READ: u64 = 0;
+const SYSTEM_CALL_WRITE: u64 = 1;
+const SYSTEM_CALL_OPEN: u64 = 2;
+const SYSTEM_CALL_CLOSE: u64 = 3;
+const SYSTEM_CALL_MMAP: u64 = 9;
+const SYSTEM_CALL_EXIT: u64 = 60;
+
+const STANDARD_INPUT: u64 = 0;
+const STANDARD_OUTPUT: u64 = 1;
+const STANDARD_ERROR: u64 = 2;
+
+const OPEN_ACCESS_MODE_MASK: u64 = 0x3;
+const OPEN_WRITE_ONLY: u64 = 0x1;
+const OPEN_READ_WRITE: u64 = 0x2;
+const OPEN_CREATE: u64 = 0x40;
+const OPEN_TRUNCATE: u64 = 0x200;
+const OPEN_APPEND: u64 = 0x400;
+
+const MAP_ANONYMOUS: u64 = 0x20;
+
+const ERROR_IO: i32 = 5;
+const ERROR_BAD_FILE: i32 = 9;
+const ERROR_NO_DEVICE: i32 = 19;
+
+pub fn execute_system_call(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    match get_register_value(interpreter, standard_register(0))? {
+        SYSTEM_CALL_READ => system_call_read(interpreter),
+        SYSTEM_CALL_WRITE => system_call_write(interpreter),
+        SYSTEM_CALL_OPEN => system_call_open(interpreter),
+        SYSTEM_CALL_CLOSE => system_call_close(interpreter),
+        SYSTEM_CALL_MMAP => system_call_mmap(interpreter),
+        SYSTEM_CALL_EXIT => system_call_exit(interpreter),
+        number => Err(unsupported_system_call_error(number))
+    }
+}
+
+fn get_system_call_arg(interpreter: &Interpreter, index: usize) -> InterpretationResult<u64> {
+    let register = match index {
+        0 => standard_register(12),
+        1 => standard_register(11),
+        2 => standard_register(2),
+        3 => standard_register(5),
+        4 => standard_register(3),
+        _ => standard_register(4)
+    };
+    get_register_value(interpreter, register)
+}
+
+fn system_call_read(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let descriptor = get_system_call_arg(interpreter, 0)?;
+    let buffer = get_system_call_arg(interpreter, 1)?;
+    let count = get_system_call_arg(interpreter, 2)?;
+
+    read_memory_bytes(interpreter, buffer, count)?;
+    let mut bytes = vec!(0; count as usize);
+
+    let result = match descriptor {
+        STANDARD_INPUT => stdin().read(&mut bytes),
+        _ => match get_open_file(interpreter, descriptor) {
+            Some(file) => file.read(&mut bytes),
+            None => return Ok(system_call_error(ERROR_BAD_FILE))
+        }
+    };
+
+    match result {
+        Ok(read) => {
+            write_memory_bytes(interpreter, buffer, &bytes[..read])?;
+            Ok(read as u64)
+        },
+        Err(error) => Ok(system_call_io_error(error))
+    }
+}
+
+fn system_call_write(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let descriptor = get_system_call_arg(interpreter, 0)?;
+    let buffer = get_system_call_arg(interpreter, 1)?;
+    let count = get_system_call_arg(interpreter, 2)?;
+
+    let bytes = read_memory_bytes(interpreter, buffer, count)?.to_vec();
+
+    let result = match descriptor {
+        STANDARD_OUTPUT => {
+            add_compiler_request(interpreter, write_standard_output_compiler_request(bytes));
+            Ok(())
+        },
+        STANDARD_ERROR => stderr().write_all(&bytes),
+        _ => match get_open_file(interpreter, descriptor) {
+            Some(file) => file.write_all(&bytes),
+            None => return Ok(system_call_error(ERROR_BAD_FILE))
+        }
+    };
+
+    match result {
+        Ok(()) => Ok(count),
+        Err(error) => Ok(system_call_io_error(error))
+    }
+}
+
+fn system_call_open(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let path = read_c_string(interpreter, get_system_call_arg(interpreter, 0)?)?;
+    let flags = get_system_call_arg(interpreter, 1)?;
+
+    let access_mode = flags & OPEN_ACCESS_MODE_MASK;
+    let result = OpenOptions::new()
+        .read(access_mode != OPEN_WRITE_ONLY)
+        .write(access_mode == OPEN_WRITE_ONLY || access_mode == OPEN_READ_WRITE)
+        .create(flags & OPEN_CREATE != 0)
+        .truncate(flags & OPEN_TRUNCATE != 0)
+        .append(flags & OPEN_APPEND != 0)
+        .open(String::from_utf8_lossy(&path).as_ref());
+
+    match result {
+        Ok(file) => Ok(add_open_file(interpreter, file)),
+        Err(error) => Ok(system_call_io_error(error))
+    }
+}
+
+fn system_call_close(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let descriptor = get_system_call_arg(interpreter, 0)?;
+
+    match remove_open_file(interpreter, descriptor) {
+        Some(_) => Ok(0),
+        None => Ok(system_call_error(ERROR_BAD_FILE))
+    }
+}
+
+fn system_call_mmap(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let length = get_system_call_arg(interpreter, 1)?;
+    let flags = get_system_call_arg(interpreter, 3)?;
+
+    if flags & MAP_ANONYMOUS == 0 {
+        return Ok(system_call_error(ERROR_NO_DEVICE));
+    }
+
+    Ok(allocate_memory(interpreter, length))
+}
+
+fn system_call_exit(interpreter: &mut Interpreter) -> InterpretationResult<u64> {
+    let status = get_system_call_arg(interpreter, 0)?;
+    exit_interpreter(interpreter);
+    Ok(status)
+}
+
+fn system_call_error(error_number: i32) -> u64 {
+    -(error_number as i64) as u64
+}
+
+fn system_call_io_error(error: Error) -> u64 {
+    system_call_error(error.raw_os_error().unwrap_or(ERROR_IO))
+}
@@ -5,6 +5,7 @@ mod parsing;
 mod typing;
 mod sizing;
 mod intermediate_representation;
+mod interpretation;
 mod backends;
 mod file_system;
 mod arguments;
@@ -22,6 +23,7 @@ pub use parsing::*;
 pub use typing::*;
 pub use sizing::*;
 pub use intermediate_representation::*;
+pub use interpretation::*;
 pub use backends::*;
 pub use file_system::*;
 pub use arguments::*;
@@ -4,16 +4,22 @@ fn main() {
     //std::env::set_var("RUST_LOG", "debug");
     pretty_env_logger::init();
     
-    match get_file_to_compile_from_invocation_arguments() {
-        Some(file_name) => { 
-            compile(
+    match (get_file_to_compile_from_invocation_arguments(), get_target_from_invocation_arguments()) {
+        (Some(file_name), Ok(target)) => { 
+            compile_for_target(
                 file_name, 
+                get_module_paths_from_invocation_arguments(),
+                target,
                 create_file_reader(), 
-                create_x64_backend(),
+                create_x64_backend(target, get_link_from_invocation_arguments()),
                 create_null_message_wire_tap()
             );
         },
-        None => panic!("No compilation file name argument passed")
+        (None, _) => panic!("No compilation file name argument passed"),
+        (_, Err(error)) => {
+            report_argument_error(error);
+            std::process::exit(1);
+        }
     }
 }
 
@@ -4,8 +4,16 @@ use crate::parsing::*;
 pub enum Directive {
     Run,
     Load,
+    Import,
     ForeignSystemLibrary,
-    Foreign
+    Foreign,
+    ScopeFile,
+    ScopeExport,
+    Assert,
+    Asm,
+    Insert,
+    Code,
+    Expand
 }
 
 pub fn create_directive_token_item(directive: Directive) -> SourceTokenItem {
@@ -16,19 +24,43 @@ type ParseDirectiveOption = Option<Directive>;
 
 const SOURCE_DIRECTIVE_RUN: &str = "run";
 const SOURCE_DIRECTIVE_LOAD: &str = "load";
+const SOURCE_DIRECTIVE_IMPORT: &str = "import";
 const SOURCE_DIRECTIVE_FOREIGN: &str = "foreign";
 const SOURCE_DIRECTIVE_FOREIGN_SYSTEM_LIBRARY: &str = "foreign_system_library";
+const SOURCE_DIRECTIVE_SCOPE_FILE: &str = "scope_file";
+const SOURCE_DIRECTIVE_SCOPE_EXPORT: &str = "scope_export";
+const SOURCE_DIRECTIVE_ASSERT: &str = "assert";
+const SOURCE_DIRECTIVE_ASM: &str = "asm";
+const SOURCE_DIRECTIVE_INSERT: &str = "insert";
+const SOURCE_DIRECTIVE_CODE: &str = "code";
+const SOURCE_DIRECTIVE_EXPAND: &str = "expand";
 
 pub fn parse_directive_token_item(from: &str) -> ParseDirectiveOption {
     match from {
-        SOURCE_DIRECTIVE_RUN => return Some(Directive::Run),
-        SOURCE_DIRECTIVE_LOAD => return Some(Directive::Load),
-        SOURCE_DIRECTIVE_FOREIGN => return Some(Directive::Foreign),
-        SOURCE_DIRECTIVE_FOREIGN_SYSTEM_LIBRARY => return Some(Directive::ForeignSystemLibrary),
+        SOURCE_DIRECTIVE_RUN => Some(Directive::Run),
+        SOURCE_DIRECTIVE_LOAD => Some(Directive::Load),
+        SOURCE_DIRECTIVE_IMPORT => Some(Directive::Import),
+        SOURCE_DIRECTIVE_FOREIGN => Some(Directive::Foreign),
+        SOURCE_DIRECTIVE_FOREIGN_SYSTEM_LIBRARY => Some(Directive::ForeignSystemLibrary),
+        SOURCE_DIRECTIVE_SCOPE_FILE => Some(Directive::ScopeFile),
+        SOURCE_DIRECTIVE_SCOPE_EXPORT => Some(Directive::ScopeExport),
+        SOURCE_DIRECTIVE_ASSERT => Some(Directive::Assert),
+        SOURCE_DIRECTIVE_ASM => Some(Directive::Asm),
+        SOURCE_DIRECTIVE_INSERT => Some(Directive::Insert),
+        SOURCE_DIRECTIVE_CODE => Some(Directive::Code),
+        SOURCE_DIRECTIVE_EXPAND => Some(Directive::Expand),
         _ => None
     }
 }
 
 pub fn is_foreign_directive(item: &SourceTokenItem) -> bool {
     item == &SourceTokenItem::Directive(Directive::Foreign)
-}
\ No newline at end of file
+}
+
+pub fn is_code_directive(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Directive(Directive::Code)
+}
+
+pub fn is_expand_directive(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Directive(Directive::Expand)
+}
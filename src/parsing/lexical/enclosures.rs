@@ -10,6 +10,7 @@ pub enum EnclosureType {
 pub enum Enclosure {
     Brace(EnclosureType),
     Parentheses(EnclosureType),
+    Bracket(EnclosureType),
 }
 
 pub fn create_enclosure_token_item(enclosure: Enclosure) -> SourceTokenItem {
@@ -24,6 +25,10 @@ pub fn create_open_parentheses_enclosure() -> Enclosure {
     Enclosure::Parentheses(EnclosureType::Open)
 }
 
+pub fn create_open_bracket_enclosure() -> Enclosure {
+    Enclosure::Bracket(EnclosureType::Open)
+}
+
 pub fn create_closed_brace_enclosure() -> Enclosure {
     Enclosure::Brace(EnclosureType::Close)
 }
@@ -32,6 +37,10 @@ pub fn create_closed_parentheses_enclosure() -> Enclosure {
     Enclosure::Parentheses(EnclosureType::Close)
 }
 
+pub fn create_closed_bracket_enclosure() -> Enclosure {
+    Enclosure::Bracket(EnclosureType::Close)
+}
+
 pub fn is_open_paren(item: &SourceTokenItem) -> bool {
     item == &SourceTokenItem::Enclosure(Enclosure::Parentheses(EnclosureType::Open))
 }
@@ -46,4 +55,12 @@ pub fn is_open_brace(item: &SourceTokenItem) -> bool {
 
 pub fn is_close_brace(item: &SourceTokenItem) -> bool {
     item == &SourceTokenItem::Enclosure(Enclosure::Brace(EnclosureType::Close))
+}
+
+pub fn is_open_bracket(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Enclosure(Enclosure::Bracket(EnclosureType::Open))
+}
+
+pub fn is_close_bracket(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Enclosure(Enclosure::Bracket(EnclosureType::Close))
 }
\ No newline at end of file
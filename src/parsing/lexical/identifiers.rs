@@ -4,6 +4,16 @@ pub fn create_identifier_token_item(name: String) -> SourceTokenItem {
     SourceTokenItem::Identifier(name)
 }
 
+const CALLER_IDENTIFIER_PREFIX: char = '`';
+
+pub fn create_caller_identifier_name(name: &str) -> String {
+    format!("{}{}", CALLER_IDENTIFIER_PREFIX, name)
+}
+
+pub fn try_get_caller_identifier_name(name: &str) -> Option<&str> {
+    name.strip_prefix(CALLER_IDENTIFIER_PREFIX)
+}
+
 pub fn create_period_token_item() -> SourceTokenItem {
     SourceTokenItem::Period
 }
@@ -6,7 +6,11 @@ pub enum Keyword {
     Struct,
     For,
     Cast,
-    Return
+    Return,
+    If,
+    Else,
+    While,
+    Uninitialised
 }
 
 pub fn create_keyword_token_item(keyword: Keyword) -> SourceTokenItem {
@@ -21,6 +25,9 @@ const SOURCE_KEYWORD_STRUCT: &str = "struct";
 const SOURCE_KEYWORD_FOR: &str = "for";
 const SOURCE_KEYWORD_CAST: &str = "cast";
 const SOURCE_KEYWORD_RETURN: &str = "return";
+const SOURCE_KEYWORD_IF: &str = "if";
+const SOURCE_KEYWORD_ELSE: &str = "else";
+const SOURCE_KEYWORD_WHILE: &str = "while";
 
 pub fn parse_keyword(from: &str) -> ParseKeywordOption {
     if from == SOURCE_KEYWORD_STRUCT {
@@ -38,9 +45,30 @@ pub fn parse_keyword(from: &str) -> ParseKeywordOption {
     if from == SOURCE_KEYWORD_RETURN {
         return Some(Keyword::Return);
     }
+    if from == SOURCE_KEYWORD_IF {
+        return Some(Keyword::If);
+    }
+    if from == SOURCE_KEYWORD_ELSE {
+        return Some(Keyword::Else);
+    }
+    if from == SOURCE_KEYWORD_WHILE {
+        return Some(Keyword::While);
+    }
     None
 }
 
+pub fn create_uninitialised_keyword() -> Keyword {
+    Keyword::Uninitialised
+}
+
+pub fn is_uninitialised_keyword(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Keyword(Keyword::Uninitialised)
+}
+
 pub fn is_struct_keyword(item: &SourceTokenItem) -> bool {
     item == &SourceTokenItem::Keyword(Keyword::Struct)
 }
+
+pub fn is_else_keyword(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Keyword(Keyword::Else)
+}
@@ -13,12 +13,17 @@ const SOURCE_SYMBOL_EQUALS: char = '=';
 const SOURCE_SYMBOL_ADD: char = '+';
 const SOURCE_SYMBOL_SUBTRACT: char = '-';
 const SOURCE_SYMBOL_GREATER_THAN: char = '>';
+const SOURCE_SYMBOL_LESS_THAN: char = '<';
+const SOURCE_SYMBOL_EXCLAMATION: char = '!';
 const SOURCE_SYMBOL_PERIOD: char = '.';
 const SOURCE_SYMBOL_OPEN_BRACE: char = '{';
 const SOURCE_SYMBOL_CLOSE_BRACE: char = '}';
 const SOURCE_SYMBOL_OPEN_PAREN: char = '(';
 const SOURCE_SYMBOL_CLOSE_PAREN: char = ')';
+const SOURCE_SYMBOL_OPEN_BRACKET: char = '[';
+const SOURCE_SYMBOL_CLOSE_BRACKET: char = ']';
 const SOURCE_SYMBOL_QUOTES: char = '"';
+const SOURCE_SYMBOL_BACKTICK: char = '`';
 
 #[derive(Clone)]
 pub struct Lexer<'a> {
@@ -112,6 +117,16 @@ fn read_next_token(lexer: &mut Lexer) -> SourceToken {
 
     if is_character(&next_character, SOURCE_SYMBOL_SUBTRACT) {
         eat_next_character(&mut lexer.reader);
+
+        let (second_character, third_character) = peek_next_two_characters(&lexer.reader);
+        if is_character(&second_character, SOURCE_SYMBOL_SUBTRACT) && is_character(&third_character, SOURCE_SYMBOL_SUBTRACT) {
+            eat_next_character(&mut lexer.reader);
+            eat_next_character(&mut lexer.reader);
+            return create_token(
+                get_character_position(&next_character), 
+                create_keyword_token_item(create_uninitialised_keyword())
+            );
+        }
         
         if is_character(&peek_next_character(&mut lexer.reader), SOURCE_SYMBOL_GREATER_THAN) {
             eat_next_character(&mut lexer.reader);
@@ -135,12 +150,64 @@ fn read_next_token(lexer: &mut Lexer) -> SourceToken {
 
     if is_character(&next_character, SOURCE_SYMBOL_EQUALS) {
         eat_next_character(&mut lexer.reader);
+        if is_character(&peek_next_character(&lexer.reader), SOURCE_SYMBOL_EQUALS) {
+            eat_next_character(&mut lexer.reader);
+            return create_token(
+                get_character_position(&next_character), 
+                create_operator_token_item(equal_operator())
+            );
+        }
         return create_token(
             get_character_position(&next_character), 
             create_assignment_token_item(create_assign_value_assignment())
         );
     }
 
+    if is_character(&next_character, SOURCE_SYMBOL_EXCLAMATION) {
+        eat_next_character(&mut lexer.reader);
+        if is_character(&peek_next_character(&lexer.reader), SOURCE_SYMBOL_EQUALS) {
+            eat_next_character(&mut lexer.reader);
+            return create_token(
+                get_character_position(&next_character), 
+                create_operator_token_item(not_equal_operator())
+            );
+        }
+        return create_token(
+            get_character_position(&next_character), 
+            create_error_token_item(create_unknown_token_error(SOURCE_SYMBOL_EXCLAMATION))
+        );
+    }
+
+    if is_character(&next_character, SOURCE_SYMBOL_LESS_THAN) {
+        eat_next_character(&mut lexer.reader);
+        if is_character(&peek_next_character(&lexer.reader), SOURCE_SYMBOL_EQUALS) {
+            eat_next_character(&mut lexer.reader);
+            return create_token(
+                get_character_position(&next_character), 
+                create_operator_token_item(less_than_or_equal_operator())
+            );
+        }
+        return create_token(
+            get_character_position(&next_character), 
+            create_operator_token_item(less_than_operator())
+        );
+    }
+
+    if is_character(&next_character, SOURCE_SYMBOL_GREATER_THAN) {
+        eat_next_character(&mut lexer.reader);
+        if is_character(&peek_next_character(&lexer.reader), SOURCE_SYMBOL_EQUALS) {
+            eat_next_character(&mut lexer.reader);
+            return create_token(
+                get_character_position(&next_character), 
+                create_operator_token_item(greater_than_or_equal_operator())
+            );
+        }
+        return create_token(
+            get_character_position(&next_character), 
+            create_operator_token_item(greater_than_operator())
+        );
+    }
+
     if is_character(&next_character, SOURCE_SYMBOL_COLON) {
         eat_next_character(&mut lexer.reader);
         if is_character(&peek_next_character(&mut lexer.reader), SOURCE_SYMBOL_EQUALS) {
@@ -210,6 +277,31 @@ fn read_next_token(lexer: &mut Lexer) -> SourceToken {
         );
     }
 
+    if is_character(&next_character, SOURCE_SYMBOL_OPEN_BRACKET) {
+        eat_next_character(&mut lexer.reader);
+        return create_token(
+            get_character_position(&next_character), 
+            create_enclosure_token_item(create_open_bracket_enclosure())
+        );
+    }
+
+    if is_character(&next_character, SOURCE_SYMBOL_CLOSE_BRACKET) {
+        eat_next_character(&mut lexer.reader);
+        return create_token(
+            get_character_position(&next_character), 
+            create_enclosure_token_item(create_closed_bracket_enclosure())
+        );
+    }
+
+    if is_character(&next_character, SOURCE_SYMBOL_BACKTICK) {
+        eat_next_character(&mut lexer.reader);
+        let name = read_up_until_non_alphanumeric(lexer);
+        return create_token(
+            get_character_position(&next_character), 
+            create_identifier_token_item(create_caller_identifier_name(&name))
+        );
+    }
+
     if is_character(&next_character, SOURCE_SYMBOL_QUOTES) {
         eat_next_character(&mut lexer.reader);
         let string = read_up_until_quotes(lexer);
@@ -220,7 +312,7 @@ fn read_next_token(lexer: &mut Lexer) -> SourceToken {
         );
     }
 
-    if is_character_alphanumeric(&next_character) {
+    if is_character_alphanumeric(&next_character) || is_character(&next_character, '_') {
         return parse_alphanumeric(lexer, false);
     }
     
@@ -293,7 +385,7 @@ fn parse_alphanumeric(lexer: &mut Lexer, is_negative: bool) -> SourceToken {
 
 
 fn read_up_until_non_alphanumeric(lexer: &mut Lexer) -> String {
-    read_characters_up_until(
+    read_characters_on_line_up_until(
         &mut lexer.reader, 
         |c| !is_character_alphanumeric(c) && !is_character(c, '_')
     )
@@ -11,4 +11,8 @@ pub fn create_range_token_item(range: Range) -> SourceTokenItem {
 
 pub fn create_left_inclusive_range() -> Range {
     Range::LeftInclusive
+}
+
+pub fn is_left_inclusive_range(item: &SourceTokenItem) -> bool {
+    item == &SourceTokenItem::Range(Range::LeftInclusive)
 }
\ No newline at end of file
@@ -75,6 +75,11 @@ pub fn read_characters_up_until<T: Fn(&SourceFileCharacter) -> bool>(reader: &mu
     result
 }
 
+pub fn read_characters_on_line_up_until<T: Fn(&SourceFileCharacter) -> bool>(reader: &mut SourceFileCharacterReader, until: T) -> String {
+    let line = get_character_position(&peek_next_character(reader)).line;
+    read_characters_up_until(reader, |c| get_character_position(c).line != line || until(c))
+}
+
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub enum SourceFileCharacter {
     Valid { position: SourceFilePosition, value: char },
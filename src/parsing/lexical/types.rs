@@ -5,6 +5,11 @@ use crate::types::*;
 type TypeOption = Option<ResolvableType>;
 
 pub fn try_parse_type(lexer: &mut Lexer) -> TypeOption {
+    if is_open_bracket(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        return try_parse_array_type(lexer);
+    }
+
     let is_pointer = is_pointer(&peek_next_token(lexer).item);
     
     if is_pointer {
@@ -14,15 +19,34 @@ pub fn try_parse_type(lexer: &mut Lexer) -> TypeOption {
     try_get_type(&peek_next_token(lexer).item, is_pointer)
 }
 
+fn try_parse_array_type(lexer: &mut Lexer) -> TypeOption {
+    let length = parse_rhs(lexer).ok()?;
+
+    if !is_close_bracket(&get_next_token(lexer).item) {
+        return None;
+    }
+
+    let mut array_type = unresolved_array_resolvable_type(length, try_parse_type(lexer)?);
+    if let Some(resolved_type) = try_resolve_declared_type(&mut array_type, &mut |_| None) {
+        return Some(resolved_resolvable_type(resolved_type));
+    }
+    Some(array_type)
+}
+
 fn try_get_type(item: &SourceTokenItem, is_pointer: bool) -> TypeOption {
     match item {
         SourceTokenItem::Type(t) => Some(resolved_resolvable_type(create_shareable(to_runtime_type(*t, is_pointer)))),
+        SourceTokenItem::Identifier(name) if is_pointer => Some(unresolved_pointer_resolvable_type(unresolved_named_resolvable_type(name.clone()))),
         SourceTokenItem::Identifier(name) => Some(unresolved_named_resolvable_type(name.clone())),
         _ => None
     }
 }
 
-fn to_runtime_type(from: BuiltInType, is_pointer: bool) -> RuntimeType {
+pub fn built_in_resolvable_type(from: BuiltInType, is_pointer: bool) -> ResolvableType {
+    resolved_resolvable_type(create_shareable(to_runtime_type(from, is_pointer)))
+}
+
+pub fn to_runtime_type(from: BuiltInType, is_pointer: bool) -> RuntimeType {
     if !is_pointer {
         return to_runtime_non_pointer_type(from)
     }
@@ -45,6 +69,12 @@ fn to_runtime_non_pointer_type(from: BuiltInType) -> RuntimeType {
         BuiltInType::String => string_runtime_type(),
         BuiltInType::Void => void_runtime_type(),
         BuiltInType::Boolean => bool_runtime_type(),
+        BuiltInType::TypeInfo => type_info_runtime_type(),
+        BuiltInType::TypeInfoMember => type_info_member_runtime_type(),
+        BuiltInType::Any => any_runtime_type(),
+        BuiltInType::Allocator => allocator_runtime_type(),
+        BuiltInType::SourceCodeLocation => source_code_location_runtime_type(),
+        BuiltInType::Context => context_runtime_type(),
     }
 }
 
@@ -63,6 +93,12 @@ fn to_runtime_pointer_type(from: BuiltInType) -> RuntimeType {
         BuiltInType::String => string_pointer_runtime_type(),
         BuiltInType::Void => void_pointer_runtime_type(),
         BuiltInType::Boolean => bool_pointer_runtime_type(),
+        BuiltInType::TypeInfo => type_info_pointer_runtime_type(),
+        BuiltInType::TypeInfoMember => type_info_member_pointer_runtime_type(),
+        BuiltInType::Any => any_pointer_runtime_type(),
+        BuiltInType::Allocator => allocator_pointer_runtime_type(),
+        BuiltInType::SourceCodeLocation => source_code_location_pointer_runtime_type(),
+        BuiltInType::Context => context_pointer_runtime_type(),
     }
 }
 
@@ -83,6 +119,12 @@ pub const SOURCE_TYPE_F64: &str = "float64";
 pub const SOURCE_TYPE_VOID: &str = "void";
 pub const SOURCE_TYPE_STRING: &str = "string";
 pub const SOURCE_TYPE_BOOL: &str = "bool";
+pub const SOURCE_TYPE_TYPE_INFO: &str = "Type_Info";
+pub const SOURCE_TYPE_TYPE_INFO_MEMBER: &str = "Type_Info_Member";
+pub const SOURCE_TYPE_ANY: &str = "Any";
+pub const SOURCE_TYPE_ALLOCATOR: &str = "Allocator";
+pub const SOURCE_TYPE_SOURCE_CODE_LOCATION: &str = "Source_Code_Location";
+pub const SOURCE_TYPE_CONTEXT: &str = "Context";
 
 pub fn parse_built_in_type(from: &str) -> BuiltInTypeOption {
     match from {
@@ -98,7 +140,6 @@ pub fn parse_built_in_type(from: &str) -> BuiltInTypeOption {
         SOURCE_TYPE_FLOAT => Some(float_32_built_in_type()),
         SOURCE_TYPE_F32 => Some(float_32_built_in_type()),
         SOURCE_TYPE_F64 => Some(float_64_built_in_type()),
-        SOURCE_TYPE_STRING => Some(string_built_in_type()),
         SOURCE_TYPE_BOOL => Some(bool_built_in_type()),
         SOURCE_TYPE_VOID => Some(void_built_in_type()),
         _=> None,
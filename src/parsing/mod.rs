@@ -12,7 +12,13 @@ use crate::strings::*;
 pub enum Operator {
     Add,
     Subtract,
-    Multiply
+    Multiply,
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual
 }
 
 pub fn add_operator() -> Operator {
@@ -27,11 +33,52 @@ pub fn multiply_operator() -> Operator {
     Operator::Multiply
 }
 
+pub fn equal_operator() -> Operator {
+    Operator::Equal
+}
+
+pub fn not_equal_operator() -> Operator {
+    Operator::NotEqual
+}
+
+pub fn less_than_operator() -> Operator {
+    Operator::LessThan
+}
+
+pub fn less_than_or_equal_operator() -> Operator {
+    Operator::LessThanOrEqual
+}
+
+pub fn greater_than_operator() -> Operator {
+    Operator::GreaterThan
+}
+
+pub fn greater_than_or_equal_operator() -> Operator {
+    Operator::GreaterThanOrEqual
+}
+
+pub fn get_operator_precedence(op: &Operator) -> usize {
+    match op {
+        Operator::Multiply => 3,
+        Operator::Add | Operator::Subtract => 2,
+        _ => 1
+    }
+}
+
+pub fn is_comparison_operator(op: &Operator) -> bool {
+    matches!(
+        op,
+        Operator::Equal | Operator::NotEqual | Operator::LessThan | Operator::LessThanOrEqual | Operator::GreaterThan | Operator::GreaterThanOrEqual
+    )
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum Scope {
     Unknown,
     Local,
-    Global
+    Global,
+    GlobalVariable,
+    Procedure
 }
 
 pub fn unknown_scope() -> Scope {
@@ -46,6 +93,28 @@ pub fn global_scope() -> Scope {
     Scope::Global
 }
 
+pub fn global_variable_scope() -> Scope {
+    Scope::GlobalVariable
+}
+
+pub fn procedure_scope() -> Scope {
+    Scope::Procedure
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Visibility {
+    Export,
+    File
+}
+
+pub fn export_visibility() -> Visibility {
+    Visibility::Export
+}
+
+pub fn file_visibility() -> Visibility {
+    Visibility::File
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum ResolvableLiteral {
     Unresolved(UnresolvedLiteral),
@@ -0,0 +1,98 @@
+use crate::parsing::*;
+
+const ASM_REGISTER_NAMES: [&str; 16] = [
+    "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rsp", "rbp",
+    "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15"
+];
+
+pub fn parse_asm_directive(lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let brace = get_next_token(lexer);
+    if !is_open_brace(&brace.item) {
+        return Err(create_error(expected_open_brace_error(), brace.position));
+    }
+
+    let mut instructions = vec!();
+
+    loop {
+        let token = peek_next_token(lexer);
+
+        if is_close_brace(&token.item) {
+            eat_next_token(lexer);
+            return Ok(create_node(asm_item(instructions), position));
+        }
+
+        if token.item == SourceTokenItem::Eof {
+            return Err(create_error(expected_close_brace_error(), token.position));
+        }
+
+        instructions.push(parse_asm_instruction(lexer)?);
+    }
+}
+
+fn parse_asm_instruction(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
+    let token = get_next_token(lexer);
+
+    if let Some(mnemonic) = try_get_identifier(token.item) {
+        return Ok(create_node(asm_instruction_item(mnemonic, parse_asm_operands(lexer)?), token.position));
+    }
+
+    Err(create_error(expected_identifier_error(), token.position))
+}
+
+fn parse_asm_operands(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
+    let mut operands = vec!();
+
+    if is_line_terminiator(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        return Ok(operands);
+    }
+
+    loop {
+        operands.push(parse_asm_operand(lexer)?);
+
+        let next_token = peek_next_token(lexer);
+
+        if is_line_terminiator(&next_token.item) {
+            eat_next_token(lexer);
+            return Ok(operands);
+        }
+
+        if is_close_brace(&next_token.item) {
+            return Ok(operands);
+        }
+
+        if is_arg_separator(&next_token.item) {
+            eat_next_token(lexer);
+        } else {
+            return Err(create_error(expected_arg_separator_error(), next_token.position));
+        }
+    }
+}
+
+fn parse_asm_operand(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
+    let token = get_next_token(lexer);
+
+    match token.item {
+        SourceTokenItem::Identifier(name) if is_asm_register_name(&name) => Ok(create_node(asm_register_item(name), token.position)),
+        SourceTokenItem::Identifier(name) => Ok(create_node(unknown_scope_identifier_item(name), token.position)),
+        SourceTokenItem::Literal(literal) => Ok(create_node(literal_item(unresolved_resolvable_literal(literal)), token.position)),
+        SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
+        _ => Err(create_error(expected_asm_operand_error(), token.position))
+    }
+}
+
+fn is_asm_register_name(name: &str) -> bool {
+    ASM_REGISTER_NAMES.contains(&name)
+}
+
+pub fn asm_item(instructions: AbstractSyntaxChildNodes) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Asm { instructions }
+}
+
+pub fn asm_instruction_item(mnemonic: String, operands: AbstractSyntaxChildNodes) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::AsmInstruction { mnemonic, operands }
+}
+
+pub fn asm_register_item(name: String) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::AsmRegister(name)
+}
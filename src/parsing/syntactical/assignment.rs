@@ -1,5 +1,6 @@
 use crate::parsing::*;
 use crate::types::*;
+use crate::errors::*;
 
 pub fn parse_inferred_value_assignment(name: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
     parse_value_assignment(name, lexer, position, unresolved_resolvable_type())
@@ -9,13 +10,31 @@ fn parse_value_assignment(name: String, lexer: &mut Lexer, position: SourceFileP
     Ok(create_node(variable_declaration_item(name, parse_value_assignment_value(lexer)?, resolvable_type), position))
 }
 
+pub fn parse_assign_value_assignment(name: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let target = create_node(unknown_scope_identifier_item(name), position);
+    Ok(create_node(assignment_item(target, parse_value_assignment_value(lexer)?, unresolved_resolvable_type()), position))
+}
+
 pub fn parse_initialise_assignment(name: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
     if let Some(resolvable_type) = try_parse_type(lexer) {
         eat_next_token(lexer);
         if is_assign_value_assignment(&peek_next_token(lexer).item) {
             eat_next_token(lexer);
+
+            let token = peek_next_token(lexer);
+            if is_uninitialised_keyword(&token.item) {
+                eat_next_token(lexer);
+                return Ok(create_node(uninitialised_variable_declaration_item(name, resolvable_type, token.position), position));
+            }
+
             return parse_value_assignment(name, lexer, position, resolvable_type);
-        }        
+        }
+        
+        let token = peek_next_token(lexer);
+        if is_line_terminiator(&token.item) {
+            return Ok(create_node(variable_declaration_item(name, create_node(null_item(), token.position), resolvable_type), position));
+        }
+
         return Err(create_error(expected_assign_value_assignment_error(), get_next_token(lexer).position));
     }
     Err(create_error(expected_type_error(), get_next_token(lexer).position))
@@ -30,6 +49,7 @@ pub fn parse_value_assignment_value(lexer: &mut Lexer) -> AbstractSyntaxNodeResu
         SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
         SourceTokenItem::Eof => Ok(create_node(create_eof_item(), token.position)),
         SourceTokenItem::Keyword(keyword) => parse_value_assignment_keyword(keyword, lexer, token.position),
+        SourceTokenItem::Directive(Directive::Insert) => parse_insert_expression(lexer, token.position),
         _ => Err(create_error(unimplemented_error(), token.position)),
     }
 }
@@ -43,6 +63,8 @@ fn parse_value_assignment_keyword(keyword: Keyword, lexer: &mut Lexer, position:
 }
 
 fn parse_value_assignment_cast(lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let checked = parse_value_assignment_cast_mode(lexer)?;
+
     if !is_open_paren(&peek_next_token(lexer).item) {
         return Err(create_error(expected_open_paren_error(), get_next_token(lexer).position))
     }
@@ -57,16 +79,47 @@ fn parse_value_assignment_cast(lexer: &mut Lexer, position: SourceFilePosition)
         }
 
         eat_next_token(lexer);
-        return Ok(create_node(cast_item(cast_type, parse_value_assignment_value(lexer)?), position));
+        let expr = parse_value_assignment_value(lexer)?;
+
+        if checked {
+            return Ok(create_node(checked_cast_item(cast_type, expr), position));
+        }
+        return Ok(create_node(cast_item(cast_type, expr), position));
     }   
 
     Err(create_error(expected_type_error(), get_next_token(lexer).position))
 }
 
+fn parse_value_assignment_cast_mode(lexer: &mut Lexer) -> Result<bool, CompilationError> {
+    if !is_arg_separator(&peek_next_token(lexer).item) {
+        return Ok(false);
+    }
+
+    eat_next_token(lexer);
+
+    let token = get_next_token(lexer);
+    match try_get_identifier(token.item) {
+        Some(mode) if mode == "checked" => Ok(true),
+        _ => Err(create_error(expected_cast_mode_error(), token.position))
+    }
+}
+
 pub fn variable_declaration_item(name: String, value: AbstractSyntaxNode, type_id: ResolvableType) -> AbstractSyntaxNodeItem {
-    AbstractSyntaxNodeItem::VariableDeclaration { name, value, variable_type: type_id }
+    AbstractSyntaxNodeItem::VariableDeclaration { name, value, variable_type: type_id, uninitialised: false }
+}
+
+pub fn uninitialised_variable_declaration_item(name: String, type_id: ResolvableType, position: SourceFilePosition) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::VariableDeclaration { name, value: create_node(null_item(), position), variable_type: type_id, uninitialised: true }
+}
+
+pub fn assignment_item(target: AbstractSyntaxNode, value: AbstractSyntaxNode, assignment_type: ResolvableType) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Assignment { target, value, assignment_type }
 }
 
 pub fn cast_item(cast_type: ResolvableType, expr: AbstractSyntaxNode) -> AbstractSyntaxNodeItem {
-    AbstractSyntaxNodeItem::Cast { cast_type, expr }
+    AbstractSyntaxNodeItem::Cast { cast_type, expr, checked: false }
+}
+
+pub fn checked_cast_item(cast_type: ResolvableType, expr: AbstractSyntaxNode) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Cast { cast_type, expr, checked: true }
 }
\ No newline at end of file
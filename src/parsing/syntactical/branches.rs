@@ -0,0 +1,58 @@
+use crate::parsing::*;
+
+pub fn parse_if_statement(lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let condition = parse_rhs(lexer)?;
+    let body = parse_branch_body(lexer)?;
+    let mut else_body = vec!();
+
+    if is_else_keyword(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        let token = peek_next_token(lexer);
+        if token.item == SourceTokenItem::Keyword(Keyword::If) {
+            eat_next_token(lexer);
+            else_body.push(parse_if_statement(lexer, token.position)?);
+        } else {
+            else_body = parse_branch_body(lexer)?;
+        }
+    }
+
+    Ok(create_node(if_item(condition, body, else_body), position))
+}
+
+pub fn parse_while_statement(lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let condition = parse_rhs(lexer)?;
+    let body = parse_branch_body(lexer)?;
+    Ok(create_node(while_item(condition, body), position))
+}
+
+fn parse_branch_body(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
+    let brace = get_next_token(lexer);
+    if !is_open_brace(&brace.item) {
+        return Err(create_error(expected_open_brace_error(), brace.position));
+    }
+
+    let statements = parse_procedure_body_statements(lexer)?;
+
+    let brace = get_next_token(lexer);
+    if !is_close_brace(&brace.item) {
+        return Err(create_error(expected_close_brace_error(), brace.position));
+    }
+
+    Ok(statements)
+}
+
+pub fn get_branch_bodies(item: &AbstractSyntaxNodeItem) -> Vec<&AbstractSyntaxChildNodes> {
+    match item {
+        AbstractSyntaxNodeItem::If { body, else_body, .. } => vec!(body, else_body),
+        AbstractSyntaxNodeItem::While { body, .. } => vec!(body),
+        _ => vec!()
+    }
+}
+
+pub fn if_item(condition: AbstractSyntaxNode, body: AbstractSyntaxChildNodes, else_body: AbstractSyntaxChildNodes) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::If { condition, body, else_body }
+}
+
+pub fn while_item(condition: AbstractSyntaxNode, body: AbstractSyntaxChildNodes) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::While { condition, body }
+}
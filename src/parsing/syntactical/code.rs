@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use crate::{parsing::*, types::*};
+
+pub fn parse_code_directive(filename: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let brace = get_next_token(lexer);
+    if !is_open_brace(&brace.item) {
+        return Err(create_error(expected_open_brace_error(), brace.position));
+    }
+
+    let mut units = create_compilation_units();
+
+    loop {
+        let token = peek_next_token(lexer);
+
+        if is_close_brace(&token.item) {
+            eat_next_token(lexer);
+            return Ok(create_node(code_item(units), position));
+        }
+
+        if token.item == SourceTokenItem::Eof {
+            return Err(create_error(expected_close_brace_error(), token.position));
+        }
+
+        if is_procedure_body_statement_in_code(lexer) {
+            let statement = parse_procedure_body_statement(lexer)?;
+            if is_line_terminiator(&peek_next_token(lexer).item) {
+                eat_next_token(lexer);
+            }
+            units.push(create_unit(filename.clone(), statement));
+            continue;
+        }
+
+        let node = parse_next_node(filename.clone(), lexer, &mut units)?;
+        units.push(create_unit(filename.clone(), node));
+    }
+}
+
+fn is_procedure_body_statement_in_code(lexer: &Lexer) -> bool {
+    let mut peek_lexer = lexer.clone();
+
+    match get_next_token(&mut peek_lexer).item {
+        SourceTokenItem::Keyword(_) => true,
+        SourceTokenItem::Identifier(_) => {
+            let next_token = peek_next_token(&peek_lexer);
+            !is_declaration_assignment(&next_token.item) && !is_initialise_assignment(&next_token.item)
+        },
+        _ => false
+    }
+}
+
+pub fn parse_code_constant(filename: String, name: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let directive = get_next_token(lexer);
+    let node = create_node(
+        constant_item(name, parse_code_directive(filename, lexer, directive.position)?, unresolved_resolvable_type()),
+        position
+    );
+
+    if is_line_terminiator(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+    }
+
+    Ok(node)
+}
+
+pub fn code_item(units: CompilationUnits) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Code { units }
+}
+
+pub fn try_get_code_units(item: &AbstractSyntaxNodeItem) -> Option<&CompilationUnits> {
+    if let AbstractSyntaxNodeItem::Code { units } = item {
+        return Some(units);
+    }
+    None
+}
+
+pub fn try_get_code_constant(item: &AbstractSyntaxNodeItem) -> Option<(&String, &CompilationUnits)> {
+    if let AbstractSyntaxNodeItem::Constant { name, value, .. } = item {
+        return try_get_code_units(value.item_ref()).map(|units| (name, units));
+    }
+    None
+}
+
+pub fn clone_code_units_with_new_ids(units: &CompilationUnits) -> CompilationUnits {
+    let new_ids: HashMap<CompilationUnitId, CompilationUnitId> = units
+        .iter()
+        .map(|unit| (unit.id, create_compilation_unit_id()))
+        .collect();
+
+    let mut cloned_units = units.clone();
+
+    for unit in &mut cloned_units {
+        unit.id = new_ids[&unit.id];
+        if let AbstractSyntaxNodeItem::ProcedureHeader { body: ProcedureBodyReference::Local(id), .. } = unit.tree.item_mut() {
+            if let Some(new_id) = new_ids.get(id) {
+                *id = *new_id;
+            }
+        }
+    }
+
+    cloned_units
+}
@@ -22,7 +22,8 @@ pub fn create_compilation_unit_id() -> CompilationUnitId {
 pub struct CompilationUnit {
     pub id: CompilationUnitId,
     pub filename: String,
-    pub tree: AbstractSyntaxNode
+    pub tree: AbstractSyntaxNode,
+    pub visibility: Visibility
 }
 
 pub fn create_unit(filename: String, tree: AbstractSyntaxNode) -> CompilationUnit {
@@ -30,6 +31,31 @@ pub fn create_unit(filename: String, tree: AbstractSyntaxNode) -> CompilationUni
         id: create_compilation_unit_id(),
         filename,
         tree,
+        visibility: export_visibility()
+    }
+}
+
+pub fn is_run_unit(unit: &CompilationUnit) -> bool {
+    matches!(unit.tree.item_ref(), AbstractSyntaxNodeItem::Run { .. }) ||
+        try_get_run_directive_constant(unit.tree.item_ref()).is_some()
+}
+
+pub fn is_assert_unit(unit: &CompilationUnit) -> bool {
+    matches!(unit.tree.item_ref(), AbstractSyntaxNodeItem::Assert { .. })
+}
+
+pub fn is_insert_unit(unit: &CompilationUnit) -> bool {
+    matches!(unit.tree.item_ref(), AbstractSyntaxNodeItem::Insert { .. }) ||
+        try_get_insert_directive_constant(unit.tree.item_ref()).is_some()
+}
+
+pub fn is_compile_time_unit(unit: &CompilationUnit) -> bool {
+    is_run_unit(unit) || is_assert_unit(unit) || is_insert_unit(unit)
+}
+
+pub fn set_units_visibility(units: &mut [CompilationUnit], visibility: &Visibility) {
+    for unit in units {
+        unit.visibility = visibility.clone();
     }
 }
 
@@ -27,6 +27,7 @@ fn parse_constant_value(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
     match token.item {
         SourceTokenItem::Directive(directive) => parse_const_directive(directive, lexer, token.position),
         SourceTokenItem::Literal(literal) => parse_literal(literal, lexer, token.position),
+        SourceTokenItem::Identifier(name) => parse_remainder_of_identifier(name, lexer, token.position),
         SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
         SourceTokenItem::Eof => Ok(create_node(create_eof_item(), token.position)),
         _ => Err(create_error(unimplemented_error(), token.position)),
@@ -1,5 +1,6 @@
 use crate::parsing::*;
 use crate::types::*;
+use crate::threading::*;
 
 pub fn parse_top_level_declaration(
     filename: String,
@@ -17,6 +18,10 @@ pub fn parse_top_level_declaration(
         eat_next_token(lexer);
         return parse_procedure_header(filename, name, lexer, position, units)
     }
+
+    if is_code_directive(&peek_next_token(lexer).item) {
+        return parse_code_constant(filename, name, lexer, position)
+    }
     
     parse_inferred_constant(name, lexer, position)
 }
@@ -27,7 +32,20 @@ pub fn parse_known_type_top_level_declaration(name: String, lexer: &mut Lexer, p
         if is_initialise_assignment( &peek_next_token(lexer).item) {
             eat_next_token(lexer);
             return parse_constant(name, lexer, position, resolvable_type);
-        }        
+        }
+        if is_assign_value_assignment(&peek_next_token(lexer).item) {
+            eat_next_token(lexer);
+
+            if is_uninitialised_keyword(&peek_next_token(lexer).item) {
+                eat_next_token(lexer);
+                return parse_zero_initialised_global_variable(name, lexer, position, resolvable_type);
+            }
+
+            return parse_global_variable(name, lexer, position, resolvable_type);
+        }
+        if is_line_terminiator(&peek_next_token(lexer).item) {
+            return parse_zero_initialised_global_variable(name, lexer, position, resolvable_type);
+        }
         return Err(create_error(expected_initialise_assignment_error(), get_next_token(lexer).position));
     }
     Err(create_error(expected_type_error(), get_next_token(lexer).position))
@@ -55,6 +73,22 @@ pub fn parse_declaration(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
     return Err(create_error(expected_declaration_name_error(), peek_next_token(lexer).position))     
 }
 
+pub fn parse_variadic_declaration(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
+    let declaration = parse_declaration(lexer)?;
+
+    if let AbstractSyntaxNodeItem::MemberDeclaration { name, member_type } = declaration.item_ref() {
+        if let ResolvableType::Resolved(element_type) = member_type {
+            if let Some(view_type) = array_view_runtime_type(element_type.as_ref().clone()) {
+                return Ok(create_node(member_declaration_item(name.clone(), resolved_resolvable_type(create_shareable(view_type))), declaration.position));
+            }
+        } else {
+            return Ok(create_node(member_declaration_item(name.clone(), unresolved_array_view_resolvable_type(member_type.clone())), declaration.position));
+        }
+    }
+
+    Err(create_error(unimplemented_error(), declaration.position))
+}
+
 pub fn member_declaration_item(name: String, type_id: ResolvableType) -> AbstractSyntaxNodeItem {
     AbstractSyntaxNodeItem::MemberDeclaration { name, member_type: type_id }
 }
\ No newline at end of file
@@ -1,9 +1,22 @@
-use crate::{parsing::*, strings::*};
+use crate::{parsing::*, types::*, strings::*};
 
-pub fn parse_directive(directive: Directive, lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+pub fn parse_directive(filename: String, directive: Directive, lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
     match directive {
         Directive::Run => parse_run_directive(lexer, position),
+        Directive::Insert => parse_insert_directive(filename, lexer, position),
         Directive::Load => parse_load_directive(lexer, position),
+        Directive::Import => parse_import_directive(lexer, position),
+        Directive::Assert => parse_assert_directive(lexer, position),
+        Directive::ScopeFile => parse_scope_directive(lexer, file_visibility(), position),
+        Directive::ScopeExport => parse_scope_directive(lexer, export_visibility(), position),
+        _ => Err(create_error(unexpected_directive_error(), position)),
+    }
+}
+
+pub fn parse_procedure_body_directive(directive: Directive, lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    match directive {
+        Directive::Asm => parse_asm_directive(lexer, position),
+        Directive::Insert => parse_insert_expression(lexer, position),
         _ => Err(create_error(unexpected_directive_error(), position)),
     }
 }
@@ -11,6 +24,8 @@ pub fn parse_directive(directive: Directive, lexer: &mut Lexer, position: Source
 pub fn parse_const_directive(directive: Directive, lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
     match directive {
         Directive::ForeignSystemLibrary => parse_foreign_system_library_directive(lexer, position),
+        Directive::Import => parse_import_directive(lexer, position),
+        Directive::Run => parse_run_directive(lexer, position),
         _ => Err(create_error(unexpected_directive_error(), position)),
     }
 }
@@ -31,6 +46,38 @@ pub fn parse_run_directive_expr(lexer: &mut Lexer)-> AbstractSyntaxNodeResult {
     }
 }
 
+fn parse_insert_directive(filename: String, lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    let token = peek_next_token(lexer);
+
+    let node = if is_code_directive(&token.item) {
+        eat_next_token(lexer);
+        create_node(insert_directive_item(parse_code_directive(filename, lexer, token.position)?), position)
+    } else {
+        parse_insert_expression(lexer, position)?
+    };
+
+    if is_line_terminiator(&peek_next_token(lexer).item) {
+        eat_next_token(lexer)
+    }
+
+    Ok(node)
+}
+
+pub fn parse_insert_expression(lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    let token = peek_next_token(lexer);
+
+    let expr = if is_code_directive(&token.item) {
+        return Err(create_error(unexpected_directive_error(), token.position));
+    } else if let Some(literal) = try_get_string_literal(&token.item) {
+        eat_next_token(lexer);
+        create_node(literal_item(unresolved_resolvable_literal(unresolved_string_literal(literal))), token.position)
+    } else {
+        parse_run_directive_expr(lexer)?
+    };
+
+    Ok(create_node(insert_directive_item(expr), position))
+}
+
 fn parse_load_directive(lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
     Ok(create_node(load_directive_item(parse_load_file(lexer)?), position))
 }
@@ -43,6 +90,40 @@ pub fn parse_load_file(lexer: &mut Lexer)-> AbstractSyntaxNodeResult {
     Err(create_error(expected_file_name_error(), token.position))
 }
 
+fn parse_import_directive(lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    Ok(create_node(import_directive_item(parse_import_module(lexer)?), position))
+}
+
+pub fn parse_import_module(lexer: &mut Lexer)-> AbstractSyntaxNodeResult {
+    let token = get_next_token(lexer);
+    if let Some(literal) = try_get_string_literal(&token.item) {
+        return parse_ending_string_literal(lexer, literal, token.position);
+    }
+    Err(create_error(expected_module_name_error(), token.position))
+}
+
+fn parse_assert_directive(lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    let expr = parse_run_directive_expr(lexer)?;
+    let message = parse_assert_message(lexer, position)?;
+    Ok(create_node(assert_directive_item(expr, message), position))
+}
+
+fn parse_assert_message(lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    let token = peek_next_token(lexer);
+    if let Some(literal) = try_get_string_literal(&token.item) {
+        eat_next_token(lexer);
+        return parse_ending_string_literal(lexer, literal, token.position);
+    }
+    parse_ending_string_literal(lexer, to_byte_string(""), position)
+}
+
+fn parse_scope_directive(lexer: &mut Lexer, visibility: Visibility, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
+    if is_line_terminiator(&peek_next_token(lexer).item) {
+        eat_next_token(lexer)
+    }
+    Ok(create_node(visibility_directive_item(visibility), position))
+}
+
 fn parse_foreign_system_library_directive(lexer: &mut Lexer, position: SourceFilePosition)-> AbstractSyntaxNodeResult {
     Ok(create_node(foreign_system_library_directive_item(parse_foreign_system_library(lexer)?), position))
 }
@@ -67,10 +148,96 @@ pub fn run_directive_item(expr: AbstractSyntaxNode)-> AbstractSyntaxNodeItem {
     AbstractSyntaxNodeItem::Run { expr }
 }
 
+pub fn try_get_run_directive_constant(item: &AbstractSyntaxNodeItem) -> Option<(&String, &ResolvableType)> {
+    if let AbstractSyntaxNodeItem::Constant { name, value, constant_type } = item {
+        if let AbstractSyntaxNodeItem::Run { .. } = value.item_ref() {
+            return Some((name, constant_type));
+        }
+    }
+    None
+}
+
+pub fn insert_directive_item(expr: AbstractSyntaxNode)-> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Insert { expr }
+}
+
+pub fn try_get_insert_directive_expr(item: &AbstractSyntaxNodeItem) -> Option<&AbstractSyntaxNode> {
+    if let AbstractSyntaxNodeItem::Insert { expr } = item {
+        return Some(expr);
+    }
+    None
+}
+
+pub fn set_insert_directive_source(insert: &mut AbstractSyntaxNode, source: ByteString) {
+    match insert.item_mut() {
+        AbstractSyntaxNodeItem::Insert { expr } =>
+            *expr = create_node(literal_item(unresolved_resolvable_literal(unresolved_string_literal(source))), expr.position),
+        AbstractSyntaxNodeItem::Constant { value, .. } =>
+            set_insert_directive_source(value, source),
+        _ => {}
+    }
+}
+
+pub fn try_get_insert_directive_constant(item: &AbstractSyntaxNodeItem) -> Option<(&String, &AbstractSyntaxNode)> {
+    if let AbstractSyntaxNodeItem::Constant { name, value, .. } = item {
+        return try_get_insert_directive_expr(value.item_ref()).map(|expr| (name, expr));
+    }
+    None
+}
+
+pub fn assert_directive_item(expr: AbstractSyntaxNode, message: AbstractSyntaxNode)-> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Assert { expr, message }
+}
+
 pub fn load_directive_item(file: AbstractSyntaxNode)-> AbstractSyntaxNodeItem {
     AbstractSyntaxNodeItem::Load { file }
 }
 
+pub fn try_get_load_directive_filename(item: &AbstractSyntaxNodeItem) -> Option<String> {
+    match item {
+        AbstractSyntaxNodeItem::Load { file } => match file.item_ref() {
+            AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(filename))) => Some(byte_string_to_string(filename)),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+pub fn import_directive_item(module: AbstractSyntaxNode)-> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Import { module }
+}
+
+pub fn try_get_import_directive(item: &AbstractSyntaxNodeItem) -> Option<(String, Option<String>)> {
+    match item {
+        AbstractSyntaxNodeItem::Import { module } => Some((try_get_import_module_name(module)?, None)),
+        AbstractSyntaxNodeItem::Constant { name, value, .. } => {
+            if let AbstractSyntaxNodeItem::Import { module } = value.item_ref() {
+                return Some((try_get_import_module_name(module)?, Some(name.clone())));
+            }
+            None
+        },
+        _ => None
+    }
+}
+
+fn try_get_import_module_name(module: &AbstractSyntaxNode) -> Option<String> {
+    if let AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(name))) = module.item_ref() {
+        return Some(byte_string_to_string(name));
+    }
+    None
+}
+
+pub fn visibility_directive_item(visibility: Visibility)-> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Visibility(visibility)
+}
+
+pub fn try_get_visibility_directive(item: &AbstractSyntaxNodeItem) -> Option<Visibility> {
+    match item {
+        AbstractSyntaxNodeItem::Visibility(visibility) => Some(visibility.clone()),
+        _ => None
+    }
+}
+
 pub fn foreign_system_library_directive_item(library: AbstractSyntaxNode)-> AbstractSyntaxNodeItem {
     AbstractSyntaxNodeItem::ForeignSystemLibrary { library }
 }
\ No newline at end of file
@@ -7,6 +7,7 @@ use crate::{
 pub enum ParseError {
     ExpectedFileName,
     ExpectedLibraryName,
+    ExpectedModuleName,
     ExpectedForeignLibraryIdentifier,
     ExpectedIdentifier,
     ExpectedDeclarationName,
@@ -16,8 +17,10 @@ pub enum ParseError {
     ExpectedEnclosure(Enclosure),
     ExpectedOperator,
     ExpectedType,
+    ExpectedCastMode,
     ExpectedLineTerminator,
     UnexpectedDirective,
+    ExpectedAsmOperand,
     TokenisationError(SourceTokenError),
     Unimplemented
 }
@@ -78,6 +81,18 @@ pub fn expected_close_paren_error() -> ParseError {
     ParseError::ExpectedEnclosure(Enclosure::Parentheses(EnclosureType::Close))
 }
 
+pub fn expected_open_brace_error() -> ParseError {
+    ParseError::ExpectedEnclosure(Enclosure::Brace(EnclosureType::Open))
+}
+
+pub fn expected_close_brace_error() -> ParseError {
+    ParseError::ExpectedEnclosure(Enclosure::Brace(EnclosureType::Close))
+}
+
+pub fn expected_close_bracket_error() -> ParseError {
+    ParseError::ExpectedEnclosure(Enclosure::Bracket(EnclosureType::Close))
+}
+
 pub fn expected_line_terminator_error() -> ParseError {
     ParseError::ExpectedLineTerminator
 }
@@ -86,6 +101,10 @@ pub fn expected_type_error() -> ParseError {
     ParseError::ExpectedType
 }
 
+pub fn expected_cast_mode_error() -> ParseError {
+    ParseError::ExpectedCastMode
+}
+
 pub fn expected_file_name_error() -> ParseError {
     ParseError::ExpectedFileName
 }
@@ -94,6 +113,15 @@ pub fn expected_library_name_error() -> ParseError {
     ParseError::ExpectedLibraryName
 }
 
+pub fn expected_module_name_error() -> ParseError {
+    ParseError::ExpectedModuleName
+}
+
 pub fn unexpected_directive_error() -> ParseError {
     ParseError::UnexpectedDirective
 }
+
+pub fn expected_asm_operand_error() -> ParseError {
+    ParseError::ExpectedAsmOperand
+}
+
@@ -4,7 +4,30 @@ use crate::types::*;
 pub fn parse_expression(lexer: &mut Lexer, lhs: AbstractSyntaxNode, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
     let op = parse_operator(lexer)?;
     let rhs_node = parse_rhs(lexer)?;
-    Ok(create_node(binary_expression_item(op, lhs, rhs_node, unresolved_resolvable_type()), position))
+    Ok(order_expression_by_precedence(op, lhs, rhs_node, position))
+}
+
+fn order_expression_by_precedence(op: AbstractSyntaxNode, lhs: AbstractSyntaxNode, rhs: AbstractSyntaxNode, position: SourceFilePosition) -> AbstractSyntaxNode {
+    if binds_at_least_as_tightly(&op, &rhs) {
+        if let AbstractSyntaxNodeItem::BinaryExpr { op: rhs_op, lhs: rhs_lhs, rhs: rhs_rhs, .. } = *rhs.item {
+            let arithmetic = order_expression_by_precedence(op, lhs, rhs_lhs, position);
+            return create_node(binary_expression_item(rhs_op, arithmetic, rhs_rhs, unresolved_resolvable_type()), position);
+        }
+    }
+    create_node(binary_expression_item(op, lhs, rhs, unresolved_resolvable_type()), position)
+}
+
+fn binds_at_least_as_tightly(op: &AbstractSyntaxNode, rhs: &AbstractSyntaxNode) -> bool {
+    if let (AbstractSyntaxNodeItem::Operator(op), AbstractSyntaxNodeItem::BinaryExpr { op: rhs_op, .. }) = (op.item_ref(), rhs.item_ref()) {
+        if let AbstractSyntaxNodeItem::Operator(rhs_op) = rhs_op.item_ref() {
+            return get_operator_precedence(op) >= get_operator_precedence(rhs_op);
+        }
+    }
+    false
+}
+
+pub fn is_comparison_operator_node(node: &AbstractSyntaxNode) -> bool {
+    matches!(node.item_ref(), AbstractSyntaxNodeItem::Operator(op) if is_comparison_operator(op))
 }
 
 pub fn parse_rhs(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
@@ -13,6 +36,7 @@ pub fn parse_rhs(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
     match token.item {
         SourceTokenItem::Identifier(name) => parse_identifier(name, lexer, token.position),
         SourceTokenItem::Literal(literal) => parse_literal(literal, lexer, token.position),
+        SourceTokenItem::Directive(Directive::Insert) => parse_insert_expression(lexer, token.position),
         SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
         SourceTokenItem::Eof => Ok(create_node(create_eof_item(), token.position)),
         _ => Err(create_error(unimplemented_error(), token.position)),
@@ -46,3 +70,10 @@ pub fn binary_expression_item(
 pub fn operator_item(op: Operator) -> AbstractSyntaxNodeItem {
     AbstractSyntaxNodeItem::Operator(op)
 }
+
+pub fn try_get_operator(item: &AbstractSyntaxNodeItem) -> Option<Operator> {
+    match item {
+        AbstractSyntaxNodeItem::Operator(op) => Some(*op),
+        _ => None
+    }
+}
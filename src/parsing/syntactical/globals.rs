@@ -0,0 +1,27 @@
+use crate::parsing::*;
+use crate::types::*;
+
+pub fn parse_global_variable(name: String, lexer: &mut Lexer, position: SourceFilePosition, resolvable_type: ResolvableType) -> AbstractSyntaxNodeResult {
+    let node = create_node(
+        global_variable_item(name, 
+            parse_value_assignment_value(lexer)?, 
+            resolvable_type
+        ),
+        position
+    );
+    
+    if is_line_terminiator(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+    }
+    
+    Ok(node)
+}
+
+pub fn parse_zero_initialised_global_variable(name: String, lexer: &mut Lexer, position: SourceFilePosition, resolvable_type: ResolvableType) -> AbstractSyntaxNodeResult {
+    let terminator = get_next_token(lexer);
+    Ok(create_node(global_variable_item(name, create_node(null_item(), terminator.position), resolvable_type), position))
+}
+
+pub fn global_variable_item(name: String, value: AbstractSyntaxNode, variable_type: ResolvableType) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::GlobalVariable { name, value, variable_type }
+}
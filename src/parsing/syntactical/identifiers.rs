@@ -37,6 +37,11 @@ pub fn parse_identifier(name: String, lexer: &mut Lexer, position: SourceFilePos
         return parse_initialise_assignment(name, lexer, position);
     }
 
+    if is_assign_value_assignment(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        return parse_assign_value_assignment(name, lexer, position);
+    }
+
     if is_period(&peek_next_token(lexer).item) {
         eat_next_token(lexer);
         return parse_struct_instance_access(name, lexer, position);
@@ -52,6 +57,11 @@ pub fn parse_remainder_of_identifier(name: String, lexer: &mut Lexer, position:
     }
 
     let node = create_node(unknown_scope_identifier_item(name), position);
+
+    if is_open_bracket(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        return parse_index_expression(node, lexer, position);
+    }
     
     if is_operator(&peek_next_token(lexer).item) {
         return parse_expression(lexer, node, position);
@@ -66,4 +76,12 @@ pub fn unknown_scope_identifier_item(name: String) -> AbstractSyntaxNodeItem {
 
 pub fn identifier_item(name: String, scope: Scope) -> AbstractSyntaxNodeItem {
     AbstractSyntaxNodeItem::Identifier { name, scope }
+}
+
+pub fn qualified_name(namespace: &str, name: &str) -> String {
+    format!("{}.{}", namespace, name)
+}
+
+pub fn try_split_qualified_name(name: &str) -> Option<(&str, &str)> {
+    name.split_once('.')
 }
\ No newline at end of file
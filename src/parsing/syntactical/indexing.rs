@@ -0,0 +1,36 @@
+use crate::parsing::*;
+use crate::types::*;
+
+pub fn parse_index_expression(instance: AbstractSyntaxNode, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let index = parse_rhs(lexer)?;
+
+    let bracket = get_next_token(lexer);
+    if !is_close_bracket(&bracket.item) {
+        return Err(create_error(expected_close_bracket_error(), bracket.position));
+    }
+
+    let node = create_node(index_expr_item(instance, index, unresolved_resolvable_type()), position);
+
+    if is_assign_value_assignment(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        return Ok(create_node(assignment_item(node, parse_value_assignment_value(lexer)?, unresolved_resolvable_type()), position));
+    }
+
+    if is_operator(&peek_next_token(lexer).item) {
+        return parse_expression(lexer, node, position);
+    }
+
+    Ok(node)
+}
+
+pub fn index_expr_item(
+    instance: AbstractSyntaxNode,
+    index: AbstractSyntaxNode,
+    index_expression_type: ResolvableType
+) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::IndexExpr {
+        instance,
+        index,
+        index_expression_type
+    }
+}
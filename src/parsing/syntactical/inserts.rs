@@ -0,0 +1,92 @@
+use crate::{parsing::*, types::*, utilities::*};
+
+pub fn hoist_computed_inserts(filename: &str, units: &mut CompilationUnits, first_new_unit: usize) {
+    let mut hoisted = create_compilation_units();
+
+    for unit in &mut units[first_new_unit..] {
+        if let AbstractSyntaxNodeItem::ProcedureBody { statements, .. } = unit.tree.item_mut() {
+            hoist_computed_inserts_in_nodes(filename, statements, &mut hoisted);
+        }
+    }
+
+    units.append(&mut hoisted);
+}
+
+fn hoist_computed_inserts_in_nodes(filename: &str, nodes: &mut AbstractSyntaxChildNodes, hoisted: &mut CompilationUnits) {
+    for node in nodes {
+        hoist_computed_inserts_in_node(filename, node, hoisted);
+    }
+}
+
+fn hoist_computed_inserts_in_node(filename: &str, node: &mut AbstractSyntaxNode, hoisted: &mut CompilationUnits) {
+    let position = node.position;
+
+    match node.item_mut() {
+        AbstractSyntaxNodeItem::Insert { expr } => {
+            if let AbstractSyntaxNodeItem::ProcedureCall { .. } = expr.item_ref() {
+                let name = format!("__insert_{:?}", create_compilation_unit_id());
+                let call = std::mem::replace(expr, create_node(unknown_scope_identifier_item(name.clone()), expr.position));
+                let constant = constant_item(name, create_node(insert_directive_item(call), position), unresolved_resolvable_type());
+                hoisted.push(create_unit(string(filename), create_node(constant, position)));
+            }
+        },
+        AbstractSyntaxNodeItem::VariableDeclaration { value: expr, .. } |
+        AbstractSyntaxNodeItem::Argument { expr, .. } |
+        AbstractSyntaxNodeItem::Cast { expr, .. } |
+        AbstractSyntaxNodeItem::MemberExpr { instance: expr, .. } =>
+            hoist_computed_inserts_in_node(filename, expr, hoisted),
+        AbstractSyntaxNodeItem::Assignment { target: lhs, value: rhs, .. } |
+        AbstractSyntaxNodeItem::BinaryExpr { lhs, rhs, .. } |
+        AbstractSyntaxNodeItem::IndexExpr { instance: lhs, index: rhs, .. } => {
+            hoist_computed_inserts_in_node(filename, lhs, hoisted);
+            hoist_computed_inserts_in_node(filename, rhs, hoisted);
+        },
+        AbstractSyntaxNodeItem::If { condition, body, else_body } => {
+            hoist_computed_inserts_in_node(filename, condition, hoisted);
+            hoist_computed_inserts_in_nodes(filename, body, hoisted);
+            hoist_computed_inserts_in_nodes(filename, else_body, hoisted);
+        },
+        AbstractSyntaxNodeItem::While { condition, body } => {
+            hoist_computed_inserts_in_node(filename, condition, hoisted);
+            hoist_computed_inserts_in_nodes(filename, body, hoisted);
+        },
+        AbstractSyntaxNodeItem::ProcedureCall { args: nodes, .. } |
+        AbstractSyntaxNodeItem::Return { args: nodes } =>
+            hoist_computed_inserts_in_nodes(filename, nodes, hoisted),
+        _ => {}
+    }
+}
+
+pub fn parse_inserted_statements(source: &str) -> AbstractSyntaxChildNodesResult {
+    let mut lexer = lex(source);
+    let mut statements = vec!();
+
+    loop {
+        let statement = parse_procedure_body_statement(&mut lexer)?;
+
+        if statement.item_ref() == &AbstractSyntaxNodeItem::Eof {
+            return Ok(statements);
+        }
+
+        statements.push(statement);
+
+        if is_line_terminiator(&peek_next_token(&lexer).item) {
+            eat_next_token(&mut lexer)
+        }
+    }
+}
+
+pub fn parse_inserted_expression(source: &str) -> AbstractSyntaxNodeResult {
+    parse_value_assignment_value(&mut lex(source))
+}
+
+pub fn code_units_to_statements(units: &CompilationUnits) -> AbstractSyntaxChildNodes {
+    units
+        .iter()
+        .map(|unit| match unit.tree.item_ref() {
+            AbstractSyntaxNodeItem::GlobalVariable { name, value, variable_type } =>
+                create_node(variable_declaration_item(name.clone(), value.clone(), variable_type.clone()), unit.tree.position),
+            _ => unit.tree.clone()
+        })
+        .collect()
+}
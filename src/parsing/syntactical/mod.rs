@@ -11,7 +11,13 @@ mod compilation_units;
 mod termination;
 mod assignment;
 mod constants;
+mod globals;
 mod structs;
+mod asm;
+mod code;
+mod branches;
+mod indexing;
+mod inserts;
 
 pub use parser::*;
 pub use expressions::*;
@@ -26,4 +32,10 @@ pub use compilation_units::*;
 pub use termination::*;
 pub use assignment::*;
 pub use constants::*;
-pub use structs::*;
\ No newline at end of file
+pub use globals::*;
+pub use structs::*;
+pub use asm::*;
+pub use code::*;
+pub use branches::*;
+pub use indexing::*;
+pub use inserts::*;
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::parsing::*;
 use crate::acting::*;
 use crate::compilation::*;
 use crate::utilities::*;
+use crate::types::*;
 use crate::errors::*;
 
 pub struct ParserActor<T: FileRead> {
@@ -39,7 +40,7 @@ fn handle_parse_file<T: FileRead>(compiler: &CompilationActorHandle, error_repor
 }
 
 fn parse_file<T: FileRead>(compiler: &CompilationActorHandle, error_reporter: &CompilationActorHandle, file_reader: &T, filename: &str) -> CompilationUnits {
-    let (units, errors) = match file_reader.read_file_to_string(filename) {
+    let (units, errors) = match read_source_file(file_reader, filename) {
         Ok(file_content) => parse(string(filename), &file_content),
         Err(_) => create_units_and_errors_for_file_not_found(string(filename))
     };
@@ -47,6 +48,13 @@ fn parse_file<T: FileRead>(compiler: &CompilationActorHandle, error_reporter: &C
     return units;
 }
 
+fn read_source_file<T: FileRead>(file_reader: &T, filename: &str) -> std::io::Result<String> {
+    if filename == PRELOAD_FILE_NAME {
+        return Ok(string(PRELOAD_SOURCE));
+    }
+    file_reader.read_file_to_string(filename)
+}
+
 fn create_units_and_errors_for_file_not_found(filename: String) -> (CompilationUnits, CompilationErrors) {
     let mut units = create_compilation_units();
     let mut errors = create_compilation_errors(filename.clone());
@@ -60,15 +68,24 @@ pub fn parse(filename: String, input: &str) -> (CompilationUnits, CompilationErr
     let mut lexer = lex(input);
     let mut units = create_compilation_units();
     let mut errors = create_compilation_errors(filename.clone());
+    let mut visibility = export_visibility();
         
     loop {
+        let first_new_unit = units.len();
         match parse_next_node(filename.clone(), &mut lexer, &mut units) {
             Ok(node) => {
                 if is_eof_node(&node) {
                     break;
                 }      
+
+                if let Some(new_visibility) = try_get_visibility_directive(node.item_ref()) {
+                    visibility = new_visibility;
+                    continue;
+                }
                 
                 units.push(create_unit(filename.clone(), node));                  
+                hoist_computed_inserts(&filename, &mut units, first_new_unit);
+                set_units_visibility(&mut units[first_new_unit..], &visibility);
             }
             Err(error) => {
                 units.push(create_unit(filename.clone(), create_error_node(&error)));
@@ -90,7 +107,7 @@ pub fn parse_next_node(filename: String, lexer: &mut Lexer, units: &mut Compilat
 
     match token.item {
         SourceTokenItem::Identifier(name) => parse_top_level_identifier(filename, name, lexer, token.position, units),
-        SourceTokenItem::Directive(name) => parse_directive(name, lexer, token.position),
+        SourceTokenItem::Directive(name) => parse_directive(filename, name, lexer, token.position),
         SourceTokenItem::Literal(literal) => parse_literal(literal, lexer, token.position),
         SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
         SourceTokenItem::Eof => Ok(create_node(create_eof_item(), token.position)),
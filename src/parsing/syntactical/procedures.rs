@@ -5,7 +5,8 @@ use crate::types::*;
 pub enum ProcedureBodyReference {
     Unknown,
     Local(CompilationUnitId),
-    Foerign(AbstractSyntaxNode)
+    Foerign(AbstractSyntaxNode),
+    Expand(AbstractSyntaxNode)
 }
 
 pub fn unknown_procedure_body_reference() -> ProcedureBodyReference {
@@ -20,13 +21,30 @@ pub fn foreign_procedure_body_reference(foreign_system_library: AbstractSyntaxNo
     ProcedureBodyReference::Foerign(foreign_system_library)
 }
 
+pub fn expand_procedure_body_reference(body: AbstractSyntaxNode) -> ProcedureBodyReference {
+    ProcedureBodyReference::Expand(body)
+}
+
+pub fn try_get_expand_procedure_body(body: &ProcedureBodyReference) -> Option<&AbstractSyntaxNode> {
+    if let ProcedureBodyReference::Expand(body) = body {
+        return Some(body);
+    }
+    None
+}
+
 pub fn parse_procedure_call(name: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
     let arguments = parse_procedure_call_args(lexer)?;
     
     assert!(is_close_paren(&peek_next_token(lexer).item));
     eat_next_token(lexer);
 
-    Ok(create_node(procedure_call_item(name, arguments, unresolved_resolvable_type()), position))
+    let node = create_node(procedure_call_item(name, arguments, unresolved_resolvable_type()), position);
+
+    if is_operator(&peek_next_token(lexer).item) {
+        return parse_expression(lexer, node, position);
+    }
+
+    Ok(node)
 }
 
 fn parse_procedure_call_args(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
@@ -67,12 +85,25 @@ pub fn parse_procedure_call_arg_expr(lexer: &mut Lexer) -> AbstractSyntaxNodeRes
         SourceTokenItem::Keyword(keyword) => parse_procedure_call_keyword(keyword, token.position),
         SourceTokenItem::Identifier(name) => parse_identifier(name, lexer, token.position),
         SourceTokenItem::Literal(literal) => parse_literal(literal, lexer, token.position),
+        SourceTokenItem::Type(built_in_type) => Ok(create_node(type_item(built_in_resolvable_type(built_in_type, false)), token.position)),
+        SourceTokenItem::Pointer => parse_procedure_call_pointer_type(lexer, token.position),
+        SourceTokenItem::Directive(Directive::Insert) => parse_insert_expression(lexer, token.position),
         SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
         SourceTokenItem::Eof => Ok(create_node(create_eof_item(), token.position)),
         _ => Err(create_error(unimplemented_error(), token.position)),
     }
 }
 
+fn parse_procedure_call_pointer_type(lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let token = get_next_token(lexer);
+
+    match token.item {
+        SourceTokenItem::Type(built_in_type) => Ok(create_node(type_item(built_in_resolvable_type(built_in_type, true)), position)),
+        SourceTokenItem::Identifier(name) => Ok(create_node(type_item(unresolved_pointer_resolvable_type(unresolved_named_resolvable_type(name))), position)),
+        _ => Err(create_error(expected_type_error(), token.position)),
+    }
+}
+
 fn parse_procedure_call_keyword(keyword: Keyword, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
     match keyword {
         Keyword::Null => Ok(create_node(null_item(), position)),
@@ -91,6 +122,15 @@ pub fn parse_procedure_header(filename: String, name: String, lexer: &mut Lexer,
 
     let mut body_ref = unknown_procedure_body_reference();
 
+    if is_expand_directive(&peek_next_token(lexer).item) {
+        eat_next_token(lexer);
+        let brace = peek_next_token(lexer);
+        if !is_open_brace(&brace.item) {
+            return Err(create_error(expected_open_brace_error(), brace.position));
+        }
+        body_ref = expand_procedure_body_reference(parse_procedure_body(lexer, name.clone(), args.clone(), return_types.clone())?);
+    }
+
     if is_open_brace(&peek_next_token(lexer).item) {
         let body = create_unit(
             filename, 
@@ -120,6 +160,17 @@ fn parse_procedure_args(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
     }
 
     loop {
+        if is_left_inclusive_range(&peek_next_token(lexer).item) {
+            eat_next_token(lexer);
+            args.push(parse_variadic_declaration(lexer)?);
+
+            let next_token = peek_next_token(lexer);
+            if !is_close_paren(&next_token.item) {
+                return Err(create_error(expected_close_paren_error(), next_token.position));
+            }
+            return Ok(args)
+        }
+
         args.push(parse_declaration(lexer)?);
 
         let next_token = peek_next_token(lexer);
@@ -161,6 +212,10 @@ fn parse_procedure_return_types(lexer: &mut Lexer) -> AbstractSyntaxChildNodesRe
             return Ok(returns)
         }
 
+        if is_expand_directive(&next_token.item) {
+            return Ok(returns)
+        }
+
         if is_arg_separator(&next_token.item) {
             eat_next_token(lexer);
         } else {
@@ -210,7 +265,7 @@ fn parse_foreign_library_identifier(lexer: &mut Lexer) -> AbstractSyntaxNodeResu
     Err(create_error(expected_foreign_library_identifier_error(), token.position))
 }
 
-fn parse_procedure_body_statements(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
+pub fn parse_procedure_body_statements(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
     if is_close_brace(&peek_next_token(lexer).item) {
         return Ok(vec!());
     }
@@ -224,9 +279,15 @@ fn parse_procedure_body_statements(lexer: &mut Lexer) -> AbstractSyntaxChildNode
             eat_next_token(lexer)
         }
 
-        if is_close_brace(&peek_next_token(lexer).item) {
+        let next_token = peek_next_token(lexer);
+
+        if is_close_brace(&next_token.item) {
             return Ok(statements);
         }
+
+        if next_token.item == SourceTokenItem::Eof {
+            return Err(create_error(expected_close_brace_error(), next_token.position));
+        }
     }
 }
 
@@ -236,6 +297,7 @@ pub fn parse_procedure_body_statement(lexer: &mut Lexer) -> AbstractSyntaxNodeRe
     match token.item {
         SourceTokenItem::Keyword(keyword) => parse_procedure_body_keyword(keyword, lexer, token.position),
         SourceTokenItem::Identifier(name) => parse_identifier(name, lexer, token.position),
+        SourceTokenItem::Directive(directive) => parse_procedure_body_directive(directive, lexer, token.position),
         SourceTokenItem::Error(error) => Err(create_error(tokenisation_error(error), token.position)),
         SourceTokenItem::Eof => Ok(create_node(create_eof_item(), token.position)),
         _ => Err(create_error(unimplemented_error(), token.position)),
@@ -245,6 +307,8 @@ pub fn parse_procedure_body_statement(lexer: &mut Lexer) -> AbstractSyntaxNodeRe
 fn parse_procedure_body_keyword(keyword: Keyword, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
     match keyword {
         Keyword::Return => parse_return_statement(lexer, position),
+        Keyword::If => parse_if_statement(lexer, position),
+        Keyword::While => parse_while_statement(lexer, position),
         _ => Err(create_error(unimplemented_error(), position)),
     }
 }
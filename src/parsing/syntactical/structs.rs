@@ -53,12 +53,27 @@ fn parse_struct_fields(lexer: &mut Lexer) -> AbstractSyntaxChildNodesResult {
 }
 
 pub fn parse_struct_instance_access(name: String, lexer: &mut Lexer, position: SourceFilePosition) -> AbstractSyntaxNodeResult {
+    let member = parse_struct_instance_member(lexer)?;
+
+    if is_open_paren(&peek_next_token(lexer).item) {
+        if let Some(member_name) = try_get_member_name(member.item_ref()) {
+            let name = qualified_name(&name, member_name);
+            eat_next_token(lexer);
+            return parse_procedure_call(name, lexer, position);
+        }
+    }
+
     let instance = create_node(
         instance_item(name, unresolved_resolvable_type(), unknown_scope()),
         position
     );
-    let member = parse_struct_instance_member(lexer)?;
-    Ok(create_node(member_expr_item(instance, member, unresolved_resolvable_type()), position))
+    let node = create_node(member_expr_item(instance, member, unresolved_resolvable_type()), position);
+
+    if is_operator(&peek_next_token(lexer).item) {
+        return parse_expression(lexer, node, position);
+    }
+
+    Ok(node)
 }
 
 pub fn parse_struct_instance_member(lexer: &mut Lexer) -> AbstractSyntaxNodeResult {
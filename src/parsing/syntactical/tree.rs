@@ -27,7 +27,12 @@ impl AbstractSyntaxNode {
 pub enum AbstractSyntaxNodeItem {
     Run { expr: AbstractSyntaxNode },
     Load { file: AbstractSyntaxNode },
+    Import { module: AbstractSyntaxNode },
+    Assert { expr: AbstractSyntaxNode, message: AbstractSyntaxNode },
+    Insert { expr: AbstractSyntaxNode },
+    Code { units: CompilationUnits },
     ForeignSystemLibrary { library: AbstractSyntaxNode },
+    Visibility(Visibility),
     ProcedureHeader {
         name: String,
         args: AbstractSyntaxChildNodes,
@@ -58,6 +63,11 @@ pub enum AbstractSyntaxNodeItem {
         member: AbstractSyntaxNode,
         member_expression_type: ResolvableType
     },
+    IndexExpr {
+        instance: AbstractSyntaxNode,
+        index: AbstractSyntaxNode,
+        index_expression_type: ResolvableType
+    },
     Member {
         name: String,
         member_type: ResolvableType
@@ -72,23 +82,59 @@ pub enum AbstractSyntaxNodeItem {
         arg_type: ResolvableType
     },
     Null,
+    Asm {
+        instructions: AbstractSyntaxChildNodes
+    },
+    AsmInstruction {
+        mnemonic: String,
+        operands: AbstractSyntaxChildNodes
+    },
+    AsmRegister(String),
     Return {
         args: AbstractSyntaxChildNodes
     },
+    If {
+        condition: AbstractSyntaxNode,
+        body: AbstractSyntaxChildNodes,
+        else_body: AbstractSyntaxChildNodes
+    },
+    While {
+        condition: AbstractSyntaxNode,
+        body: AbstractSyntaxChildNodes
+    },
     Constant {
         name: String,
         value: AbstractSyntaxNode,
         constant_type: ResolvableType
     },
-    VariableDeclaration {
+    GlobalVariable {
         name: String,
         value: AbstractSyntaxNode,
         variable_type: ResolvableType
     },
+    VariableDeclaration {
+        name: String,
+        value: AbstractSyntaxNode,
+        variable_type: ResolvableType,
+        uninitialised: bool
+    },
+    Assignment {
+        target: AbstractSyntaxNode,
+        value: AbstractSyntaxNode,
+        assignment_type: ResolvableType
+    },
     Cast {
         cast_type: ResolvableType,
+        expr: AbstractSyntaxNode,
+        checked: bool
+    },
+    Boxed {
+        boxed_type: ResolvableType,
         expr: AbstractSyntaxNode
     },
+    VariadicArgs {
+        args: AbstractSyntaxChildNodes
+    },
     BinaryExpr {
         op: AbstractSyntaxNode,
         lhs: AbstractSyntaxNode,
@@ -96,6 +142,7 @@ pub enum AbstractSyntaxNodeItem {
         expression_type: ResolvableType,
     },
     Type(ResolvableType),
+    TypeInfo(ResolvableType),
     Literal(ResolvableLiteral),
     Identifier{ name: String, scope: Scope },
     Operator(Operator),
@@ -1,7 +1,8 @@
-
 use crate::parsing::*;
 use crate::acting::*;
 use crate::compilation::*;
+use crate::threading::*;
+use crate::types::*;
 use crate::utilities::*;
 
 pub struct SizingActor {    
@@ -37,19 +38,59 @@ fn handle_perform_sizing(
     mut unit: CompilationUnit, 
     has_prior_errors: bool
 ) -> AfterReceiveAction {
-    perform_sizing(ctx, type_repository, &mut unit, has_prior_errors);
-    notify_compiler_unit_is_sized(&compiler, unit);    
+    let sized_types = perform_sizing(ctx, type_repository, &mut unit, has_prior_errors);
+    notify_compiler_unit_is_sized(&compiler, sized_types, unit);    
     shutdown_after_receive()
 }
 
-fn notify_compiler_unit_is_sized(compiler: &CompilationActorHandle, unit: CompilationUnit) {
-    send_message_to_actor(compiler, create_unit_sized_event(unit));
+fn notify_compiler_unit_is_sized(compiler: &CompilationActorHandle, sized_types: RuntimeTypePointers, unit: CompilationUnit) {
+    send_message_to_actor(compiler, create_unit_sized_event(sized_types, unit));
 }
 
 pub fn perform_sizing(
     _ctx: &CompilationMessageContext,
     _type_repository: &CompilationActorHandle,
-    _unit: &mut CompilationUnit,
-    _has_prior_errors: bool
-) {
-}
\ No newline at end of file
+    unit: &mut CompilationUnit,
+    has_prior_errors: bool
+) -> RuntimeTypePointers {
+    let mut sized_types = vec!();
+
+    if has_prior_errors {
+        return sized_types;
+    }
+
+    if let AbstractSyntaxNodeItem::Struct { name, fields } = unit.tree.item_ref() {
+        if let Some(sized_type) = perform_sizing_for_struct(unit.id, &unit.filename, name, fields) {
+            sized_types.push(sized_type);
+        }
+    }
+
+    sized_types
+}
+
+fn perform_sizing_for_struct(unit_id: CompilationUnitId, filename: &str, name: &str, fields: &AbstractSyntaxChildNodes) -> OptionalRuntimeTypePointer {
+    let mut members = vec!();
+
+    for field in fields {
+        if let AbstractSyntaxNodeItem::MemberDeclaration { name, member_type } = field.item_ref() {
+            members.push(runtime_type_member(name.clone(), try_get_resolved_runtime_type_pointer(member_type)?));
+        }
+    }
+
+    if let Some(built_in_type) = try_get_preload_built_in_type(filename, name) {
+        return Some(create_shareable(preload_struct_runtime_type(built_in_type, members)));
+    }
+
+    let size_in_bytes = get_members_size(&members);
+
+    Some(
+        create_shareable(
+            create_type(
+                user_defined_runtime_type_id(unit_id),
+                string(name),
+                struct_type_item(members),
+                resolved_type_size(size_in_bytes)
+            )
+        )
+    )
+}
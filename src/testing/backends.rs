@@ -5,11 +5,28 @@ use crate::backends::*;
 pub struct TestBackend; 
 
 impl BackendBuild for TestBackend {
-    fn build_backend(&mut self, _ir: IntermediateRepresentation, _has_prior_errors: bool) -> BackendErrorResult {
+    fn build_backend(&mut self, _file_name: &str, _irs: IntermediateRepresentations, _has_prior_errors: bool) -> BackendErrorResult {
         Ok(())
     }
 }
 
 pub fn create_test_backend() -> TestBackend {
     TestBackend
-}
\ No newline at end of file
+}
+
+pub fn read_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes(bytes[at..at + 2].try_into().unwrap())
+}
+
+pub fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+pub fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+pub fn read_name(bytes: &[u8], at: usize) -> String {
+    let length = bytes[at..].iter().position(|byte| *byte == 0).unwrap();
+    String::from_utf8(bytes[at..at + length].to_vec()).unwrap()
+}
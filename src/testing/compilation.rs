@@ -9,6 +9,9 @@ use crate::typing::*;
 use crate::types::*;
 use crate::acting::*;
 use crate::errors::*;
+use crate::utilities::*;
+use crate::interpretation::*;
+use crate::strings::*;
 
 
 pub fn compile_source_and_get_intemediate_representation(source: &str) -> Vec<IntermediateRepresentation> {
@@ -24,7 +27,7 @@ pub fn compile_file_and_get_intemediate_representation(file_path: &str, reader:
     loop {
         let next_message = message_receiver.recv().unwrap();
         match next_message {
-            CompilationMessage::ByteCodeBuilt { code, .. } => result.push(code),
+            CompilationMessage::ByteCodeBuilt { code, .. } if !is_preload_file(&code.filename) => result.push(code),
             CompilationMessage::CompilationComplete => break,           
             _ => {}
         }
@@ -33,6 +36,85 @@ pub fn compile_file_and_get_intemediate_representation(file_path: &str, reader:
     return result
 }
 
+pub fn compile_source_and_get_executed_intemediate_representation(source: &str) -> Vec<IntermediateRepresentation> {
+    let (file_path, reader) = add_source_to_test_file_system(source);    
+    compile_file_with_module_paths_and_get_executed_intemediate_representation(file_path, vec!(), reader)
+}
+
+pub fn compile_file_with_module_paths_and_get_executed_intemediate_representation(
+    file_path: &str,
+    module_paths: Vec<String>,
+    reader: MockFileReader
+) -> Vec<IntermediateRepresentation> {
+    let message_receiver = compile_with_module_paths_and_get_message_receiver(file_path, module_paths, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::ByteCodeExecuted { code, .. } if !is_preload_file(&code.filename) => result.push(code),
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    result
+}
+
+pub fn compile_file_with_module_paths_and_get_standard_output(
+    file_path: &str,
+    module_paths: Vec<String>,
+    reader: MockFileReader
+) -> String {
+    let message_receiver = compile_with_module_paths_and_get_message_receiver(file_path, module_paths, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::ByteCodeExecuted { requests, .. } => {
+                for request in requests {
+                    if let CompilerRequest::WriteStandardOutput(output) = request {
+                        result.extend(output);
+                    }
+                }
+            },
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    byte_string_to_string(&result)
+}
+
+pub fn compile_source_and_get_intercepted_messages(source: &str) -> Vec<InterceptedMessage> {
+    let (file_path, reader) = add_source_to_test_file_system(source);    
+    compile_file_and_get_intercepted_messages(file_path, reader)
+}
+
+pub fn compile_file_and_get_intercepted_messages(file_path: &str, reader: MockFileReader) -> Vec<InterceptedMessage> {
+    let message_receiver = compile_and_get_message_receiver(file_path, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::InterceptedMessageDelivered { message, .. } => result.push(message),
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    result
+}
+
+fn is_preload_file(file_name: &str) -> bool {
+    file_name == PRELOAD_FILE_NAME
+}
+
 pub fn get_first_ir_with_byte_code_named<'a>(irs: &'a Vec<IntermediateRepresentation>, name: &str) -> &'a IntermediateRepresentation {
     irs
         .iter()
@@ -41,6 +123,13 @@ pub fn get_first_ir_with_byte_code_named<'a>(irs: &'a Vec<IntermediateRepresenta
         .unwrap()
 }
 
+pub fn find_baked_values(irs: &IntermediateRepresentations) -> Vec<ByteCodeDataItems> {
+    irs.iter()
+        .filter(|ir| !ir.data.items.is_empty())
+        .map(|ir| ir.data.items.clone())
+        .collect()
+}
+
 pub fn get_first_ir_named<'a>(irs: &'a Vec<IntermediateRepresentation>, name: &str) -> &'a IntermediateRepresentation {
     irs
         .iter()
@@ -50,13 +139,86 @@ pub fn get_first_ir_named<'a>(irs: &'a Vec<IntermediateRepresentation>, name: &s
 }
 
 pub fn compile_and_get_message_receiver(file_path: &str, file_reader: MockFileReader) -> Receiver<CompilationMessage> {    
+    compile_with_module_paths_and_get_message_receiver(file_path, vec!(), file_reader)
+}
+
+pub fn compile_with_module_paths_and_get_message_receiver(file_path: &str, module_paths: Vec<String>, file_reader: MockFileReader) -> Receiver<CompilationMessage> {    
+    compile_for_target_and_get_message_receiver(file_path, module_paths, windows_target(), file_reader)
+}
+
+pub fn compile_for_target_and_get_message_receiver(
+    file_path: &str,
+    module_paths: Vec<String>,
+    target: CompilationTarget,
+    file_reader: MockFileReader
+) -> Receiver<CompilationMessage> {
     let (message_sender, message_receiver) = channel::<CompilationMessage>();
     let message_wire_tap = create_send_message_wire_tap(message_sender);
     let interpreter = create_test_backend();
-    compile(file_path.to_string(), file_reader, interpreter, message_wire_tap);
+    compile_for_target(file_path.to_string(), module_paths, target, file_reader, interpreter, message_wire_tap);
     message_receiver
 }
 
+pub fn compile_file_for_target_and_get_intemediate_representation(file_path: &str, target: CompilationTarget, reader: MockFileReader) -> Vec<IntermediateRepresentation> {
+    compile_file_for_target_with_module_paths_and_get_intemediate_representation(file_path, vec!(), target, reader)
+}
+
+pub fn compile_file_for_target_with_module_paths_and_get_intemediate_representation(
+    file_path: &str,
+    module_paths: Vec<String>,
+    target: CompilationTarget,
+    reader: MockFileReader
+) -> Vec<IntermediateRepresentation> {
+    let message_receiver = compile_for_target_and_get_message_receiver(file_path, module_paths, target, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::ByteCodeBuilt { code, .. } if !is_preload_file(&code.filename) => result.push(code),
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    result
+}
+
+pub fn compile_file_for_target_and_get_executed_intemediate_representation(file_path: &str, target: CompilationTarget, reader: MockFileReader) -> Vec<IntermediateRepresentation> {
+    let message_receiver = compile_for_target_and_get_message_receiver(file_path, vec!(), target, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::ByteCodeExecuted { code, .. } if !is_preload_file(&code.filename) => result.push(code),
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    result
+}
+
+pub fn compile_file_for_target_and_get_errors(file_path: &str, target: CompilationTarget, reader: MockFileReader) -> Vec<CompilationErrors> {
+    let message_receiver = compile_for_target_and_get_message_receiver(file_path, vec!(), target, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::ErrorsReported(errors) => result.push(errors),
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    result
+}
+
 pub struct SendMessageWireTap {
     sender: Sender<CompilationMessage>
 }
@@ -84,7 +246,7 @@ fn compile_file_and_get_types_and_unit(file_path: &str, reader: MockFileReader)
     loop {
         let next_message = message_receiver.recv().unwrap();
         match next_message {
-            CompilationMessage::UnitTyped { resolved_types, unit } => result.push((unit, resolved_types)),
+            CompilationMessage::UnitTyped { resolved_types, unit } if !is_preload_file(&unit.filename) => result.push((unit, resolved_types)),
             CompilationMessage::CompilationComplete => break,           
             _ => {}
         }
@@ -98,8 +260,12 @@ pub fn compile_source_and_get_errors(source: &str) -> Vec<CompilationErrors> {
     compile_file_and_get_errors(file_path, reader)
 }
 
-fn compile_file_and_get_errors(file_path: &str, reader: MockFileReader) -> Vec<CompilationErrors> {
-    let message_receiver = compile_and_get_message_receiver(file_path, reader);
+pub fn compile_file_and_get_errors(file_path: &str, reader: MockFileReader) -> Vec<CompilationErrors> {
+    compile_file_with_module_paths_and_get_errors(file_path, vec!(), reader)
+}
+
+pub fn compile_file_with_module_paths_and_get_errors(file_path: &str, module_paths: Vec<String>, reader: MockFileReader) -> Vec<CompilationErrors> {
+    let message_receiver = compile_with_module_paths_and_get_message_receiver(file_path, module_paths, reader);
     
     let mut result = vec!();
 
@@ -116,6 +282,27 @@ fn compile_file_and_get_errors(file_path: &str, reader: MockFileReader) -> Vec<C
 }
 
 
+pub fn compile_file_and_get_parsed_file_names(file_path: &str, reader: MockFileReader) -> Vec<String> {
+    compile_file_with_module_paths_and_get_parsed_file_names(file_path, vec!(), reader)
+}
+
+pub fn compile_file_with_module_paths_and_get_parsed_file_names(file_path: &str, module_paths: Vec<String>, reader: MockFileReader) -> Vec<String> {
+    let message_receiver = compile_with_module_paths_and_get_message_receiver(file_path, module_paths, reader);
+    
+    let mut result = vec!();
+
+    loop {
+        let next_message = message_receiver.recv().unwrap();
+        match next_message {
+            CompilationMessage::FileParsed { file_name, .. } if !is_preload_file(&file_name) => result.push(file_name),
+            CompilationMessage::CompilationComplete => break,           
+            _ => {}
+        }
+    }
+
+    result
+}
+
 pub fn compile_source_and_get_parsed_units_and_errors(source: &str) -> (CompilationUnits, Vec<CompilationErrors>) {
     let (file_path, reader) = add_source_to_test_file_system(source);    
     compile_file_and_get_parsed_units_and_errors(file_path, reader)
@@ -130,7 +317,7 @@ fn compile_file_and_get_parsed_units_and_errors(file_path: &str, reader: MockFil
     loop {
         let next_message = message_receiver.recv().unwrap();
         match next_message {
-            CompilationMessage::FileParsed { mut units, .. } => units_result.append(&mut units),
+            CompilationMessage::FileParsed { mut units, file_name } if !is_preload_file(&file_name) => units_result.append(&mut units),
             CompilationMessage::ErrorsReported(errors) => errors_result.push(errors),
             CompilationMessage::CompilationComplete => break,           
             _ => {}
@@ -186,13 +373,21 @@ pub fn get_first_typed_procedure_header_unit_named_with_arg_count<'a>(
 
 pub fn start_type_repository_actor() -> CompilationActorHandle {
     let (handle, _) = start_singleton_actor(create_type_repository_actor());
+
+    for preload_type in load_preload_runtime_types().values() {
+        send_message_to_actor(
+            &handle, 
+            create_add_resolved_type_command(create_shareable(preload_type.clone()), type_declaration(string(PRELOAD_FILE_NAME), no_position(), export_visibility()))
+        );
+    }
+
     handle
 }
 
 pub fn add_resolved_type(typing_repository: &CompilationActorHandle, resolved_type: RuntimeType) {
     send_message_to_actor(
         typing_repository, 
-        create_add_resolved_type_command(create_shareable(resolved_type))
+        create_add_resolved_type_command(create_shareable(resolved_type), type_declaration(empty_string(), no_position(), export_visibility()))
     );
 }
 
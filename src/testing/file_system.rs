@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::{file_system::*, empty_string};
+use crate::{file_system::*, compilation::*, empty_string};
 use std::io::*;
 
 #[derive(Clone)]
@@ -17,6 +17,10 @@ impl FileRead for MockFileReader {
     fn read_line_from_file(&self, _location: &str, _line_number: usize) -> Result<String> {
         Ok(empty_string())
     }
+
+    fn file_exists(&self, location: &str) -> bool {
+        self.0.contains_key(location)
+    }
 }
 
 pub fn create_mock_file_reader() -> MockFileReader {
@@ -34,3 +38,25 @@ pub fn add_source_to_test_file_system(source: &str) -> (&str, MockFileReader) {
     
     (file_path, reader)
 }
+
+pub fn add_bundled_module_to_mock_file_reader(reader: &mut MockFileReader, module: &str) {
+    let module_path = format!("{}/{}", get_bundled_modules_path(), module);
+    for entry in std::fs::read_dir(module_path).unwrap() {
+        let file_path = entry.unwrap().path();
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        add_mock_file(reader, &normalise_file_name(&file_path.to_string_lossy()), &content);
+    }
+}
+
+pub fn add_target_runtime_to_mock_file_reader(reader: &mut MockFileReader, target: CompilationTarget) {
+    if let Some(file_name) = get_target_runtime_file_name(&get_bundled_modules_path(), target) {
+        let content = std::fs::read_to_string(&file_name).unwrap();
+        add_mock_file(reader, &file_name, &content);
+    }
+    for main_returns_status in [true, false] {
+        if let Some(file_name) = get_target_entry_file_name(&get_bundled_modules_path(), target, main_returns_status) {
+            let content = std::fs::read_to_string(&file_name).unwrap();
+            add_mock_file(reader, &file_name, &content);
+        }
+    }
+}
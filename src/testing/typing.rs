@@ -14,7 +14,7 @@ pub fn run_typing_on_unit(typing_repository: CompilationActorHandle, unit: Compi
     let (error_reporter, ..) = create_test_message_receiver_actor();
     
     let (typing_actor, ..) = start_singleton_actor(
-        create_typing_actor(message_receiver_handle, typing_repository, error_reporter, unit.id),
+        create_typing_actor(message_receiver_handle, typing_repository, error_reporter, unit.id, unit.filename.clone(), None),
         
     );
         
@@ -95,10 +95,17 @@ pub struct ThreadPool<T: ParallelisableClone> {
 }
 
 pub fn schedule_task<T: ParallelisableClone>(thread_pool: &ThreadPool<T>, task: Task<T>) {
-    if let Some(worker) = find_and_acquire_free_worker(&thread_pool.workers) {            
-        send_task_to_worker(worker, task);
-    } else {
-        enqueue(&mut lock(&thread_pool.tasks), task);
+    enqueue(&mut lock(&thread_pool.tasks), task);
+    dispatch_queued_tasks(&thread_pool.workers, &thread_pool.tasks);
+}
+
+fn dispatch_queued_tasks<T: ParallelisableClone>(workers: &Workers<T>, tasks: &ConcurrentTasks<T>) {
+    let mut tasks = lock(tasks);
+    while !is_queue_empty(&tasks) {
+        match find_and_acquire_free_worker(workers) {
+            Some(worker) => send_task_to_worker(worker, dequeue(&mut tasks).unwrap()),
+            None => break
+        }
     }
 }
 
@@ -118,7 +125,7 @@ pub fn create_thread_pool<T: ParallelisableClone>(number_of_workers: u8) -> Thre
     start_schedule_tasks_thread(
         worker_free_notification_receiver, 
         clone_shareable(&tasks),
-        clone_shareable(&workers)
+        Arc::downgrade(&workers)
     );
 
     ThreadPool::<T> {
@@ -130,14 +137,13 @@ pub fn create_thread_pool<T: ParallelisableClone>(number_of_workers: u8) -> Thre
 fn start_schedule_tasks_thread<T: ParallelisableClone>(
     worker_free_notification_receiver: NotificationReceiver, 
     tasks: ConcurrentTasks<T>,
-    workers: ShareableWorkers<T>
+    workers: Weak<Workers<T>>
 ) {
     thread::spawn(move || {            
         for _ in worker_free_notification_receiver {                
-            if let Some(task) = dequeue(&mut lock(&tasks)) {
-                if let Some(worker) = find_and_acquire_free_worker(&workers) {
-                    send_task_to_worker(worker, task);
-                }
+            match workers.upgrade() {
+                Some(workers) => dispatch_queued_tasks(&workers, &tasks),
+                None => break
             }
         }
     });
@@ -0,0 +1,50 @@
+use crate::{
+    types::*,
+    threading::*,
+    utilities::*
+};
+
+pub fn array_view_runtime_type(element_type: RuntimeType) -> Option<RuntimeType> {
+    let element_pointer_id = try_get_pointer_runtime_type_id(&element_type.id)?;
+    let members = vec!(
+        runtime_type_member(string("count"), create_shareable(signed_int_64_runtime_type())),
+        runtime_type_member(string("data"), create_shareable(pointer_runtime_type(element_pointer_id, element_type.clone())))
+    );
+    let size = get_members_size(&members);
+
+    Some(
+        create_type(
+            array_view_runtime_type_id(element_type.id.clone()),
+            format!("[] {}", element_type.name),
+            array_view_type_item(Box::new(element_type), members),
+            resolved_type_size(size)
+        )
+    )
+}
+
+pub fn try_get_array_view_element_type(runtime_type: &RuntimeType) -> Option<&RuntimeType> {
+    if let RuntimeTypeItem::ArrayView { element_type, .. } = &runtime_type.item {
+        return Some(element_type.as_ref());
+    }
+    None
+}
+
+pub fn try_get_variadic_element_type(arg_types: &RuntimeTypePointers) -> Option<RuntimeTypePointer> {
+    arg_types
+        .last()
+        .and_then(|arg_type| try_get_array_view_element_type(arg_type))
+        .map(|element_type| create_shareable(element_type.clone()))
+}
+
+pub fn get_call_arg_types(arg_types: &RuntimeTypePointers, arg_count: usize) -> RuntimeTypePointers {
+    match try_get_variadic_element_type(arg_types) {
+        Some(element_type) => {
+            let mut call_arg_types = arg_types[..arg_types.len() - 1].to_vec();
+            while call_arg_types.len() < arg_count {
+                call_arg_types.push(element_type.clone());
+            }
+            call_arg_types
+        },
+        None => arg_types.clone()
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{
+    parsing::*,
+    types::*
+};
+
+pub fn array_runtime_type(element_type: RuntimeType, length: usize) -> Option<RuntimeType> {
+    let element_size = try_get_resolved_type_size(&element_type.size)?;
+
+    Some(
+        create_type(
+            array_runtime_type_id(element_type.id.clone(), length),
+            format!("[{}] {}", length, element_type.name),
+            array_type_item(Box::new(element_type), length),
+            resolved_type_size(element_size * length)
+        )
+    )
+}
+
+pub fn try_get_array_element_type(runtime_type: &RuntimeType) -> Option<&RuntimeType> {
+    if let RuntimeTypeItem::Array { element_type, .. } = &runtime_type.item {
+        return Some(element_type.as_ref());
+    }
+    None
+}
+
+pub fn is_array_runtime_type(runtime_type: &RuntimeType) -> bool {
+    try_get_array_element_type(runtime_type).is_some()
+}
+
+pub fn try_get_array_length(length: &AbstractSyntaxNode) -> Option<usize> {
+    if let AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::Int(value))) = length.item_ref() {
+        return value.parse().ok();
+    }
+    None
+}
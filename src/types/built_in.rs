@@ -14,7 +14,13 @@ pub enum BuiltInType {
     Float64,
     String,
     Boolean,
-    Void
+    Void,
+    TypeInfo,
+    TypeInfoMember,
+    Any,
+    Allocator,
+    SourceCodeLocation,
+    Context
 }
 
 pub fn unsigned_int_8_built_in_type() -> BuiltInType {
@@ -69,9 +75,36 @@ pub fn void_built_in_type() -> BuiltInType {
     BuiltInType::Void
 }
 
+pub fn type_info_built_in_type() -> BuiltInType {
+    BuiltInType::TypeInfo
+}
+
+pub fn type_info_member_built_in_type() -> BuiltInType {
+    BuiltInType::TypeInfoMember
+}
+
+pub fn any_built_in_type() -> BuiltInType {
+    BuiltInType::Any
+}
+
+pub fn allocator_built_in_type() -> BuiltInType {
+    BuiltInType::Allocator
+}
+
+pub fn source_code_location_built_in_type() -> BuiltInType {
+    BuiltInType::SourceCodeLocation
+}
+
+pub fn context_built_in_type() -> BuiltInType {
+    BuiltInType::Context
+}
+
 pub fn try_get_built_in_type(id: &RuntimeTypeId) -> Option<(BuiltInType, bool)> {
     if let RuntimeTypeId::BuiltInType { built_in_type, is_pointer } = id {
         return Some((*built_in_type, *is_pointer));
     }
     None
-}
\ No newline at end of file
+}
+pub fn is_any_type(runtime_type: &RuntimeType) -> bool {
+    try_get_built_in_type(&runtime_type.id) == Some((any_built_in_type(), false))
+}
@@ -2,8 +2,16 @@ mod resolvable;
 mod runtime;
 mod built_in;
 mod size;
+mod type_info;
+mod preload;
+mod array_views;
+mod arrays;
 
 pub use resolvable::*;
 pub use runtime::*;
 pub use built_in::*;
-pub use size::*;
\ No newline at end of file
+pub use size::*;
+pub use type_info::*;
+pub use preload::*;
+pub use array_views::*;
+pub use arrays::*;
\ No newline at end of file
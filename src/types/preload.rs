@@ -0,0 +1,146 @@
+use std::collections::*;
+use std::sync::OnceLock;
+
+use crate::{
+    parsing::*,
+    types::*,
+    threading::*,
+    utilities::*
+};
+
+pub const PRELOAD_FILE_NAME: &str = "<preload>";
+pub const PRELOAD_SOURCE: &str = include_str!("../../modules/preload.hep");
+
+const PRELOAD_BUILT_IN_TYPES: [(BuiltInType, &str); 7] = [
+    (BuiltInType::String, SOURCE_TYPE_STRING),
+    (BuiltInType::TypeInfoMember, SOURCE_TYPE_TYPE_INFO_MEMBER),
+    (BuiltInType::TypeInfo, SOURCE_TYPE_TYPE_INFO),
+    (BuiltInType::Any, SOURCE_TYPE_ANY),
+    (BuiltInType::Allocator, SOURCE_TYPE_ALLOCATOR),
+    (BuiltInType::SourceCodeLocation, SOURCE_TYPE_SOURCE_CODE_LOCATION),
+    (BuiltInType::Context, SOURCE_TYPE_CONTEXT)
+];
+
+pub type PreloadRuntimeTypes = HashMap<BuiltInType, RuntimeType>;
+
+static PRELOAD_RUNTIME_TYPES: OnceLock<PreloadRuntimeTypes> = OnceLock::new();
+
+pub fn load_preload_runtime_types() -> &'static PreloadRuntimeTypes {
+    PRELOAD_RUNTIME_TYPES.get_or_init(|| read_preload_runtime_types(PRELOAD_SOURCE))
+}
+
+pub fn preload_runtime_type(built_in_type: BuiltInType) -> RuntimeType {
+    match load_preload_runtime_types().get(&built_in_type) {
+        Some(runtime_type) => runtime_type.clone(),
+        None => panic!("{:?} is not declared in {}", built_in_type, PRELOAD_FILE_NAME)
+    }
+}
+
+pub fn read_preload_runtime_types(source: &str) -> PreloadRuntimeTypes {
+    let (units, errors) = parse(string(PRELOAD_FILE_NAME), source);
+    assert!(errors.items.is_empty(), "{} can not be parsed: {:?}", PRELOAD_FILE_NAME, errors.items);
+
+    let mut runtime_types = PreloadRuntimeTypes::default();
+
+    for unit in units {
+        if let AbstractSyntaxNodeItem::Struct { name, fields } = unit.tree.item_ref() {
+            if let Some(built_in_type) = try_get_preload_built_in_type(PRELOAD_FILE_NAME, name) {
+                let members = read_preload_struct_members(fields, &runtime_types);
+                runtime_types.insert(built_in_type, preload_struct_runtime_type(built_in_type, members));
+            }
+        }
+    }
+
+    runtime_types
+}
+
+fn read_preload_struct_members(fields: &AbstractSyntaxChildNodes, runtime_types: &PreloadRuntimeTypes) -> RuntimeTypeMembers {
+    let mut members = vec!();
+
+    for field in fields {
+        if let AbstractSyntaxNodeItem::MemberDeclaration { name, member_type } = field.item_ref() {
+            let mut find_named_type = |type_name: &mut String| find_read_preload_runtime_type(runtime_types, type_name);
+            match try_resolve_declared_type(&mut member_type.clone(), &mut find_named_type) {
+                Some(member_type) => members.push(runtime_type_member(name.clone(), member_type)),
+                None => panic!("{} is used before it is declared in {}", name, PRELOAD_FILE_NAME)
+            }
+        }
+    }
+
+    members
+}
+
+fn find_read_preload_runtime_type(runtime_types: &PreloadRuntimeTypes, name: &str) -> OptionalRuntimeTypePointer {
+    let built_in_type = try_get_preload_built_in_type(PRELOAD_FILE_NAME, name)?;
+    runtime_types
+        .get(&built_in_type)
+        .map(|runtime_type| create_shareable(runtime_type.clone()))
+}
+
+pub fn try_get_preload_built_in_type(filename: &str, name: &str) -> Option<BuiltInType> {
+    if filename != PRELOAD_FILE_NAME {
+        return None;
+    }
+
+    PRELOAD_BUILT_IN_TYPES
+        .iter()
+        .find(|(_, preload_name)| *preload_name == name)
+        .map(|(built_in_type, _)| *built_in_type)
+}
+
+fn get_preload_type_name(built_in_type: BuiltInType) -> String {
+    PRELOAD_BUILT_IN_TYPES
+        .iter()
+        .find(|(preload_type, _)| *preload_type == built_in_type)
+        .map(|(_, name)| name.to_string())
+        .unwrap()
+}
+
+pub fn preload_struct_runtime_type(built_in_type: BuiltInType, members: RuntimeTypeMembers) -> RuntimeType {
+    let size = get_members_size(&members);
+    let item = if built_in_type == string_built_in_type() {
+        string_type_item(members)
+    } else {
+        struct_type_item(members)
+    };
+
+    create_type(
+        built_in_type_runtime_type_id(built_in_type),
+        get_preload_type_name(built_in_type),
+        item,
+        resolved_type_size(size)
+    )
+}
+
+pub fn allocator_runtime_type() -> RuntimeType {
+    preload_runtime_type(allocator_built_in_type())
+}
+
+pub fn source_code_location_runtime_type() -> RuntimeType {
+    preload_runtime_type(source_code_location_built_in_type())
+}
+
+pub fn context_runtime_type() -> RuntimeType {
+    preload_runtime_type(context_built_in_type())
+}
+
+pub fn allocator_pointer_runtime_type() -> RuntimeType {
+    pointer_runtime_type(
+        built_in_type_pointer_runtime_type_id(allocator_built_in_type()),
+        allocator_runtime_type()
+    )
+}
+
+pub fn source_code_location_pointer_runtime_type() -> RuntimeType {
+    pointer_runtime_type(
+        built_in_type_pointer_runtime_type_id(source_code_location_built_in_type()),
+        source_code_location_runtime_type()
+    )
+}
+
+pub fn context_pointer_runtime_type() -> RuntimeType {
+    pointer_runtime_type(
+        built_in_type_pointer_runtime_type_id(context_built_in_type()),
+        context_runtime_type()
+    )
+}
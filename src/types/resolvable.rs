@@ -1,10 +1,17 @@
 
-use crate::types::*;
+use crate::{
+    parsing::*,
+    types::*,
+    threading::*
+};
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum ResolvableType {
     Resolved(RuntimeTypePointer),
     UnresolvedNamed(String),
+    UnresolvedPointer(Box<ResolvableType>),
+    UnresolvedArrayView(Box<ResolvableType>),
+    UnresolvedArray { length: Box<AbstractSyntaxNode>, element_type: Box<ResolvableType> },
     Unresolved
 }
 
@@ -16,33 +23,39 @@ pub fn unresolved_named_resolvable_type(name: String) -> ResolvableType {
     ResolvableType::UnresolvedNamed(name)
 }
 
+pub fn unresolved_pointer_resolvable_type(to_type: ResolvableType) -> ResolvableType {
+    ResolvableType::UnresolvedPointer(Box::new(to_type))
+}
+
+pub fn unresolved_array_view_resolvable_type(element_type: ResolvableType) -> ResolvableType {
+    ResolvableType::UnresolvedArrayView(Box::new(element_type))
+}
+
+pub fn unresolved_array_resolvable_type(length: AbstractSyntaxNode, element_type: ResolvableType) -> ResolvableType {
+    ResolvableType::UnresolvedArray { length: Box::new(length), element_type: Box::new(element_type) }
+}
+
 pub fn resolved_resolvable_type(type_pointer: RuntimeTypePointer) -> ResolvableType {
     ResolvableType::Resolved(type_pointer)
 }
 
 pub fn try_get_instance_member_offset(instance_type: &ResolvableType, member_name: &str) -> Option<usize> {
     if let Some(pointer) = try_get_resolved_runtime_type_pointer(instance_type) {
-        return match &pointer.item {
-            RuntimeTypeItem::String { members } => Some(get_member_offset(members, member_name)),
-            //RuntimeTypeItem::Struct { members } => get_member_offset(members, member_name),
-            _ => None
-        }
+        return try_get_instance_members(&pointer).and_then(|members| get_member_offset(members, member_name));
     }
     None
 }
 
-fn get_member_offset(members: &RuntimeTypeMembers, member_name: &str) -> usize {
-    let mut offset = 0;
-    for member in members {
-        if member.name == member_name {
-            break;
-        }
-        
-        if let Some(type_size) = try_get_resolved_type_size(&member.field_type.size) {
-            offset += type_size
-        }
+pub fn try_get_instance_members(instance_type: &RuntimeType) -> Option<&RuntimeTypeMembers> {
+    let instance_type = match &instance_type.item {
+        RuntimeTypeItem::Pointer { to_type } => to_type.as_ref(),
+        _ => instance_type
+    };
+
+    match &instance_type.item {
+        RuntimeTypeItem::String { members } | RuntimeTypeItem::Struct { members } | RuntimeTypeItem::ArrayView { members, .. } => Some(members),
+        _ => None
     }
-    offset
 }
 
 pub fn try_get_resolved_runtime_type_pointer(resolvable_type: &ResolvableType) -> OptionalRuntimeTypePointer {
@@ -50,4 +63,29 @@ pub fn try_get_resolved_runtime_type_pointer(resolvable_type: &ResolvableType) -
        return Some(pointer.clone());
     }
     None
-}
\ No newline at end of file
+}
+
+pub fn try_resolve_declared_type(
+    declared_type: &mut ResolvableType,
+    find_named_type: &mut impl FnMut(&mut String) -> OptionalRuntimeTypePointer
+) -> OptionalRuntimeTypePointer {
+    match declared_type {
+        ResolvableType::Resolved(resolved_type) => Some(resolved_type.clone()),
+        ResolvableType::UnresolvedNamed(name) => find_named_type(name),
+        ResolvableType::UnresolvedPointer(to_type) => {
+            let to_type = try_resolve_declared_type(to_type, find_named_type)?;
+            let pointer_id = try_get_pointer_runtime_type_id(&to_type.id)?;
+            Some(create_shareable(pointer_runtime_type(pointer_id, to_type.as_ref().clone())))
+        },
+        ResolvableType::UnresolvedArrayView(element_type) => {
+            let element_type = try_resolve_declared_type(element_type, find_named_type)?;
+            array_view_runtime_type(element_type.as_ref().clone()).map(create_shareable)
+        },
+        ResolvableType::UnresolvedArray { length, element_type } => {
+            let length = try_get_array_length(length)?;
+            let element_type = try_resolve_declared_type(element_type, find_named_type)?;
+            array_runtime_type(element_type.as_ref().clone(), length).map(create_shareable)
+        },
+        ResolvableType::Unresolved => None
+    }
+}
@@ -1,4 +1,4 @@
-use std::mem::*;
+use std::{mem::*, hash::*};
 
 use crate::{
     parsing::*,
@@ -11,7 +11,9 @@ use crate::{
 #[derive(PartialEq, Debug, Clone, Hash, Eq)]
 pub enum RuntimeTypeId {
     BuiltInType { built_in_type: BuiltInType, is_pointer: bool },
-    UserDefined { unit_id: CompilationUnitId, is_pointer: bool }
+    UserDefined { unit_id: CompilationUnitId, is_pointer: bool },
+    ArrayView { element_type: Box<RuntimeTypeId> },
+    Array { element_type: Box<RuntimeTypeId>, length: usize }
 }
 
 pub fn built_in_type_runtime_type_id(built_in_type: BuiltInType) -> RuntimeTypeId {
@@ -26,6 +28,22 @@ pub fn user_defined_runtime_type_id(unit_id: CompilationUnitId) -> RuntimeTypeId
     RuntimeTypeId::UserDefined { unit_id, is_pointer: false }
 }
 
+pub fn array_view_runtime_type_id(element_type: RuntimeTypeId) -> RuntimeTypeId {
+    RuntimeTypeId::ArrayView { element_type: Box::new(element_type) }
+}
+
+pub fn array_runtime_type_id(element_type: RuntimeTypeId, length: usize) -> RuntimeTypeId {
+    RuntimeTypeId::Array { element_type: Box::new(element_type), length }
+}
+
+pub fn try_get_pointer_runtime_type_id(id: &RuntimeTypeId) -> Option<RuntimeTypeId> {
+    match id {
+        RuntimeTypeId::BuiltInType { built_in_type, is_pointer: false } => Some(built_in_type_pointer_runtime_type_id(*built_in_type)),
+        RuntimeTypeId::UserDefined { unit_id, is_pointer: false } => Some(RuntimeTypeId::UserDefined { unit_id: *unit_id, is_pointer: true }),
+        _ => None
+    }
+}
+
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub struct RuntimeTypeMember {
@@ -33,7 +51,7 @@ pub struct RuntimeTypeMember {
     pub field_type: RuntimeTypePointer,
 }
 
-fn runtime_type_member(name: String, field_type: RuntimeTypePointer) -> RuntimeTypeMember {
+pub fn runtime_type_member(name: String, field_type: RuntimeTypePointer) -> RuntimeTypeMember {
     RuntimeTypeMember {
         name,
         field_type
@@ -42,13 +60,6 @@ fn runtime_type_member(name: String, field_type: RuntimeTypePointer) -> RuntimeT
 
 pub type RuntimeTypeMembers = Vec<RuntimeTypeMember>;
 
-fn string_runtime_type_members() -> RuntimeTypeMembers {
-    vec!(
-        runtime_type_member(string("count"), create_shareable(signed_int_64_runtime_type())),
-        runtime_type_member(string("data"), create_shareable(unsigned_int_8_pointer_runtime_type()))
-    )
-}
-
 pub fn get_type_of_member_by_member_name(fields: &RuntimeTypeMembers, name: &str) -> OptionalRuntimeTypePointer {
     let member = fields
         .iter()
@@ -174,12 +185,7 @@ pub fn float_64_runtime_type() -> RuntimeType {
 }
 
 pub fn string_runtime_type() -> RuntimeType {
-    create_type(
-        built_in_type_runtime_type_id(string_built_in_type()),
-        SOURCE_TYPE_STRING.to_string(),
-        string_type_item(string_runtime_type_members()),
-        resolved_type_size(16)
-    )
+    preload_runtime_type(string_built_in_type())
 }
 
 pub fn bool_runtime_type() -> RuntimeType {
@@ -303,16 +309,64 @@ pub fn pointer_runtime_type(id: RuntimeTypeId, to_type: RuntimeType) -> RuntimeT
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub enum RuntimeTypeItem {
     None,
-    ProcedureDefinition { arg_types: RuntimeTypePointers, return_types: RuntimeTypePointers },
-    ConstantDefinition { constant_type: RuntimeTypePointer },
+    ProcedureDefinition { arg_types: RuntimeTypePointers, return_types: RuntimeTypePointers, symbol_qualifier: Option<String> },
+    MacroDefinition { arg_types: RuntimeTypePointers, body: MacroBody },
+    CodeDefinition { body: CodeBody },
+    ConstantDefinition { constant_type: RuntimeTypePointer, symbol_qualifier: Option<String> },
+    GlobalVariableDefinition { variable_type: RuntimeTypePointer, symbol_qualifier: Option<String> },
     Pointer { to_type: Box<RuntimeType> },
     Int { is_signed: bool },
     Float,
     String { members: RuntimeTypeMembers },
+    Struct { members: RuntimeTypeMembers },
+    ArrayView { element_type: Box<RuntimeType>, members: RuntimeTypeMembers },
+    Array { element_type: Box<RuntimeType>, length: usize },
     Bool,
     Void
 }
 
+#[derive(PartialEq, Debug, Clone)]
+pub struct MacroBody {
+    pub filename: String,
+    pub body: AbstractSyntaxNode
+}
+
+impl Eq for MacroBody {}
+
+impl Hash for MacroBody {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.filename.hash(state);
+    }
+}
+
+pub fn macro_body(filename: String, body: AbstractSyntaxNode) -> MacroBody {
+    MacroBody {
+        filename,
+        body
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct CodeBody {
+    pub filename: String,
+    pub code: AbstractSyntaxNode
+}
+
+impl Eq for CodeBody {}
+
+impl Hash for CodeBody {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.filename.hash(state);
+    }
+}
+
+pub fn code_body(filename: String, code: AbstractSyntaxNode) -> CodeBody {
+    CodeBody {
+        filename,
+        code
+    }
+}
+
 impl Default for RuntimeTypeItem {
     fn default() -> Self {
         RuntimeTypeItem::None
@@ -320,11 +374,57 @@ impl Default for RuntimeTypeItem {
 }
 
 pub fn procedure_definition_type_item(arg_types: RuntimeTypePointers, return_types: RuntimeTypePointers) -> RuntimeTypeItem {
-    RuntimeTypeItem::ProcedureDefinition { arg_types, return_types }
+    qualified_procedure_definition_type_item(arg_types, return_types, None)
+}
+
+pub fn qualified_procedure_definition_type_item(arg_types: RuntimeTypePointers, return_types: RuntimeTypePointers, symbol_qualifier: Option<String>) -> RuntimeTypeItem {
+    RuntimeTypeItem::ProcedureDefinition { arg_types, return_types, symbol_qualifier }
+}
+
+pub fn qualified_symbol_name(name: &str, symbol_qualifier: &Option<String>) -> String {
+    match symbol_qualifier {
+        Some(symbol_qualifier) => format!("{}::{}", symbol_qualifier, name),
+        None => string(name)
+    }
+}
+
+pub fn try_get_procedure_symbol_name(resolved_type: &RuntimeType) -> Option<String> {
+    if let RuntimeTypeItem::ProcedureDefinition { symbol_qualifier, .. } = &resolved_type.item {
+        return Some(qualified_symbol_name(&resolved_type.name, symbol_qualifier));
+    }
+    None
+}
+
+pub fn try_get_global_symbol_name(resolved_type: &RuntimeType) -> Option<String> {
+    match &resolved_type.item {
+        RuntimeTypeItem::ConstantDefinition { symbol_qualifier, .. } | RuntimeTypeItem::GlobalVariableDefinition { symbol_qualifier, .. } =>
+            Some(qualified_symbol_name(&resolved_type.name, symbol_qualifier)),
+        _ => None
+    }
+}
+
+pub fn macro_definition_type_item(arg_types: RuntimeTypePointers, body: MacroBody) -> RuntimeTypeItem {
+    RuntimeTypeItem::MacroDefinition { arg_types, body }
+}
+
+pub fn code_definition_type_item(body: CodeBody) -> RuntimeTypeItem {
+    RuntimeTypeItem::CodeDefinition { body }
 }
 
 pub fn constant_definition_type_item(constant_type: RuntimeTypePointer) -> RuntimeTypeItem {
-    RuntimeTypeItem::ConstantDefinition { constant_type }
+    qualified_constant_definition_type_item(constant_type, None)
+}
+
+pub fn qualified_constant_definition_type_item(constant_type: RuntimeTypePointer, symbol_qualifier: Option<String>) -> RuntimeTypeItem {
+    RuntimeTypeItem::ConstantDefinition { constant_type, symbol_qualifier }
+}
+
+pub fn global_variable_definition_type_item(variable_type: RuntimeTypePointer) -> RuntimeTypeItem {
+    qualified_global_variable_definition_type_item(variable_type, None)
+}
+
+pub fn qualified_global_variable_definition_type_item(variable_type: RuntimeTypePointer, symbol_qualifier: Option<String>) -> RuntimeTypeItem {
+    RuntimeTypeItem::GlobalVariableDefinition { variable_type, symbol_qualifier }
 }
 
 pub fn pointer_type_item(to_type: Box<RuntimeType>) -> RuntimeTypeItem {
@@ -343,10 +443,22 @@ fn float_type_item() -> RuntimeTypeItem {
     RuntimeTypeItem::Float
 }
 
-fn string_type_item(fields: RuntimeTypeMembers) -> RuntimeTypeItem {
+pub fn string_type_item(fields: RuntimeTypeMembers) -> RuntimeTypeItem {
     RuntimeTypeItem::String { members: fields }
 }
 
+pub fn struct_type_item(members: RuntimeTypeMembers) -> RuntimeTypeItem {
+    RuntimeTypeItem::Struct { members }
+}
+
+pub fn array_view_type_item(element_type: Box<RuntimeType>, members: RuntimeTypeMembers) -> RuntimeTypeItem {
+    RuntimeTypeItem::ArrayView { element_type, members }
+}
+
+pub fn array_type_item(element_type: Box<RuntimeType>, length: usize) -> RuntimeTypeItem {
+    RuntimeTypeItem::Array { element_type, length }
+}
+
 fn bool_type_item() -> RuntimeTypeItem {
     RuntimeTypeItem::Bool
 }
@@ -356,15 +468,47 @@ fn void_type_item() -> RuntimeTypeItem {
 }
 
 pub fn try_get_procedure_definition_runtime_type_item(item: &RuntimeTypeItem) -> Option<(RuntimeTypePointers, RuntimeTypePointers )> {
-    if let RuntimeTypeItem::ProcedureDefinition { return_types, arg_types } = item {
+    if let RuntimeTypeItem::ProcedureDefinition { return_types, arg_types, .. } = item {
        return Some((arg_types.clone(), return_types.clone()));
     }
     None
 }
 
+pub fn try_get_macro_definition_runtime_type_item(item: &RuntimeTypeItem) -> Option<MacroBody> {
+    if let RuntimeTypeItem::MacroDefinition { body, .. } = item {
+       return Some(body.clone());
+    }
+    None
+}
+
+pub fn try_get_code_definition_runtime_type_item(item: &RuntimeTypeItem) -> Option<CodeBody> {
+    if let RuntimeTypeItem::CodeDefinition { body } = item {
+       return Some(body.clone());
+    }
+    None
+}
+
+pub fn try_get_struct_runtime_type_item(item: &RuntimeTypeItem) -> Option<RuntimeTypeMembers> {
+    if let RuntimeTypeItem::Struct { members } = item {
+       return Some(members.clone());
+    }
+    None
+}
+
+pub fn is_instance_runtime_type(runtime_type: &RuntimeType) -> bool {
+    matches!(runtime_type.item, RuntimeTypeItem::String { .. } | RuntimeTypeItem::Struct { .. })
+}
+
 pub fn try_get_constant_definition_runtime_type_item(item: &RuntimeTypeItem) -> Option<RuntimeTypePointer> {
-    if let RuntimeTypeItem::ConstantDefinition { constant_type } = item {
+    if let RuntimeTypeItem::ConstantDefinition { constant_type, .. } = item {
        return Some(constant_type.clone());
     }
     None
+}
+
+pub fn try_get_global_variable_definition_runtime_type_item(item: &RuntimeTypeItem) -> Option<RuntimeTypePointer> {
+    if let RuntimeTypeItem::GlobalVariableDefinition { variable_type, .. } = item {
+       return Some(variable_type.clone());
+    }
+    None
 }
\ No newline at end of file
@@ -28,4 +28,56 @@ pub fn try_get_type_size_from_resolvable_type(variable_type: &ResolvableType) ->
         }
     }
     None
-}
\ No newline at end of file
+}
+const MAX_TYPE_ALIGNMENT: usize = 8;
+
+pub fn get_type_alignment(runtime_type: &RuntimeType) -> usize {
+    match &runtime_type.item {
+        RuntimeTypeItem::String { members } => get_members_alignment(members),
+        RuntimeTypeItem::Struct { members } => get_members_alignment(members),
+        RuntimeTypeItem::Array { element_type, .. } => get_type_alignment(element_type),
+        _ => try_get_resolved_type_size(&runtime_type.size)
+            .map(|size_in_bytes| size_in_bytes.clamp(1, MAX_TYPE_ALIGNMENT))
+            .unwrap_or(1)
+    }
+}
+
+fn get_members_alignment(members: &RuntimeTypeMembers) -> usize {
+    members
+        .iter()
+        .map(|member| get_type_alignment(&member.field_type))
+        .max()
+        .unwrap_or(1)
+}
+
+pub fn get_member_offset(members: &RuntimeTypeMembers, member_name: &str) -> Option<usize> {
+    let mut offset = 0;
+    for member in members {
+        offset = align_offset(offset, get_type_alignment(&member.field_type));
+        
+        if member.name == member_name {
+            return Some(offset);
+        }
+
+        if let Some(type_size) = try_get_resolved_type_size(&member.field_type.size) {
+            offset += type_size;
+        }
+    }
+    None
+}
+
+pub fn get_members_size(members: &RuntimeTypeMembers) -> usize {
+    let mut offset = 0;
+    for member in members {
+        offset = align_offset(offset, get_type_alignment(&member.field_type));
+
+        if let Some(type_size) = try_get_resolved_type_size(&member.field_type.size) {
+            offset += type_size;
+        }
+    }
+    align_offset(offset, get_members_alignment(members))
+}
+
+fn align_offset(offset: usize, alignment: usize) -> usize {
+    offset.div_ceil(alignment) * alignment
+}
@@ -0,0 +1,105 @@
+use crate::{
+    parsing::*,
+    types::*
+};
+
+pub const TYPE_INFO_KIND_NONE: u64 = 0;
+pub const TYPE_INFO_KIND_INT: u64 = 1;
+pub const TYPE_INFO_KIND_FLOAT: u64 = 2;
+pub const TYPE_INFO_KIND_BOOL: u64 = 3;
+pub const TYPE_INFO_KIND_STRING: u64 = 4;
+pub const TYPE_INFO_KIND_POINTER: u64 = 5;
+pub const TYPE_INFO_KIND_STRUCT: u64 = 6;
+pub const TYPE_INFO_KIND_PROCEDURE: u64 = 7;
+pub const TYPE_INFO_KIND_VOID: u64 = 8;
+pub const TYPE_INFO_KIND_ARRAY_VIEW: u64 = 9;
+pub const TYPE_INFO_KIND_ARRAY: u64 = 10;
+
+const TYPE_INFO_SYMBOL_PREFIX: &str = "__type_info_";
+
+pub fn type_info_runtime_type() -> RuntimeType {
+    preload_runtime_type(type_info_built_in_type())
+}
+
+pub fn type_info_member_runtime_type() -> RuntimeType {
+    preload_runtime_type(type_info_member_built_in_type())
+}
+
+pub fn any_runtime_type() -> RuntimeType {
+    preload_runtime_type(any_built_in_type())
+}
+
+pub fn any_pointer_runtime_type() -> RuntimeType {
+    pointer_runtime_type(
+        built_in_type_pointer_runtime_type_id(any_built_in_type()),
+        any_runtime_type()
+    )
+}
+
+pub fn type_info_pointer_runtime_type() -> RuntimeType {
+    pointer_runtime_type(
+        built_in_type_pointer_runtime_type_id(type_info_built_in_type()),
+        type_info_runtime_type()
+    )
+}
+
+pub fn type_info_member_pointer_runtime_type() -> RuntimeType {
+    pointer_runtime_type(
+        built_in_type_pointer_runtime_type_id(type_info_member_built_in_type()),
+        type_info_member_runtime_type()
+    )
+}
+
+pub fn get_type_info_kind(item: &RuntimeTypeItem) -> u64 {
+    match item {
+        RuntimeTypeItem::None => TYPE_INFO_KIND_NONE,
+        RuntimeTypeItem::Int { .. } => TYPE_INFO_KIND_INT,
+        RuntimeTypeItem::Float => TYPE_INFO_KIND_FLOAT,
+        RuntimeTypeItem::Bool => TYPE_INFO_KIND_BOOL,
+        RuntimeTypeItem::String { .. } => TYPE_INFO_KIND_STRING,
+        RuntimeTypeItem::Pointer { .. } => TYPE_INFO_KIND_POINTER,
+        RuntimeTypeItem::Struct { .. } => TYPE_INFO_KIND_STRUCT,
+        RuntimeTypeItem::ArrayView { .. } => TYPE_INFO_KIND_ARRAY_VIEW,
+        RuntimeTypeItem::Array { .. } => TYPE_INFO_KIND_ARRAY,
+        RuntimeTypeItem::ProcedureDefinition { .. } | RuntimeTypeItem::MacroDefinition { .. } => TYPE_INFO_KIND_PROCEDURE,
+        RuntimeTypeItem::ConstantDefinition { constant_type, .. } => get_type_info_kind(&constant_type.item),
+        RuntimeTypeItem::GlobalVariableDefinition { variable_type, .. } => get_type_info_kind(&variable_type.item),
+        RuntimeTypeItem::CodeDefinition { .. } | RuntimeTypeItem::Void => TYPE_INFO_KIND_VOID,
+    }
+}
+
+pub fn get_type_info_signed(item: &RuntimeTypeItem) -> u64 {
+    match item {
+        RuntimeTypeItem::Int { is_signed: true } => 1,
+        RuntimeTypeItem::ConstantDefinition { constant_type, .. } => get_type_info_signed(&constant_type.item),
+        RuntimeTypeItem::GlobalVariableDefinition { variable_type, .. } => get_type_info_signed(&variable_type.item),
+        _ => 0
+    }
+}
+
+pub fn get_type_info_members(runtime_type: &RuntimeType) -> RuntimeTypeMembers {
+    match &runtime_type.item {
+        RuntimeTypeItem::String { members } | RuntimeTypeItem::Struct { members } | RuntimeTypeItem::ArrayView { members, .. } => members.clone(),
+        _ => vec!()
+    }
+}
+
+pub fn type_info_symbol_name(runtime_type: &RuntimeType) -> String {
+    let name = format!("{}{}", TYPE_INFO_SYMBOL_PREFIX, runtime_type.name.replace('*', "ptr_").replace("[] ", "view_").replace('[', "array_").replace("] ", "_"));
+    match try_get_user_defined_unit_id(&runtime_type.id) {
+        Some(unit_id) => format!("{}_{:?}", name, unit_id),
+        None => name
+    }
+}
+
+fn try_get_user_defined_unit_id(id: &RuntimeTypeId) -> Option<CompilationUnitId> {
+    match id {
+        RuntimeTypeId::UserDefined { unit_id, .. } => Some(*unit_id),
+        RuntimeTypeId::ArrayView { element_type } | RuntimeTypeId::Array { element_type, .. } => try_get_user_defined_unit_id(element_type),
+        RuntimeTypeId::BuiltInType { .. } => None
+    }
+}
+
+pub fn is_type_info_symbol_name(name: &str) -> bool {
+    name.starts_with(TYPE_INFO_SYMBOL_PREFIX)
+}
@@ -35,20 +35,132 @@ pub fn perform_typing_for_procedure_body(
                 _ => add_type_inference_error(errors, not_viable_procedure_body_return_type_error(), return_type_position) 
         }
     }
-    
-    for statement in statements {
+
+    let mut expansion_count = 0;
+    perform_typing_for_procedure_body_statements(typing_actor, ctx, &mut local_type_map, &local_return_types, statements, &mut expansion_count, errors);
+}
+
+fn perform_typing_for_procedure_body_statements(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &mut IdentifierTypeLookup,
+    local_return_types: &RuntimeTypePointers,
+    statements: &mut AbstractSyntaxChildNodes, 
+    expansion_count: &mut usize,
+    errors: &mut CompilationErrors
+) {
+    let mut index = 0;
+
+    while index < statements.len() {
+        if let AbstractSyntaxNodeItem::Insert { expr } = statements[index].item_ref() {
+            let inserted_statements = perform_typing_for_procedure_body_insert(typing_actor, ctx, expr, errors);
+            statements.splice(index..index + 1, inserted_statements);
+            continue;
+        }
+
+        let statement = &mut statements[index];
         let statement_position = statement.position.clone();
-        match statement.item_mut() {
+        let expansion = match statement.item_mut() {
             AbstractSyntaxNodeItem::ProcedureCall { name, args, procedure_call_type: type_id} => 
-                perform_typing_for_procedure_body_procedure_call(typing_actor, ctx, &mut local_type_map, name, args, type_id, statement_position, errors),
-            AbstractSyntaxNodeItem::VariableDeclaration { name, value, variable_type: type_id } => 
-                perform_typing_for_procedure_body_assignment(typing_actor, ctx, &mut local_type_map, name, value, type_id, errors),
+                perform_typing_for_procedure_body_procedure_call(typing_actor, ctx, local_type_map, name, args, type_id, statement_position, errors),
+            AbstractSyntaxNodeItem::VariableDeclaration { name, value, variable_type: type_id, .. } => {
+                perform_typing_for_procedure_body_assignment(typing_actor, ctx, local_type_map, name, value, type_id, errors);
+                None
+            },
+            AbstractSyntaxNodeItem::Assignment { target, value, assignment_type } => {
+                perform_typing_for_procedure_body_assign_value(typing_actor, ctx, local_type_map, target, value, assignment_type, errors);
+                None
+            },
+            AbstractSyntaxNodeItem::Asm { instructions } => {
+                perform_typing_for_procedure_body_asm(typing_actor, ctx, local_type_map, instructions, errors);
+                None
+            },
             AbstractSyntaxNodeItem::Return { args } => {
-                perform_typing_for_procedure_body_return_args(typing_actor, ctx, &mut local_type_map, args, statement_position, &local_return_types, errors);
+                perform_typing_for_procedure_body_return_args(typing_actor, ctx, local_type_map, args, statement_position, local_return_types, errors);
+                None
+            },
+            AbstractSyntaxNodeItem::If { condition, body, else_body } => {
+                perform_typing_for_procedure_body_condition(typing_actor, ctx, local_type_map, condition, errors);
+                perform_typing_for_procedure_body_statements(typing_actor, ctx, local_type_map, local_return_types, body, expansion_count, errors);
+                perform_typing_for_procedure_body_statements(typing_actor, ctx, local_type_map, local_return_types, else_body, expansion_count, errors);
+                None
+            },
+            AbstractSyntaxNodeItem::While { condition, body } => {
+                perform_typing_for_procedure_body_condition(typing_actor, ctx, local_type_map, condition, errors);
+                perform_typing_for_procedure_body_statements(typing_actor, ctx, local_type_map, local_return_types, body, expansion_count, errors);
+                None
             },
-            _ => add_type_inference_error(errors, not_viable_procedure_body_statement_error(), statement_position) 
+            _ => {
+                add_type_inference_error(errors, not_viable_procedure_body_statement_error(), statement_position);
+                None
+            }
+        };
+
+        if let Some(macro_body) = expansion {
+            let (expanded_statements, expansion_errors) = perform_typing_for_procedure_body_expansion(
+                typing_actor, ctx, local_type_map, local_return_types, &macro_body, &statements[index], expansion_count
+            );
+            for error in expansion_errors.items {
+                add_compilation_error(errors, compilation_error(expansion_error(macro_body.filename.clone(), error), statement_position));
+            }
+            let expanded_statement_count = expanded_statements.len();
+            statements.splice(index..index + 1, expanded_statements);
+            index += expanded_statement_count;
+        } else {
+            index += 1;
+        }
+    }
+}
+
+fn perform_typing_for_procedure_body_expansion(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    local_return_types: &RuntimeTypePointers,
+    macro_body: &MacroBody,
+    call: &AbstractSyntaxNode,
+    expansion_count: &mut usize
+) -> (AbstractSyntaxChildNodes, CompilationErrors) {
+    let mut expansion_errors = create_compilation_errors(macro_body.filename.clone());
+
+    let (macro_args, mut macro_statements, call_args) = match (macro_body.body.item_ref(), call.item_ref()) {
+        (AbstractSyntaxNodeItem::ProcedureBody { args, statements, .. }, AbstractSyntaxNodeItem::ProcedureCall { args: call_args, .. }) => 
+            (args.clone(), statements.clone(), call_args),
+        _ => return (vec!(), expansion_errors)
+    };
+
+    *expansion_count += 1;
+    let expansion_number = *expansion_count;
+
+    let mut expansion_type_map = create_identifier_type_lookup();
+    let mut expanded_statements = vec!();
+
+    for (macro_arg, call_arg) in macro_args.iter().zip(call_args) {
+        if let (AbstractSyntaxNodeItem::MemberDeclaration { name, member_type }, AbstractSyntaxNodeItem::Argument { expr, .. }) = (macro_arg.item_ref(), call_arg.item_ref()) {
+            if let Some(arg_type) = try_get_resolved_runtime_type_pointer(member_type) {
+                let name = hygienic_name(name, expansion_number);
+                add_to_identifier_type_lookup(&mut expansion_type_map, name.clone(), arg_type.clone());
+                expanded_statements.push(create_node(variable_declaration_item(name, expr.clone(), resolved_resolvable_type(arg_type)), call.position));
+            }
+        }
+    }
+
+    let macro_locals = get_macro_local_names(&macro_args, &macro_statements);
+    let mut caller_identifiers = vec!();
+    rename_expansion_identifiers(&mut macro_statements, &macro_locals, expansion_number, &mut caller_identifiers);
+
+    for (name, position) in caller_identifiers {
+        if let Some(caller_type) = get_type_for_identifier(local_type_map, &name) {
+            add_to_identifier_type_lookup(&mut expansion_type_map, name, caller_type.clone());
+        } else {
+            add_type_inference_error(&mut expansion_errors, caller_local_not_found_error(name), position);
         }
-    }    
+    }
+
+    perform_typing_for_procedure_body_statements(typing_actor, ctx, &mut expansion_type_map, local_return_types, &mut macro_statements, expansion_count, &mut expansion_errors);
+
+    expanded_statements.extend(macro_statements);
+    (expanded_statements, expansion_errors)
 }
 
 fn perform_typing_for_procedure_body_return_args(
@@ -84,8 +196,11 @@ fn perform_typing_for_procedure_body_procedure_call(
     type_id: &mut ResolvableType,
     position: SourceFilePosition,
     errors: &mut CompilationErrors
-) {   
+) -> Option<MacroBody> {   
     perform_typing_for_procedure_call_return_first_return_type(typing_actor, ctx, local_type_map, args, name, type_id, position, errors);
+
+    try_get_resolved_runtime_type_pointer(type_id)
+        .and_then(|resolved_type| try_get_macro_definition_runtime_type_item(&resolved_type.item))
 }
 
 fn perform_typing_for_procedure_body_assignment(
@@ -134,6 +249,58 @@ fn perform_typing_for_inferred_procedure_body_assignment(
     }
 }
 
+fn perform_typing_for_procedure_body_assign_value(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    target: &mut AbstractSyntaxNode,
+    value: &mut AbstractSyntaxNode,
+    assignment_type: &mut ResolvableType,
+    errors: &mut CompilationErrors
+) {
+    if let Some(target_type) = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, target, errors) {
+        if let AbstractSyntaxNodeItem::Identifier { name, scope: Scope::Global } = target.item_ref() {
+            add_type_inference_error(errors, assignment_to_constant_error(name.clone()), target.position);
+            return;
+        }
+        perform_typing_for_known_target_type_expression(typing_actor, ctx, local_type_map, value, &target_type, errors);
+        *assignment_type = resolved_resolvable_type(target_type);
+    }
+}
+
+fn perform_typing_for_procedure_body_condition(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    condition: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let condition_type = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, condition, errors);
+    if let Some(condition_type) = condition_type {
+        if condition_type.item != RuntimeTypeItem::Bool {
+            add_type_inference_error(errors, condition_is_not_bool_error(), condition.position);
+        }
+    }
+}
+
+fn perform_typing_for_procedure_body_asm(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    instructions: &mut AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) {
+    for instruction in instructions {
+        if let AbstractSyntaxNodeItem::AsmInstruction { operands, .. } = instruction.item_mut() {
+            for operand in operands {
+                if !matches!(operand.item_ref(), AbstractSyntaxNodeItem::AsmRegister(_)) {
+                    perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, operand, errors);
+                }
+            }
+        }
+    }
+}
+
 pub fn perform_typing_for_procedure_call_return_first_return_type(
     typing_actor: &TypingActor,
     ctx: &CompilationMessageContext,
@@ -146,10 +313,15 @@ pub fn perform_typing_for_procedure_call_return_first_return_type(
 ) -> RuntimeTypePointers {  
     let resolved_arg_types = perform_typing_for_unknown_target_type_args(typing_actor, ctx, local_type_map, args, errors);
     
-    match find_type_by_name_and_args(ctx, &typing_actor.type_repository, name, resolved_arg_types, typing_actor.unit_id, typing_actor.compiler.clone()) {
+    match find_type_by_name_and_args(ctx, &typing_actor.type_repository, name, resolved_arg_types, typing_actor.unit_id, &typing_actor.filename, typing_actor.compiler.clone()) {
         Ok(resolved_type) => {
             *type_id = resolved_resolvable_type(resolved_type.clone());
-            if let Some((_arg_types, return_types)) = try_get_procedure_definition_runtime_type_item(&resolved_type.item) {
+            if let Some(symbol_name) = try_get_procedure_symbol_name(&resolved_type) {
+                *name = symbol_name;
+            }
+            if let Some((arg_types, return_types)) = try_get_procedure_definition_runtime_type_item(&resolved_type.item) {
+                perform_boxing_for_any_args(args, &get_call_arg_types(&arg_types, args.len()));
+                perform_packing_for_variadic_args(args, &arg_types, position);
                 return return_types;
             }
         }        
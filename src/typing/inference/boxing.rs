@@ -0,0 +1,30 @@
+use crate::parsing::*;
+use crate::threading::*;
+use crate::types::*;
+
+pub fn perform_boxing_for_any_args(args: &mut AbstractSyntaxChildNodes, arg_types: &RuntimeTypePointers) {
+    for (arg, arg_type) in args.iter_mut().zip(arg_types) {
+        if is_any_type(arg_type) {
+            perform_boxing_for_any_arg(arg);
+        }
+    }
+}
+
+fn perform_boxing_for_any_arg(arg: &mut AbstractSyntaxNode) {
+    if let AbstractSyntaxNodeItem::Argument { expr, arg_type } = arg.item_mut() {
+        if let Some(resolved_type) = try_get_resolved_runtime_type_pointer(arg_type) {
+            if is_any_type(&resolved_type) {
+                return;
+            }
+
+            let expr_position = expr.position;
+            let unboxed_expr = std::mem::replace(expr, create_node(AbstractSyntaxNodeItem::Error, expr_position));
+            *expr = create_node(boxed_item(resolved_resolvable_type(resolved_type), unboxed_expr), expr_position);
+            *arg_type = resolved_resolvable_type(create_shareable(any_runtime_type()));
+        }
+    }
+}
+
+pub fn boxed_item(boxed_type: ResolvableType, expr: AbstractSyntaxNode) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::Boxed { boxed_type, expr }
+}
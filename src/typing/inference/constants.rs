@@ -15,6 +15,10 @@ pub fn perform_typing_for_constant(
     constant_type: &mut ResolvableType,
     errors: &mut CompilationErrors
 ) {
+    if let AbstractSyntaxNodeItem::Run { expr } = value.item_mut() {
+        return perform_typing_for_constant(typing_actor, ctx, resolved_types, name, expr, constant_type, errors);
+    }
+
     if let Some(resolved_constant_type) = try_get_resolved_runtime_type_pointer(constant_type) {
         perform_typing_for_known_type_constant(typing_actor, ctx, value, &resolved_constant_type, errors);
     } else {
@@ -22,16 +26,16 @@ pub fn perform_typing_for_constant(
     }
 
     if let Some(resolved_constant_type) = try_get_resolved_runtime_type_pointer(constant_type) {
-        resolved_types.push(create_constant_definition_type(typing_actor.unit_id, name, resolved_constant_type));  
+        resolved_types.push(create_constant_definition_type(typing_actor.unit_id, name, resolved_constant_type, typing_actor.symbol_qualifier.clone()));  
     }
 }
 
-fn create_constant_definition_type(unit_id: CompilationUnitId, name: &str, constant_type: RuntimeTypePointer) -> RuntimeTypePointer {
+fn create_constant_definition_type(unit_id: CompilationUnitId, name: &str, constant_type: RuntimeTypePointer, symbol_qualifier: Option<String>) -> RuntimeTypePointer {
     create_shareable(
         create_type(
             user_defined_runtime_type_id(unit_id),
             string(&name),
-            constant_definition_type_item(constant_type),
+            qualified_constant_definition_type_item(constant_type, symbol_qualifier),
             not_required_type_size()
         )
     )
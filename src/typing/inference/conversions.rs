@@ -0,0 +1,39 @@
+use crate::parsing::*;
+use crate::types::*;
+use crate::errors::*;
+use crate::typing::*;
+
+pub fn perform_implicit_conversion(
+    expr: &mut AbstractSyntaxNode,
+    expr_type: &OptionalRuntimeTypePointer,
+    target_type: &RuntimeTypePointer,
+    errors: &mut CompilationErrors
+) {
+    if let Some(expr_type) = expr_type {
+        if let (Some(from), Some(to)) = (try_get_int_type_layout(expr_type), try_get_int_type_layout(target_type)) {
+            if from == to {
+                return;
+            }
+
+            let expr_position = expr.position;
+
+            if !is_safe_int_widening(from, to) {
+                return add_type_inference_error(errors, lossy_implicit_conversion_error(expr_type.name.clone(), target_type.name.clone()), expr_position);
+            }
+
+            let unconverted_expr = std::mem::replace(expr, create_node(AbstractSyntaxNodeItem::Error, expr_position));
+            *expr = create_node(cast_item(resolved_resolvable_type(target_type.clone()), unconverted_expr), expr_position);
+        }
+    }
+}
+
+fn try_get_int_type_layout(int_type: &RuntimeTypePointer) -> Option<(usize, bool)> {
+    if let (RuntimeTypeItem::Int { is_signed }, TypeSize::Resolved { size_in_bytes }) = (&int_type.item, &int_type.size) {
+        return Some((*size_in_bytes, *is_signed));
+    }
+    None
+}
+
+fn is_safe_int_widening((from_size, from_signed): (usize, bool), (to_size, to_signed): (usize, bool)) -> bool {
+    to_size > from_size && (from_signed == to_signed || !from_signed)
+}
@@ -16,12 +16,43 @@ pub enum TypeInferenceError {
     LiteralShouldNotBeResolved,
     TypeSizeInBytesInvalidError(usize),
     TypeCanNotBeFound,
+    TypeNotVisible { declaration_filename: String, declaration_position: SourceFilePosition },
+    NotViableStructMember,
+    NotAType,
+    MemberNotFound(String),
+    IntrinsicArgsInvalid(String),
+    LossyImplicitConversion { from: String, to: String },
+    AssignmentToConstant(String),
+    CallerLocalNotFound(String),
+    ExpansionNotAStatement(String),
+    ProcedureHasNoReturnValue(String),
+    ConditionIsNotBool,
+    IndexIsNotAnInt,
+    NotIndexable,
+    ArrayLengthIsNotConstant,
+    NotCode,
 }
 
 pub fn args_and_known_types_are_not_same_length_error() ->  TypeInferenceError {
     TypeInferenceError::ArgsAndKnownTypesAreNotSameLength
 }
 
+pub fn not_viable_struct_member_error() -> TypeInferenceError {
+    TypeInferenceError::NotViableStructMember
+}
+
+pub fn not_a_type_error() -> TypeInferenceError {
+    TypeInferenceError::NotAType
+}
+
+pub fn member_not_found_error(name: String) -> TypeInferenceError {
+    TypeInferenceError::MemberNotFound(name)
+}
+
+pub fn intrinsic_args_invalid_error(name: String) -> TypeInferenceError {
+    TypeInferenceError::IntrinsicArgsInvalid(name)
+}
+
 pub fn not_viable_procedure_body_statement_error() -> TypeInferenceError {
     TypeInferenceError::NotViableProcedureBodyStatement
 }
@@ -66,6 +97,50 @@ pub fn type_cannot_be_found_error() -> TypeInferenceError {
     TypeInferenceError::TypeCanNotBeFound
 }
 
+pub fn type_not_visible_error(declaration_filename: String, declaration_position: SourceFilePosition) -> TypeInferenceError {
+    TypeInferenceError::TypeNotVisible { declaration_filename, declaration_position }
+}
+
+pub fn lossy_implicit_conversion_error(from: String, to: String) -> TypeInferenceError {
+    TypeInferenceError::LossyImplicitConversion { from, to }
+}
+
+pub fn assignment_to_constant_error(name: String) -> TypeInferenceError {
+    TypeInferenceError::AssignmentToConstant(name)
+}
+
+pub fn caller_local_not_found_error(name: String) -> TypeInferenceError {
+    TypeInferenceError::CallerLocalNotFound(name)
+}
+
+pub fn expansion_not_a_statement_error(name: String) -> TypeInferenceError {
+    TypeInferenceError::ExpansionNotAStatement(name)
+}
+
+pub fn procedure_has_no_return_value_error(name: String) -> TypeInferenceError {
+    TypeInferenceError::ProcedureHasNoReturnValue(name)
+}
+
+pub fn condition_is_not_bool_error() -> TypeInferenceError {
+    TypeInferenceError::ConditionIsNotBool
+}
+
+pub fn index_is_not_an_int_error() -> TypeInferenceError {
+    TypeInferenceError::IndexIsNotAnInt
+}
+
+pub fn not_indexable_error() -> TypeInferenceError {
+    TypeInferenceError::NotIndexable
+}
+
+pub fn array_length_is_not_constant_error() -> TypeInferenceError {
+    TypeInferenceError::ArrayLengthIsNotConstant
+}
+
+pub fn not_code_error() -> TypeInferenceError {
+    TypeInferenceError::NotCode
+}
+
 pub fn add_type_inference_error(errors: &mut CompilationErrors, error: TypeInferenceError, position: SourceFilePosition) {
     add_compilation_error(errors, compilation_error(type_inference_error(error), position));
 }
@@ -0,0 +1,104 @@
+use crate::parsing::*;
+use crate::utilities::*;
+
+pub type CallerIdentifiers = Vec<(String, SourceFilePosition)>;
+
+pub fn hygienic_name(name: &str, expansion_number: usize) -> String {
+    format!("{}#expand{}", name, expansion_number)
+}
+
+pub fn get_macro_local_names(args: &AbstractSyntaxChildNodes, statements: &AbstractSyntaxChildNodes) -> Vec<String> {
+    let mut names = vec!();
+    add_macro_local_names(&mut names, args);
+    add_macro_local_names(&mut names, statements);
+    names
+}
+
+fn add_macro_local_names(names: &mut Vec<String>, nodes: &AbstractSyntaxChildNodes) {
+    for node in nodes {
+        match node.item_ref() {
+            AbstractSyntaxNodeItem::MemberDeclaration { name, .. } |
+            AbstractSyntaxNodeItem::VariableDeclaration { name, .. } => names.push(name.clone()),
+            item => {
+                for body in get_branch_bodies(item) {
+                    add_macro_local_names(names, body);
+                }
+            }
+        }
+    }
+}
+
+pub fn rename_expansion_identifiers(
+    nodes: &mut AbstractSyntaxChildNodes,
+    macro_locals: &[String],
+    expansion_number: usize,
+    caller_identifiers: &mut CallerIdentifiers
+) {
+    for node in nodes {
+        rename_expansion_identifiers_in_node(node, macro_locals, expansion_number, caller_identifiers);
+    }
+}
+
+fn rename_expansion_identifiers_in_node(
+    node: &mut AbstractSyntaxNode,
+    macro_locals: &[String],
+    expansion_number: usize,
+    caller_identifiers: &mut CallerIdentifiers
+) {
+    let position = node.position;
+
+    match node.item_mut() {
+        AbstractSyntaxNodeItem::Identifier { name, .. } | AbstractSyntaxNodeItem::Instance { name, .. } =>
+            rename_expansion_identifier(name, position, macro_locals, expansion_number, caller_identifiers),
+        AbstractSyntaxNodeItem::VariableDeclaration { name, value, .. } => {
+            rename_expansion_identifier(name, position, macro_locals, expansion_number, caller_identifiers);
+            rename_expansion_identifiers_in_node(value, macro_locals, expansion_number, caller_identifiers);
+        },
+        AbstractSyntaxNodeItem::Assignment { target, value, .. } => {
+            rename_expansion_identifiers_in_node(target, macro_locals, expansion_number, caller_identifiers);
+            rename_expansion_identifiers_in_node(value, macro_locals, expansion_number, caller_identifiers);
+        },
+        AbstractSyntaxNodeItem::BinaryExpr { lhs, rhs, .. } |
+        AbstractSyntaxNodeItem::IndexExpr { instance: lhs, index: rhs, .. } => {
+            rename_expansion_identifiers_in_node(lhs, macro_locals, expansion_number, caller_identifiers);
+            rename_expansion_identifiers_in_node(rhs, macro_locals, expansion_number, caller_identifiers);
+        },
+        AbstractSyntaxNodeItem::Argument { expr, .. } |
+        AbstractSyntaxNodeItem::Cast { expr, .. } |
+        AbstractSyntaxNodeItem::Boxed { expr, .. } =>
+            rename_expansion_identifiers_in_node(expr, macro_locals, expansion_number, caller_identifiers),
+        AbstractSyntaxNodeItem::If { condition, body, else_body } => {
+            rename_expansion_identifiers_in_node(condition, macro_locals, expansion_number, caller_identifiers);
+            rename_expansion_identifiers(body, macro_locals, expansion_number, caller_identifiers);
+            rename_expansion_identifiers(else_body, macro_locals, expansion_number, caller_identifiers);
+        },
+        AbstractSyntaxNodeItem::While { condition, body } => {
+            rename_expansion_identifiers_in_node(condition, macro_locals, expansion_number, caller_identifiers);
+            rename_expansion_identifiers(body, macro_locals, expansion_number, caller_identifiers);
+        },
+        AbstractSyntaxNodeItem::MemberExpr { instance, .. } =>
+            rename_expansion_identifiers_in_node(instance, macro_locals, expansion_number, caller_identifiers),
+        AbstractSyntaxNodeItem::ProcedureCall { args: nodes, .. } |
+        AbstractSyntaxNodeItem::VariadicArgs { args: nodes } |
+        AbstractSyntaxNodeItem::Return { args: nodes } |
+        AbstractSyntaxNodeItem::Asm { instructions: nodes } |
+        AbstractSyntaxNodeItem::AsmInstruction { operands: nodes, .. } =>
+            rename_expansion_identifiers(nodes, macro_locals, expansion_number, caller_identifiers),
+        _ => {}
+    }
+}
+
+fn rename_expansion_identifier(
+    name: &mut String,
+    position: SourceFilePosition,
+    macro_locals: &[String],
+    expansion_number: usize,
+    caller_identifiers: &mut CallerIdentifiers
+) {
+    if let Some(caller_name) = try_get_caller_identifier_name(name).map(string) {
+        caller_identifiers.push((caller_name.clone(), position));
+        *name = caller_name;
+    } else if macro_locals.contains(name) {
+        *name = hygienic_name(name, expansion_number);
+    }
+}
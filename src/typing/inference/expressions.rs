@@ -14,26 +14,35 @@ pub fn perform_typing_for_known_target_type_expression(
     known_target_type: &RuntimeTypePointer,
     errors: &mut CompilationErrors
 ) { 
+    perform_typing_for_inserted_expression(typing_actor, ctx, expr, errors);
+    perform_typing_for_intrinsic(typing_actor, ctx, local_type_map, expr, errors);
+
     let expr_position = expr.position.clone();
     
-    match expr.item_mut() {
+    let expr_type = match expr.item_mut() {
         AbstractSyntaxNodeItem::Literal(literal) => {
             perform_typing_for_known_target_type_expression_literal(literal, known_target_type, expr_position, errors);
+            None
         },
-        AbstractSyntaxNodeItem::Identifier { name, scope }  => {
-            perform_typing_for_expression_identifier(typing_actor, ctx, local_type_map, name, scope, expr_position, errors);
-        },
-        AbstractSyntaxNodeItem::BinaryExpr { lhs, rhs, expression_type, ..} => {
-            perform_typing_for_expression_expression(typing_actor, ctx, local_type_map, lhs, rhs, expression_type, errors);
-        },
-        AbstractSyntaxNodeItem::ProcedureCall { name, args, procedure_call_type } => {
-            perform_typing_for_expression_procedure_call(typing_actor, ctx, local_type_map, name, args, procedure_call_type, expr_position, errors);
-        },
-        AbstractSyntaxNodeItem::MemberExpr { instance, member, member_expression_type } => {
-            perform_typing_for_member_expression(typing_actor, ctx, local_type_map, instance, member, member_expression_type, errors);
+        AbstractSyntaxNodeItem::Identifier { name, scope }  =>
+            perform_typing_for_expression_identifier(typing_actor, ctx, local_type_map, name, scope, expr_position, errors),
+        AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, expression_type } => {
+            let operand_types = perform_typing_for_expression_operands(typing_actor, ctx, local_type_map, lhs, rhs, errors);
+            let operand_type = perform_typing_for_expression_expression(op, operand_types, expression_type, errors);
+            perform_typing_for_comparison_expression(op, operand_type, expression_type)
         },
-        _ => {}
-    }
+        AbstractSyntaxNodeItem::ProcedureCall { name, args, procedure_call_type } =>
+            perform_typing_for_expression_procedure_call(typing_actor, ctx, local_type_map, name, args, procedure_call_type, expr_position, errors),
+        AbstractSyntaxNodeItem::Cast { cast_type, expr, .. } =>
+            perform_typing_for_expression_cast(typing_actor, ctx, local_type_map, cast_type, expr, errors),
+        AbstractSyntaxNodeItem::MemberExpr { instance, member, member_expression_type } =>
+            perform_typing_for_member_expression(typing_actor, ctx, local_type_map, instance, member, member_expression_type, errors),
+        AbstractSyntaxNodeItem::IndexExpr { instance, index, index_expression_type } =>
+            perform_typing_for_index_expression(typing_actor, ctx, local_type_map, instance, index, index_expression_type, errors),
+        _ => None
+    };
+
+    perform_implicit_conversion(expr, &expr_type, known_target_type, errors);
 }
 
 fn perform_typing_for_known_target_type_expression_literal(
@@ -179,6 +188,9 @@ pub fn perform_typing_for_inferred_type_expression(
     expr: &mut AbstractSyntaxNode,
     errors: &mut CompilationErrors
 )  -> OptionalRuntimeTypePointer { 
+    perform_typing_for_inserted_expression(typing_actor, ctx, expr, errors);
+    perform_typing_for_intrinsic(typing_actor, ctx, local_type_map, expr, errors);
+
     let expr_position = expr.position.clone();
 
     match expr.item_mut() {
@@ -188,14 +200,21 @@ pub fn perform_typing_for_inferred_type_expression(
             perform_typing_for_inferred_type_expression_literal(literal, expr_position, errors),
         AbstractSyntaxNodeItem::Identifier { name, scope} =>
             perform_typing_for_expression_identifier(typing_actor, ctx, local_type_map, name, scope, expr_position, errors),
-        AbstractSyntaxNodeItem::BinaryExpr { lhs, rhs, expression_type: type_id, ..} =>
-            perform_typing_for_expression_expression(typing_actor, ctx, local_type_map, lhs, rhs, type_id, errors),
+        AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, expression_type: type_id } => {
+            let operand_types = perform_typing_for_expression_operands(typing_actor, ctx, local_type_map, lhs, rhs, errors);
+            let operand_type = perform_typing_for_expression_expression(op, operand_types, type_id, errors);
+            perform_typing_for_comparison_expression(op, operand_type, type_id)
+        },
         AbstractSyntaxNodeItem::ProcedureCall { name, args, procedure_call_type: type_id } =>
             perform_typing_for_expression_procedure_call(typing_actor, ctx, local_type_map, name, args, type_id, expr_position, errors),
-        AbstractSyntaxNodeItem::Cast { cast_type, expr} =>
+        AbstractSyntaxNodeItem::Cast { cast_type, expr, .. } =>
             perform_typing_for_expression_cast(typing_actor, ctx, local_type_map, cast_type, expr, errors),
         AbstractSyntaxNodeItem::MemberExpr { instance, member, member_expression_type } =>
             perform_typing_for_member_expression(typing_actor, ctx, local_type_map, instance, member, member_expression_type, errors),
+        AbstractSyntaxNodeItem::IndexExpr { instance, index, index_expression_type } =>
+            perform_typing_for_index_expression(typing_actor, ctx, local_type_map, instance, index, index_expression_type, errors),
+        AbstractSyntaxNodeItem::TypeInfo(_) =>
+            Some(create_shareable(type_info_pointer_runtime_type())),
         _ => None
     }
 }
@@ -237,23 +256,36 @@ fn perform_typing_for_expression_identifier(
         *scope = local_scope();
         return Some(local_identifier_type.clone());
     }
-    *scope = global_scope();
-    get_global_type_for_identifier(typing_actor, ctx, name, identifier_position, errors)
+    get_global_type_for_identifier(typing_actor, ctx, name, scope, identifier_position, errors)
 }
 
 fn get_global_type_for_identifier(
     typing_actor: &TypingActor,
     ctx: &CompilationMessageContext,
     name: &mut String,
+    scope: &mut Scope,
     identifier_position: SourceFilePosition,   
     errors: &mut CompilationErrors
 ) -> OptionalRuntimeTypePointer {
+    *scope = global_scope();
 
-    match find_type_by_name(ctx, &typing_actor.type_repository, name, typing_actor.unit_id, typing_actor.compiler.clone()) {
+    match find_value_by_name(ctx, &typing_actor.type_repository, name, typing_actor.unit_id, &typing_actor.filename, typing_actor.compiler.clone()) {
         Ok(global_type) => {
+            if let Some(symbol_name) = try_get_global_symbol_name(&global_type) {
+                *name = symbol_name;
+            }
             if let Some(global_type) = try_get_constant_definition_runtime_type_item(&global_type.item) {
                 return Some(global_type);
             }        
+            if let Some(global_type) = try_get_global_variable_definition_runtime_type_item(&global_type.item) {
+                *scope = global_variable_scope();
+                return Some(global_type);
+            }        
+            if let Some(symbol_name) = try_get_procedure_symbol_name(&global_type) {
+                *name = symbol_name;
+                *scope = procedure_scope();
+                return Some(create_shareable(void_pointer_runtime_type()));
+            }
         },
         Err(error) => {
             add_compilation_error(errors, compilation_error(error, identifier_position));
@@ -263,19 +295,32 @@ fn get_global_type_for_identifier(
     None
 }
 
-fn perform_typing_for_expression_expression(
+fn perform_typing_for_expression_operands(
     typing_actor: &TypingActor,
     ctx: &CompilationMessageContext,
     local_type_map: &IdentifierTypeLookup,
     lhs: &mut AbstractSyntaxNode,
     rhs: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) -> (OptionalRuntimeTypePointer, OptionalRuntimeTypePointer) {
+    if is_unresolved_literal(lhs) && !is_unresolved_literal(rhs) {
+        let rhs_resolved_type = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, rhs, errors);
+        let lhs_resolved_type = perform_typing_for_operand_expression(typing_actor, ctx, local_type_map, lhs, &rhs_resolved_type, errors);
+        (lhs_resolved_type, rhs_resolved_type)
+    } else {
+        let lhs_resolved_type = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, lhs, errors);
+        let rhs_resolved_type = perform_typing_for_operand_expression(typing_actor, ctx, local_type_map, rhs, &lhs_resolved_type, errors);
+        (lhs_resolved_type, rhs_resolved_type)
+    }
+}
+
+fn perform_typing_for_expression_expression(
+    op: &AbstractSyntaxNode,
+    (lhs_resolved_type, rhs_resolved_type): (OptionalRuntimeTypePointer, OptionalRuntimeTypePointer),
     type_id: &mut ResolvableType,
     errors: &mut CompilationErrors
 ) -> OptionalRuntimeTypePointer {
-    let lhs_resolved_type = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, lhs, errors);
-    let rhs_resolved_type = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, rhs, errors);
-    
-    if lhs_resolved_type != rhs_resolved_type {
+    if lhs_resolved_type != rhs_resolved_type && !is_pointer_offset_expression(op, &lhs_resolved_type, &rhs_resolved_type) {
         todo(errors, function!(), "deal with different types on either side of expression");
     }
 
@@ -287,6 +332,57 @@ fn perform_typing_for_expression_expression(
     None
 }
 
+fn perform_typing_for_operand_expression(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    operand: &mut AbstractSyntaxNode,
+    other_operand_type: &OptionalRuntimeTypePointer,
+    errors: &mut CompilationErrors
+) -> OptionalRuntimeTypePointer {
+    if let Some(other_operand_type) = other_operand_type {
+        if is_unresolved_literal_for_type(operand, other_operand_type) {
+            perform_typing_for_known_target_type_expression(typing_actor, ctx, local_type_map, operand, other_operand_type, errors);
+            return Some(other_operand_type.clone());
+        }
+    }
+    perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, operand, errors)
+}
+
+fn is_pointer_offset_expression(op: &AbstractSyntaxNode, lhs_type: &OptionalRuntimeTypePointer, rhs_type: &OptionalRuntimeTypePointer) -> bool {
+    let is_offset_operator = matches!(op.item_ref(), AbstractSyntaxNodeItem::Operator(Operator::Add | Operator::Subtract));
+    match (lhs_type, rhs_type) {
+        (Some(lhs_type), Some(rhs_type)) =>
+            is_offset_operator && matches!((&lhs_type.item, &rhs_type.item), (RuntimeTypeItem::Pointer { .. }, RuntimeTypeItem::Int { .. })),
+        _ => false
+    }
+}
+
+fn is_unresolved_literal(node: &AbstractSyntaxNode) -> bool {
+    matches!(node.item_ref(), AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(_)))
+}
+
+fn is_unresolved_literal_for_type(node: &AbstractSyntaxNode, target_type: &RuntimeTypePointer) -> bool {
+    matches!(
+        (node.item_ref(), &target_type.item),
+        (AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::Float(_))), RuntimeTypeItem::Float) |
+        (AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::Int(_))), RuntimeTypeItem::Int { .. })
+    )
+}
+
+fn perform_typing_for_comparison_expression(
+    op: &AbstractSyntaxNode,
+    operand_type: OptionalRuntimeTypePointer,
+    type_id: &mut ResolvableType
+) -> OptionalRuntimeTypePointer {
+    if operand_type.is_some() && is_comparison_operator_node(op) {
+        let resolved_type = create_shareable(bool_runtime_type());
+        *type_id = resolved_resolvable_type(resolved_type.clone());
+        return Some(resolved_type);
+    }
+    operand_type
+}
+
 fn perform_typing_for_expression_procedure_call(
     typing_actor: &TypingActor,
     ctx: &CompilationMessageContext,
@@ -307,12 +403,19 @@ fn perform_typing_for_expression_procedure_call(
         position,
         errors
     );
+
+    if try_get_resolved_runtime_type_pointer(type_id).and_then(|resolved_type| try_get_macro_definition_runtime_type_item(&resolved_type.item)).is_some() {
+        add_type_inference_error(errors, expansion_not_a_statement_error(name.clone()), position);
+        return None;
+    }
     
     if resolved_types.len() > 0 {
         return Some(resolved_types.first().unwrap().clone());
     }
 
-    todo(errors, function!(), "Deal with no resolved types returned");
+    if try_get_resolved_runtime_type_pointer(type_id).is_some() {
+        add_type_inference_error(errors, procedure_has_no_return_value_error(name.clone()), position);
+    }
     None
 }
 
@@ -325,14 +428,11 @@ fn perform_typing_for_expression_cast(
     errors: &mut CompilationErrors
 ) -> OptionalRuntimeTypePointer {
     if let Some(resolved_cast_type) = try_get_resolved_runtime_type_pointer(&cast_type) {
-        perform_typing_for_known_target_type_expression(
-            typing_actor,
-            ctx,
-            local_type_map,
-            expr,
-            &resolved_cast_type,
-            errors
-        );
+        if is_unresolved_literal(expr) {
+            perform_typing_for_known_target_type_expression(typing_actor, ctx, local_type_map, expr, &resolved_cast_type, errors);
+        } else {
+            perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, expr, errors);
+        }
         return Some(resolved_cast_type.clone());
     }
     None
@@ -363,6 +463,52 @@ fn perform_typing_for_member_expression(
     None
 }
 
+fn perform_typing_for_index_expression(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    instance: &mut AbstractSyntaxNode,
+    index: &mut AbstractSyntaxNode,
+    index_expression_type: &mut ResolvableType,
+    errors: &mut CompilationErrors
+) -> OptionalRuntimeTypePointer {
+    let index_type = if is_unresolved_literal(index) {
+        let s64_type = create_shareable(signed_int_64_runtime_type());
+        perform_typing_for_known_target_type_expression(typing_actor, ctx, local_type_map, index, &s64_type, errors);
+        Some(s64_type)
+    } else {
+        perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, index, errors)
+    };
+
+    if let Some(index_type) = index_type {
+        if !matches!(index_type.item, RuntimeTypeItem::Int { .. }) {
+            add_type_inference_error(errors, index_is_not_an_int_error(), index.position);
+        }
+    }
+
+    let instance_position = instance.position;
+    if let Some(instance_type) = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, instance, errors) {
+        if let Some(element_type) = try_get_indexed_element_type(&instance_type) {
+            *index_expression_type = resolved_resolvable_type(element_type.clone());
+            return Some(element_type);
+        }
+        add_type_inference_error(errors, not_indexable_error(), instance_position);
+    }
+    None
+}
+
+fn try_get_indexed_element_type(instance_type: &RuntimeTypePointer) -> OptionalRuntimeTypePointer {
+    if let RuntimeTypeItem::Pointer { to_type } = &instance_type.item {
+        if let TypeSize::Resolved { .. } = to_type.size {
+            return Some(create_shareable(to_type.as_ref().clone()));
+        }
+    }
+    if let Some(element_type) = try_get_array_element_type(instance_type) {
+        return Some(create_shareable(element_type.clone()));
+    }
+    None
+}
+
 fn perform_typing_for_member_expression_instance(
     typing_actor: &TypingActor,
     ctx: &CompilationMessageContext,
@@ -400,15 +546,9 @@ fn perform_typing_for_member_expression_member_member(
     name: &mut String,
     member_type: &mut ResolvableType
 ) -> OptionalRuntimeTypePointer {
-    match &instance_type.item {
-        RuntimeTypeItem::String { members } => {
-            if let Some(field_type) = get_type_of_member_by_member_name(members, name) {
-                *member_type = resolved_resolvable_type(field_type.clone());
-                return Some(field_type);
-            }
-            None
-
-        },
-        _ => None
+    if let Some(field_type) = try_get_instance_members(instance_type).and_then(|members| get_type_of_member_by_member_name(members, name)) {
+        *member_type = resolved_resolvable_type(field_type.clone());
+        return Some(field_type);
     }
+    None
 }
@@ -0,0 +1,48 @@
+use crate::parsing::*;
+use crate::compilation::*;
+use crate::threading::*;
+use crate::typing::*;
+use crate::types::*;
+use crate::utilities::*;
+use crate::errors::*;
+
+pub fn perform_typing_for_global_variable(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    resolved_types: &mut RuntimeTypePointers,
+    name: &str,
+    value: &mut AbstractSyntaxNode,
+    variable_type: &ResolvableType,
+    errors: &mut CompilationErrors
+) {
+    if let Some(resolved_variable_type) = try_get_resolved_runtime_type_pointer(variable_type) {
+        if !is_zero_initialised_global_variable(value) {
+            perform_typing_for_known_target_type_expression(
+                typing_actor,
+                ctx,
+                &create_identifier_type_lookup(), 
+                value,
+                &resolved_variable_type,
+                errors
+            );
+        }
+        resolved_types.push(create_global_variable_definition_type(typing_actor.unit_id, name, resolved_variable_type, typing_actor.symbol_qualifier.clone()));
+    } else {
+        todo(errors, function!(), "Global variables of user defined types");
+    }
+}
+
+pub fn is_zero_initialised_global_variable(value: &AbstractSyntaxNode) -> bool {
+    matches!(value.item_ref(), AbstractSyntaxNodeItem::Null)
+}
+
+fn create_global_variable_definition_type(unit_id: CompilationUnitId, name: &str, variable_type: RuntimeTypePointer, symbol_qualifier: Option<String>) -> RuntimeTypePointer {
+    create_shareable(
+        create_type(
+            user_defined_runtime_type_id(unit_id),
+            string(name),
+            qualified_global_variable_definition_type_item(variable_type, symbol_qualifier),
+            not_required_type_size()
+        )
+    )
+}
@@ -6,11 +6,12 @@ use crate::utilities::*;
 use crate::errors::*;
 
 pub fn perform_typing_for_procedure_header(
-    unit_id: CompilationUnitId,
+    typing_actor: &TypingActor,
     name: &str,
     resolved_types: &mut RuntimeTypePointers,
     args: &mut AbstractSyntaxChildNodes,
     return_args: &mut AbstractSyntaxChildNodes, 
+    body: &ProcedureBodyReference,
     errors: &mut CompilationErrors
 ) {
     let mut arg_types = vec!();
@@ -34,20 +35,43 @@ pub fn perform_typing_for_procedure_header(
         }
     }
 
-    resolved_types.push(create_procedure_definition_type(unit_id, name, arg_types, return_arg_types));  
+    if let Some(body) = try_get_expand_procedure_body(body) {
+        resolved_types.push(create_macro_definition_type(typing_actor.unit_id, name, arg_types, macro_body(errors.filename.clone(), body.clone())));
+    } else if let ProcedureBodyReference::Foerign(_) = body {
+        resolved_types.push(create_procedure_definition_type(typing_actor.unit_id, name, arg_types, return_arg_types, None));
+    } else {
+        resolved_types.push(create_procedure_definition_type(typing_actor.unit_id, name, arg_types, return_arg_types, typing_actor.symbol_qualifier.clone()));
+    }
 }
 
 fn create_procedure_definition_type(
     unit_id: CompilationUnitId,
     name: &str,
     arg_types: RuntimeTypePointers,
-    return_arg_types: RuntimeTypePointers
+    return_arg_types: RuntimeTypePointers,
+    symbol_qualifier: Option<String>
 ) -> RuntimeTypePointer {
     create_shareable(
         create_type(
             user_defined_runtime_type_id(unit_id),
             string(&name),
-            procedure_definition_type_item(arg_types, return_arg_types),
+            qualified_procedure_definition_type_item(arg_types, return_arg_types, symbol_qualifier),
+            not_required_type_size()
+        )
+    )
+}
+
+fn create_macro_definition_type(
+    unit_id: CompilationUnitId,
+    name: &str,
+    arg_types: RuntimeTypePointers,
+    body: MacroBody
+) -> RuntimeTypePointer {
+    create_shareable(
+        create_type(
+            user_defined_runtime_type_id(unit_id),
+            string(name),
+            macro_definition_type_item(arg_types, body),
             not_required_type_size()
         )
     )
@@ -0,0 +1,149 @@
+use crate::parsing::*;
+use crate::compilation::*;
+use crate::threading::*;
+use crate::typing::*;
+use crate::types::*;
+use crate::strings::*;
+use crate::utilities::*;
+use crate::errors::*;
+
+pub fn perform_typing_for_code_constant(
+    typing_actor: &TypingActor,
+    resolved_types: &mut RuntimeTypePointers,
+    name: &str,
+    value: &AbstractSyntaxNode
+) {
+    resolved_types.push(create_code_definition_type(typing_actor.unit_id, name, code_body(typing_actor.filename.clone(), value.clone())));
+}
+
+pub fn create_code_definition_type(unit_id: CompilationUnitId, name: &str, body: CodeBody) -> RuntimeTypePointer {
+    create_shareable(
+        create_type(
+            user_defined_runtime_type_id(unit_id),
+            string(name),
+            code_definition_type_item(body),
+            not_required_type_size()
+        )
+    )
+}
+
+pub fn perform_typing_for_insert(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    expr: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    if let AbstractSyntaxNodeItem::Identifier { .. } = expr.item_ref() {
+        if let Some(code) = find_inserted_code(typing_actor, ctx, expr, errors) {
+            *expr = code;
+        }
+        return;
+    }
+
+    perform_typing_for_inferred_type_expression(typing_actor, ctx, &create_identifier_type_lookup(), expr, errors);
+}
+
+pub fn perform_typing_for_procedure_body_insert(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    expr: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) -> AbstractSyntaxChildNodes {
+    let mut statements = match find_inserted_code(typing_actor, ctx, expr, errors).as_ref().map(|code| code.item_ref()) {
+        Some(AbstractSyntaxNodeItem::Code { units }) => code_units_to_statements(units),
+        Some(AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(source)))) =>
+            match parse_inserted_statements(&byte_string_to_string(source)) {
+                Ok(statements) => statements,
+                Err(error) => {
+                    add_compilation_error(errors, error);
+                    vec!()
+                }
+            },
+        _ => vec!()
+    };
+
+    for statement in &mut statements {
+        perform_typing_for_named_types(typing_actor, ctx, statement, errors);
+    }
+
+    statements
+}
+
+pub fn perform_typing_for_inserted_expression(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    expr: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let inserted = if let AbstractSyntaxNodeItem::Insert { expr: inserted } = expr.item_ref() {
+        find_inserted_code(typing_actor, ctx, inserted, errors)
+    } else {
+        return;
+    };
+
+    let position = expr.position;
+
+    let value = match inserted.as_ref().map(|code| code.item_ref()) {
+        Some(AbstractSyntaxNodeItem::Code { units }) if units.len() == 1 => Some(units[0].tree.clone()),
+        Some(AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(source)))) =>
+            match parse_inserted_expression(&byte_string_to_string(source)) {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    add_compilation_error(errors, error);
+                    None
+                }
+            },
+        Some(_) => {
+            add_type_inference_error(errors, not_code_error(), position);
+            None
+        },
+        None => None
+    };
+
+    if let Some(mut value) = value {
+        perform_typing_for_named_types(typing_actor, ctx, &mut value, errors);
+        *expr = value;
+    }
+}
+
+fn find_inserted_code(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    expr: &AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) -> Option<AbstractSyntaxNode> {
+    match expr.item_ref() {
+        AbstractSyntaxNodeItem::Identifier { name, .. } =>
+            find_code_by_name(typing_actor, ctx, name, expr.position, errors).map(|body| body.code),
+        AbstractSyntaxNodeItem::Literal(ResolvableLiteral::Unresolved(UnresolvedLiteral::String(_))) =>
+            Some(expr.clone()),
+        _ => {
+            add_type_inference_error(errors, not_code_error(), expr.position);
+            None
+        }
+    }
+}
+
+fn find_code_by_name(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    name: &str,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> Option<CodeBody> {
+    let mut name = string(name);
+
+    match find_type_by_name(ctx, &typing_actor.type_repository, &mut name, typing_actor.unit_id, &typing_actor.filename, typing_actor.compiler.clone()) {
+        Ok(code_type) => {
+            let code = try_get_code_definition_runtime_type_item(&code_type.item);
+            if code.is_none() {
+                add_type_inference_error(errors, not_code_error(), position);
+            }
+            code
+        },
+        Err(error) => {
+            add_compilation_error(errors, compilation_error(error, position));
+            None
+        }
+    }
+}
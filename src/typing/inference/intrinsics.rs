@@ -0,0 +1,190 @@
+use crate::parsing::*;
+use crate::compilation::*;
+use crate::typing::*;
+use crate::types::*;
+use crate::errors::*;
+use crate::utilities::*;
+
+const INTRINSIC_SIZE_OF: &str = "size_of";
+const INTRINSIC_ALIGN_OF: &str = "align_of";
+const INTRINSIC_OFFSET_OF: &str = "offset_of";
+const INTRINSIC_TYPE_OF: &str = "type_of";
+const INTRINSIC_TYPE_INFO: &str = "type_info";
+
+pub fn perform_typing_for_intrinsic(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    expr: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let expr_position = expr.position;
+
+    if let AbstractSyntaxNodeItem::ProcedureCall { name, args, .. } = expr.item_mut() {
+        let folded = match name.as_str() {
+            INTRINSIC_SIZE_OF => perform_typing_for_size_of(typing_actor, ctx, local_type_map, args, expr_position, errors),
+            INTRINSIC_ALIGN_OF => perform_typing_for_align_of(typing_actor, ctx, local_type_map, args, expr_position, errors),
+            INTRINSIC_OFFSET_OF => perform_typing_for_offset_of(typing_actor, ctx, local_type_map, args, expr_position, errors),
+            INTRINSIC_TYPE_OF => perform_typing_for_type_of(typing_actor, ctx, local_type_map, args, expr_position, errors),
+            INTRINSIC_TYPE_INFO => perform_typing_for_type_info(typing_actor, ctx, local_type_map, args, expr_position, errors),
+            _ => return
+        };
+
+        *expr.item_mut() = folded.unwrap_or(AbstractSyntaxNodeItem::Error);
+    }
+}
+
+fn perform_typing_for_size_of(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    args: &mut AbstractSyntaxChildNodes,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> Option<AbstractSyntaxNodeItem> {
+    if args.len() != 1 {
+        add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_SIZE_OF)), position);
+        return None;
+    }
+
+    let size_of_type = perform_typing_for_intrinsic_type_arg(typing_actor, ctx, local_type_map, &mut args[0], errors)?;
+    let size_in_bytes = try_get_resolved_type_size(&size_of_type.size).unwrap_or(0);
+    Some(intrinsic_int_literal_item(size_in_bytes))
+}
+
+fn perform_typing_for_align_of(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    args: &mut AbstractSyntaxChildNodes,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> Option<AbstractSyntaxNodeItem> {
+    if args.len() != 1 {
+        add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_ALIGN_OF)), position);
+        return None;
+    }
+
+    let align_of_type = perform_typing_for_intrinsic_type_arg(typing_actor, ctx, local_type_map, &mut args[0], errors)?;
+    Some(intrinsic_int_literal_item(get_type_alignment(&align_of_type)))
+}
+
+fn perform_typing_for_offset_of(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    args: &mut AbstractSyntaxChildNodes,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> Option<AbstractSyntaxNodeItem> {
+    if args.len() != 2 {
+        add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_OFFSET_OF)), position);
+        return None;
+    }
+
+    let offset_of_type = perform_typing_for_intrinsic_type_arg(typing_actor, ctx, local_type_map, &mut args[0], errors)?;
+    let member_position = args[1].position;
+
+    let member_name = match try_get_intrinsic_arg_expr(&args[1]).map(|expr| expr.item_ref()) {
+        Some(AbstractSyntaxNodeItem::Identifier { name, .. }) => name.clone(),
+        _ => {
+            add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_OFFSET_OF)), member_position);
+            return None;
+        }
+    };
+
+    let offset = match &offset_of_type.item {
+        RuntimeTypeItem::String { members } | RuntimeTypeItem::Struct { members } => get_member_offset(members, &member_name),
+        _ => None
+    };
+
+    if let Some(offset) = offset {
+        return Some(intrinsic_int_literal_item(offset));
+    }
+
+    add_type_inference_error(errors, member_not_found_error(member_name), member_position);
+    None
+}
+
+fn perform_typing_for_type_of(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    args: &mut AbstractSyntaxChildNodes,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> Option<AbstractSyntaxNodeItem> {
+    if args.len() != 1 {
+        add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_TYPE_OF)), position);
+        return None;
+    }
+
+    if let AbstractSyntaxNodeItem::Argument { expr, .. } = args[0].item_mut() {
+        let type_of_type = perform_typing_for_inferred_type_expression(typing_actor, ctx, local_type_map, expr, errors)?;
+        return Some(type_item(resolved_resolvable_type(type_of_type)));
+    }
+
+    add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_TYPE_OF)), position);
+    None
+}
+
+fn perform_typing_for_type_info(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    args: &mut AbstractSyntaxChildNodes,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> Option<AbstractSyntaxNodeItem> {
+    if args.len() != 1 {
+        add_type_inference_error(errors, intrinsic_args_invalid_error(string(INTRINSIC_TYPE_INFO)), position);
+        return None;
+    }
+
+    let type_info_type = perform_typing_for_intrinsic_type_arg(typing_actor, ctx, local_type_map, &mut args[0], errors)?;
+    Some(type_info_item(resolved_resolvable_type(type_info_type)))
+}
+
+fn perform_typing_for_intrinsic_type_arg(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    arg: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) -> OptionalRuntimeTypePointer {
+    let arg_position = arg.position;
+
+    if let AbstractSyntaxNodeItem::Argument { expr, .. } = arg.item_mut() {
+        perform_typing_for_intrinsic(typing_actor, ctx, local_type_map, expr, errors);
+
+        let expr_position = expr.position;
+        return match expr.item_mut() {
+            AbstractSyntaxNodeItem::Type(ResolvableType::Resolved(resolved_type)) => Some(resolved_type.clone()),
+            AbstractSyntaxNodeItem::Type(ResolvableType::UnresolvedNamed(name)) |
+            AbstractSyntaxNodeItem::Identifier { name, .. } => find_struct_type_by_name(typing_actor, ctx, name, expr_position, errors),
+            AbstractSyntaxNodeItem::Error => None,
+            _ => {
+                add_type_inference_error(errors, not_a_type_error(), expr_position);
+                None
+            }
+        };
+    }
+
+    add_type_inference_error(errors, not_a_type_error(), arg_position);
+    None
+}
+
+fn try_get_intrinsic_arg_expr(arg: &AbstractSyntaxNode) -> Option<&AbstractSyntaxNode> {
+    if let AbstractSyntaxNodeItem::Argument { expr, .. } = arg.item_ref() {
+        return Some(expr);
+    }
+    None
+}
+
+fn intrinsic_int_literal_item(value: usize) -> AbstractSyntaxNodeItem {
+    literal_item(unresolved_resolvable_literal(unresolved_int_literal(value.to_string())))
+}
+
+pub fn type_info_item(t: ResolvableType) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::TypeInfo(t)
+}
@@ -1,13 +1,31 @@
 mod header;
 mod body;
 mod constants;
+mod globals;
 mod expressions;
+mod structs;
+mod named_types;
+mod intrinsics;
+mod boxing;
+mod variadics;
+mod conversions;
+mod expansion;
+mod inserts;
 mod errors;
 
 pub use header::*;
 pub use body::*;
 pub use constants::*;
+pub use globals::*;
 pub use expressions::*;
+pub use structs::*;
+pub use named_types::*;
+pub use intrinsics::*;
+pub use boxing::*;
+pub use variadics::*;
+pub use conversions::*;
+pub use expansion::*;
+pub use inserts::*;
 pub use errors::*;
 
 use std::collections::*;
@@ -23,19 +41,25 @@ pub struct TypingActor {
     type_repository: CompilationActorHandle,
     error_reporter: CompilationActorHandle, 
     unit_id: CompilationUnitId,
+    filename: String,
+    symbol_qualifier: Option<String>
 }
 
 pub fn create_typing_actor(
     compiler: CompilationActorHandle,
     type_repository: CompilationActorHandle,
     error_reporter: CompilationActorHandle, 
-    unit_id: CompilationUnitId
+    unit_id: CompilationUnitId,
+    filename: String,
+    symbol_qualifier: Option<String>
 ) -> TypingActor {
     TypingActor {
         compiler,
         type_repository,
         error_reporter,
-        unit_id
+        unit_id,
+        filename,
+        symbol_qualifier
     }
 }
 
@@ -101,17 +125,42 @@ pub fn perform_typing(
         return resolved_types;
     }
 
+    perform_typing_for_named_types(typing_actor, ctx, &mut unit.tree, errors);
+
     match unit.tree.item_mut() {
         AbstractSyntaxNodeItem::Run { expr } => {
             perform_typing_for_inferred_type_expression(typing_actor, ctx, &create_identifier_type_lookup(), expr, errors);        
         },
+        AbstractSyntaxNodeItem::Insert { expr } => {
+            perform_typing_for_insert(typing_actor, ctx, expr, errors);
+        },
+        AbstractSyntaxNodeItem::Assert { expr, .. } => {
+            perform_typing_for_inferred_type_expression(typing_actor, ctx, &create_identifier_type_lookup(), expr, errors);        
+        },
+        AbstractSyntaxNodeItem::Constant { name, value, .. } if try_get_code_units(value.item_ref()).is_some() => {
+            perform_typing_for_code_constant(typing_actor, &mut resolved_types, name, value);
+        },
+        AbstractSyntaxNodeItem::Constant { value, .. } if try_get_insert_directive_expr(value.item_ref()).is_some() => {
+            if let AbstractSyntaxNodeItem::Insert { expr } = value.item_mut() {
+                perform_typing_for_insert(typing_actor, ctx, expr, errors);
+            }
+        },
         AbstractSyntaxNodeItem::Constant { name, value, constant_type } => {
             perform_typing_for_constant(typing_actor, ctx, &mut resolved_types, name, value, constant_type, errors);        
+            *name = qualified_symbol_name(name, &typing_actor.symbol_qualifier);
+        },
+        AbstractSyntaxNodeItem::GlobalVariable { name, value, variable_type } => {
+            perform_typing_for_global_variable(typing_actor, ctx, &mut resolved_types, name, value, variable_type, errors);
+            *name = qualified_symbol_name(name, &typing_actor.symbol_qualifier);
+        },
+        AbstractSyntaxNodeItem::ProcedureHeader { name, args, return_args, body } => {
+            perform_typing_for_procedure_header(typing_actor, name, &mut resolved_types, args, return_args, body, errors);                      
         },
-        AbstractSyntaxNodeItem::ProcedureHeader { name, args, return_args, .. } => {
-            perform_typing_for_procedure_header(unit.id, name, &mut resolved_types, args, return_args, errors);                      
+        AbstractSyntaxNodeItem::Struct { fields, .. } => {
+            perform_typing_for_struct(fields, errors);
         },
-        AbstractSyntaxNodeItem::ProcedureBody { args, return_types, statements, .. } => {
+        AbstractSyntaxNodeItem::ProcedureBody { name, args, return_types, statements } => {
+            *name = qualified_symbol_name(name, &typing_actor.symbol_qualifier);
             perform_typing_for_procedure_body(typing_actor, ctx, args, return_types, statements, errors);
         },
         _ => {}
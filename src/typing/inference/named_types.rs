@@ -0,0 +1,144 @@
+use crate::parsing::*;
+use crate::compilation::*;
+use crate::typing::*;
+use crate::types::*;
+use crate::errors::*;
+
+pub fn perform_typing_for_named_types(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    node: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) {
+    let position = node.position;
+
+    match node.item_mut() {
+        AbstractSyntaxNodeItem::Constant { value, constant_type: declared_type, .. } |
+        AbstractSyntaxNodeItem::GlobalVariable { value, variable_type: declared_type, .. } |
+        AbstractSyntaxNodeItem::VariableDeclaration { value, variable_type: declared_type, .. } |
+        AbstractSyntaxNodeItem::Cast { expr: value, cast_type: declared_type, .. } => {
+            perform_typing_for_named_type(typing_actor, ctx, declared_type, position, errors);
+            perform_typing_for_named_types(typing_actor, ctx, value, errors);
+        },
+        AbstractSyntaxNodeItem::MemberDeclaration { member_type: declared_type, .. } |
+        AbstractSyntaxNodeItem::Type(declared_type) =>
+            perform_typing_for_named_type(typing_actor, ctx, declared_type, position, errors),
+        AbstractSyntaxNodeItem::ProcedureHeader { args, return_args, body, .. } => {
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, args, errors);
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, return_args, errors);
+            if let ProcedureBodyReference::Expand(body) = body {
+                perform_typing_for_named_types(typing_actor, ctx, body, errors);
+            }
+        },
+        AbstractSyntaxNodeItem::ProcedureBody { args, return_types, statements, .. } => {
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, args, errors);
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, return_types, errors);
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, statements, errors);
+        },
+        AbstractSyntaxNodeItem::Assignment { target: lhs, value: rhs, .. } |
+        AbstractSyntaxNodeItem::BinaryExpr { lhs, rhs, .. } |
+        AbstractSyntaxNodeItem::IndexExpr { instance: lhs, index: rhs, .. } => {
+            perform_typing_for_named_types(typing_actor, ctx, lhs, errors);
+            perform_typing_for_named_types(typing_actor, ctx, rhs, errors);
+        },
+        AbstractSyntaxNodeItem::If { condition, body, else_body } => {
+            perform_typing_for_named_types(typing_actor, ctx, condition, errors);
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, body, errors);
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, else_body, errors);
+        },
+        AbstractSyntaxNodeItem::While { condition, body } => {
+            perform_typing_for_named_types(typing_actor, ctx, condition, errors);
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, body, errors);
+        },
+        AbstractSyntaxNodeItem::Run { expr } |
+        AbstractSyntaxNodeItem::Insert { expr } |
+        AbstractSyntaxNodeItem::Assert { expr, .. } |
+        AbstractSyntaxNodeItem::Argument { expr, .. } |
+        AbstractSyntaxNodeItem::MemberExpr { instance: expr, .. } =>
+            perform_typing_for_named_types(typing_actor, ctx, expr, errors),
+        AbstractSyntaxNodeItem::Struct { fields: nodes, .. } |
+        AbstractSyntaxNodeItem::ProcedureCall { args: nodes, .. } |
+        AbstractSyntaxNodeItem::Return { args: nodes } =>
+            perform_typing_for_named_types_in_nodes(typing_actor, ctx, nodes, errors),
+        _ => {}
+    }
+}
+
+fn perform_typing_for_named_types_in_nodes(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    nodes: &mut AbstractSyntaxChildNodes,
+    errors: &mut CompilationErrors
+) {
+    for node in nodes {
+        perform_typing_for_named_types(typing_actor, ctx, node, errors);
+    }
+}
+
+fn perform_typing_for_named_type(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    declared_type: &mut ResolvableType,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    if let ResolvableType::Resolved(_) | ResolvableType::Unresolved = declared_type {
+        return;
+    }
+
+    fold_array_lengths(typing_actor, ctx, declared_type, position, errors);
+
+    let mut find_named_type = |name: &mut String| find_struct_type_by_name(typing_actor, ctx, name, position, errors);
+    if let Some(resolved_type) = try_resolve_declared_type(declared_type, &mut find_named_type) {
+        *declared_type = resolved_resolvable_type(resolved_type);
+    }
+}
+
+fn fold_array_lengths(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    declared_type: &mut ResolvableType,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) {
+    match declared_type {
+        ResolvableType::UnresolvedArray { length, element_type } => {
+            fold_array_lengths(typing_actor, ctx, element_type, position, errors);
+            if try_get_array_length(length).is_some() {
+                return;
+            }
+
+            let local_type_map = create_identifier_type_lookup();
+            match try_fold_array_length(typing_actor, ctx, &local_type_map, length, errors) {
+                Some(value) => *length.item_mut() = literal_item(unresolved_resolvable_literal(unresolved_int_literal(value.to_string()))),
+                None => add_type_inference_error(errors, array_length_is_not_constant_error(), position)
+            }
+        },
+        ResolvableType::UnresolvedPointer(inner) |
+        ResolvableType::UnresolvedArrayView(inner) => fold_array_lengths(typing_actor, ctx, inner, position, errors),
+        _ => {}
+    }
+}
+
+fn try_fold_array_length(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    local_type_map: &IdentifierTypeLookup,
+    length: &mut AbstractSyntaxNode,
+    errors: &mut CompilationErrors
+) -> Option<usize> {
+    perform_typing_for_intrinsic(typing_actor, ctx, local_type_map, length, errors);
+
+    if let AbstractSyntaxNodeItem::BinaryExpr { op, lhs, rhs, .. } = length.item_mut() {
+        let lhs = try_fold_array_length(typing_actor, ctx, local_type_map, lhs, errors)?;
+        let rhs = try_fold_array_length(typing_actor, ctx, local_type_map, rhs, errors)?;
+        return match op.item_ref() {
+            AbstractSyntaxNodeItem::Operator(Operator::Add) => lhs.checked_add(rhs),
+            AbstractSyntaxNodeItem::Operator(Operator::Subtract) => lhs.checked_sub(rhs),
+            AbstractSyntaxNodeItem::Operator(Operator::Multiply) => lhs.checked_mul(rhs),
+            _ => None
+        };
+    }
+
+    try_get_array_length(length)
+}
@@ -0,0 +1,32 @@
+use crate::parsing::*;
+use crate::compilation::*;
+use crate::typing::*;
+use crate::types::*;
+use crate::errors::*;
+
+pub fn perform_typing_for_struct(fields: &AbstractSyntaxChildNodes, errors: &mut CompilationErrors) {
+    for field in fields {
+        if !matches!(field.item_ref(), AbstractSyntaxNodeItem::MemberDeclaration { .. }) {
+            add_type_inference_error(errors, not_viable_struct_member_error(), field.position);
+        }
+    }
+}
+
+pub fn find_struct_type_by_name(
+    typing_actor: &TypingActor,
+    ctx: &CompilationMessageContext,
+    name: &mut String,
+    position: SourceFilePosition,
+    errors: &mut CompilationErrors
+) -> OptionalRuntimeTypePointer {
+    match find_type_by_name(ctx, &typing_actor.type_repository, name, typing_actor.unit_id, &typing_actor.filename, typing_actor.compiler.clone()) {
+        Ok(resolved_type) => {
+            if is_instance_runtime_type(&resolved_type) {
+                return Some(resolved_type);
+            }
+            add_type_inference_error(errors, not_a_type_error(), position);
+        },
+        Err(error) => add_compilation_error(errors, compilation_error(error, position))
+    }
+    None
+}
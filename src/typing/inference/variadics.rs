@@ -0,0 +1,23 @@
+use crate::parsing::*;
+use crate::types::*;
+
+pub fn perform_packing_for_variadic_args(args: &mut AbstractSyntaxChildNodes, arg_types: &RuntimeTypePointers, position: SourceFilePosition) {
+    if try_get_variadic_element_type(arg_types).is_none() {
+        return;
+    }
+
+    let fixed_count = arg_types.len() - 1;
+    if args.len() < fixed_count {
+        return;
+    }
+
+    let view_type = arg_types[fixed_count].clone();
+    let variadic_args = args.split_off(fixed_count);
+    let variadic_position = variadic_args.first().map(|arg| arg.position).unwrap_or(position);
+    let expr = create_node(variadic_args_item(variadic_args), variadic_position);
+    args.push(create_node(arg_item(expr, resolved_resolvable_type(view_type)), variadic_position));
+}
+
+pub fn variadic_args_item(args: AbstractSyntaxChildNodes) -> AbstractSyntaxNodeItem {
+    AbstractSyntaxNodeItem::VariadicArgs { args }
+}
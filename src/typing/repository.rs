@@ -3,6 +3,7 @@ use log::debug;
 
 use crate::CompilationUnitId;
 use crate::acting::*;
+use crate::parsing::*;
 use crate::compilation::*;
 use crate::types::*;
 use crate::typing::*;
@@ -12,7 +13,8 @@ use crate::utilities::*;
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct FindTypeCriteria { 
     name: String,
-    args: RuntimeTypePointers
+    args: RuntimeTypePointers,
+    procedure_value: bool
 }
 
 pub fn find_type_by_name(
@@ -20,11 +22,23 @@ pub fn find_type_by_name(
     type_repository: &CompilationActorHandle,
     name: &mut String,
     caller_unit_id: CompilationUnitId,
+    caller_filename: &str,
     compiler: CompilationActorHandle 
 ) -> RuntimeTypePointerResult {
-    find_type_by_name_and_args(ctx, type_repository, name, vec!(), caller_unit_id, compiler)
+    find_type_by_name_and_args(ctx, type_repository, name, vec!(), caller_unit_id, caller_filename, compiler)
 }
 
+pub fn find_value_by_name(
+    ctx: &CompilationMessageContext,
+    type_repository: &CompilationActorHandle,
+    name: &mut String,
+    caller_unit_id: CompilationUnitId,
+    caller_filename: &str,
+    compiler: CompilationActorHandle 
+) -> RuntimeTypePointerResult {
+    let criteria = create_find_value_criteria_with_name(name.to_string());
+    find_type_by_criteria_and_declared_name(ctx, type_repository, criteria, name, caller_unit_id, caller_filename, compiler)
+}
 
 pub fn find_type_by_name_and_args(
     ctx: &CompilationMessageContext,
@@ -32,15 +46,31 @@ pub fn find_type_by_name_and_args(
     name: &mut String,
     arg_types: RuntimeTypePointers,
     caller_unit_id: CompilationUnitId,
+    caller_filename: &str,
     compiler: CompilationActorHandle  
 ) -> RuntimeTypePointerResult {
-    find_type_from_criteria(
-        create_find_type_criteria_with_name_and_args(name.to_string(), arg_types),
-        caller_unit_id, 
-        ctx,
-        type_repository,
-        compiler
-    )
+    let criteria = create_find_type_criteria_with_name_and_args(name.to_string(), arg_types);
+    find_type_by_criteria_and_declared_name(ctx, type_repository, criteria, name, caller_unit_id, caller_filename, compiler)
+}
+
+fn find_type_by_criteria_and_declared_name(
+    ctx: &CompilationMessageContext,
+    type_repository: &CompilationActorHandle,
+    criteria: FindTypeCriteria,
+    name: &mut String,
+    caller_unit_id: CompilationUnitId,
+    caller_filename: &str,
+    compiler: CompilationActorHandle  
+) -> RuntimeTypePointerResult {
+    let result = find_type_from_criteria(criteria, caller_unit_id, caller_filename, ctx, type_repository, compiler);
+
+    if let Ok(resolved_type) = &result {
+        if try_split_qualified_name(name).is_some() {
+            *name = resolved_type.name.clone();
+        }
+    }
+
+    result
 }
 
 pub fn create_find_type_criteria_with_name(name: String) -> FindTypeCriteria {
@@ -48,18 +78,23 @@ pub fn create_find_type_criteria_with_name(name: String) -> FindTypeCriteria {
 }
 
 pub fn create_find_type_criteria_with_name_and_args(name: String, args: RuntimeTypePointers) -> FindTypeCriteria {
-    FindTypeCriteria { name, args }
+    FindTypeCriteria { name, args, procedure_value: false }
+}
+
+pub fn create_find_value_criteria_with_name(name: String) -> FindTypeCriteria {
+    FindTypeCriteria { name, args: vec!(), procedure_value: true }
 }
 
 pub fn find_type_from_criteria(
     criteria: FindTypeCriteria,
     caller_unit_id: CompilationUnitId,
+    caller_filename: &str,
     ctx: &CompilationMessageContext,
     type_repository: &CompilationActorHandle,
     compiler: CompilationActorHandle
 ) -> RuntimeTypePointerResult {
     log_finding_type(&criteria);
-    send_find_type_request(type_repository, criteria, caller_unit_id, ctx, compiler);  
+    send_find_type_request(type_repository, criteria, caller_unit_id, caller_filename, ctx, compiler);  
     await_type_found_response(ctx)
 }
 
@@ -67,6 +102,7 @@ fn send_find_type_request(
     type_repository: &CompilationActorHandle,
     criteria: FindTypeCriteria,
     caller_unit_id: CompilationUnitId,
+    caller_filename: &str,
     ctx: &CompilationMessageContext,
     compiler: CompilationActorHandle
 ) {
@@ -74,7 +110,7 @@ fn send_find_type_request(
         type_repository, 
         create_find_type_request(
             criteria, 
-            find_type_caller(create_self_handle(ctx), caller_unit_id),
+            find_type_caller(create_self_handle(ctx), caller_unit_id, string(caller_filename)),
             compiler
         )
     )
@@ -107,7 +143,120 @@ fn await_type_found_response(ctx: &CompilationMessageContext) -> RuntimeTypePoin
     result
 }
 
-type RuntimeTypeMap = HashMap<FindTypeCriteria, RuntimeTypePointer>;
+#[derive(Clone, Debug)]
+pub struct TypeDeclaration {
+    filename: String,
+    position: SourceFilePosition,
+    visibility: Visibility
+}
+
+pub fn type_declaration(filename: String, position: SourceFilePosition, visibility: Visibility) -> TypeDeclaration {
+    TypeDeclaration {
+        filename,
+        position,
+        visibility
+    }
+}
+
+pub fn unit_type_declaration(unit: &CompilationUnit) -> TypeDeclaration {
+    type_declaration(unit.filename.clone(), unit.tree.position, unit.visibility.clone())
+}
+
+fn is_declaration_visible_to_caller(repository: &TypeRepositoryActor, declaration: &TypeDeclaration, caller: &FindTypeCaller, namespaces: &Namespaces) -> bool {
+    if !namespaces.contains(&get_file_namespace(repository, &declaration.filename)) {
+        return false;
+    }
+    match declaration.visibility {
+        Visibility::Export => true,
+        Visibility::File => declaration.filename == caller.caller_filename
+    }
+}
+
+type Namespace = Option<String>;
+type Namespaces = Vec<Namespace>;
+
+fn program_namespace() -> Namespace {
+    None
+}
+
+fn module_namespace(module: String) -> Namespace {
+    Some(module)
+}
+
+#[derive(Clone, Debug)]
+struct ModuleImport {
+    importer: Namespace,
+    module: String,
+    alias: Option<String>
+}
+
+fn module_import(importer: Namespace, module: String, alias: Option<String>) -> ModuleImport {
+    ModuleImport {
+        importer,
+        module,
+        alias
+    }
+}
+
+type ModuleImports = Vec<ModuleImport>;
+type ModuleFiles = HashMap<String, String>;
+
+fn preload_namespace() -> Namespace {
+    module_namespace(string(PRELOAD_FILE_NAME))
+}
+
+fn get_file_namespace(repository: &TypeRepositoryActor, filename: &str) -> Namespace {
+    if filename == PRELOAD_FILE_NAME {
+        return preload_namespace();
+    }
+
+    match repository.module_files.get(filename) {
+        Some(module) => module_namespace(module.clone()),
+        None => program_namespace()
+    }
+}
+
+fn get_search_criteria_and_namespaces(repository: &TypeRepositoryActor, request: &FindTypeRequest) -> (FindTypeCriteria, Namespaces) {
+    let caller_namespace = get_file_namespace(repository, &request.respond_to.caller_filename);
+
+    if let Some((alias, name)) = try_split_qualified_name(&request.criteria.name) {
+        let namespaces = find_module_imports(repository, &caller_namespace)
+            .filter(|import| import.alias.as_deref() == Some(alias))
+            .map(|import| module_namespace(import.module.clone()))
+            .collect();
+        return (FindTypeCriteria { name: string(name), ..request.criteria.clone() }, namespaces);
+    }
+
+    let mut namespaces: Namespaces = find_module_imports(repository, &caller_namespace)
+        .filter(|import| import.alias.is_none())
+        .map(|import| module_namespace(import.module.clone()))
+        .collect();
+    namespaces.push(caller_namespace);
+    namespaces.push(preload_namespace());
+    (request.criteria.clone(), namespaces)
+}
+
+fn find_module_imports<'a>(repository: &'a TypeRepositoryActor, importer: &'a Namespace) -> impl Iterator<Item = &'a ModuleImport> {
+    repository.module_imports
+        .iter()
+        .filter(move |import| &import.importer == importer)
+}
+
+#[derive(Clone, Debug)]
+struct DeclaredType {
+    resolved_type: RuntimeTypePointer,
+    declaration: TypeDeclaration
+}
+
+fn declared_type(resolved_type: RuntimeTypePointer, declaration: TypeDeclaration) -> DeclaredType {
+    DeclaredType {
+        resolved_type,
+        declaration
+    }
+}
+
+type DeclaredTypes = Vec<DeclaredType>;
+type RuntimeTypeMap = HashMap<FindTypeCriteria, DeclaredTypes>;
 
 fn create_type_map() -> RuntimeTypeMap {
     HashMap::default()
@@ -115,26 +264,30 @@ fn create_type_map() -> RuntimeTypeMap {
 
 struct FindTypeRequest {
     criteria: FindTypeCriteria,
-    respond_to: FindTypeCaller
+    respond_to: FindTypeCaller,
+    compiler: CompilationActorHandle
 }
 
 #[derive(Clone, Debug)]
 pub struct FindTypeCaller {
     caller: CompilationActorHandle,
-    caller_unit_id: CompilationUnitId
+    caller_unit_id: CompilationUnitId,
+    caller_filename: String
 }
 
-pub fn find_type_caller(caller: CompilationActorHandle, caller_unit_id: CompilationUnitId) -> FindTypeCaller {
+pub fn find_type_caller(caller: CompilationActorHandle, caller_unit_id: CompilationUnitId, caller_filename: String) -> FindTypeCaller {
     FindTypeCaller {
         caller,
-        caller_unit_id
+        caller_unit_id,
+        caller_filename
     }
 }
 
-fn find_type_request(criteria: FindTypeCriteria, respond_to: FindTypeCaller) -> FindTypeRequest {
+fn find_type_request(criteria: FindTypeCriteria, respond_to: FindTypeCaller, compiler: CompilationActorHandle) -> FindTypeRequest {
     FindTypeRequest {
         criteria,
-        respond_to
+        respond_to,
+        compiler
     }
 }
 
@@ -146,13 +299,17 @@ fn create_find_type_requests() -> FindTypeRequests {
 
 pub struct TypeRepositoryActor {
     type_map: RuntimeTypeMap,
-    find_type_requests: FindTypeRequests 
+    find_type_requests: FindTypeRequests,
+    module_files: ModuleFiles,
+    module_imports: ModuleImports
 }
 
 pub fn create_type_repository_actor() -> TypeRepositoryActor {
     TypeRepositoryActor {
         type_map: create_type_map(),
-        find_type_requests: create_find_type_requests()
+        find_type_requests: create_find_type_requests(),
+        module_files: ModuleFiles::default(),
+        module_imports: vec!()
     }
 }
 
@@ -161,10 +318,16 @@ impl Actor<CompilationMessage> for TypeRepositoryActor {
         match message {
             CompilationMessage::FindType { criteria, respond_to, compiler } =>
                 handle_find_type(self, criteria, respond_to, compiler),
-            CompilationMessage::AddResolvedType(resolved_type) => 
-                handle_add_resolved_type(self, resolved_type),
+            CompilationMessage::AddResolvedType { resolved_type, declaration } => 
+                handle_add_resolved_type(self, resolved_type, declaration),
+            CompilationMessage::AddModuleFile { filename, module } =>
+                handle_add_module_file(self, filename, module),
+            CompilationMessage::AddModuleImport { importer_filename, module, alias } =>
+                handle_add_module_import(self, importer_filename, module, alias),
             CompilationMessage::CircuitBreakAllTypeRequests(reason) =>
                 handle_release_all_type_requests(self, reason),
+            CompilationMessage::ConfirmAwaitedTypeRequests { check, compiler } =>
+                handle_confirm_awaited_type_requests(check, compiler),
             CompilationMessage::ShutDown => shutdown_after_receive(),
             _ => continue_listening_after_receive()
         }
@@ -176,35 +339,60 @@ impl Actor<CompilationMessage> for TypeRepositoryActor {
 }
 
 fn handle_find_type(repository: &mut TypeRepositoryActor, criteria: FindTypeCriteria, respond_to: FindTypeCaller, compiler: CompilationActorHandle) -> AfterReceiveAction {
-    let awaiting_unit_id = respond_to.caller_unit_id;
-    add_find_type_request(repository, find_type_request(criteria, respond_to));
-    notify_compiler_of_find_requested(compiler, awaiting_unit_id);
-    service_find_type_requests(repository);
+    let request = find_type_request(criteria, respond_to, compiler);
+    if !service_find_type_request(repository, &request) {
+        notify_compiler_of_find_requested(&request);
+        add_find_type_request(repository, request);
+    }
     continue_listening_after_receive()
 }
 
-fn handle_add_resolved_type(repository: &mut TypeRepositoryActor, resolved_type: RuntimeTypePointer) -> AfterReceiveAction {
+fn handle_add_resolved_type(repository: &mut TypeRepositoryActor, resolved_type: RuntimeTypePointer, declaration: TypeDeclaration) -> AfterReceiveAction {
     match parse_find_type_criteria(&resolved_type) {
-        Ok(criteria) => add_resolved_type(repository, criteria, resolved_type), 
+        Ok(criteria) => add_resolved_type(repository, criteria, declared_type(resolved_type, declaration)), 
         Err(error) => release_all_type_requests(repository, compilation_error_type_request_circuit_break_reason(error))
     };
     service_find_type_requests(repository);
     continue_listening_after_receive()
 }
 
+fn handle_add_module_file(repository: &mut TypeRepositoryActor, filename: String, module: String) -> AfterReceiveAction {
+    repository.module_files.insert(filename, module);
+    continue_listening_after_receive()
+}
+
+fn handle_add_module_import(repository: &mut TypeRepositoryActor, importer_filename: String, module: String, alias: Option<String>) -> AfterReceiveAction {
+    let importer = get_file_namespace(repository, &importer_filename);
+    repository.module_imports.push(module_import(importer, module, alias));
+    service_find_type_requests(repository);
+    continue_listening_after_receive()
+}
+
 fn handle_release_all_type_requests(repository: &mut TypeRepositoryActor, reason: TypeRequestCircuitBreakReason ) -> AfterReceiveAction {
     release_all_type_requests(repository, reason);
     continue_listening_after_receive()
 }
 
+fn handle_confirm_awaited_type_requests(check: usize, compiler: CompilationActorHandle) -> AfterReceiveAction {
+    send_message_to_actor(&compiler, create_awaited_type_requests_confirmed_event(check));
+    continue_listening_after_receive()
+}
+
 fn add_find_type_request(repository: &mut TypeRepositoryActor,  request: FindTypeRequest) {
     repository.find_type_requests.push(request);
 }
 
-fn notify_compiler_of_find_requested(compiler: CompilationActorHandle, awaiting_unit_id: CompilationUnitId) {
+fn notify_compiler_of_find_requested(request: &FindTypeRequest) {
+    send_message_to_actor(
+        &request.compiler,
+        create_type_find_requested_event(request.respond_to.caller_unit_id)
+    );
+}
+
+fn notify_compiler_of_find_completed(request: &FindTypeRequest) {
     send_message_to_actor(
-        &compiler,
-        create_type_find_requested_event(awaiting_unit_id)
+        &request.compiler,
+        create_type_find_completed_event(request.respond_to.caller_unit_id)
     );
 }
 
@@ -218,8 +406,9 @@ fn service_find_type_requests(repository: &mut TypeRepositoryActor) {
         }
     }
 
-    for index in removals {
-        remove_find_type_request(repository, index);
+    for index in removals.into_iter().rev() {
+        let request = remove_find_type_request(repository, index);
+        notify_compiler_of_find_completed(&request);
     }
 }
 
@@ -228,22 +417,80 @@ fn remove_find_type_request(repository: &mut TypeRepositoryActor, index: usize)
 }
 
 fn service_find_type_request(repository: &TypeRepositoryActor, request: &FindTypeRequest) -> bool {
-    let resolved_type = repository.type_map.get(&request.criteria);
-
-    if let Some(resolved_type) = resolved_type {
-        send_message_to_actor(&request.respond_to.caller, create_type_found_event(resolved_type.clone()));
+    if let Some(declared_type) = find_visible_declared_type(repository, request) {
+        send_message_to_actor(&request.respond_to.caller, create_type_found_event(declared_type.resolved_type.clone()));
         return true;
     }
     false
 }
 
+fn find_visible_declared_type<'a>(repository: &'a TypeRepositoryActor, request: &FindTypeRequest) -> Option<&'a DeclaredType> {
+    let (criteria, namespaces) = get_search_criteria_and_namespaces(repository, request);
+    find_declared_types(repository, &criteria)?
+        .iter()
+        .find(|declared_type| is_declaration_visible_to_caller(repository, &declared_type.declaration, &request.respond_to, &namespaces))
+}
+
+fn find_invisible_declared_type<'a>(repository: &'a TypeRepositoryActor, request: &FindTypeRequest) -> Option<&'a DeclaredType> {
+    let (criteria, namespaces) = get_search_criteria_and_namespaces(repository, request);
+    find_declared_types(repository, &criteria)?
+        .iter()
+        .find(|declared_type| !is_declaration_visible_to_caller(repository, &declared_type.declaration, &request.respond_to, &namespaces))
+}
+
+fn find_declared_types<'a>(repository: &'a TypeRepositoryActor, criteria: &FindTypeCriteria) -> Option<&'a DeclaredTypes> {
+    if let Some(declared_types) = repository.type_map.get(criteria) {
+        return Some(declared_types);
+    }
+
+    repository.type_map
+        .iter()
+        .find(|(declared_criteria, declared_types)|
+            is_criteria_satisfied_by_boxing(declared_criteria, criteria) ||
+            is_criteria_satisfied_by_procedure_value(declared_criteria, declared_types, criteria)
+        )
+        .map(|(_, declared_types)| declared_types)
+}
+
+fn is_criteria_satisfied_by_boxing(declared_criteria: &FindTypeCriteria, criteria: &FindTypeCriteria) -> bool {
+    let declared_args = get_call_arg_types(&declared_criteria.args, criteria.args.len());
+    declared_criteria.name == criteria.name &&
+    declared_args.len() == criteria.args.len() &&
+    declared_args
+        .iter()
+        .zip(&criteria.args)
+        .all(|(declared_arg, arg)| declared_arg == arg || is_any_type(declared_arg))
+}
+
+fn is_criteria_satisfied_by_procedure_value(declared_criteria: &FindTypeCriteria, declared_types: &DeclaredTypes, criteria: &FindTypeCriteria) -> bool {
+    criteria.procedure_value &&
+    declared_criteria.name == criteria.name &&
+    declared_types
+        .iter()
+        .any(|declared_type| try_get_procedure_symbol_name(&declared_type.resolved_type).is_some())
+}
+
 fn release_all_type_requests(repository: &mut TypeRepositoryActor, reason: TypeRequestCircuitBreakReason) {
     for request in &repository.find_type_requests {
-        release_type_request(request, reason.clone())
+        release_type_request(request, reason_for_releasing_type_request(repository, request, &reason));
+        notify_compiler_of_find_completed(request);
     }
     repository.find_type_requests.clear();
 }
 
+fn reason_for_releasing_type_request(repository: &TypeRepositoryActor, request: &FindTypeRequest, reason: &TypeRequestCircuitBreakReason) -> TypeRequestCircuitBreakReason {
+    if let TypeRequestCircuitBreakReason::TypesNotFound = reason {
+        if let Some(declared_type) = find_invisible_declared_type(repository, request) {
+            return compilation_error_type_request_circuit_break_reason(
+                type_inference_error(
+                    type_not_visible_error(declared_type.declaration.filename.clone(), declared_type.declaration.position)
+                )
+            );
+        }
+    }
+    reason.clone()
+}
+
 fn release_type_request(request: &FindTypeRequest, reason: TypeRequestCircuitBreakReason) {
     send_message_to_actor(&request.respond_to.caller, circuit_break_type_request(reason));
 }
@@ -252,14 +499,21 @@ pub type FindTypeCriteriaResult = Result<FindTypeCriteria, CompilationErrorItem>
 
 pub fn parse_find_type_criteria(resolved_type: &RuntimeTypePointer) -> FindTypeCriteriaResult {
     match &resolved_type.item {
-        RuntimeTypeItem::ProcedureDefinition { arg_types, .. } => 
+        RuntimeTypeItem::ProcedureDefinition { arg_types, .. } | RuntimeTypeItem::MacroDefinition { arg_types, .. } => 
             Ok(create_find_type_criteria_with_name_and_args(resolved_type.name.clone(), arg_types.clone())),
-        RuntimeTypeItem::ConstantDefinition { .. } => 
+        RuntimeTypeItem::ConstantDefinition { .. } | RuntimeTypeItem::CodeDefinition { .. } => 
+            Ok(create_find_type_criteria_with_name(resolved_type.name.clone())),
+        RuntimeTypeItem::GlobalVariableDefinition { .. } => 
+            Ok(create_find_type_criteria_with_name(resolved_type.name.clone())),
+        RuntimeTypeItem::Struct { .. } | RuntimeTypeItem::String { .. } => 
             Ok(create_find_type_criteria_with_name(resolved_type.name.clone())),
         _ => Err(todo_error(function!(), "parse criteria for other types"))
     }
 }
 
-fn add_resolved_type(repository: &mut TypeRepositoryActor, criteria: FindTypeCriteria, resolved_type: RuntimeTypePointer) {
-    repository.type_map.insert(criteria, resolved_type);
+fn add_resolved_type(repository: &mut TypeRepositoryActor, criteria: FindTypeCriteria, declared_type: DeclaredType) {
+    repository.type_map
+        .entry(criteria)
+        .or_default()
+        .push(declared_type);
 }
@@ -0,0 +1,53 @@
+use rust_hephaestus::*;
+
+fn arguments(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| string(arg)).collect()
+}
+
+#[test]
+fn file_to_compile_skips_option_values() {
+    let args = arguments(&["-target", "linux", "-import_dir", "modules", "main.hep"]);
+
+    assert_eq!(get_file_to_compile_from_arguments(&args), Some(string("main.hep")));
+}
+
+#[test]
+fn file_to_compile_skips_link_flag() {
+    let args = arguments(&["-link", "main.hep"]);
+
+    assert_eq!(get_file_to_compile_from_arguments(&args), Some(string("main.hep")));
+}
+
+#[test]
+fn linking_is_only_done_when_asked_for() {
+    assert!(!get_link_from_arguments(&arguments(&["main.hep"])));
+    assert!(get_link_from_arguments(&arguments(&["-target", "linux", "-link", "main.hep"])));
+}
+
+#[test]
+fn module_paths_are_kept_in_the_order_given() {
+    let args = arguments(&["-import_dir", "first", "main.hep", "-import_dir", "second"]);
+
+    assert_eq!(get_module_paths_from_arguments(&args), vec!(string("first"), string("second")));
+}
+
+#[test]
+fn target_defaults_to_windows() {
+    let args = arguments(&["main.hep"]);
+
+    assert_eq!(get_target_from_arguments(&args), Ok(windows_target()));
+}
+
+#[test]
+fn last_target_given_is_used() {
+    let args = arguments(&["-target", "windows", "-target", "linux", "main.hep"]);
+
+    assert_eq!(get_target_from_arguments(&args), Ok(linux_target()));
+}
+
+#[test]
+fn unknown_target_is_an_argument_error() {
+    let args = arguments(&["-target", "amiga", "main.hep"]);
+
+    assert_eq!(get_target_from_arguments(&args), Err(unknown_target_error("amiga")));
+}
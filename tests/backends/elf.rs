@@ -0,0 +1,258 @@
+use rust_hephaestus::*;
+
+const HELLO_WORLD_SOURCE: &str = "STD_OUTPUT_HANDLE : s32 : -11;
+
+Kernel32 :: #foreign_system_library \"kernel32\";
+WriteFile :: (handle: *void, to_write: *void, bytes_to_write: u32, bytes_written: *void, overlapped: *void) -> bool #foreign Kernel32;
+GetStdHandle :: (handle_type: s32) -> *void #foreign Kernel32;
+
+print :: (to_print: string) {
+    handle := GetStdHandle(STD_OUTPUT_HANDLE);
+    to_write := cast(*void) to_print.data;
+    length := cast(u32) to_print.count
+    bytes_written: *void = null;
+    overlapped: *void = null;
+    WriteFile(handle, to_write, length, bytes_written, overlapped);
+}
+
+main :: () {
+    print(\"hello world!\\r\\n\\0\");
+}";
+
+struct TestSection {
+    name: String,
+    section_type: u32,
+    offset: usize,
+    size: usize,
+    link: u32,
+    info: u32
+}
+
+struct TestSymbol {
+    name: String,
+    binding: u8,
+    section_index: u16,
+    value: u64
+}
+
+struct TestRelocation {
+    offset: u64,
+    symbol: String,
+    relocation_type: u32,
+    addend: i64
+}
+
+fn build_elf_bytes_for_procedure(name: &str) -> Vec<u8> {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let ir = get_first_ir_with_byte_code_named(&irs, name).clone();
+    elf_to_bytes(&build_x64_elf_object("test.hep", &[ir]).unwrap())
+}
+
+fn get_sections(bytes: &[u8]) -> Vec<TestSection> {
+    let section_header_offset = read_u64(bytes, 0x28) as usize;
+    let number_of_sections = read_u16(bytes, 0x3C) as usize;
+    let names_index = read_u16(bytes, 0x3E) as usize;
+    let names_offset = read_u64(bytes, section_header_offset + names_index * 64 + 24) as usize;
+
+    (0..number_of_sections)
+        .map(|index| {
+            let header = section_header_offset + index * 64;
+            TestSection {
+                name: read_name(bytes, names_offset + read_u32(bytes, header) as usize),
+                section_type: read_u32(bytes, header + 4),
+                offset: read_u64(bytes, header + 24) as usize,
+                size: read_u64(bytes, header + 32) as usize,
+                link: read_u32(bytes, header + 40),
+                info: read_u32(bytes, header + 44)
+            }
+        })
+        .collect()
+}
+
+fn get_section<'a>(sections: &'a [TestSection], name: &str) -> &'a TestSection {
+    sections.iter().find(|section| section.name == name).unwrap()
+}
+
+fn get_symbols(bytes: &[u8]) -> Vec<TestSymbol> {
+    let sections = get_sections(bytes);
+    let symbols = get_section(&sections, ".symtab");
+    let strings = get_section(&sections, ".strtab");
+
+    (0..symbols.size / 24)
+        .map(|index| {
+            let entry = symbols.offset + index * 24;
+            TestSymbol {
+                name: read_name(bytes, strings.offset + read_u32(bytes, entry) as usize),
+                binding: bytes[entry + 4] >> 4,
+                section_index: read_u16(bytes, entry + 6),
+                value: read_u64(bytes, entry + 8)
+            }
+        })
+        .collect()
+}
+
+fn get_relocations(bytes: &[u8], section_name: &str) -> Vec<TestRelocation> {
+    let sections = get_sections(bytes);
+    let symbols = get_symbols(bytes);
+    let relocations = get_section(&sections, section_name);
+
+    (0..relocations.size / 24)
+        .map(|index| {
+            let entry = relocations.offset + index * 24;
+            let info = read_u64(bytes, entry + 8);
+            let symbol = &symbols[(info >> 32) as usize];
+            TestRelocation {
+                offset: read_u64(bytes, entry),
+                symbol: if symbol.name.is_empty() { sections[symbol.section_index as usize].name.clone() } else { symbol.name.clone() },
+                relocation_type: info as u32,
+                addend: read_u64(bytes, entry + 16) as i64
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn elf_object_has_relocatable_x64_header_and_sections() {
+    let bytes = build_elf_bytes_for_procedure("main");
+
+    assert_eq!(&bytes[0..4], &[0x7F, b'E', b'L', b'F']);
+    assert_eq!(bytes[4], 2);
+    assert_eq!(bytes[5], 1);
+    assert_eq!(read_u16(&bytes, 0x10), 1);
+    assert_eq!(read_u16(&bytes, 0x12), 62);
+
+    let sections = get_sections(&bytes);
+    let names: Vec<&str> = sections.iter().map(|section| section.name.as_str()).collect();
+    assert_eq!(names, vec!("", ".text", ".rela.text", ".data", ".rela.data", ".rodata", ".bss", ".symtab", ".strtab", ".shstrtab"));
+
+    let text_relocations = get_section(&sections, ".rela.text");
+    assert_eq!(text_relocations.section_type, 4);
+    assert_eq!(text_relocations.link, ELF_SECTION_SYMTAB as u32);
+    assert_eq!(text_relocations.info, ELF_SECTION_TEXT as u32);
+}
+
+#[test]
+fn elf_object_symbol_table_puts_locals_before_globals() {
+    let bytes = build_elf_bytes_for_procedure("main");
+
+    let sections = get_sections(&bytes);
+    let symbols = get_symbols(&bytes);
+    let first_global = get_section(&sections, ".symtab").info as usize;
+
+    assert!(symbols[..first_global].iter().all(|symbol| symbol.binding == 0));
+    assert!(symbols[first_global..].iter().all(|symbol| symbol.binding == 1));
+
+    let main = symbols.iter().find(|symbol| symbol.name == "main").unwrap();
+    assert_eq!(main.section_index, ELF_SECTION_TEXT);
+    let print = symbols.iter().find(|symbol| symbol.name == "print").unwrap();
+    assert_eq!(print.section_index, 0);
+}
+
+#[test]
+fn elf_object_uses_plt_relocation_for_calls_and_pc_relative_relocation_for_data() {
+    let bytes = build_elf_bytes_for_procedure("main");
+
+    let relocations = get_relocations(&bytes, ".rela.text");
+
+    assert_eq!(relocations.len(), 2);
+    assert_eq!(relocations[0].symbol, ".data");
+    assert_eq!(relocations[0].relocation_type, R_X86_64_PC32);
+    assert_eq!(relocations[0].addend, -4);
+    assert_eq!(relocations[1].symbol, "print");
+    assert_eq!(relocations[1].relocation_type, R_X86_64_PLT32);
+    assert_eq!(relocations[1].addend, -4);
+
+    let sections = get_sections(&bytes);
+    let text = get_section(&sections, ".text");
+    for relocation in relocations {
+        let start = text.offset + relocation.offset as usize;
+        assert_eq!(&bytes[start..start + 4], &[0, 0, 0, 0]);
+    }
+}
+
+#[test]
+fn elf_object_uses_absolute_relocation_for_pointers_to_read_only_data() {
+    let bytes = build_elf_bytes_for_procedure("main");
+
+    let relocations = get_relocations(&bytes, ".rela.data");
+
+    assert_eq!(relocations.len(), 1);
+    assert_eq!(relocations[0].offset, 8);
+    assert_eq!(relocations[0].symbol, ".rodata");
+    assert_eq!(relocations[0].relocation_type, R_X86_64_64);
+    assert_eq!(relocations[0].addend, 0);
+}
+
+#[test]
+fn elf_object_keeps_string_literals_in_rodata() {
+    let bytes = build_elf_bytes_for_procedure("main");
+
+    let sections = get_sections(&bytes);
+    let rodata = get_section(&sections, ".rodata");
+
+    assert_eq!(&bytes[rodata.offset..rodata.offset + rodata.size], b"hello world!\r\n\0");
+}
+
+#[test]
+fn elf_object_uses_sign_extended_relocation_for_constant_args() {
+    let bytes = build_elf_bytes_for_procedure("print");
+
+    let relocations = get_relocations(&bytes, ".rela.text");
+
+    let constant = relocations.iter().find(|relocation| relocation.symbol == "STD_OUTPUT_HANDLE").unwrap();
+    assert_eq!(constant.relocation_type, R_X86_64_32S);
+    assert_eq!(constant.addend, 0);
+
+    let foreign_calls: Vec<&TestRelocation> = relocations.iter().filter(|relocation| relocation.relocation_type == R_X86_64_PLT32).collect();
+    assert_eq!(foreign_calls.len(), 2);
+}
+
+#[test]
+fn elf_object_sign_extends_absolute_32_bit_constants() {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let ir = irs.iter().find(|ir| ir.top_level_symbol == "STD_OUTPUT_HANDLE" && !ir.symbols.is_empty()).unwrap();
+    let bytes = elf_to_bytes(&build_x64_elf_object("test.hep", std::slice::from_ref(ir)).unwrap());
+
+    let symbols = get_symbols(&bytes);
+
+    let constant = symbols.iter().find(|symbol| symbol.name == "STD_OUTPUT_HANDLE").unwrap();
+    assert_eq!(constant.binding, 1);
+    assert_eq!(constant.section_index, 0xFFF1);
+    assert_eq!(constant.value, -11i64 as u64);
+}
+
+#[test]
+fn elf_object_for_whole_program_defines_procedures_called_between_units() {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let bytes = elf_to_bytes(&build_x64_elf_object("test.hep", &irs).unwrap());
+
+    let symbols = get_symbols(&bytes);
+
+    let prints: Vec<&TestSymbol> = symbols.iter().filter(|symbol| symbol.name == "print").collect();
+    assert_eq!(prints.len(), 1);
+    assert_eq!(prints[0].section_index, ELF_SECTION_TEXT);
+
+    let main = symbols.iter().find(|symbol| symbol.name == "main").unwrap();
+    assert_eq!(main.section_index, ELF_SECTION_TEXT);
+    assert_ne!(main.value, prints[0].value);
+
+    let write_file = symbols.iter().find(|symbol| symbol.name == "WriteFile").unwrap();
+    assert_eq!(write_file.section_index, 0);
+
+    let relocations = get_relocations(&bytes, ".rela.text");
+    assert!(relocations.iter().any(|relocation| relocation.symbol == "print" && relocation.relocation_type == R_X86_64_PLT32));
+    assert!(relocations.iter().any(|relocation| relocation.symbol == "GetStdHandle" && relocation.relocation_type == R_X86_64_PLT32));
+}
+
+#[test]
+fn elf_object_keeps_absolute_64_bit_constants_at_full_width() {
+    let irs = compile_source_and_get_intemediate_representation("LIMIT : s64 : 4294967296;");
+    let bytes = elf_to_bytes(&build_x64_elf_object("test.hep", &irs).unwrap());
+
+    let symbols = get_symbols(&bytes);
+
+    let constant = symbols.iter().find(|symbol| symbol.name == "LIMIT").unwrap();
+    assert_eq!(constant.binding, 1);
+    assert_eq!(constant.section_index, 0xFFF1);
+    assert_eq!(constant.value, 4294967296);
+}
@@ -0,0 +1,514 @@
+use rust_hephaestus::*;
+
+const HELLO_WORLD_SOURCE: &str = "print :: (to_print: string) {
+    to_write := cast(*void) to_print.data;
+    length := to_print.count;
+    fd : s32 = 1;
+    write(fd, to_write, length);
+}
+
+main :: () -> int {
+    print(\"hello world!\\n\");
+    return 0;
+}";
+
+fn build_linux_object(file_name: &str, source: &str) -> Elf {
+    let (file_path, reader) = add_source_to_test_file_system(source);
+    build_linux_object_from_files(file_name, file_path, vec!(), reader)
+}
+
+fn build_linux_object_from_files(file_name: &str, file_path: &str, module_paths: Vec<String>, mut reader: MockFileReader) -> Elf {
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+    let irs = compile_file_for_target_with_module_paths_and_get_intemediate_representation(file_path, module_paths, linux_target(), reader);
+    build_x64_elf_object(file_name, &irs).unwrap()
+}
+
+fn link_linux_executable(source: &str) -> ElfExecutable {
+    link_elf_executable(&[build_linux_object("test.hep", source)], ELF_ENTRY_POINT).unwrap()
+}
+
+fn get_global_symbol_value(elf: &Elf, name: &str) -> u64 {
+    get_elf_global_symbol_definitions(elf)
+        .into_iter()
+        .find(|symbol| symbol.name == name)
+        .unwrap()
+        .value
+}
+
+#[test]
+fn elf_executable_has_static_x64_header_entering_at_start() {
+    let elf = build_linux_object("test.hep", HELLO_WORLD_SOURCE);
+    let executable = link_elf_executable(std::slice::from_ref(&elf), ELF_ENTRY_POINT).unwrap();
+    let bytes = elf_executable_to_bytes(&executable);
+
+    assert_eq!(&bytes[0..4], &[0x7F, b'E', b'L', b'F']);
+    assert_eq!(read_u16(&bytes, 0x10), 2);
+    assert_eq!(read_u16(&bytes, 0x12), 62);
+
+    let (text_address, _) = get_elf_executable_text_section(&executable);
+    let expected = text_address + get_global_symbol_value(&elf, "_start");
+    assert_eq!(get_elf_executable_entry(&executable), expected);
+    assert_eq!(read_u64(&bytes, 0x18), expected);
+}
+
+#[test]
+fn elf_executable_maps_code_executable_read_only_data_readable_and_data_writable() {
+    let executable = link_linux_executable(HELLO_WORLD_SOURCE);
+    let bytes = elf_executable_to_bytes(&executable);
+
+    let program_header_offset = read_u64(&bytes, 0x20) as usize;
+    let number_of_program_headers = read_u16(&bytes, 0x38) as usize;
+    let segments: Vec<(u32, u32, u64, u64)> = (0..number_of_program_headers)
+        .map(|index| {
+            let header = program_header_offset + index * 56;
+            (read_u32(&bytes, header), read_u32(&bytes, header + 4), read_u64(&bytes, header + 8), read_u64(&bytes, header + 16))
+        })
+        .collect();
+
+    let (text_address, _) = get_elf_executable_text_section(&executable);
+    let (rodata_address, _) = get_elf_executable_rodata_section(&executable);
+    let (data_address, _) = get_elf_executable_data_section(&executable);
+    assert!(segments.contains(&(1, 5, text_address - 0x400000, text_address)));
+    assert!(segments.contains(&(1, 4, rodata_address - 0x400000, rodata_address)));
+    assert!(segments.contains(&(1, 6, data_address - 0x400000, data_address)));
+    assert!(segments.iter().any(|(segment_type, flags, ..)| *segment_type == 0x6474E551 && *flags == 6));
+    assert!(segments.iter().all(|(_, flags, ..)| flags & 3 != 3));
+    for (segment_type, _, offset, address) in segments {
+        if segment_type == 1 {
+            assert_eq!(offset % 0x1000, address % 0x1000);
+        }
+    }
+}
+
+#[test]
+fn elf_executable_without_read_only_data_has_no_rodata_section() {
+    let executable = link_linux_executable("main :: () -> s64 {
+    return 3;
+}");
+    let bytes = elf_executable_to_bytes(&executable);
+
+    let section_header_offset = read_u64(&bytes, 0x28) as usize;
+    let number_of_sections = read_u16(&bytes, 0x3C) as usize;
+    let section_names_header = section_header_offset + read_u16(&bytes, 0x3E) as usize * 64;
+    let section_names = &bytes[read_u64(&bytes, section_names_header + 24) as usize..];
+    let names: Vec<String> = (0..number_of_sections)
+        .map(|index| read_u32(&bytes, section_header_offset + index * 64) as usize)
+        .map(|name| String::from_utf8(section_names[name..name + section_names[name..].iter().position(|byte| *byte == 0).unwrap()].to_vec()).unwrap())
+        .collect();
+
+    assert_eq!(names, vec!("", ".text", ".data", ".bss", ".shstrtab"));
+}
+
+#[test]
+fn elf_executable_relocates_data_pointers_to_loaded_addresses() {
+    let executable = link_linux_executable(HELLO_WORLD_SOURCE);
+    let (_, data) = get_elf_executable_data_section(&executable);
+    let (rodata_address, rodata) = get_elf_executable_rodata_section(&executable);
+
+    let pointed_to: Vec<&[u8]> = (0..=data.len() - 8)
+        .map(|at| read_u64(data, at))
+        .filter(|address| *address >= rodata_address && *address < rodata_address + rodata.len() as u64)
+        .map(|address| &rodata[(address - rodata_address) as usize..])
+        .collect();
+    assert!(pointed_to.iter().any(|characters| characters.starts_with(b"hello world!\n")));
+}
+
+#[test]
+fn linking_foreign_procedure_reports_undefined_symbol_with_referencing_procedure() {
+    let elf = build_linux_object("test.hep", "Crt :: #foreign_system_library \"libc\";
+puts :: (to_print: *u8) -> s32 #foreign Crt;
+
+main :: () -> int {
+    message: *u8 = null;
+    puts(message);
+    return 0;
+}");
+
+    let result = link_elf_executable(&[elf], ELF_ENTRY_POINT);
+
+    assert_eq!(result.err(), Some(undefined_symbol_error("puts", "main")));
+}
+
+#[test]
+fn linking_same_program_twice_reports_duplicate_symbol_in_second_unit() {
+    let first = build_linux_object("first.hep", HELLO_WORLD_SOURCE);
+    let second = build_linux_object("second.hep", HELLO_WORLD_SOURCE);
+    let defined: Vec<String> = get_elf_global_symbol_definitions(&first)
+        .into_iter()
+        .filter(|symbol| symbol.section_index != SHN_UNDEF)
+        .map(|symbol| symbol.name)
+        .collect();
+
+    let result = link_elf_executable(&[first, second], ELF_ENTRY_POINT);
+
+    match result.err() {
+        Some(BackendError::DuplicateSymbol(name, defined_by)) => {
+            assert!(defined.contains(&name));
+            assert_eq!(defined_by, "second.hep");
+        },
+        other => panic!("expected duplicate symbol, got {:?}", other)
+    }
+}
+
+#[test]
+fn building_object_with_procedure_defined_by_two_units_reports_duplicate_symbol() {
+    let (file_path, mut reader) = add_source_to_test_file_system(HELLO_WORLD_SOURCE);
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+    let mut irs = compile_file_for_target_and_get_intemediate_representation(file_path, linux_target(), reader);
+    let mut other = get_first_ir_with_byte_code_named(&irs, "print").clone();
+    other.filename = string("other.hep");
+    irs.push(other);
+
+    let result = build_x64_elf_object("test.hep", &irs);
+
+    assert_eq!(result.err(), Some(duplicate_symbol_error("print", "other.hep")));
+}
+
+fn build_linux_backend_in_temp_directory(name: &str, link: bool) -> (std::path::PathBuf, std::path::PathBuf) {
+    let (file_path, mut reader) = add_source_to_test_file_system(HELLO_WORLD_SOURCE);
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+    let irs = compile_file_for_target_and_get_intemediate_representation(file_path, linux_target(), reader);
+
+    let file_name = std::env::temp_dir().join(format!("hephaestus-{}-{}.hep", name, std::process::id()));
+    let file_name = file_name.to_str().unwrap();
+    create_x64_backend(linux_target(), link).build_backend(file_name, irs, false).unwrap();
+
+    (get_object_file_name(file_name, "o").into(), get_executable_file_name(file_name, linux_target()).into())
+}
+
+#[test]
+fn backend_only_writes_object_when_linking_is_not_asked_for() {
+    let (object_path, executable_path) = build_linux_backend_in_temp_directory("object-only", false);
+
+    assert!(object_path.exists());
+    assert!(!executable_path.exists());
+    std::fs::remove_file(&object_path).unwrap();
+}
+
+#[test]
+fn backend_writes_executable_when_linking_is_asked_for() {
+    let (object_path, executable_path) = build_linux_backend_in_temp_directory("linked", true);
+
+    assert!(object_path.exists());
+    assert!(executable_path.exists());
+    std::fs::remove_file(&object_path).unwrap();
+    std::fs::remove_file(&executable_path).unwrap();
+}
+
+#[test]
+fn linking_without_entry_point_reports_it_not_found() {
+    let elf = build_linux_object("test.hep", HELLO_WORLD_SOURCE);
+
+    let result = link_elf_executable(&[elf], "start");
+
+    assert_eq!(result.err(), Some(entry_point_not_found_error("start")));
+}
+
+#[test]
+fn executable_file_name_drops_source_extension_on_linux() {
+    assert_eq!(get_executable_file_name("examples/hello.hep", linux_target()), "examples/hello");
+    assert_eq!(get_executable_file_name("examples/hello", linux_target()), "examples/hello.out");
+    assert_eq!(get_executable_file_name("examples/hello.hep", windows_target()), "examples/hello.exe");
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn run_linux_executable(name: &str, source: &str) -> std::process::Output {
+    run_linked_linux_executable(name, link_linux_executable(source))
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn run_linux_executable_from_files(name: &str, file_path: &str, module_paths: Vec<String>, reader: MockFileReader) -> std::process::Output {
+    let object = build_linux_object_from_files("test.hep", file_path, module_paths, reader);
+    run_linked_linux_executable(name, link_elf_executable(&[object], ELF_ENTRY_POINT).unwrap())
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn run_compiled_linux_executable(name: &str, source: &str) -> std::process::Output {
+    let file_name = std::env::temp_dir().join(format!("hephaestus-{}-{}.hep", name, std::process::id()));
+    let file_name = file_name.to_str().unwrap();
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, file_name, source);
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+
+    compile_for_target(string(file_name), vec!(), linux_target(), reader, create_x64_backend(linux_target(), true), create_null_message_wire_tap());
+
+    let executable_path = get_executable_file_name(file_name, linux_target());
+    let output = std::process::Command::new(&executable_path).output().unwrap();
+    std::fs::remove_file(get_object_file_name(file_name, "o")).unwrap();
+    std::fs::remove_file(&executable_path).unwrap();
+    output
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn run_linked_linux_executable(name: &str, executable: ElfExecutable) -> std::process::Output {
+    let path = std::env::temp_dir().join(format!("hephaestus-{}-{}", name, std::process::id()));
+    write_elf_executable_to_file(&executable, &mut create_elf_executable_file(path.to_str().unwrap()).unwrap()).unwrap();
+
+    let output = std::process::Command::new(&path).output().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    output
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_hello_world_writes_to_stdout_and_exits_successfully() {
+    let output = run_linux_executable("hello", HELLO_WORLD_SOURCE);
+
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "hello world!\n");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_exits_with_return_value_of_main() {
+    let output = run_linux_executable("exit", "main :: () -> int {
+    return 42;
+}");
+
+    assert_eq!(output.status.code(), Some(42));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_exits_with_return_value_of_main_returning_smaller_int() {
+    let output = run_linux_executable("exit-s32", "main :: () -> s32 {
+    return 7;
+}");
+
+    assert_eq!(output.status.code(), Some(7));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_exits_successfully_when_main_returns_nothing() {
+    let output = run_linux_executable("void", "main :: () {
+}");
+
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_calls_module_procedure_with_same_name_as_program_procedure() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#import \"M\";
+helper :: () -> int {
+    return 1;
+}
+main :: () -> int {
+    result := answer();
+    return result;
+}");
+    add_mock_file(&mut reader, "modules/M/module.hep", "helper :: () -> int {
+    return 2;
+}
+answer :: () -> int {
+    result := helper();
+    return result;
+}");
+
+    let output = run_linux_executable_from_files("module-helper", "test.hep", vec!(string("modules")), reader);
+
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_reads_module_constants_and_globals_with_same_names_as_program_ones() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "M :: #import \"M\";
+LIMIT : int : 10;
+total : int = 20;
+main :: () -> int {
+    result := M.answer();
+    value := total;
+    sum := LIMIT + value + result;
+    return sum;
+}");
+    add_mock_file(&mut reader, "modules/M/module.hep", "LIMIT : int : 1;
+total : int = 2;
+answer :: () -> int {
+    value := total;
+    result := LIMIT + value;
+    return result;
+}");
+
+    let output = run_linux_executable_from_files("module-globals", "test.hep", vec!(string("modules")), reader);
+
+    assert_eq!(output.status.code(), Some(33));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_calls_file_private_procedure_of_calling_file() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"other.hep\";
+#scope_file
+helper :: () -> int {
+    return 1;
+}
+#scope_export
+main :: () -> int {
+    result := other();
+    return result;
+}");
+    add_mock_file(&mut reader, "other.hep", "#scope_file
+helper :: () -> int {
+    return 2;
+}
+#scope_export
+other :: () -> int {
+    result := helper();
+    return result;
+}");
+
+    let output = run_linux_executable_from_files("private-helper", "test.hep", vec!(), reader);
+
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_reads_file_private_constants_and_globals_of_each_file() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"other.hep\";
+#scope_file
+LIMIT : int : 10;
+total : int = 20;
+#scope_export
+main :: () -> int {
+    result := other();
+    value := total;
+    sum := LIMIT + value + result;
+    return sum;
+}");
+    add_mock_file(&mut reader, "other.hep", "#scope_file
+LIMIT : int : 1;
+total : int = 2;
+#scope_export
+other :: () -> int {
+    value := total;
+    result := LIMIT + value;
+    return result;
+}");
+
+    let output = run_linux_executable_from_files("private-globals", "test.hep", vec!(), reader);
+
+    assert_eq!(output.status.code(), Some(33));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_runs_loops_and_branches() {
+    let output = run_linux_executable("branches", "main :: () -> int {
+    total := 0;
+    index := -3;
+    while index < 10 {
+        if index * 2 >= 4 {
+            total = total + index;
+        } else if index < 0 {
+            total = total - 1;
+        }
+        index = index + 1;
+    }
+    return total;
+}");
+
+    assert_eq!(output.status.code(), Some(41));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_computes_with_global_variables() {
+    let output = run_linux_executable("global-expressions", "counter: s32 = 4;
+limit: s32 = 20;
+
+main :: () -> int {
+    while counter < 20 {
+        counter = counter + 3;
+    }
+    counter = limit - counter * 2;
+    total := cast(s64) counter;
+    result := total + 50;
+    return result;
+}");
+
+    assert_eq!(output.status.code(), Some(26));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_reads_constant_computed_at_compile_time() {
+    let output = run_compiled_linux_executable("run-constant", "compute :: () -> int {
+    result := 40 + 2;
+    return result;
+}
+
+ANSWER :: #run compute();
+
+main :: () -> int {
+    total := ANSWER + 1;
+    return total;
+}");
+
+    assert_eq!(output.status.code(), Some(43));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_reads_elements_through_pointers() {
+    let output = run_linux_executable("indexing", "sum_pairs :: (text: string) -> int {
+    pairs := cast(*u16) text.data;
+    first := pairs[0];
+    second := pairs[1];
+    byte := text.data;
+    last := byte[3];
+    total := cast(s64) first;
+    other := cast(s64) second;
+    final := cast(s64) last;
+    result := other - total + final;
+    return result;
+}
+
+main :: () -> int {
+    result := sum_pairs(\"abde\");
+    return result;
+}");
+
+    assert_eq!(output.status.code(), Some(104));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn compiling_with_linking_backend_writes_executable_that_runs() {
+    let output = run_compiled_linux_executable("compiled", HELLO_WORLD_SOURCE);
+
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "hello world!\n");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[test]
+fn linked_executable_passes_strings_in_registers_and_on_the_stack() {
+    let output = run_linux_executable("strings", "count_both :: (first: string, second: string) -> int {
+    first_count := first.count;
+    second_count := second.count;
+    total := first_count + second_count;
+    return total;
+}
+
+count_last :: (a: int, b: int, c: int, d: int, e: int, text: string, f: int) -> int {
+    count := text.count;
+    total := a + b + c + d + e + f + count;
+    return total;
+}
+
+main :: () -> int {
+    both := count_both(\"one\", \"three\");
+    last := count_last(1, 2, 3, 4, 5, \"spilled\", 6);
+    result := both + last;
+    return result;
+}");
+
+    assert_eq!(output.status.code(), Some(36));
+}
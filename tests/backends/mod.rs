@@ -0,0 +1,5 @@
+mod elf;
+mod executables;
+mod objects;
+mod operations;
+mod pe;
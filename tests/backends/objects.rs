@@ -0,0 +1,145 @@
+use rust_hephaestus::*;
+
+const TWO_UNITS_WITH_DATA_SOURCE: &str = "output :: (to_output: string) {
+}
+
+first :: () {
+    output(\"first\\0\");
+}
+
+main :: () {
+    output(\"second\\0\");
+    first();
+}";
+
+fn get_data_section_text_references(coff: &Coff) -> Vec<u32> {
+    let text = get_text_section(coff);
+    get_text_section_relocation_entries(coff)
+        .iter()
+        .map(get_relocation_entry_parts)
+        .filter(|(_, symbol_index, _)| *symbol_index == 0x02)
+        .map(|(offset, ..)| u32::from_le_bytes(text[offset as usize..offset as usize + 4].try_into().unwrap()))
+        .collect()
+}
+
+#[test]
+fn object_for_program_places_each_unit_data_after_the_last_and_rebases_references() {
+    let irs = compile_source_and_get_intemediate_representation(TWO_UNITS_WITH_DATA_SOURCE);
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+
+    let data = get_data_section(&coff);
+    let read_only_data = get_read_only_data_section(&coff);
+    let mut referenced: Vec<&[u8]> = get_data_section_text_references(&coff)
+        .into_iter()
+        .map(|offset| read_u64(data, offset as usize + 8) as usize)
+        .map(|characters| &read_only_data[characters..characters + read_only_data[characters..].iter().position(|byte| *byte == 0).unwrap()])
+        .collect();
+    referenced.sort();
+
+    assert_eq!(referenced, vec!(b"first".as_slice(), b"second".as_slice()));
+}
+
+#[test]
+fn object_for_program_has_text_of_every_unit() {
+    let irs = compile_source_and_get_intemediate_representation(TWO_UNITS_WITH_DATA_SOURCE);
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+
+    let unit_objects: Vec<Coff> = irs
+        .iter()
+        .map(|ir| build_x64_coff_object("test.hep", std::slice::from_ref(ir)).unwrap())
+        .collect();
+    let unit_text_size: usize = unit_objects.iter().map(|unit| get_text_section(unit).len()).sum();
+    let unit_relocation_count: usize = unit_objects.iter().map(|unit| get_text_section_relocation_entries(unit).len()).sum();
+
+    assert_eq!(get_text_section(&coff).len(), unit_text_size);
+    assert_eq!(get_text_section_relocation_entries(&coff).len(), unit_relocation_count);
+}
+
+#[test]
+fn backend_is_emitted_once_after_every_unit_is_built() {
+    let (file_path, reader) = add_source_to_test_file_system(TWO_UNITS_WITH_DATA_SOURCE);
+    let message_receiver = compile_and_get_message_receiver(file_path, reader);
+
+    let mut units_built = 0;
+    let mut units_built_when_emitted = vec!();
+    loop {
+        match message_receiver.recv().unwrap() {
+            CompilationMessage::BackendBuilt { .. } => units_built += 1,
+            CompilationMessage::BackendEmitted => units_built_when_emitted.push(units_built),
+            CompilationMessage::CompilationComplete => break,
+            _ => {}
+        }
+    }
+
+    assert!(units_built > 2);
+    assert_eq!(units_built_when_emitted, vec!(units_built));
+}
+
+
+#[test]
+fn object_for_jumps_sets_displacements_to_their_labels() {
+    let mut ir = create_intermediate_representation(create_compilation_unit_id(), string("test.hep"));
+    let start = create_label(&mut ir);
+    let end = create_label(&mut ir);
+    ir.byte_code = vec!(
+        label_instruction(start),
+        jump_instruction(end),
+        jump_on_condition_instruction(ByteCodeCondition::Equal, start),
+        label_instruction(end),
+        ret_instruction()
+    );
+
+    let coff = build_x64_coff_object("test.hep", &[ir]).unwrap();
+
+    assert_eq!(get_text_section(&coff), &[
+        // jmp end
+        0xE9, 0x06, 0x00, 0x00, 0x00,
+        // je start
+        0x0F, 0x84, 0xF5, 0xFF, 0xFF, 0xFF,
+        // ret
+        0xC3
+    ]);
+}
+
+#[test]
+fn object_for_64_bit_constant_holds_value_as_32_bit_absolute_symbol() {
+    let irs = compile_source_and_get_intemediate_representation("LIMIT : s64 : -5;");
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+
+    let limit = get_coff_symbol_definitions(&coff)
+        .into_iter()
+        .map(|(_, definition)| definition)
+        .find(|definition| definition.name == "LIMIT")
+        .unwrap();
+
+    assert_eq!(limit.section_number, IMAGE_SYM_ABSOLUTE);
+    assert_eq!(limit.value, -5i32 as u32);
+}
+
+#[test]
+fn object_for_64_bit_constant_beyond_32_bits_reports_it_out_of_range() {
+    let irs = compile_source_and_get_intemediate_representation("LIMIT : s64 : 4294967296;");
+
+    assert_eq!(build_x64_coff_object("test.hep", &irs).err(), Some(relocation_out_of_range_error("LIMIT")));
+}
+
+#[test]
+fn object_for_program_importing_basic_builds_for_both_targets() {
+    let (file_path, mut reader) = add_source_to_test_file_system("#import \"Basic\";
+main :: () {
+    print(\"value %\\n\", 42);
+}");
+    add_bundled_module_to_mock_file_reader(&mut reader, "Basic");
+    let irs = compile_file_for_target_with_module_paths_and_get_intemediate_representation(file_path, vec!(), windows_target(), reader);
+
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+    let type_info_kind = get_coff_symbol_definitions(&coff)
+        .into_iter()
+        .map(|(_, definition)| definition)
+        .find(|definition| definition.name == "Basic::TYPE_INFO_KIND_STRING")
+        .unwrap();
+
+    assert_eq!(type_info_kind.section_number, IMAGE_SYM_ABSOLUTE);
+    assert_eq!(type_info_kind.value, 4);
+    assert!(build_x64_elf_object("test.hep", &irs).is_ok());
+}
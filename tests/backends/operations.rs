@@ -0,0 +1,64 @@
+use rust_hephaestus::*;
+
+#[test]
+fn offsets_which_fit_a_signed_byte_use_a_byte_displacement() {
+    let mut coff = create_coff();
+
+    add_mov_qword_reg_plus_offset_pointer_to_reg_op(&mut coff, REG_BP, 16, REG_AX);
+    add_mov_reg_to_reg_plus_offset_qword_pointer_op(&mut coff, REG_AX, REG_SP, 120);
+
+    assert_eq!(get_text_section(&coff), &[
+        // mov rax, [rbp+16]
+        0x48, 0x8B, 0x45, 0x10,
+        // mov [rsp+120], rax
+        0x48, 0x89, 0x44, 0x24, 0x78
+    ]);
+}
+
+#[test]
+fn offsets_past_a_signed_byte_use_a_dword_displacement() {
+    let mut coff = create_coff();
+
+    add_mov_qword_reg_plus_offset_pointer_to_reg_op(&mut coff, REG_BP, 136, REG_AX);
+    add_mov_reg_to_reg_plus_offset_qword_pointer_op(&mut coff, REG_AX, REG_SP, 128);
+    add_movsx_reg_plus_offset_pointer_to_reg_op(&mut coff, 1, REG_BP, -200, REG_AX);
+
+    assert_eq!(get_text_section(&coff), &[
+        // mov rax, [rbp+136]
+        0x48, 0x8B, 0x85, 0x88, 0x00, 0x00, 0x00,
+        // mov [rsp+128], rax
+        0x48, 0x89, 0x84, 0x24, 0x80, 0x00, 0x00, 0x00,
+        // movsx rax, byte [rbp-200]
+        0x48, 0x0F, 0xBE, 0x85, 0x38, 0xFF, 0xFF, 0xFF
+    ]);
+}
+
+#[test]
+fn stack_sizes_past_a_signed_byte_use_a_dword_immediate() {
+    let mut coff = create_coff();
+
+    add_sub_dword_value_from_reg_op(&mut coff, 128, REG_SP);
+    add_add_dword_value_to_reg_op(&mut coff, 128, REG_SP);
+
+    assert_eq!(get_text_section(&coff), &[
+        // sub rsp, 128
+        0x48, 0x81, 0xEC, 0x80, 0x00, 0x00, 0x00,
+        // add rsp, 128
+        0x48, 0x81, 0xC4, 0x80, 0x00, 0x00, 0x00
+    ]);
+}
+
+#[test]
+fn zeroing_a_register_xors_it_with_itself() {
+    let mut coff = create_coff();
+
+    add_xor_qword_reg_into_reg_op(&mut coff, REG_CX, REG_CX);
+    add_xor_qword_reg_into_reg_op(&mut coff, REG_R9, REG_R9);
+
+    assert_eq!(get_text_section(&coff), &[
+        // xor rcx, rcx
+        0x48, 0x31, 0xC9,
+        // xor r9, r9
+        0x4D, 0x31, 0xC9
+    ]);
+}
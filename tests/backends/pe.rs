@@ -0,0 +1,274 @@
+use rust_hephaestus::*;
+
+const HELLO_WORLD_SOURCE: &str = "STD_OUTPUT_HANDLE : s32 : -11;
+
+Kernel32 :: #foreign_system_library \"kernel32\";
+WriteFile :: (handle: *void, to_write: *void, bytes_to_write: u32, bytes_written: *void, overlapped: *void) -> bool #foreign Kernel32;
+GetStdHandle :: (handle_type: s32) -> *void #foreign Kernel32;
+
+Crt :: #foreign_system_library \"msvcrt\";
+strlen :: (to_measure: *u8) -> s64 #foreign Crt;
+
+print :: (to_print: string) {
+    handle := GetStdHandle(STD_OUTPUT_HANDLE);
+    to_write := cast(*void) to_print.data;
+    length := cast(u32) to_print.count
+    bytes_written: *void = null;
+    overlapped: *void = null;
+    WriteFile(handle, to_write, length, bytes_written, overlapped);
+}
+
+main :: () {
+    print(\"hello world!\\r\\n\\0\");
+}";
+
+fn link_hello_world() -> (Coff, Pe) {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+    let pe = link_pe_executable(std::slice::from_ref(&coff), &get_pe_import_libraries(&irs), PE_ENTRY_POINT).unwrap();
+    (coff, pe)
+}
+
+fn get_text_symbol_value(coff: &Coff, name: &str) -> u32 {
+    get_coff_symbol_definitions(coff)
+        .into_iter()
+        .map(|(_, symbol)| symbol)
+        .find(|symbol| symbol.name == name && symbol.section_number == COFF_SECTION_TEXT)
+        .unwrap()
+        .value
+}
+
+fn read_idata_name(pe: &Pe, virtual_address: u32) -> String {
+    let idata = get_pe_section_contents(pe, ".idata").unwrap();
+    read_name(idata, (virtual_address - get_pe_section_virtual_address(pe, ".idata").unwrap()) as usize)
+}
+
+const EXIT_PROCESS_SOURCE: &str = "Kernel32 :: #foreign_system_library \"kernel32\";
+ExitProcess :: (exit_code: u32) #foreign Kernel32;
+
+main :: () {
+    exit_code : u32 = 0;
+    ExitProcess(exit_code);
+}";
+
+const EXIT_PROCESS_HEADERS: &str = "
+4D 5A 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 40 00 00 00
+50 45 00 00 64 86 02 00 00 00 00 00 00 00 00 00
+00 00 00 00 F0 00 23 00 0B 02 00 01 00 02 00 00
+00 02 00 00 00 00 00 00 00 10 00 00 00 10 00 00
+00 00 40 00 00 00 00 00 00 10 00 00 00 02 00 00
+06 00 00 00 00 00 00 00 06 00 00 00 00 00 00 00
+00 30 00 00 00 02 00 00 00 00 00 00 03 00 00 81
+00 00 10 00 00 00 00 00 00 10 00 00 00 00 00 00
+00 00 10 00 00 00 00 00 00 10 00 00 00 00 00 00
+00 00 00 00 10 00 00 00 00 00 00 00 00 00 00 00
+10 20 00 00 28 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 20 00 00 10 00 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 2E 74 65 78 74 00 00 00
+38 00 00 00 00 10 00 00 00 02 00 00 00 02 00 00
+00 00 00 00 00 00 00 00 00 00 00 00 20 00 00 60
+2E 69 64 61 74 61 00 00 64 00 00 00 00 20 00 00
+00 02 00 00 00 04 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 40 00 00 C0";
+
+const EXIT_PROCESS_IMPORTS: &str = "
+48 20 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+38 20 00 00 00 00 00 00 00 00 00 00 56 20 00 00
+00 20 00 00 00 00 00 00 00 00 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 48 20 00 00 00 00 00 00
+00 00 00 00 00 00 00 00 00 00 45 78 69 74 50 72
+6F 63 65 73 73 00 6B 65 72 6E 65 6C 33 32 2E 64
+6C 6C 00 00";
+
+fn read_hex(hex: &str) -> Vec<u8> {
+    hex.split_whitespace().map(|byte| u8::from_str_radix(byte, 16).unwrap()).collect()
+}
+
+#[test]
+fn pe_executable_headers_and_imports_match_golden_bytes() {
+    let irs = compile_source_and_get_intemediate_representation(EXIT_PROCESS_SOURCE);
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+    let pe = link_pe_executable(&[coff], &get_pe_import_libraries(&irs), PE_ENTRY_POINT).unwrap();
+    let bytes = pe_to_bytes(&pe);
+    let headers = read_hex(EXIT_PROCESS_HEADERS);
+
+    assert_eq!(bytes.len(), 0x600);
+    assert_eq!(&bytes[0..headers.len()], headers.as_slice());
+    assert!(bytes[headers.len()..0x200].iter().all(|byte| *byte == 0));
+    assert_eq!(get_pe_section_contents(&pe, ".idata").unwrap(), read_hex(EXIT_PROCESS_IMPORTS).as_slice());
+    assert_eq!(&bytes[0x400..0x464], read_hex(EXIT_PROCESS_IMPORTS).as_slice());
+}
+
+#[test]
+fn pe_executable_has_headers_for_amd64_console_image() {
+    let (_, pe) = link_hello_world();
+    let bytes = pe_to_bytes(&pe);
+
+    assert_eq!(&bytes[0..2], b"MZ");
+    assert_eq!(read_u32(&bytes, 0x3C), 0x40);
+    assert_eq!(&bytes[0x40..0x44], b"PE\0\0");
+    assert_eq!(read_u16(&bytes, 0x44), 0x8664);
+    assert_eq!(read_u16(&bytes, 0x46), 4);
+    assert_eq!(read_u32(&bytes, 0x48), 0);
+    assert_eq!(read_u16(&bytes, 0x54), 240);
+    assert_eq!(read_u16(&bytes, 0x56), 0x23);
+    assert_eq!(read_u16(&bytes, 0x58), 0x20B);
+    assert_eq!(read_u64(&bytes, 0x70), 0x400000);
+    assert_eq!(read_u32(&bytes, 0x78), 0x1000);
+    assert_eq!(read_u32(&bytes, 0x7C), 0x200);
+    assert_eq!(read_u32(&bytes, 0x90), 0x5000);
+    assert_eq!(read_u32(&bytes, 0x94), 0x200);
+    assert_eq!(read_u16(&bytes, 0x9C), 3);
+    assert_eq!(read_u32(&bytes, 0xC4), 16);
+    assert_eq!(bytes.len() % 0x200, 0);
+}
+
+#[test]
+fn pe_executable_section_headers_place_each_section_on_its_own_page() {
+    let (_, pe) = link_hello_world();
+    let bytes = pe_to_bytes(&pe);
+
+    let expected = [
+        (".text\0\0\0", 0x1000, 0x200, 0x60000020),
+        (".idata\0\0", 0x2000, 0x400, 0xC0000040),
+        (".rdata\0\0", 0x3000, 0x600, 0x40000040),
+        (".data\0\0\0", 0x4000, 0x800, 0xC0000040)
+    ];
+    for (index, (name, virtual_address, pointer_to_section, flags)) in expected.into_iter().enumerate() {
+        let header = 0x148 + index * 40;
+        assert_eq!(&bytes[header..header + 8], name.as_bytes());
+        assert_eq!(read_u32(&bytes, header + 12), virtual_address);
+        assert_eq!(read_u32(&bytes, header + 16), 0x200);
+        assert_eq!(read_u32(&bytes, header + 20), pointer_to_section);
+        assert_eq!(read_u32(&bytes, header + 36), flags);
+    }
+
+    let text = get_pe_section_contents(&pe, ".text").unwrap();
+    assert_eq!(&bytes[0x200..0x200 + text.len()], text);
+}
+
+#[test]
+fn pe_executable_entry_point_is_main() {
+    let (coff, pe) = link_hello_world();
+    let bytes = pe_to_bytes(&pe);
+
+    let expected = get_pe_section_virtual_address(&pe, ".text").unwrap() + get_text_symbol_value(&coff, "main");
+    assert_eq!(get_pe_entry_point(&pe), expected);
+    assert_eq!(read_u32(&bytes, 0x68), expected);
+}
+
+#[test]
+fn pe_executable_imports_only_referenced_procedures_by_name() {
+    let (_, pe) = link_hello_world();
+    let idata_address = get_pe_section_virtual_address(&pe, ".idata").unwrap();
+    let idata = get_pe_section_contents(&pe, ".idata").unwrap();
+
+    let import_directory = get_pe_data_directory(&pe, 1);
+    assert_eq!(import_directory, PeDataDirectory { virtual_address: idata_address + 24, size: 40 });
+    assert_eq!(get_pe_data_directory(&pe, 12), PeDataDirectory { virtual_address: idata_address, size: 24 });
+
+    let descriptor = (import_directory.virtual_address - idata_address) as usize;
+    assert_eq!(read_idata_name(&pe, read_u32(idata, descriptor + 12)), "kernel32.dll");
+    assert_eq!(read_u32(idata, descriptor + 16), idata_address);
+    assert_eq!(&idata[descriptor + 20..descriptor + 40], &[0; 20]);
+
+    let lookup_table = (read_u32(idata, descriptor) - idata_address) as usize;
+    let names: Vec<String> = [0, 8]
+        .iter()
+        .map(|entry| read_idata_name(&pe, read_u64(idata, lookup_table + entry) as u32 + 2))
+        .collect();
+    assert_eq!(names, vec!("GetStdHandle", "WriteFile"));
+    assert_eq!(read_u64(idata, lookup_table + 16), 0);
+    assert_eq!(&idata[lookup_table..lookup_table + 24], &idata[0..24]);
+}
+
+#[test]
+fn pe_executable_calls_imports_through_thunks_reading_the_import_address_table() {
+    let (coff, pe) = link_hello_world();
+    let text_address = get_pe_section_virtual_address(&pe, ".text").unwrap();
+    let text = get_pe_section_contents(&pe, ".text").unwrap();
+    let idata_address = get_pe_section_virtual_address(&pe, ".idata").unwrap();
+    let idata = get_pe_section_contents(&pe, ".idata").unwrap();
+
+    let mut called = vec!();
+    for entry in get_text_section_relocation_entries(&coff) {
+        let (offset, ..) = get_relocation_entry_parts(entry);
+        let offset = offset as usize;
+        if text[offset - 1] != 0xE8 {
+            continue;
+        }
+        let thunk = (offset as i64 + 4 + read_u32(text, offset) as i32 as i64) as usize;
+        if text[thunk..thunk + 2] != [0xFF, 0x25] {
+            continue;
+        }
+        let slot = text_address + thunk as u32 + 6 + read_u32(text, thunk + 2);
+        let hint_name = read_u64(idata, (slot - idata_address) as usize) as u32;
+        called.push(read_idata_name(&pe, hint_name + 2));
+    }
+    called.sort();
+
+    assert_eq!(called, vec!("GetStdHandle", "WriteFile"));
+}
+
+#[test]
+fn pe_executable_relocates_data_pointers_to_image_addresses() {
+    let (coff, pe) = link_hello_world();
+    let data = get_pe_section_contents(&pe, ".data").unwrap();
+    let rdata_address = get_pe_section_virtual_address(&pe, ".rdata").unwrap() as u64;
+    let rdata = get_pe_section_contents(&pe, ".rdata").unwrap();
+
+    let (pointer, ..) = get_relocation_entry_parts(&get_data_section_relocation_entries(&coff)[0]);
+    let characters = read_u64(data, pointer as usize) - 0x400000 - rdata_address;
+    assert_eq!(read_name(rdata, characters as usize), "hello world!\r\n");
+}
+
+#[test]
+fn pe_executable_is_the_same_when_linked_again() {
+    let (coff, pe) = link_hello_world();
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let relinked = link_pe_executable(&[coff], &get_pe_import_libraries(&irs), PE_ENTRY_POINT).unwrap();
+
+    assert_eq!(pe_to_bytes(&pe), pe_to_bytes(&relinked));
+}
+
+#[test]
+fn import_libraries_name_the_dll_of_each_foreign_procedure() {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+
+    assert_eq!(
+        get_pe_import_libraries(&irs),
+        vec!(
+            pe_import_library(string("kernel32.dll"), vec!(string("GetStdHandle"), string("WriteFile"))),
+            pe_import_library(string("msvcrt.dll"), vec!(string("strlen")))
+        )
+    );
+}
+
+#[test]
+fn linking_foreign_procedure_without_import_reports_undefined_symbol() {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+    let libraries = vec!(pe_import_library(string("kernel32.dll"), vec!(string("WriteFile"))));
+
+    let result = link_pe_executable(&[coff], &libraries, PE_ENTRY_POINT);
+
+    assert_eq!(result.err(), Some(undefined_symbol_error("GetStdHandle", "print")));
+}
+
+#[test]
+fn linking_without_entry_point_reports_it_not_found() {
+    let irs = compile_source_and_get_intemediate_representation(HELLO_WORLD_SOURCE);
+    let coff = build_x64_coff_object("test.hep", &irs).unwrap();
+
+    let result = link_pe_executable(&[coff], &get_pe_import_libraries(&irs), "start");
+
+    assert_eq!(result.err(), Some(entry_point_not_found_error("start")));
+}
@@ -0,0 +1,168 @@
+use rust_hephaestus::*;
+
+#[test]
+fn byte_code_for_asm_block_saves_clobbered_registers_and_accesses_locals() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () {
+    x : s64 = 2;
+    #asm { mov rax, x; add rax, 5; mov x, rax; }
+}"
+    );   
+    
+    assert_eq!(irs.len(), 2);
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.symbols.len(), 1);
+    assert_eq!(main_body_ir.data.items.len(), 0);
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 1 local assignment
+        sub_value_from_reg_instruction(instruction_value_8(8), stack_pointer_register()),
+        //store x
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(2), base_pointer_register(), negative_address_offset(8)),
+
+        //save clobbered rax
+        push_reg_instruction(register_size_64(), standard_register(0)),
+        //mov rax, x
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(8), standard_register(0)),
+        //add rax, 5
+        add_value_to_reg_instruction(instruction_value_8(5), standard_register(0)),
+        //mov x, rax
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), base_pointer_register(), negative_address_offset(8)),
+        //restore rax
+        pop_reg_instruction(register_size_64(), standard_register(0)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+}
+
+#[test]
+fn byte_code_for_asm_block_with_unavailable_register_errors() {
+    let errors = compile_source_and_get_errors("main :: () {
+    #asm { mov rbx, 1; }
+}"
+    );
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            intermediate_representation_error(asm_register_not_available_error(string("rbx"))),
+            position(28, 2, 16)
+        )
+    );
+}
+
+#[test]
+fn byte_code_for_asm_system_call_saves_registers_clobbered_by_kernel() {
+    let (file_path, mut reader) = add_source_to_test_file_system("main :: () {
+    x : s64 = 0;
+    #asm { mov rdi, x; mov rax, 60; syscall; }
+}"
+    );
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+
+    let irs = compile_file_for_target_and_get_intemediate_representation(file_path, linux_target(), reader);
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+
+        //reserve space for 1 local assignment
+        sub_value_from_reg_instruction(instruction_value_8(8), stack_pointer_register()),
+        //store x
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(0), base_pointer_register(), negative_address_offset(8)),
+
+        //save clobbered rdi, rax, and the rcx and r11 used by the kernel
+        push_reg_instruction(register_size_64(), standard_register(12)),
+        push_reg_instruction(register_size_64(), standard_register(0)),
+        push_reg_instruction(register_size_64(), standard_register(1)),
+        push_reg_instruction(register_size_64(), standard_register(6)),
+        //mov rdi, x
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(8), standard_register(12)),
+        //mov rax, 60
+        move_value_to_reg_instruction(instruction_value_64(60), standard_register(0)),
+        //syscall
+        system_call_instruction(),
+        //restore registers
+        pop_reg_instruction(register_size_64(), standard_register(6)),
+        pop_reg_instruction(register_size_64(), standard_register(1)),
+        pop_reg_instruction(register_size_64(), standard_register(0)),
+        pop_reg_instruction(register_size_64(), standard_register(12)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+
+        ret_instruction()
+    ));
+}
+
+#[test]
+fn byte_code_for_asm_block_saves_callee_saved_registers_for_whole_procedure() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () -> int {
+    x : s64 = 2;
+    #asm { mov r12, x; mov rax, r12; }
+    y := 0;
+    #asm { mov rdi, rax; }
+    return y;
+}"
+    );
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+
+    assert_eq!(main_body_ir.saved_registers, vec!(standard_register(7), standard_register(12)));
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+
+        //reserve space for 2 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(16), stack_pointer_register()),
+        //save callee saved r12 and rdi
+        push_reg_instruction(register_size_64(), standard_register(7)),
+        push_reg_instruction(register_size_64(), standard_register(12)),
+        //store x
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(2), base_pointer_register(), negative_address_offset(8)),
+
+        //save clobbered rax only
+        push_reg_instruction(register_size_64(), standard_register(0)),
+        //mov r12, x
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(8), standard_register(7)),
+        //mov rax, r12
+        move_reg_to_reg_instruction(register_size_64(), standard_register(7), standard_register(0)),
+        pop_reg_instruction(register_size_64(), standard_register(0)),
+
+        //store y
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(0), base_pointer_register(), negative_address_offset(16)),
+
+        //mov rdi, rax
+        move_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(12)),
+
+        //return y
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(16), call_return_arg_register(0)),
+        //restore callee saved registers then epilogue
+        pop_reg_instruction(register_size_64(), standard_register(12)),
+        pop_reg_instruction(register_size_64(), standard_register(7)),
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        ret_instruction(),
+
+        //epilogue
+        pop_reg_instruction(register_size_64(), standard_register(12)),
+        pop_reg_instruction(register_size_64(), standard_register(7)),
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        ret_instruction()
+    ));
+}
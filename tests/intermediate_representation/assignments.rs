@@ -67,6 +67,76 @@ fn byte_code_for_cast_type_assignment_generates_correctly() {
     assert_eq!(main_body_ir.foreign_libraries.len(), 0);
 }
 
+#[test]
+fn byte_code_for_checked_narrowing_cast_assignment_traps_on_overflow() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () {
+    x: s64 = 300;
+    y := cast,checked(u8) x;
+}"
+    );   
+    
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 2 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(9), stack_pointer_register()),
+        //store x
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(300), base_pointer_register(), negative_address_offset(8)),
+        //load x and check it is positive
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_64(), true, base_pointer_register(), negative_address_offset(8), standard_register(0)),
+        zero_reg_instruction(register_size_64(), standard_register(1)),
+        compare_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+        trap_on_condition_instruction(ByteCodeCondition::Less),
+        //check x survives the round trip through u8
+        extend_reg_to_reg_instruction(register_size_8(), false, standard_register(0), standard_register(1)),
+        compare_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+        trap_on_condition_instruction(ByteCodeCondition::NotEqual),
+        //store y
+        move_reg_to_reg_plus_offset_instruction(register_size_8(), standard_register(0), base_pointer_register(), negative_address_offset(9)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+}
+
+#[test]
+fn byte_code_for_implicit_widening_assignment_zero_extends() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () {
+    x: u8 = 200;
+    y: u32 = x;
+}"
+    );   
+    
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 2 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(5), stack_pointer_register()),
+        //store x
+        move_value_to_reg_plus_offset_instruction(instruction_value_8(200), base_pointer_register(), negative_address_offset(1)),
+        //widen x into y
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_8(), false, base_pointer_register(), negative_address_offset(1), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_32(), standard_register(0), base_pointer_register(), negative_address_offset(5)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+}
+
 #[test]
 fn byte_code_for_string_assignment_generates_correctly() {
     let irs = compile_source_and_get_intemediate_representation("main :: () {
@@ -78,9 +148,10 @@ fn byte_code_for_string_assignment_generates_correctly() {
 
     let some_proc_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
     
-    assert_eq!(some_proc_body_ir.symbols.len(), 2);
+    assert_eq!(some_proc_body_ir.symbols.len(), 1);
 
-    assert_eq!(some_proc_body_ir.data.items, vec!(
+    assert_eq!(some_proc_body_ir.data.items, vec!());
+    assert_eq!(some_proc_body_ir.read_only_data.items, vec!(
         string_data_item(to_byte_string("test"))
     ));
 
@@ -93,7 +164,7 @@ fn byte_code_for_string_assignment_generates_correctly() {
         sub_value_from_reg_instruction(instruction_value_8(16), stack_pointer_register()),
         //store x
         move_value_to_reg_plus_offset_instruction(instruction_value_64(4), base_pointer_register(), negative_address_offset(16)),
-        load_data_section_address_to_reg(register_size_64(), data_section_offset(0), call_arg_register(0)),
+        load_read_only_data_address_to_reg(register_size_64(), data_section_offset(0), call_arg_register(0)),
         move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), negative_address_offset(8)),
         
         //epilogue
@@ -157,4 +228,36 @@ fn byte_code_for_string_field_assignment_generates_correctly() {
     ));
 
     assert_eq!(some_proc_body_ir.foreign_libraries.len(), 0);
-}
\ No newline at end of file
+}
+#[test]
+fn byte_code_for_uninitialised_assignment_generates_correctly() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () {
+    x : u32 = ---;
+    y : u64 = 2;
+}"
+    );   
+    
+    assert_eq!(irs.len(), 2);
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.symbols.len(), 1);
+    assert_eq!(main_body_ir.data.items.len(), 0);
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 2 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(12), stack_pointer_register()),
+        //store y
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(2), base_pointer_register(), negative_address_offset(12)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+    assert_eq!(main_body_ir.foreign_libraries.len(), 0);
+}
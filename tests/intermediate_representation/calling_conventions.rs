@@ -0,0 +1,187 @@
+use rust_hephaestus::*;
+
+fn struct_type(members: Vec<(&str, RuntimeType)>) -> RuntimeType {
+    let members: RuntimeTypeMembers = members
+        .into_iter()
+        .map(|(name, field_type)| runtime_type_member(string(name), create_shareable(field_type)))
+        .collect();
+    let size = get_members_size(&members);
+    create_type(
+        user_defined_runtime_type_id(create_compilation_unit_id()),
+        string("Test_Struct"),
+        struct_type_item(members),
+        resolved_type_size(size)
+    )
+}
+
+#[test]
+fn win64_assigns_argument_registers_by_position_then_stack_after_shadow_space() {
+    let integer = integer_argument_class();
+    let float = float_argument_class();
+
+    let locations = get_argument_locations(win64_calling_convention(), &[integer, float, integer, float, integer, float]);
+
+    assert_eq!(locations, vec!(
+        register_argument_location(call_arg_register(0)),
+        register_argument_location(float_call_arg_register(1)),
+        register_argument_location(call_arg_register(2)),
+        register_argument_location(float_call_arg_register(3)),
+        stack_argument_location(address_offset(32)),
+        stack_argument_location(address_offset(40))
+    ));
+}
+
+#[test]
+fn system_v_counts_integer_and_float_argument_registers_separately() {
+    let integer = integer_argument_class();
+    let float = float_argument_class();
+
+    let mut classes = vec!(float, integer);
+    classes.extend([integer; 6]);
+    classes.extend([float; 8]);
+
+    let locations = get_argument_locations(system_v_calling_convention(), &classes);
+
+    assert_eq!(locations[0], register_argument_location(float_call_arg_register(0)));
+    assert_eq!(locations[1], register_argument_location(call_arg_register(0)));
+    assert_eq!(locations[6], register_argument_location(call_arg_register(5)));
+    assert_eq!(locations[7], stack_argument_location(address_offset(0)));
+    assert_eq!(locations[14], register_argument_location(float_call_arg_register(7)));
+    assert_eq!(locations[15], stack_argument_location(address_offset(8)));
+}
+
+#[test]
+fn call_arg_registers_alias_the_convention_argument_registers() {
+    let win64: Vec<_> = (0..4).map(|number| get_call_arg_standard_register(win64_calling_convention(), number).unwrap()).collect();
+    let system_v: Vec<_> = (0..6).map(|number| get_call_arg_standard_register(system_v_calling_convention(), number).unwrap()).collect();
+
+    // rcx, rdx, r8, r9
+    assert_eq!(win64, vec!(standard_register(1), standard_register(2), standard_register(3), standard_register(4)));
+    // rdi, rsi, rdx, rcx, r8, r9
+    assert_eq!(system_v, vec!(standard_register(12), standard_register(11), standard_register(2), standard_register(1), standard_register(3), standard_register(4)));
+
+    assert_eq!(get_call_arg_standard_register(win64_calling_convention(), 4), None);
+    assert_eq!(get_call_arg_standard_register(system_v_calling_convention(), 6), None);
+    assert!(is_float_call_arg_available(system_v_calling_convention(), 7));
+    assert!(!is_float_call_arg_available(win64_calling_convention(), 4));
+}
+
+#[test]
+fn call_stack_space_includes_shadow_space_only_for_win64() {
+    let integer = integer_argument_class();
+
+    let win64_locations = get_argument_locations(win64_calling_convention(), &[integer; 6]);
+    let system_v_locations = get_argument_locations(system_v_calling_convention(), &[integer; 8]);
+
+    assert_eq!(get_call_stack_space_size(win64_calling_convention(), &[]), 32);
+    assert_eq!(get_call_stack_space_size(win64_calling_convention(), &win64_locations), 48);
+    assert_eq!(get_call_stack_space_size(system_v_calling_convention(), &[]), 0);
+    assert_eq!(get_call_stack_space_size(system_v_calling_convention(), &system_v_locations), 16);
+}
+
+#[test]
+fn parameter_homes_are_in_shadow_space_for_win64_and_callee_frame_for_system_v() {
+    let integer = integer_argument_class();
+
+    let win64_locations = get_argument_locations(win64_calling_convention(), &[integer; 5]);
+    let system_v_locations = get_argument_locations(system_v_calling_convention(), &[integer; 7]);
+
+    assert_eq!(get_parameter_home(win64_calling_convention(), 1, win64_locations[1]), ParameterHome::CallerFrame(address_offset(24)));
+    assert_eq!(get_parameter_home(win64_calling_convention(), 4, win64_locations[4]), ParameterHome::CallerFrame(address_offset(48)));
+    assert_eq!(get_parameter_home(system_v_calling_convention(), 0, system_v_locations[0]), ParameterHome::CalleeFrame);
+    assert_eq!(get_parameter_home(system_v_calling_convention(), 6, system_v_locations[6]), ParameterHome::CallerFrame(address_offset(16)));
+}
+
+#[test]
+fn callee_saved_registers_follow_the_convention() {
+    for calling_convention in [win64_calling_convention(), system_v_calling_convention()] {
+        assert!(is_callee_saved_register(calling_convention, base_pointer_register()));
+        assert!(is_callee_saved_register(calling_convention, stack_pointer_register()));
+        // r12
+        assert!(is_callee_saved_register(calling_convention, standard_register(7)));
+        // rax and r10
+        assert!(!is_callee_saved_register(calling_convention, standard_register(0)));
+        assert!(!is_callee_saved_register(calling_convention, standard_register(5)));
+    }
+
+    // rsi and rdi
+    assert!(is_callee_saved_register(win64_calling_convention(), standard_register(11)));
+    assert!(is_callee_saved_register(win64_calling_convention(), standard_register(12)));
+    assert!(!is_callee_saved_register(system_v_calling_convention(), standard_register(11)));
+    assert!(!is_callee_saved_register(system_v_calling_convention(), standard_register(12)));
+}
+
+#[test]
+fn win64_passes_power_of_two_structs_up_to_eight_bytes_in_registers() {
+    let small = struct_type(vec!(("x", float_32_runtime_type()), ("y", float_32_runtime_type())));
+    let odd = struct_type(vec!(("x", float_32_runtime_type()), ("y", float_32_runtime_type()), ("z", float_32_runtime_type())));
+
+    assert_eq!(classify_struct_argument(win64_calling_convention(), &small), StructArgumentPassing::Registers(vec!(integer_argument_class())));
+    assert_eq!(classify_struct_argument(win64_calling_convention(), &odd), StructArgumentPassing::Reference);
+    assert_eq!(classify_struct_argument(win64_calling_convention(), &string_runtime_type()), StructArgumentPassing::Reference);
+}
+
+#[test]
+fn system_v_classifies_each_eightbyte_of_structs_up_to_sixteen_bytes() {
+    let mixed = struct_type(vec!(("x", float_32_runtime_type()), ("y", float_32_runtime_type()), ("count", signed_int_64_runtime_type())));
+    let shared = struct_type(vec!(("x", float_32_runtime_type()), ("flags", signed_int_32_runtime_type())));
+    let nested = struct_type(vec!(("inner", shared.clone()), ("y", float_64_runtime_type())));
+    let large = struct_type(vec!(("x", float_64_runtime_type()), ("y", float_64_runtime_type()), ("z", float_64_runtime_type())));
+
+    assert_eq!(
+        classify_struct_argument(system_v_calling_convention(), &mixed),
+        StructArgumentPassing::Registers(vec!(float_argument_class(), integer_argument_class()))
+    );
+    assert_eq!(
+        classify_struct_argument(system_v_calling_convention(), &shared),
+        StructArgumentPassing::Registers(vec!(integer_argument_class()))
+    );
+    assert_eq!(
+        classify_struct_argument(system_v_calling_convention(), &nested),
+        StructArgumentPassing::Registers(vec!(integer_argument_class(), float_argument_class()))
+    );
+    assert_eq!(
+        classify_struct_argument(system_v_calling_convention(), &string_runtime_type()),
+        StructArgumentPassing::Registers(vec!(integer_argument_class(), integer_argument_class()))
+    );
+    assert_eq!(classify_struct_argument(system_v_calling_convention(), &large), StructArgumentPassing::Memory);
+}
+
+#[test]
+fn system_v_passes_struct_eightbytes_in_registers_only_when_all_of_them_fit() {
+    let integer = Some(create_shareable(signed_int_64_runtime_type()));
+    let text = Some(create_shareable(string_runtime_type()));
+    let large = Some(create_shareable(struct_type(vec!(("x", float_64_runtime_type()), ("y", float_64_runtime_type()), ("z", float_64_runtime_type())))));
+
+    let arguments = get_passed_arguments(
+        system_v_calling_convention(),
+        &[text.clone(), integer.clone(), integer.clone(), integer.clone(), text, integer, large]
+    );
+
+    assert_eq!(arguments[0], passed_argument(
+        true,
+        vec!(integer_argument_class(), integer_argument_class()),
+        vec!(register_argument_location(call_arg_register(0)), register_argument_location(call_arg_register(1)))
+    ));
+    assert_eq!(arguments[4].locations, vec!(stack_argument_location(address_offset(0)), stack_argument_location(address_offset(8))));
+    assert_eq!(arguments[5].locations, vec!(register_argument_location(call_arg_register(5))));
+    assert!(arguments[6].is_struct_value);
+    assert_eq!(arguments[6].locations, vec!(
+        stack_argument_location(address_offset(16)),
+        stack_argument_location(address_offset(24)),
+        stack_argument_location(address_offset(32))
+    ));
+}
+
+#[test]
+fn win64_passes_a_pointer_to_structs_it_does_not_pass_in_registers() {
+    let small = Some(create_shareable(struct_type(vec!(("x", float_32_runtime_type()), ("y", float_32_runtime_type())))));
+    let text = Some(create_shareable(string_runtime_type()));
+
+    let arguments = get_passed_arguments(win64_calling_convention(), &[small, text]);
+
+    assert_eq!(arguments, vec!(
+        passed_argument(true, vec!(integer_argument_class()), vec!(register_argument_location(call_arg_register(0)))),
+        passed_argument(false, vec!(integer_argument_class()), vec!(register_argument_location(call_arg_register(1))))
+    ));
+}
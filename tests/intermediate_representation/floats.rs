@@ -0,0 +1,82 @@
+use rust_hephaestus::*;
+
+#[test]
+fn byte_code_for_float_arithmetic_and_comparison_generates_correctly() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () {
+    x: float64 = 2.5;
+    y := x * 4.0;
+    z := x < y;
+}"
+    );   
+    
+    assert_eq!(irs.len(), 2);
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.symbols.len(), 1);
+    assert_eq!(main_body_ir.data.items, vec!(
+        quad_word_data_item(2.5f64.to_bits()),
+        quad_word_data_item(4.0f64.to_bits())
+    ));
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 3 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(17), stack_pointer_register()),
+        //store x
+        move_data_section_to_float_reg_instruction(register_size_float_64(), data_section_offset(0), float_standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_float_64(), float_standard_register(0), base_pointer_register(), negative_address_offset(8)),
+        //store y
+        move_reg_plus_offset_to_reg_instruction(register_size_float_64(), base_pointer_register(), negative_address_offset(8), float_standard_register(0)),
+        move_data_section_to_float_reg_instruction(register_size_float_64(), data_section_offset(8), float_standard_register(1)),
+        mul_float_reg_to_float_reg_instruction(register_size_float_64(), float_standard_register(1), float_standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_float_64(), float_standard_register(0), base_pointer_register(), negative_address_offset(16)),
+        //store z
+        move_reg_plus_offset_to_reg_instruction(register_size_float_64(), base_pointer_register(), negative_address_offset(8), float_standard_register(0)),
+        move_reg_plus_offset_to_reg_instruction(register_size_float_64(), base_pointer_register(), negative_address_offset(16), float_standard_register(1)),
+        compare_float_reg_to_float_reg_instruction(register_size_float_64(), float_standard_register(0), float_standard_register(1)),
+        set_reg_on_condition_instruction(register_size_8(), ByteCodeCondition::Below, standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_8(), standard_register(0), base_pointer_register(), negative_address_offset(17)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+}
+
+#[test]
+fn byte_code_for_int_to_float_cast_converts_through_registers() {
+    let irs = compile_source_and_get_intemediate_representation("main :: () {
+    x: s32 = 3;
+    y := cast(float32) x;
+}"
+    );   
+    
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.data.items.len(), 0);
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 2 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(8), stack_pointer_register()),
+        //store x
+        move_value_to_reg_plus_offset_instruction(instruction_value_32(3), base_pointer_register(), negative_address_offset(4)),
+        //convert x into y
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(4), standard_register(0)),
+        convert_int_reg_to_float_reg_instruction(register_size_32(), register_size_float_32(), standard_register(0), float_standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_float_32(), float_standard_register(0), base_pointer_register(), negative_address_offset(8)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+}
@@ -0,0 +1,114 @@
+use rust_hephaestus::*;
+
+#[test]
+fn byte_code_for_initialised_global_variable_stores_value_in_data_section() {
+    let irs = compile_source_and_get_intemediate_representation("counter: s32 = 7;");
+    
+    assert_eq!(irs.len(), 1);
+
+    let global_ir = get_first_ir_named(&irs, "counter");
+    
+    assert_eq!(global_ir.symbols, vec!(external_data_section_item(string("counter"), 0)));
+    assert_eq!(global_ir.data.items, vec!(double_word_data_item(7)));
+    assert_eq!(global_ir.bss.size, 0);
+    assert_eq!(global_ir.byte_code.len(), 0);
+}
+
+#[test]
+fn byte_code_for_zero_initialised_global_variable_reserves_bss_space() {
+    let irs = compile_source_and_get_intemediate_representation("buffer: u16;");
+    
+    assert_eq!(irs.len(), 1);
+
+    let global_ir = get_first_ir_named(&irs, "buffer");
+    
+    assert_eq!(global_ir.symbols, vec!(external_bss_section_item(string("buffer"), 0)));
+    assert_eq!(global_ir.data.items.len(), 0);
+    assert_eq!(global_ir.bss.size, 2);
+    assert_eq!(global_ir.byte_code.len(), 0);
+}
+
+#[test]
+fn byte_code_for_uninitialised_global_variable_reserves_bss_space() {
+    let irs = compile_source_and_get_intemediate_representation("buffer: u64 = ---;");
+    
+    assert_eq!(irs.len(), 1);
+
+    let global_ir = get_first_ir_named(&irs, "buffer");
+    
+    assert_eq!(global_ir.symbols, vec!(external_bss_section_item(string("buffer"), 0)));
+    assert_eq!(global_ir.data.items.len(), 0);
+    assert_eq!(global_ir.bss.size, 8);
+    assert_eq!(global_ir.byte_code.len(), 0);
+}
+
+#[test]
+fn byte_code_for_procedure_writing_and_reading_global_variable_uses_symbol_address() {
+    let irs = compile_source_and_get_intemediate_representation("counter: s64 = 1;
+
+bump :: () -> s64 {
+    counter = 2;
+    return counter;
+}");
+
+    let bump_ir = get_first_ir_with_byte_code_named(&irs, "bump");
+
+    assert_eq!(bump_ir.symbols, vec!(
+        external_code_label(string("bump"), 0),
+        foreign_external(string("counter")),
+        foreign_external(string("counter"))
+    ));
+
+    assert_eq!(bump_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+
+        //counter = 2
+        load_symbol_address_to_reg(register_size_64(), symbol_index(1), standard_register(0)),
+        move_value_to_reg_plus_offset_instruction(instruction_value_64(2), standard_register(0), address_offset(0)),
+
+        //return counter
+        load_symbol_address_to_reg(register_size_64(), symbol_index(2), standard_register(0)),
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), standard_register(0), address_offset(0), call_return_arg_register(0)),
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        ret_instruction(),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        ret_instruction()
+    ));
+}
+
+#[test]
+fn byte_code_for_global_variable_assigned_expression_of_itself_reads_it_with_its_type() {
+    let irs = compile_source_and_get_intemediate_representation("counter: s32 = 1;
+
+bump :: () {
+    counter = counter + 3;
+}");
+
+    let bump_ir = get_first_ir_with_byte_code_named(&irs, "bump");
+
+    assert_eq!(bump_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+
+        //counter = counter + 3
+        load_symbol_address_to_reg(register_size_64(), symbol_index(1), standard_register(0)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_32(), true, standard_register(0), address_offset(0), standard_register(0)),
+        move_value_to_reg_instruction(instruction_value_64(3), standard_register(1)),
+        add_reg_to_reg_instruction(register_size_64(), standard_register(1), standard_register(0)),
+        move_reg_to_reg_instruction(register_size_64(), standard_register(0), standard_register(1)),
+        load_symbol_address_to_reg(register_size_64(), symbol_index(2), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_32(), standard_register(1), standard_register(0), address_offset(0)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        ret_instruction()
+    ));
+}
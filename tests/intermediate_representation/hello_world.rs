@@ -26,15 +26,17 @@ main :: () {
     let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
     let print_body_ir = get_first_ir_with_byte_code_named(&irs, "print");
     
-    assert_eq!(main_body_ir.symbols.len(), 4);
-    assert_eq!(main_body_ir.data.items, vec!(
+    assert_eq!(main_body_ir.symbols.len(), 3);
+    assert_eq!(main_body_ir.read_only_data.items, vec!(
         // string lit
         string_data_item(to_byte_string("hello world!\\r\\n\\0")),
+    ));
+    assert_eq!(main_body_ir.data.items, vec!(
         // string instance
         // length
         quad_word_data_item(15),
-        // data (ptr to item 0)
-        pointer_data_item(0),
+        // data (ptr to the string lit)
+        read_only_pointer_data_item(0),
     ));
     assert_eq!(main_body_ir.byte_code, vec!(
         //prologue
@@ -44,9 +46,9 @@ main :: () {
         // reserve shadow space for print proc call
         sub_value_from_reg_instruction(instruction_value_8(32), stack_pointer_register()),
         // set call arg registers for GetStdHandle proc call
-        load_data_section_address_to_reg(register_size_64(), data_section_offset(15), call_arg_register(0)),
+        load_data_section_address_to_reg(register_size_64(), data_section_offset(0), call_arg_register(0)),
         // call print
-        call_to_symbol_instruction(symbol_index(3)),
+        call_to_symbol_instruction(symbol_index(2)),
         // release shadow space for print proc call
         add_value_to_reg_instruction(instruction_value_8(32), stack_pointer_register()),
         
@@ -78,7 +80,7 @@ main :: () {
         // call GetStdHandle
         call_to_symbol_instruction(symbol_index(2)),
         // store returned handle value
-        move_reg_to_reg_plus_offset_instruction(register_size_32(), call_return_arg_register(0), base_pointer_register(), negative_address_offset(8)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_return_arg_register(0), base_pointer_register(), negative_address_offset(8)),
         // release shadow space for GetStdHandle proc call
         add_value_to_reg_instruction(instruction_value_8(32), stack_pointer_register()),
         
@@ -109,8 +111,9 @@ main :: () {
         move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(16), call_arg_register(1)),
         move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(20), call_arg_register(2)),
         move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(28), call_arg_register(3)),
-        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(36), call_arg_register(4)),
-        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(4), stack_pointer_register(), address_offset(32)),
+        // fifth arg goes on the stack after the shadow space
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(36), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(32)),
         call_to_symbol_instruction(symbol_index(3)),
         // release shadow space for WriteFile proc call
         add_value_to_reg_instruction(instruction_value_8(40), stack_pointer_register()),
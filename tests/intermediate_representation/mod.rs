@@ -1,4 +1,9 @@
 mod procedures;
 mod constants;
 mod assignments;
-mod hello_world;
\ No newline at end of file
+mod hello_world;
+mod type_infos;
+mod floats;
+mod globals;
+mod asm;
+mod calling_conventions;
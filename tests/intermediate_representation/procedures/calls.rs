@@ -37,7 +37,7 @@ main :: () {
         // proc call
         call_to_symbol_instruction(symbol_index(1)),
         // store proc call return value
-        move_reg_to_reg_plus_offset_instruction(register_size_32(), call_return_arg_register(0), base_pointer_register(), negative_address_offset(8)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_return_arg_register(0), base_pointer_register(), negative_address_offset(8)),
         // release shadow space for proc call
         add_value_to_reg_instruction(instruction_value_8(32), stack_pointer_register()),
         
@@ -166,14 +166,16 @@ main :: () {
     assert_eq!(main_body_ir.symbols, vec!(
         external_code_label(string("main"), 0),
         data_section_item(string("ds0"), 0),
-        data_section_item(string("ds4"), 4),
         foreign_external(string("some_procedure"))
     ));
 
+    assert_eq!(main_body_ir.read_only_data.items, vec!(
+        string_data_item(to_byte_string("test"))
+    ));
+
     assert_eq!(main_body_ir.data.items, vec!(
-        string_data_item(to_byte_string("test")),
         quad_word_data_item(4),
-        pointer_data_item(0)
+        read_only_pointer_data_item(0)
     ));
     
     assert_eq!(main_body_ir.byte_code, vec!(
@@ -184,10 +186,10 @@ main :: () {
         // reserve shadow space for proc call
         sub_value_from_reg_instruction(instruction_value_8(32), stack_pointer_register()),
         // set call arg register to point at
-        load_data_section_address_to_reg(register_size_64(), data_section_offset(4), call_arg_register(0)),
+        load_data_section_address_to_reg(register_size_64(), data_section_offset(0), call_arg_register(0)),
         
         // proc call
-        call_to_symbol_instruction(symbol_index(3)),
+        call_to_symbol_instruction(symbol_index(2)),
         
         // release shadow space for proc call
         add_value_to_reg_instruction(instruction_value_8(32), stack_pointer_register()),
@@ -287,3 +289,235 @@ main :: (x: s32) {
         ret_instruction()
     ));
 }
+
+#[test]
+fn byte_code_for_procedure_call_with_any_arg_boxes_value_on_stack() {
+    let irs = compile_source_and_get_intemediate_representation("print :: (value: Any) {
+}
+    
+main :: () {
+    x: u32 = 14;
+    print(x);
+}"
+    );   
+    
+    assert_eq!(irs.len(), 4);
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.symbols, vec!(
+        external_code_label(string("main"), 0),
+        foreign_external(string("__type_info_u32")),
+        foreign_external(string("print"))
+    ));
+
+    assert_eq!(main_body_ir.data.items.len(), 0);
+    
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+        
+        //reserve space for 1 local assignment, the boxed value and the any
+        sub_value_from_reg_instruction(instruction_value_8(24), stack_pointer_register()),
+        move_value_to_reg_plus_offset_instruction(instruction_value_32(14), base_pointer_register(), negative_address_offset(4)),
+        
+        // reserve shadow space for proc call
+        sub_value_from_reg_instruction(instruction_value_8(32), stack_pointer_register()),
+        // spill boxed value
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(4), call_arg_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_32(), call_arg_register(0), base_pointer_register(), negative_address_offset(8)),
+        // build any
+        load_symbol_address_to_reg(register_size_64(), symbol_index(1), call_arg_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), negative_address_offset(24)),
+        load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), negative_address_offset(8), call_arg_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), negative_address_offset(16)),
+        // pass any by pointer
+        load_address_in_reg_plus_offset_to_reg(register_size_64(), base_pointer_register(), negative_address_offset(24), call_arg_register(0)),
+        // proc call
+        call_to_symbol_instruction(symbol_index(2)),
+        // release shadow space for proc call
+        add_value_to_reg_instruction(instruction_value_8(32), stack_pointer_register()),
+        
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+
+    assert_eq!(main_body_ir.type_infos.len(), 1);
+    assert_eq!(main_body_ir.type_infos[0].name, "u32");
+}
+
+#[test]
+fn byte_code_for_procedure_call_with_more_than_four_args_passes_rest_on_stack_after_shadow_space() {
+    let irs = compile_source_and_get_intemediate_representation("some_procedure :: (a: s64, b: s64, c: s64, d: s64, e: s64, f: s64) {
+}
+    
+main :: () {
+    some_procedure(1, 2, 3, 4, 5, 6);
+}"
+    );   
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+
+        // reserve shadow space and two stack args for proc call
+        sub_value_from_reg_instruction(instruction_value_8(48), stack_pointer_register()),
+        // set call arg registers
+        move_value_to_reg_instruction(instruction_value_64(1), call_arg_register(0)),
+        move_value_to_reg_instruction(instruction_value_64(2), call_arg_register(1)),
+        move_value_to_reg_instruction(instruction_value_64(3), call_arg_register(2)),
+        move_value_to_reg_instruction(instruction_value_64(4), call_arg_register(3)),
+        // store stack args above the shadow space
+        move_value_to_reg_instruction(instruction_value_64(5), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(32)),
+        move_value_to_reg_instruction(instruction_value_64(6), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(40)),
+        // proc call
+        call_to_symbol_instruction(symbol_index(1)),
+        // release stack space for proc call
+        add_value_to_reg_instruction(instruction_value_8(48), stack_pointer_register()),
+        
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+        
+        ret_instruction()
+    ));
+
+    let procedure_body_ir = get_first_ir_with_byte_code_named(&irs, "some_procedure");
+
+    assert_eq!(procedure_body_ir.byte_code[2..6], vec!(
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), address_offset(16)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(1), base_pointer_register(), address_offset(24)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(2), base_pointer_register(), address_offset(32)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(3), base_pointer_register(), address_offset(40)),
+    ));
+    assert_eq!(procedure_body_ir.byte_code[6], move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()));
+}
+
+#[test]
+fn byte_code_for_procedure_call_for_linux_uses_system_v_calling_convention() {
+    let (file_path, mut reader) = add_source_to_test_file_system("some_procedure :: (a: s64, x: float, b: s64, c: s64, d: s64, e: s64, f: s64, g: s64) {
+}
+    
+main :: () -> int {
+    some_procedure(1, 0.5, 2, 3, 4, 5, 6, 7);
+    return 0;
+}");
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+
+    let irs = compile_file_for_target_and_get_intemediate_representation(file_path, linux_target(), reader);
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+    assert_eq!(main_body_ir.calling_convention, system_v_calling_convention());
+    
+    assert_eq!(main_body_ir.byte_code[2..15], vec!(
+        // no shadow space, only room for the stack arg
+        sub_value_from_reg_instruction(instruction_value_8(8), stack_pointer_register()),
+        // int and float args take the next register of their own class
+        move_value_to_reg_instruction(instruction_value_64(1), call_arg_register(0)),
+        move_data_section_to_float_reg_instruction(register_size_float_32(), data_section_offset(0), float_call_arg_register(0)),
+        move_value_to_reg_instruction(instruction_value_64(2), call_arg_register(1)),
+        move_value_to_reg_instruction(instruction_value_64(3), call_arg_register(2)),
+        move_value_to_reg_instruction(instruction_value_64(4), call_arg_register(3)),
+        move_value_to_reg_instruction(instruction_value_64(5), call_arg_register(4)),
+        move_value_to_reg_instruction(instruction_value_64(6), call_arg_register(5)),
+        // seventh int arg goes on the stack
+        move_value_to_reg_instruction(instruction_value_64(7), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(0)),
+        call_to_symbol_instruction(symbol_index(1)),
+        add_value_to_reg_instruction(instruction_value_8(8), stack_pointer_register()),
+        move_value_to_reg_instruction(instruction_value_64(0), call_return_arg_register(0)),
+    ));
+
+    let procedure_body_ir = get_first_ir_with_byte_code_named(&irs, "some_procedure");
+
+    assert_eq!(procedure_body_ir.byte_code[2..10], vec!(
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), negative_address_offset(8)),
+        move_reg_to_reg_plus_offset_instruction(register_size_float_32(), float_call_arg_register(0), base_pointer_register(), negative_address_offset(16)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(1), base_pointer_register(), negative_address_offset(24)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(2), base_pointer_register(), negative_address_offset(32)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(3), base_pointer_register(), negative_address_offset(40)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(4), base_pointer_register(), negative_address_offset(48)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(5), base_pointer_register(), negative_address_offset(56)),
+        sub_value_from_reg_instruction(instruction_value_8(56), stack_pointer_register()),
+    ));
+}
+
+#[test]
+fn byte_code_for_foreign_procedure_call_with_sixteen_mixed_size_args_passes_rest_on_stack() {
+    let irs = compile_source_and_get_intemediate_representation("Library :: #foreign_system_library \"library\";
+sixteen :: (a: s8, b: s16, c: s32, d: s64, e: u8, f: u16, g: u32, h: float, i: s8, j: s16, k: s32, l: s64, m: u8, n: u16, o: u32, p: *void) #foreign Library;
+
+main :: () {
+    a: s8 = 1; b: s16 = 2; c: s32 = 3; d: s64 = 4; e: u8 = 5; f: u16 = 6; g: u32 = 7; h: float = 8.0;
+    i: s8 = 9; j: s16 = 10; k: s32 = 11; l: s64 = 12; m: u8 = 13; n: u16 = 14; o: u32 = 15; p: *void = null;
+    sixteen(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p);
+}"
+    );
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+
+    assert_eq!(main_body_ir.byte_code[20..51], vec!(
+        // shadow space and twelve stack args no longer fit a signed byte
+        sub_value_from_reg_instruction(instruction_value_32(128), stack_pointer_register()),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_8(), false, base_pointer_register(), negative_address_offset(1), call_arg_register(0)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_16(), false, base_pointer_register(), negative_address_offset(3), call_arg_register(1)),
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(7), call_arg_register(2)),
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(15), call_arg_register(3)),
+        // every arg after the fourth goes through rax to the next stack slot whatever its size
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_8(), false, base_pointer_register(), negative_address_offset(16), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(32)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_16(), false, base_pointer_register(), negative_address_offset(18), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(40)),
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(22), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(48)),
+        // floats on the stack are moved as their bits
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(26), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(56)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_8(), false, base_pointer_register(), negative_address_offset(27), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(64)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_16(), false, base_pointer_register(), negative_address_offset(29), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(72)),
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(33), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(80)),
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(41), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(88)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_8(), false, base_pointer_register(), negative_address_offset(42), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(96)),
+        move_reg_plus_offset_to_reg_extended_instruction(register_size_16(), false, base_pointer_register(), negative_address_offset(44), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(104)),
+        move_reg_plus_offset_to_reg_instruction(register_size_32(), base_pointer_register(), negative_address_offset(48), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(112)),
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), negative_address_offset(56), standard_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), standard_register(0), stack_pointer_register(), address_offset(120)),
+        call_to_symbol_instruction(symbol_index(1)),
+        add_value_to_reg_instruction(instruction_value_32(128), stack_pointer_register()),
+    ));
+}
+
+#[test]
+fn byte_code_for_procedure_with_sixteen_args_reads_last_arg_from_caller_frame() {
+    let irs = compile_source_and_get_intemediate_representation("last :: (a: s8, b: s16, c: s32, d: s64, e: u8, f: u16, g: u32, h: float, i: s8, j: s16, k: s32, l: s64, m: u8, n: u16, o: u32, p: int) -> int {
+    return p;
+}"
+    );
+
+    let procedure_body_ir = get_first_ir_with_byte_code_named(&irs, "last");
+
+    assert_eq!(procedure_body_ir.byte_code[2..7], vec!(
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), address_offset(16)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(1), base_pointer_register(), address_offset(24)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(2), base_pointer_register(), address_offset(32)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(3), base_pointer_register(), address_offset(40)),
+        // sixteenth arg is past the return address, saved base pointer, shadow space and eleven stack args
+        move_reg_plus_offset_to_reg_instruction(register_size_64(), base_pointer_register(), address_offset(136), call_return_arg_register(0)),
+    ));
+}
@@ -14,4 +14,5 @@ fn byte_code_for_foreign_procedure_header_with_args_generates_correctly() {
     assert_eq!(foreign_library_const_ir.data.items.len(), 0);
     assert_eq!(foreign_library_const_ir.byte_code.len(), 0);
     assert_eq!(foreign_library_const_ir.foreign_libraries.len(), 0);
+    assert_eq!(foreign_library_const_ir.foreign_library_identifier, Some(string("Kernel32")));
 }
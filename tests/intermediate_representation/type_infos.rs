@@ -0,0 +1,162 @@
+use rust_hephaestus::*;
+
+#[test]
+fn byte_code_for_type_info_assignment_references_type_info_table() {
+    let irs = compile_source_and_get_intemediate_representation("Header :: struct {
+    id: u32;
+    size: u64;
+}
+
+main :: () {
+    info := type_info(Header);
+}"
+    );
+
+    let main_body_ir = get_first_ir_with_byte_code_named(&irs, "main");
+
+    assert_eq!(main_body_ir.symbols, vec!(
+        external_code_label(string("main"), 0),
+        foreign_external(type_info_symbol_name(&main_body_ir.type_infos[0]))
+    ));
+    assert_eq!(main_body_ir.data.items.len(), 0);
+    assert_eq!(main_body_ir.byte_code, vec!(
+        //prologue
+        push_reg_instruction(register_size_64(), base_pointer_register()),
+        move_reg_to_reg_instruction(register_size_64(), stack_pointer_register(), base_pointer_register()),
+
+        //reserve space for 1 local assignments
+        sub_value_from_reg_instruction(instruction_value_8(8), stack_pointer_register()),
+        //store info
+        load_symbol_address_to_reg(register_size_64(), symbol_index(1), call_arg_register(0)),
+        move_reg_to_reg_plus_offset_instruction(register_size_64(), call_arg_register(0), base_pointer_register(), negative_address_offset(8)),
+
+        //epilogue
+        move_reg_to_reg_instruction(register_size_64(), base_pointer_register(), stack_pointer_register()),
+        pop_reg_instruction(register_size_64(), base_pointer_register()),
+
+        ret_instruction()
+    ));
+    assert_eq!(main_body_ir.type_infos.len(), 1);
+    assert_eq!(main_body_ir.type_infos[0].name, "Header");
+}
+
+#[test]
+fn type_info_table_for_struct_lays_out_record_members_and_names() {
+    let header_type = create_shareable(create_type(
+        user_defined_runtime_type_id(create_compilation_unit_id()),
+        string("Header"),
+        struct_type_item(vec!(
+            runtime_type_member(string("id"), create_shareable(unsigned_int_32_runtime_type())),
+            runtime_type_member(string("size"), create_shareable(unsigned_int_64_runtime_type()))
+        )),
+        resolved_type_size(16)
+    ));
+
+    let ir = build_type_info_intermediate_representation(create_compilation_unit_id(), string("test.hep"), &header_type);
+
+    assert!(ir.top_level_symbol.starts_with("__type_info_Header_"));
+    assert_eq!(ir.byte_code, vec!());
+    assert_eq!(ir.symbols, vec!(
+        foreign_external(string("__type_info_u32")),
+        foreign_external(string("__type_info_u64")),
+        external_data_section_item(type_info_symbol_name(&header_type), 0)
+    ));
+    assert_eq!(ir.data.items, vec!(
+        //record
+        quad_word_data_item(TYPE_INFO_KIND_STRUCT),
+        quad_word_data_item(6),
+        pointer_data_item(120),
+        quad_word_data_item(16),
+        quad_word_data_item(2),
+        pointer_data_item(56),
+        quad_word_data_item(0),
+        //id member
+        quad_word_data_item(2),
+        pointer_data_item(126),
+        quad_word_data_item(0),
+        symbol_pointer_data_item(symbol_index(0)),
+        //size member
+        quad_word_data_item(4),
+        pointer_data_item(128),
+        quad_word_data_item(8),
+        symbol_pointer_data_item(symbol_index(1)),
+        //names
+        string_data_item(to_byte_string("Header")),
+        string_data_item(to_byte_string("id")),
+        string_data_item(to_byte_string("size"))
+    ));
+    assert_eq!(
+        ir.type_infos.iter().map(|type_info| type_info.name.clone()).collect::<Vec<String>>(),
+        vec!(string("u32"), string("u64"))
+    );
+}
+
+#[test]
+fn type_info_table_for_pointer_type_has_no_members() {
+    let pointer_type = create_shareable(unsigned_int_8_pointer_runtime_type());
+
+    let ir = build_type_info_intermediate_representation(create_compilation_unit_id(), string("test.hep"), &pointer_type);
+
+    assert_eq!(ir.top_level_symbol, "__type_info_ptr_u8");
+    assert_eq!(ir.symbols, vec!(external_data_section_item(string("__type_info_ptr_u8"), 0)));
+    assert_eq!(ir.data.items, vec!(
+        quad_word_data_item(TYPE_INFO_KIND_POINTER),
+        quad_word_data_item(3),
+        pointer_data_item(56),
+        quad_word_data_item(8),
+        quad_word_data_item(0),
+        quad_word_data_item(0),
+        quad_word_data_item(0),
+        string_data_item(to_byte_string("*u8"))
+    ));
+    assert_eq!(ir.type_infos.len(), 0);
+}
+
+#[test]
+fn type_info_table_for_signed_int_type_is_signed() {
+    let int_type = create_shareable(signed_int_32_runtime_type());
+
+    let ir = build_type_info_intermediate_representation(create_compilation_unit_id(), string("test.hep"), &int_type);
+
+    assert_eq!(ir.top_level_symbol, "__type_info_s32");
+    assert_eq!(ir.data.items, vec!(
+        quad_word_data_item(TYPE_INFO_KIND_INT),
+        quad_word_data_item(3),
+        pointer_data_item(56),
+        quad_word_data_item(4),
+        quad_word_data_item(0),
+        quad_word_data_item(0),
+        quad_word_data_item(1),
+        string_data_item(to_byte_string("s32"))
+    ));
+}
+
+#[test]
+fn type_info_tables_for_module_and_program_types_of_same_name_are_kept_apart() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "M :: #import \"M\";
+Point :: struct {
+    x: s32;
+}
+
+main :: () {
+    info := type_info(Point);
+    M.describe();
+}");
+    add_mock_file(&mut reader, "modules/M/module.hep", "Point :: struct {
+    x: s64;
+    y: s64;
+}
+
+describe :: () {
+    info := type_info(Point);
+}");
+
+    let irs = compile_file_for_target_with_module_paths_and_get_intemediate_representation("test.hep", vec!(string("modules")), windows_target(), reader);
+
+    let program_info = &get_first_ir_with_byte_code_named(&irs, "main").type_infos[0];
+    let module_info = &get_first_ir_with_byte_code_named(&irs, "M::describe").type_infos[0];
+
+    assert_eq!(program_info.name, module_info.name);
+    assert_ne!(type_info_symbol_name(program_info), type_info_symbol_name(module_info));
+}
@@ -0,0 +1,54 @@
+use rust_hephaestus::*;
+
+#[test]
+fn assert_of_true_comparison_reports_no_errors() {
+    let errors = compile_source_and_get_errors("HEADER_SIZE :: 16;
+#assert HEADER_SIZE == 16 \"header layout changed\";");
+
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn assert_of_false_comparison_reports_message_and_operands() {
+    let errors = compile_source_and_get_errors("HEADER_SIZE :: 24;
+#assert HEADER_SIZE == 16 \"header layout changed\";");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            interpretation_error(assertion_failed_error(string("header layout changed"), vec!(24, 16))),
+            position(19, 2, 1)
+        )
+    );
+}
+
+#[test]
+fn assert_evaluates_arithmetic_before_comparison() {
+    let errors = compile_source_and_get_errors("#assert A + B * 2 <= 8 \"too big\";
+A :: 4;
+B :: 3;");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            interpretation_error(assertion_failed_error(string("too big"), vec!(10, 8))),
+            position(0, 1, 1)
+        )
+    );
+}
+
+#[test]
+fn assert_without_message_of_false_comparison_reports_operands() {
+    let errors = compile_source_and_get_errors("#assert 1 != 1;");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            interpretation_error(assertion_failed_error(string(""), vec!(1, 1))),
+            position(0, 1, 1)
+        )
+    );
+}
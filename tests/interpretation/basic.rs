@@ -0,0 +1,131 @@
+use rust_hephaestus::*;
+
+fn run_with_basic_and_get_result(source: &str) -> Vec<ByteCodeDataItem> {
+    let (file_path, mut reader) = add_source_to_test_file_system(source);
+    add_bundled_module_to_mock_file_reader(&mut reader, "Basic");
+
+    let irs = compile_file_with_module_paths_and_get_executed_intemediate_representation(file_path, vec!(), reader);
+
+    assert_eq!(irs.len(), 1);
+    irs[0].data.items.clone()
+}
+
+fn run_with_basic_and_get_standard_output(source: &str) -> String {
+    let (file_path, mut reader) = add_source_to_test_file_system(source);
+    add_bundled_module_to_mock_file_reader(&mut reader, "Basic");
+
+    compile_file_with_module_paths_and_get_standard_output(file_path, vec!(), reader)
+}
+
+#[test]
+fn run_of_to_c_string_copies_and_terminates_string() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    c_string := to_c_string(\"hello\");
+    empty : s64 = 0;
+    length := _scprintf(c_string, empty);
+    return length;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(5)));
+}
+
+#[test]
+fn run_of_compare_strings_of_equal_strings_is_zero() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    result := compare_strings(\"hello\", \"hello\");
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(0)));
+}
+
+#[test]
+fn run_of_compare_strings_orders_by_bytes() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    result := compare_strings(\"abd\", \"abc\");
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(1)));
+}
+
+#[test]
+fn run_of_compare_strings_orders_prefix_first() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    result := compare_strings(\"ab\", \"abc\");
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(-1i64 as u64)));
+}
+
+#[test]
+fn run_of_concatenate_joins_strings() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    joined := concatenate(\"hello\", \" world\");
+    result := compare_strings(joined, \"hello world\");
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(0)));
+}
+
+#[test]
+fn run_of_sprint_formats_value() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    formatted := sprint(\"answer % at %%\", 42);
+    expected := to_c_string(\"answer 42 at %\");
+    result := strcmp(formatted, expected);
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(0)));
+}
+
+#[test]
+fn run_of_memset_and_memcpy_fill_allocated_memory() {
+    let result = run_with_basic_and_get_result("#import \"Basic\";
+check :: () -> int {
+    filled := alloc(4);
+    value : s32 = 65;
+    count : s64 = 3;
+    memset(filled, value, count);
+    terminate_c_string(filled, count);
+    copied := alloc(4);
+    size : s64 = 4;
+    memcpy(copied, filled, size);
+    copied_c_string := cast(*u8) copied;
+    expected := to_c_string(\"AAA\");
+    result := strcmp(copied_c_string, expected);
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(0)));
+}
+
+#[test]
+fn run_of_print_writes_formatted_value() {
+    let output = run_with_basic_and_get_standard_output("#import \"Basic\";
+check :: () -> int {
+    count : u8 = 3;
+    print(\"basic print % of % at %%%\\n\", count, \"args\", -42);
+    write_string(\"basic write_string\\n\");
+    return 1;
+}
+#run check()");
+
+    assert_eq!(output, "basic print 3 of args at %-42\nbasic write_string\n");
+}
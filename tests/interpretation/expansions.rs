@@ -0,0 +1,79 @@
+use rust_hephaestus::*;
+
+#[test]
+fn expand_macro_can_assign_caller_local() {
+    let irs = compile_source_and_get_executed_intemediate_representation("set :: (value: int) #expand {
+    `x = value;
+}
+
+main :: () -> int {
+    x := 0;
+    set(42);
+    return x;
+}
+
+#run main()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn expand_macro_can_return_from_caller() {
+    let irs = compile_source_and_get_executed_intemediate_representation("return_value :: (value: int) #expand {
+    return value;
+}
+
+main :: () -> int {
+    return_value(42);
+    return 0;
+}
+
+#run main()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn expand_macro_locals_do_not_clash_with_caller_locals() {
+    let irs = compile_source_and_get_executed_intemediate_representation("set :: (value: int) #expand {
+    x := value;
+    `y = x;
+}
+
+main :: () -> int {
+    x := 42;
+    y := 0;
+    set(1);
+    return x;
+}
+
+#run main()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn expand_macro_can_expand_macro_using_exposed_caller_local() {
+    let irs = compile_source_and_get_executed_intemediate_representation("set :: (value: int) #expand {
+    `x = value;
+}
+
+set_again :: (value: int) #expand {
+    `x = 0;
+    set(value);
+}
+
+main :: () -> int {
+    x := 1;
+    set_again(42);
+    return x;
+}
+
+#run main()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
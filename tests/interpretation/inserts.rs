@@ -0,0 +1,159 @@
+use rust_hephaestus::*;
+
+#[test]
+fn insert_of_code_block_makes_procedure_available_to_run() {
+    let irs = compile_source_and_get_executed_intemediate_representation("#insert #code {
+    answer :: () -> int { return 42; }
+}
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn insert_of_code_constant_makes_procedure_available_to_run() {
+    let irs = compile_source_and_get_executed_intemediate_representation("answer_code :: #code {
+    answer :: () -> int { return 42; }
+}
+
+#insert answer_code;
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn insert_of_string_literal_makes_procedure_available_to_run() {
+    let irs = compile_source_and_get_executed_intemediate_representation("#insert \"answer :: () -> int { return 42; }\";
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn insert_of_procedure_call_parses_and_types_generated_source() {
+    let irs = compile_source_and_get_executed_intemediate_representation("generate :: () -> string {
+    return \"answer :: () -> int { return 42; }\";
+}
+
+#insert generate();
+
+#run answer()");
+
+    assert_eq!(irs.len(), 2);
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn insert_of_unknown_code_constant_reports_error() {
+    let errors = compile_source_and_get_errors("#insert missing;");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(type_cannot_be_found_error()),
+            position(8, 1, 9)
+        )
+    );
+}
+
+#[test]
+fn insert_of_string_literal_in_procedure_body_adds_statements() {
+    let irs = compile_source_and_get_executed_intemediate_representation("answer :: () -> int {
+    x := 40;
+    #insert \"x = x + 2;\";
+    return x;
+}
+
+#run answer()");
+
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn insert_of_code_constant_in_procedure_body_adds_statements() {
+    let irs = compile_source_and_get_executed_intemediate_representation("add_two :: #code {
+    x = x + 2;
+}
+
+answer :: () -> int {
+    x := 38;
+    #insert add_two;
+    #insert add_two;
+    return x;
+}
+
+#run answer()");
+
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn insert_of_code_constant_in_procedure_body_declares_locals() {
+    let irs = compile_source_and_get_executed_intemediate_representation("declare_x :: #code {
+    x : s64 = 40;
+}
+
+answer :: () -> int {
+    #insert declare_x;
+    y := x + 2;
+    return y;
+}
+
+#run answer()");
+
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn insert_of_procedure_call_in_procedure_body_adds_generated_statements() {
+    let irs = compile_source_and_get_executed_intemediate_representation("generate :: () -> string {
+    return \"x = x * 2;\";
+}
+
+answer :: () -> int {
+    x := 21;
+    #insert generate();
+    return x;
+}
+
+#run answer()");
+
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn insert_of_string_literal_as_value_is_typed_as_expression() {
+    let irs = compile_source_and_get_executed_intemediate_representation("answer :: () -> int {
+    x := 40;
+    y := #insert \"x + 2\";
+    return y;
+}
+
+#run answer()");
+
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn insert_of_constant_that_is_not_code_reports_error() {
+    let errors = compile_source_and_get_errors("answer :: 42;
+
+#insert answer;");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(not_code_error()),
+            position(23, 3, 9)
+        )
+    );
+}
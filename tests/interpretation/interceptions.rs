@@ -0,0 +1,213 @@
+use rust_hephaestus::*;
+
+const METAPROGRAM: &str = "#import \"Compiler\";
+";
+
+fn add_metaprogram_to_test_file_system(source: &str) -> (&str, MockFileReader) {
+    let (file_path, mut reader) = add_source_to_test_file_system(source);
+    add_bundled_module_to_mock_file_reader(&mut reader, "Compiler");
+    (file_path, reader)
+}
+
+fn run_metaprogram_and_get_executed_intemediate_representation(source: &str) -> IntermediateRepresentations {
+    let (file_path, reader) = add_metaprogram_to_test_file_system(source);
+    compile_file_with_module_paths_and_get_executed_intemediate_representation(file_path, vec!(), reader)
+}
+
+fn compile_metaprogram_and_get_intercepted_messages(source: &str) -> Vec<InterceptedMessage> {
+    let (file_path, reader) = add_metaprogram_to_test_file_system(source);
+    compile_file_and_get_intercepted_messages(file_path, reader)
+}
+
+fn compile_metaprogram_and_get_errors(source: &str) -> Vec<CompilationErrors> {
+    let (file_path, reader) = add_metaprogram_to_test_file_system(source);
+    compile_file_and_get_errors(file_path, reader)
+}
+
+#[test]
+fn complete_message_handler_can_add_build_string() {
+    let irs = run_metaprogram_and_get_executed_intemediate_representation(&format!("{}
+on_complete :: (message: *Compiler_Message) {{
+    add_build_string(\"answer :: () -> int {{ return 42; }} #run answer()\");
+}}
+
+build :: () -> int {{
+    compiler_intercept(MESSAGE_KIND_COMPLETE, on_complete);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    assert_eq!(find_baked_values(&irs), vec!(vec!(quad_word_data_item(0)), vec!(quad_word_data_item(42))));
+}
+
+#[test]
+fn file_parsed_messages_are_delivered_to_handler_registered_after_parsing() {
+    let messages = compile_metaprogram_and_get_intercepted_messages(&format!("{}
+on_file_parsed :: (message: *Compiler_Message) {{
+}}
+
+build :: () -> int {{
+    compiler_intercept(MESSAGE_KIND_FILE_PARSED, on_file_parsed);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    assert_eq!(messages.len(), 3);
+    assert!(messages.iter().all(|message| message.kind == InterceptedMessageKind::FileParsed));
+    assert_eq!(messages[0].name, PRELOAD_FILE_NAME);
+    assert_eq!(messages[1].name, "test.hep");
+    assert_eq!(messages[1].count, 6);
+    assert_eq!(messages[2].name, module_file_path(&get_bundled_modules_path(), "Compiler"));
+}
+
+#[test]
+fn unit_typed_messages_carry_unit_name_node_kind_and_type_info() {
+    let messages = compile_metaprogram_and_get_intercepted_messages(&format!("{}
+Point :: struct {{
+    x: s64;
+    y: s64;
+}}
+
+on_unit_typed :: (message: *Compiler_Message) {{
+}}
+
+build :: () -> int {{
+    compiler_intercept(MESSAGE_KIND_UNIT_TYPED, on_unit_typed);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    let point = messages
+        .iter()
+        .find(|message| message.name == "Point")
+        .unwrap();
+
+    assert_eq!(point.kind, InterceptedMessageKind::UnitTyped);
+    assert_eq!(point.node_kind, INTERCEPTED_NODE_KIND_STRUCT);
+    assert_eq!(point.count, 1);
+    assert_eq!(point.type_info.as_ref().map(|info| info.name.clone()), Some(string("Point")));
+}
+
+#[test]
+fn units_from_build_strings_are_not_delivered_back_to_handlers() {
+    let messages = compile_metaprogram_and_get_intercepted_messages(&format!("{}
+on_code_generated :: (message: *Compiler_Message) {{
+    add_build_string(\"#run 1\");
+}}
+
+build :: () -> int {{
+    compiler_intercept(MESSAGE_KIND_CODE_GENERATED, on_code_generated);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    assert!(!messages.is_empty());
+    assert!(messages.iter().all(|message| message.kind == InterceptedMessageKind::CodeGenerated));
+    assert!(messages.iter().all(|message| message.name != run_symbol_name(position(0, 1, 1))));
+}
+
+#[test]
+fn handler_reads_typed_message_fields() {
+    let irs = run_metaprogram_and_get_executed_intemediate_representation(&format!("{}
+Point :: struct {{
+    x: s64;
+    y: s64;
+    z: s64;
+    w: s64;
+    v: s64;
+}}
+
+on_unit_typed :: (message: *Compiler_Message) {{
+    node_kind := message.node_kind;
+    if node_kind == NODE_KIND_STRUCT {{
+        info := message.type_info;
+        size := info.size;
+        if size == 40 {{
+            add_build_string(\"#run 40\");
+        }}
+    }}
+}}
+
+build :: () -> int {{
+    compiler_intercept(MESSAGE_KIND_UNIT_TYPED, on_unit_typed);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    let baked_values = find_baked_values(&irs);
+    assert_eq!(baked_values.len(), 2);
+    assert!(baked_values.contains(&vec!(quad_word_data_item(0))));
+    assert!(baked_values.contains(&vec!(quad_word_data_item(40))));
+}
+
+#[test]
+fn intercept_with_unknown_handler_reports_error() {
+    let errors = compile_metaprogram_and_get_errors(&format!("{}
+build :: () -> int {{
+    compiler_intercept(MESSAGE_KIND_COMPLETE, missing);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    let items: Vec<CompilationError> = errors
+        .into_iter()
+        .flat_map(|errors| errors.items)
+        .collect();
+
+    assert!(items.contains(
+        &compilation_error(
+            type_inference_error(type_cannot_be_found_error()),
+            position(46, 4, 5)
+        )
+    ));
+}
+
+#[test]
+fn intercept_with_handler_that_is_not_a_procedure_reports_error() {
+    let errors = compile_metaprogram_and_get_errors(&format!("{}
+build :: () -> int {{
+    handler : *void = null;
+    compiler_intercept(MESSAGE_KIND_COMPLETE, handler);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            interpretation_error(not_a_procedure_error(0)),
+            position(143, 9, 1)
+        )
+    );
+}
+
+#[test]
+fn intercept_with_unknown_message_kind_reports_error() {
+    let errors = compile_metaprogram_and_get_errors(&format!("{}
+on_complete :: (message: *Compiler_Message) {{
+}}
+
+build :: () -> int {{
+    compiler_intercept(9, on_complete);
+    return 0;
+}}
+
+#run build()", METAPROGRAM));
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            interpretation_error(unknown_intercepted_message_kind_error(9)),
+            position(148, 11, 1)
+        )
+    );
+}
@@ -0,0 +1,175 @@
+use rust_hephaestus::*;
+
+fn add_source_and_linux_runtime_to_test_file_system(source: &str) -> (&str, MockFileReader) {
+    let (file_path, mut reader) = add_source_to_test_file_system(source);
+    add_target_runtime_to_mock_file_reader(&mut reader, linux_target());
+    (file_path, reader)
+}
+
+fn run_for_linux_and_get_result(source: &str) -> Vec<ByteCodeDataItem> {
+    let (file_path, reader) = add_source_and_linux_runtime_to_test_file_system(source);
+
+    let irs = compile_file_for_target_and_get_executed_intemediate_representation(file_path, linux_target(), reader);
+
+    assert_eq!(irs.len(), 1);
+    irs[0].data.items.clone()
+}
+
+#[test]
+fn linux_target_compiles_runtime_with_program() {
+    let (file_path, reader) = add_source_and_linux_runtime_to_test_file_system("main :: () -> int {
+    return 0;
+}");
+
+    let irs = compile_file_for_target_and_get_intemediate_representation(file_path, linux_target(), reader);
+
+    for name in ["_start", "read", "write", "open", "close", "mmap", "exit"] {
+        assert!(irs.iter().any(|ir| ir.top_level_symbol == name && !ir.byte_code.is_empty()), "{} not compiled", name);
+    }
+}
+
+#[test]
+fn linux_runtime_is_found_in_bundled_modules_path() {
+    assert_eq!(get_target_runtime_file_name("/opt/hephaestus/modules", linux_target()), Some(string("/opt/hephaestus/modules/Runtime_Support/linux.hep")));
+    assert_eq!(get_target_runtime_file_name("/opt/hephaestus/modules", windows_target()), None);
+}
+
+#[test]
+fn linux_entry_point_is_chosen_by_whether_main_returns_status() {
+    assert_eq!(get_target_entry_file_name("/opt/hephaestus/modules", linux_target(), true), Some(string("/opt/hephaestus/modules/Runtime_Support/linux_start.hep")));
+    assert_eq!(get_target_entry_file_name("/opt/hephaestus/modules", linux_target(), false), Some(string("/opt/hephaestus/modules/Runtime_Support/linux_start_void.hep")));
+    assert_eq!(get_target_entry_file_name("/opt/hephaestus/modules", windows_target(), true), None);
+}
+
+#[test]
+fn windows_target_does_not_compile_linux_runtime() {
+    let (file_path, reader) = add_source_and_linux_runtime_to_test_file_system("main :: () -> int {
+    return 0;
+}");
+
+    let irs = compile_file_for_target_and_get_intemediate_representation(file_path, windows_target(), reader);
+
+    assert!(irs.iter().all(|ir| ir.top_level_symbol != "_start"));
+}
+
+#[test]
+fn run_of_procedure_call_passes_args_past_six_on_the_stack() {
+    let result = run_for_linux_and_get_result("main :: () -> int {
+    return 0;
+}
+eighth :: (a: int, b: int, c: int, d: int, e: int, f: int, g: int, h: int) -> int {
+    return h;
+}
+#run eighth(1, 2, 3, 4, 5, 6, 7, 8)");
+
+    assert_eq!(result, vec!(quad_word_data_item(8)));
+}
+
+#[test]
+fn run_of_start_exits_with_return_value_of_main() {
+    let result = run_for_linux_and_get_result("main :: () -> int {
+    return 42;
+}
+check :: () -> int {
+    _start();
+    return 0;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn run_of_start_exits_with_zero_when_main_returns_nothing() {
+    let result = run_for_linux_and_get_result("main :: () {
+}
+check :: () -> int {
+    _start();
+    return 1;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(0)));
+}
+
+#[test]
+fn run_of_write_to_standard_output_returns_count_written() {
+    let result = run_for_linux_and_get_result("main :: () -> int {
+    return 0;
+}
+write_text :: (text: string) -> int {
+    buffer := cast(*void) text.data;
+    count := text.count;
+    fd : s32 = 1;
+    written := write(fd, buffer, count);
+    return written;
+}
+check :: () -> int {
+    written := write_text(\"linux write\\n\");
+    return written;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(12)));
+}
+
+#[test]
+fn run_of_close_of_unopened_descriptor_returns_bad_file_error() {
+    let result = run_for_linux_and_get_result("main :: () -> int {
+    return 0;
+}
+check :: () -> s32 {
+    fd : s32 = 42;
+    result := close(fd);
+    return result;
+}
+#run check()");
+
+    assert_eq!(result, vec!(quad_word_data_item(-9i32 as u32 as u64)));
+}
+
+#[test]
+fn run_of_open_write_read_and_close_round_trips_file() {
+    let path = std::env::temp_dir().join("hephaestus_linux_runtime_round_trip.txt");
+    let _ = std::fs::remove_file(&path);
+
+    let result = run_for_linux_and_get_result(&format!("main :: () -> int {{
+    return 0;
+}}
+write_file :: (path: string, text: string) {{
+    c_path := path.data;
+    flags : s32 = 577;
+    mode : s32 = 420;
+    fd := open(c_path, flags, mode);
+    buffer := cast(*void) text.data;
+    count := text.count;
+    write(fd, buffer, count);
+    close(fd);
+}}
+read_file :: (path: string) -> int {{
+    c_path := path.data;
+    flags : s32 = 0;
+    mode : s32 = 0;
+    fd := open(c_path, flags, mode);
+    address : *void = null;
+    length : s64 = 4096;
+    protection : s32 = 3;
+    map_flags : s32 = 34;
+    no_fd : s32 = -1;
+    offset : s64 = 0;
+    memory := mmap(address, length, protection, map_flags, no_fd, offset);
+    read_count := read(fd, memory, length);
+    close(fd);
+    return read_count;
+}}
+check :: () -> int {{
+    write_file(\"{0}\\0\", \"round trip\");
+    read_count := read_file(\"{0}\\0\");
+    return read_count;
+}}
+#run check()", path.display()));
+
+    assert_eq!(result, vec!(quad_word_data_item(10)));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "round trip");
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,7 @@
+mod runs;
+mod basic;
+mod asserts;
+mod inserts;
+mod expansions;
+mod interceptions;
+mod linux;
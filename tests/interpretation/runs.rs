@@ -0,0 +1,626 @@
+use rust_hephaestus::*;
+
+#[test]
+fn run_of_literal_bakes_value_into_data_section() {
+    let irs = compile_source_and_get_executed_intemediate_representation("#run 42");
+
+    assert_eq!(irs.len(), 1);
+    let ir = &irs[0];
+    assert_eq!(ir.top_level_symbol, "__run_0");
+    assert_eq!(ir.byte_code, vec!());
+    assert_eq!(ir.symbols, vec!(data_section_item(string("__run_0"), 0)));
+    assert_eq!(ir.data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn run_of_procedure_call_bakes_return_value_into_data_section() {
+    let irs = compile_source_and_get_executed_intemediate_representation("answer :: () -> int {
+    return 42;
+}
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].byte_code, vec!());
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn run_of_procedure_call_with_args_bakes_return_value_into_data_section() {
+    let irs = compile_source_and_get_executed_intemediate_representation("#run second(1, 2)
+
+second :: (a: int, b: int) -> int {
+    return b;
+}");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(2)));
+}
+
+#[test]
+fn run_of_procedure_call_with_stack_args_bakes_return_value_into_data_section() {
+    let irs = compile_source_and_get_executed_intemediate_representation("#run sixth(1, 2, 3, 4, 5, 6)
+
+sixth :: (a: int, b: int, c: int, d: int, e: int, f: int) -> int {
+    return f;
+}");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(6)));
+}
+
+#[test]
+fn run_of_procedure_call_through_nested_calls_bakes_return_value_into_data_section() {
+    let irs = compile_source_and_get_executed_intemediate_representation("outer :: () -> int {
+    x := inner(7);
+    return x;
+}
+
+inner :: (a: int) -> int {
+    return a;
+}
+
+#run outer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(7)));
+}
+
+#[test]
+fn run_as_constant_initialiser_bakes_return_value_into_constant_symbol() {
+    let irs = compile_source_and_get_executed_intemediate_representation("answer :: () -> int {
+    return 42;
+}
+
+ANSWER :: #run answer();");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].top_level_symbol, "ANSWER");
+    assert_eq!(irs[0].byte_code, vec!());
+    assert_eq!(irs[0].symbols, vec!(absolute_external_64(string("ANSWER"), 42)));
+}
+
+#[test]
+fn run_as_constant_initialiser_of_smaller_int_bakes_value_of_its_size() {
+    let irs = compile_source_and_get_executed_intemediate_representation("answer :: () -> s32 {
+    value: s32 = -3;
+    return value;
+}
+
+ANSWER :: #run answer();");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].symbols, vec!(absolute_external_32(string("ANSWER"), -3i32 as u32)));
+}
+
+#[test]
+fn run_of_foreign_procedure_not_available_at_compile_time_reports_error() {
+    let errors = compile_source_and_get_errors("Kernel32 :: #foreign_system_library \"kernel32\";
+GetTickCount :: () -> u32 #foreign Kernel32;
+
+#run GetTickCount()");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            interpretation_error(symbol_not_found_error(string("GetTickCount"))),
+            position(94, 4, 1)
+        )
+    );
+}
+
+#[test]
+fn run_of_procedure_using_type_info_resolves_emitted_tables() {
+    let irs = compile_source_and_get_executed_intemediate_representation("Header :: struct {
+    id: u32;
+    size: u64;
+}
+
+answer :: () -> int {
+    info := type_info(Header);
+    return 42;
+}
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn run_of_procedure_passing_any_arg_resolves_boxed_type_info() {
+    let irs = compile_source_and_get_executed_intemediate_representation("accept :: (value: Any) -> int {
+    return 3;
+}
+
+answer :: () -> int {
+    x := accept(42);
+    return x;
+}
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(3)));
+}
+
+#[test]
+fn run_of_procedure_with_float_arithmetic_and_casts_bakes_result() {
+    let irs = compile_source_and_get_executed_intemediate_representation("area :: () -> int {
+    w: float64 = 2.5;
+    h: float64 = 4.0;
+    a := w * h;
+    b := a - 0.5;
+    r := cast(s64) b;
+    return r;
+}
+
+#run area()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(9)));
+}
+
+#[test]
+fn run_of_procedure_with_int_widening_and_truncation_bakes_result() {
+    let irs = compile_source_and_get_executed_intemediate_representation("convert :: () -> int {
+    big: s64 = 300;
+    small := cast(u8) big;
+    wide: s64 = small;
+    total: s32 = cast(s32) wide;
+    result: s64 = total;
+    return result;
+}
+
+#run convert()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(44)));
+}
+
+#[test]
+fn run_of_procedure_returning_narrow_signed_local_sign_extends_result() {
+    let irs = compile_source_and_get_executed_intemediate_representation("convert :: () -> int {
+    negative: s8 = -2;
+    return negative;
+}
+
+#run convert()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(-2i64 as u64)));
+}
+
+#[test]
+fn run_of_procedure_with_checked_cast_in_range_bakes_result() {
+    let irs = compile_source_and_get_executed_intemediate_representation("convert :: () -> int {
+    big: s64 = 300;
+    small := cast(u8) big;
+    checked := cast,checked(u8) small;
+    wide: s64 = checked;
+    return wide;
+}
+
+#run convert()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(44)));
+}
+
+#[test]
+fn run_of_procedure_with_checked_cast_out_of_range_reports_trap() {
+    let errors = compile_source_and_get_errors("convert :: () -> int {
+    big: s64 = 300;
+    small := cast,checked(u8) big;
+    return big;
+}
+
+#run convert()");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            interpretation_error(trap_error()),
+            position(97, 7, 1)
+        )
+    );
+}
+
+#[test]
+fn run_of_procedure_with_checked_cast_of_negative_to_unsigned_reports_trap() {
+    let errors = compile_source_and_get_errors("convert :: () -> int {
+    negative: s32 = -1;
+    wide := cast,checked(u64) negative;
+    return 0;
+}
+
+#run convert()");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            interpretation_error(trap_error()),
+            position(104, 7, 1)
+        )
+    );
+}
+
+#[test]
+fn run_of_procedure_reading_zero_initialised_global_bakes_zero() {
+    let irs = compile_source_and_get_executed_intemediate_representation("hits: s64;
+
+read :: () -> int {
+    return hits;
+}
+
+#run read()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(0)));
+}
+
+#[test]
+fn run_of_procedure_writing_globals_bakes_value_read_back() {
+    let irs = compile_source_and_get_executed_intemediate_representation("hits: s64;
+seed: s64 = 44;
+
+bump :: () -> int {
+    hits = seed;
+    seed = 1;
+    return hits;
+}
+
+#run bump()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(44)));
+}
+
+#[test]
+fn run_of_procedure_with_asm_block_bakes_value_written_to_local() {
+    let irs = compile_source_and_get_executed_intemediate_representation("calc :: () -> int {
+    x : s64 = 2;
+    #asm {
+        mov rax, x;
+        mov rcx, 40;
+        add rax, rcx;
+        mov x, rax;
+    }
+    return x;
+}
+
+#run calc()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn run_of_procedure_call_with_sixteen_mixed_size_args_reads_last_args_from_caller_frame() {
+    let irs = compile_source_and_get_executed_intemediate_representation("#run outer()
+
+outer :: () -> int {
+    a: s8 = 1;
+    b: s16 = 2;
+    c: s32 = 3;
+    d: s64 = 4;
+    e: u8 = 5;
+    f: u16 = 6;
+    g: u32 = 7;
+    h: float = 8.0;
+    i: s8 = 9;
+    j: s16 = 10;
+    k: s32 = 11;
+    l: s64 = 12;
+    m: u8 = 13;
+    n: u16 = 14;
+    o: u32 = 15;
+    p: int = 16;
+    x := sixteenth(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p);
+    return x;
+}
+
+sixteenth :: (a: s8, b: s16, c: s32, d: s64, e: u8, f: u16, g: u32, h: float, i: s8, j: s16, k: s32, l: s64, m: u8, n: u16, o: u32, p: int) -> int {
+    return p;
+}");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(16)));
+}
+
+
+#[test]
+fn run_of_integer_arithmetic_respects_precedence_and_associativity() {
+    let irs = compile_source_and_get_executed_intemediate_representation("calculate :: (a: int, b: int, c: int) -> int {
+    x := a + b * c - 2 - 1;
+    return x;
+}
+
+#run calculate(10, 3, 4)");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(19)));
+}
+
+#[test]
+fn run_of_signed_integer_arithmetic_wraps_below_zero() {
+    let irs = compile_source_and_get_executed_intemediate_representation("calculate :: (a: int, b: int) -> int {
+    x := a - b * 3;
+    return x;
+}
+
+#run calculate(2, 4)");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(-10i64 as u64)));
+}
+
+#[test]
+fn run_of_integer_comparison_of_signed_values_is_signed() {
+    let irs = compile_source_and_get_executed_intemediate_representation("less :: (a: int, b: int) -> bool {
+    x := a < b;
+    return x;
+}
+
+#run less(-1, 1)");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(1)));
+}
+
+#[test]
+fn run_of_integer_comparison_of_arithmetic_compares_results() {
+    let irs = compile_source_and_get_executed_intemediate_representation("greater :: (a: int, b: int) -> bool {
+    x := a * 2 > b + 5;
+    return x;
+}
+
+#run greater(4, 1)");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(1)));
+}
+
+#[test]
+fn run_of_procedure_with_while_loop_bakes_accumulated_value() {
+    let irs = compile_source_and_get_executed_intemediate_representation("sum_to :: (count: int) -> int {
+    total := 0;
+    index := 1;
+    while index <= count {
+        total = total + index;
+        index = index + 1;
+    }
+    return total;
+}
+
+#run sum_to(10)");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(55)));
+}
+
+#[test]
+fn run_of_procedure_with_if_else_chain_bakes_taken_branch() {
+    let irs = compile_source_and_get_executed_intemediate_representation("classify :: (value: int) -> int {
+    result := 0;
+    if value < 0 {
+        result = 1;
+    } else if value == 0 {
+        result = 2;
+    } else {
+        result = 3;
+    }
+    return result;
+}
+
+run_all :: () -> int {
+    negative := classify(-5);
+    zero := classify(0);
+    positive := classify(7);
+    result := negative * 100 + zero * 10 + positive;
+    return result;
+}
+
+#run run_all()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(123)));
+}
+
+#[test]
+fn run_of_procedure_returning_from_inside_loop_bakes_value() {
+    let irs = compile_source_and_get_executed_intemediate_representation("first_square_above :: (limit: int) -> int {
+    value := 1;
+    while value < limit {
+        square := value * value;
+        if square > limit {
+            return square;
+        }
+        value = value + 1;
+    }
+    return 0;
+}
+
+#run first_square_above(50)");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(64)));
+}
+
+#[test]
+fn run_of_indexing_through_pointer_reads_each_element() {
+    let irs = compile_source_and_get_executed_intemediate_representation("checksum :: (text: string) -> int {
+    bytes := text.data;
+    count := text.count;
+    total := 0;
+    i := 0;
+    while i < count {
+        byte := bytes[i];
+        value := cast(s64) byte;
+        total = total * 2 + value;
+        i = i + 1;
+    }
+    return total;
+}
+
+#run checksum(\"abc\")");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(683)));
+}
+
+#[test]
+fn run_of_indexing_through_pointer_counts_elements_of_the_type_pointed_to() {
+    let irs = compile_source_and_get_executed_intemediate_representation("second_pair :: (text: string) -> u16 {
+    pairs := cast(*u16) text.data;
+    pair := pairs[0 + 1];
+    return pair;
+}
+
+#run second_pair(\"abcd\")");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(25699)));
+}
+
+#[test]
+fn run_of_member_access_through_pointers_reads_boxed_type_info_and_value() {
+    let irs = compile_source_and_get_executed_intemediate_representation("describe :: (value: Any) -> int {
+    info := value.type;
+    kind := info.kind;
+    size := info.size;
+    values := cast(*s64) value.value_pointer;
+    boxed := values[0];
+    result := kind * 100 + size + boxed;
+    return result;
+}
+
+answer :: () -> int {
+    x := describe(42);
+    return x;
+}
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(150)));
+}
+
+#[test]
+fn run_of_variadic_args_passes_remaining_args_as_view_of_any() {
+    let irs = compile_source_and_get_executed_intemediate_representation("sum :: (first: int, ..args: Any) -> int {
+    count := args.count;
+    data := args.data;
+    second := data + 1;
+    info := second.type;
+    kind := info.kind;
+    values := cast(*s64) second.value_pointer;
+    value := values[0];
+    result := first + count * 10 + kind * 100 + value;
+    return result;
+}
+
+count_of :: (..args: Any) -> int {
+    count := args.count;
+    return count;
+}
+
+answer :: () -> int {
+    x := sum(5, \"hi\", 7, 9);
+    y := count_of();
+    z := x + y;
+    return z;
+}
+
+#run answer()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(142)));
+}
+
+#[test]
+fn run_of_fixed_array_zero_initialises_and_writes_elements() {
+    let irs = compile_source_and_get_executed_intemediate_representation("sum_of_squares :: () -> int {
+    squares: [16] u8;
+    i := 0;
+    while i < 4 {
+        product := i * i;
+        squares[i] = cast(u8) product;
+        i = i + 1;
+    }
+    total := 0;
+    i = 0;
+    while i < 16 {
+        square := squares[i];
+        value := cast(s64) square;
+        total = total + value;
+        i = i + 1;
+    }
+    return total;
+}
+
+#run sum_of_squares()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(14)));
+}
+
+#[test]
+fn run_of_fixed_array_sized_by_size_of_holds_a_struct() {
+    let irs = compile_source_and_get_executed_intemediate_representation("Header :: struct {
+    id: s64;
+    count: s64;
+}
+
+last_byte :: () -> int {
+    buffer: [size_of(Header) * 2] u8 = ---;
+    buffer[31] = 7;
+    last := buffer[31];
+    value := cast(s64) last;
+    return value;
+}
+
+#run last_byte()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(7)));
+}
+
+#[test]
+fn run_of_large_fixed_array_is_zeroed_over_a_used_stack() {
+    let irs = compile_source_and_get_executed_intemediate_representation("fill :: () {
+    bytes: [133] u8 = ---;
+    i := 0;
+    while i < 133 {
+        bytes[i] = 1;
+        i = i + 1;
+    }
+}
+
+total :: () -> int {
+    bytes: [133] u8;
+    sum := 0;
+    i := 0;
+    while i < 133 {
+        byte := bytes[i];
+        value := cast(s64) byte;
+        sum = sum + value;
+        i = i + 1;
+    }
+    return sum;
+}
+
+filled_then_total :: () -> int {
+    fill();
+    result := total();
+    return result;
+}
+
+#run filled_then_total()");
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(0)));
+}
@@ -105,4 +105,13 @@ fn read_characters_up_until_stops_at_eof_regardless() {
     );
     
     assert_eq!(&read_characters, "ab");
-}
\ No newline at end of file
+}
+#[test]
+fn read_characters_on_line_up_until_stops_at_end_of_line() {
+    let mut reader = create_reader("ab
+cd");
+
+    let read_characters = read_characters_on_line_up_until(&mut reader, is_character_whitespace);
+    
+    assert_eq!(&read_characters, "ab");
+}
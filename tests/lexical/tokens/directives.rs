@@ -12,4 +12,99 @@ fn compound_get_for_directive_with_string_literal() {
         
     let token = get_next_token(&mut lexer);
     assert_eq!(token.item, SourceTokenItem::Terminator(Terminator::Line));
-}
\ No newline at end of file
+}
+
+#[test]
+fn get_import_directive_with_module_name() {
+    let mut lexer = lex("#import \"Basic\";");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::Import));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Literal(UnresolvedLiteral::String(to_byte_string("Basic"))));
+        
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Terminator(Terminator::Line));
+}
+
+#[test]
+fn get_scope_directives() {
+    let mut lexer = lex("#scope_file\n#scope_export");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::ScopeFile));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::ScopeExport));
+}
+
+#[test]
+fn get_assert_directive_with_comparison_operators() {
+    let mut lexer = lex("#assert == != < <= > >=");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::Assert));
+
+    for op in [equal_operator(), not_equal_operator(), less_than_operator(), less_than_or_equal_operator(), greater_than_operator(), greater_than_or_equal_operator()] {
+        let token = get_next_token(&mut lexer);
+        assert_eq!(token.item, SourceTokenItem::Operator(op));
+    }
+}
+
+#[test]
+fn get_asm_directive() {
+    let mut lexer = lex("#asm { mov rax, 5; }");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::Asm));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Enclosure(Enclosure::Brace(EnclosureType::Open)));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Identifier("mov".to_string()));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Identifier("rax".to_string()));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Terminator(Terminator::Arg));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Literal(UnresolvedLiteral::Int(string("5"))));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Terminator(Terminator::Line));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Enclosure(Enclosure::Brace(EnclosureType::Close)));
+}
+
+#[test]
+fn get_insert_and_code_directives() {
+    let mut lexer = lex("#insert #code { }");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::Insert));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::Code));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Enclosure(Enclosure::Brace(EnclosureType::Open)));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Enclosure(Enclosure::Brace(EnclosureType::Close)));
+}
+
+#[test]
+fn get_expand_directive_and_caller_identifier() {
+    let mut lexer = lex("#expand `x");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Directive(Directive::Expand));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Identifier("`x".to_string()));
+}
@@ -383,4 +383,14 @@ fn compound_get_for_function_call() {
     let token = get_next_token(&mut lexer);
     assert_eq!(token.item, SourceTokenItem::Terminator(Terminator::Line));
 
-}
\ No newline at end of file
+}
+#[test]
+fn get_identifier_starting_with_underscore() {
+    let mut lexer = lex("_snprintf :: ");
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Identifier("_snprintf".to_string()));
+
+    let token = get_next_token(&mut lexer);
+    assert_eq!(token.item, SourceTokenItem::Assignment(Assignment::Declaration));
+}
@@ -1,5 +1,6 @@
 mod assignment;
 mod loops;
+mod indexing;
 mod functions;
 mod directives;
 mod structs;
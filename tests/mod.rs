@@ -1,4 +1,7 @@
 pub mod parsing;
 pub mod lexical;
 pub mod typing;
-pub mod intermediate_representation;
\ No newline at end of file
+pub mod intermediate_representation;
+pub mod interpretation;
+pub mod backends;
+pub mod arguments;
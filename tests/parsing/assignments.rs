@@ -100,6 +100,46 @@ fn parse_casted_assigment_parses_correctly() {
 }                       
 
 
+#[test]
+fn parse_checked_casted_assigment_parses_correctly() {        
+    let units = run_parse_file_return_only_units("main :: () {
+    x := cast,checked(u8) y;
+}");
+
+    assert_eq!(units.len(), 2);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        variable_declaration_item(            
+                            string("x"),                     
+                            node(
+                                position(22, 2, 10),
+                                checked_cast_item(
+                                    resolved_resolvable_type(create_shareable(unsigned_int_8_runtime_type())),
+                                    node(
+                                        position(39, 2, 27),
+                                        identifier_item(string("y"), unknown_scope()),
+                                    )
+                                )
+                            ),
+                            unresolved_resolvable_type()
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
+
+
 #[test]
 fn parse_struct_member_access_assignment_parses_correctly() {        
     let units = run_parse_file_return_only_units("proc :: (s: string) {
@@ -118,7 +158,7 @@ fn parse_struct_member_access_assignment_parses_correctly() {
                         position(9, 1, 10),
                         member_declaration_item(
                             string("s"),
-                            resolved_resolvable_type(create_shareable(string_runtime_type()))
+                            unresolved_named_resolvable_type(string("string"))
                         )
                     )
                 ),
@@ -156,4 +196,65 @@ fn parse_struct_member_access_assignment_parses_correctly() {
             )
         )
     )
-}
\ No newline at end of file
+}
+#[test]
+fn parse_uninitialised_assigment_parses_correctly() {        
+    let units_and_types = compile_source_and_get_units_and_types("main :: () {
+        x : s32 = ---;
+    }");
+
+    assert_eq!(units_and_types.len(), 2);
+    let (proc_body_unit, _,) = get_first_typed_procedure_body_unit_named(&units_and_types, "main");
+    assert_eq!(
+        proc_body_unit.tree, 
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(21, 2, 9),
+                        uninitialised_variable_declaration_item(
+                            string("x"),
+                            resolved_resolvable_type(create_shareable(signed_int_32_runtime_type())),
+                            position(31, 2, 19)
+                        )
+                    )
+                )
+            )
+        )
+    )
+}                       
+
+#[test]
+fn parse_array_declaration_without_value_is_zero_initialised() {
+    let units_and_types = compile_source_and_get_units_and_types("main :: () {
+        buffer : [16] u8;
+    }");
+
+    assert_eq!(units_and_types.len(), 2);
+    let (proc_body_unit, _,) = get_first_typed_procedure_body_unit_named(&units_and_types, "main");
+    assert_eq!(
+        proc_body_unit.tree,
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(21, 2, 9),
+                        variable_declaration_item(
+                            string("buffer"),
+                            node(position(37, 2, 25), null_item()),
+                            resolved_resolvable_type(create_shareable(array_runtime_type(unsigned_int_8_runtime_type(), 16).unwrap()))
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
@@ -40,4 +40,43 @@ fn parse_const_declaration_with_type_parses_correctly() {
             )    
         )
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn parse_const_declaration_with_run_directive_parses_correctly() {
+    let units = run_parse_file_return_only_units("Answer :: #run 40 + 2;");
+
+    assert_eq!(
+        units[0].tree,
+        node(
+            position(0, 1, 1),
+            constant_item(
+                string("Answer"),
+                node(
+                    position(10, 1, 11),
+                    run_directive_item(
+                        node(
+                            position(15, 1, 16),
+                            binary_expression_item(
+                                node(
+                                    position(18, 1, 19),
+                                    operator_item(add_operator())
+                                ),
+                                node(
+                                    position(15, 1, 16),
+                                    literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("40")))),
+                                ),
+                                node(
+                                    position(20, 1, 21),
+                                    literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("2")))),
+                                ),
+                                unresolved_resolvable_type()
+                            )
+                        )
+                    )
+                ),
+                unresolved_resolvable_type()
+            )
+        )
+    );
+}
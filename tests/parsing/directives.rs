@@ -33,6 +33,43 @@ fn parse_run_directive_parses_correctly() {
     )
 }
 
+#[test]
+fn parse_assert_directive_parses_correctly() {        
+    let units = run_parse_file_return_only_units("#assert SIZE == 16 \"size changed\";");
+    
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            assert_directive_item(
+                node(
+                    position(8, 1, 9),
+                    binary_expression_item(
+                        node(
+                            position(13, 1, 14),
+                            operator_item(equal_operator())
+                        ),
+                        node(
+                            position(8, 1, 9),
+                            unknown_scope_identifier_item(string("SIZE")),
+                        ),
+                        node(
+                            position(16, 1, 17),
+                            literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("16")))),
+                        ),
+                        unresolved_resolvable_type()
+                    )
+                ),
+                node(
+                    position(19, 1, 20),
+                    literal_item(unresolved_resolvable_literal(unresolved_string_literal(to_byte_string("size changed")))),
+                )
+            )
+        )
+    )
+}
+
 #[test]
 fn parse_load_directive_parses_correctly() {
     let units = run_parse_file_return_only_units("#load \"test.jai\"");
@@ -52,6 +89,66 @@ fn parse_load_directive_parses_correctly() {
     );
 }
 
+#[test]
+fn parse_import_directive_parses_correctly() {
+    let units = run_parse_file_return_only_units("#import \"Basic\";");
+       
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            import_directive_item(
+                node(
+                    position(8, 1, 9),
+                    literal_item(unresolved_resolvable_literal(unresolved_string_literal(to_byte_string("Basic")))),
+                )
+            )
+        )
+    );
+    assert_eq!(try_get_import_directive(units[0].tree.item_ref()), Some((string("Basic"), None)));
+}
+
+#[test]
+fn parse_import_directive_as_constant_parses_correctly() {
+    let units = run_parse_file_return_only_units("Basic :: #import \"Basic\";");
+       
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            constant_item(
+                string("Basic"),
+                node(
+                    position(9, 1, 10),
+                    import_directive_item(
+                        node(
+                            position(17, 1, 18),
+                            literal_item(unresolved_resolvable_literal(unresolved_string_literal(to_byte_string("Basic")))),
+                        )
+                    )
+                ),
+                unresolved_resolvable_type()
+            )
+        )
+    );
+    assert_eq!(try_get_import_directive(units[0].tree.item_ref()), Some((string("Basic"), Some(string("Basic")))));
+}
+
+#[test]
+fn parse_import_directive_without_module_name_returns_error() {
+    let (_, errors) = compile_source_and_get_parsed_units_and_errors("#import Basic;");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            parser_error(expected_module_name_error()),
+            position(8, 1, 9)
+        )
+    );
+}
 
 #[test]
 fn parse_foreign_system_library_directive_parses_correctly() {
@@ -79,3 +176,205 @@ fn parse_foreign_system_library_directive_parses_correctly() {
         )
     );
 }
+
+#[test]
+fn parse_scope_directives_set_visibility_of_following_units() {
+    let units = run_parse_file_return_only_units("First :: () {}
+#scope_file
+Second :: () {}
+#scope_export
+Third :: 1;");
+       
+    assert_eq!(units.len(), 5);
+    assert_eq!(units[0].visibility, export_visibility());
+    assert_eq!(units[1].visibility, export_visibility());
+    assert_eq!(units[2].visibility, file_visibility());
+    assert_eq!(units[3].visibility, file_visibility());
+    assert_eq!(units[4].visibility, export_visibility());
+}
+
+#[test]
+fn parse_asm_directive_parses_correctly() {
+    let units = run_parse_file_return_only_units("main :: () {
+    #asm { mov rax, x; add rax, 5; }
+}"
+    );
+
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        asm_item(vec!(
+                            node(
+                                position(24, 2, 12),
+                                asm_instruction_item(
+                                    string("mov"),
+                                    vec!(
+                                        node(position(28, 2, 16), asm_register_item(string("rax"))),
+                                        node(position(33, 2, 21), unknown_scope_identifier_item(string("x")))
+                                    )
+                                )
+                            ),
+                            node(
+                                position(36, 2, 24),
+                                asm_instruction_item(
+                                    string("add"),
+                                    vec!(
+                                        node(position(40, 2, 28), asm_register_item(string("rax"))),
+                                        node(position(45, 2, 33), literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("5")))))
+                                    )
+                                )
+                            )
+                        ))
+                    )
+                )
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_insert_directive_with_procedure_call_parses_correctly() {
+    let units = run_parse_file_return_only_units("#insert generate();");
+
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            insert_directive_item(
+                node(
+                    position(8, 1, 9),
+                    procedure_call_item(
+                        string("generate"),
+                        vec!(),
+                        unresolved_resolvable_type()
+                    )
+                )
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_insert_directive_with_code_parses_correctly() {
+    let units = run_parse_file_return_only_units("#insert #code { x :: 42; }");
+
+    assert_eq!(units.len(), 1);
+
+    let code_units = match units[0].tree.item_ref() {
+        AbstractSyntaxNodeItem::Insert { expr } => try_get_code_units(expr.item_ref()).unwrap().clone(),
+        item => panic!("expected insert, found {:?}", item)
+    };
+
+    assert_eq!(code_units.len(), 1);
+    assert_eq!(
+        code_units[0].tree,
+        node(
+            position(16, 1, 17),
+            constant_item(
+                string("x"),
+                node(
+                    position(21, 1, 22),
+                    literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("42"))))
+                ),
+                unresolved_resolvable_type()
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_code_constant_parses_correctly() {
+    let units = run_parse_file_return_only_units("answer :: #code {
+    get_answer :: () -> int { return 42; }
+}");
+
+    assert_eq!(units.len(), 1);
+
+    let (name, code_units) = try_get_code_constant(units[0].tree.item_ref()).unwrap();
+
+    assert_eq!(name, "answer");
+    assert_eq!(code_units.len(), 2);
+    assert!(matches!(code_units[0].tree.item_ref(), AbstractSyntaxNodeItem::ProcedureBody { .. }));
+    assert!(matches!(
+        code_units[1].tree.item_ref(),
+        AbstractSyntaxNodeItem::ProcedureHeader { body: ProcedureBodyReference::Local(id), .. } if *id == code_units[0].id
+    ));
+}
+
+#[test]
+fn parse_code_constant_with_statements_parses_them_as_statements() {
+    let units = run_parse_file_return_only_units("add_two :: #code {
+    x = x + 2;
+}");
+
+    let (_, code_units) = try_get_code_constant(units[0].tree.item_ref()).unwrap();
+
+    assert_eq!(code_units.len(), 1);
+    assert!(matches!(code_units[0].tree.item_ref(), AbstractSyntaxNodeItem::Assignment { .. }));
+}
+
+#[test]
+fn parse_insert_directive_as_value_parses_correctly() {
+    let units = run_parse_file_return_only_units("answer :: () {
+    x := #insert \"42\";
+}");
+
+    let statements = match units[0].tree.item_ref() {
+        AbstractSyntaxNodeItem::ProcedureBody { statements, .. } => statements.clone(),
+        item => panic!("expected procedure body, found {:?}", item)
+    };
+
+    assert_eq!(
+        statements[0],
+        node(
+            position(19, 2, 5),
+            variable_declaration_item(
+                string("x"),
+                node(
+                    position(24, 2, 10),
+                    insert_directive_item(
+                        node(
+                            position(32, 2, 18),
+                            literal_item(unresolved_resolvable_literal(unresolved_string_literal(to_byte_string("42"))))
+                        )
+                    )
+                ),
+                unresolved_resolvable_type()
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_insert_directive_in_procedure_body_with_procedure_call_hoists_the_call() {
+    let units = run_parse_file_return_only_units("answer :: () {
+    #insert generate();
+}");
+
+    assert_eq!(units.len(), 3);
+
+    let inserted_name = match units[0].tree.item_ref() {
+        AbstractSyntaxNodeItem::ProcedureBody { statements, .. } => match statements[0].item_ref() {
+            AbstractSyntaxNodeItem::Insert { expr } => match expr.item_ref() {
+                AbstractSyntaxNodeItem::Identifier { name, .. } => name.clone(),
+                item => panic!("expected identifier, found {:?}", item)
+            },
+            item => panic!("expected insert, found {:?}", item)
+        },
+        item => panic!("expected procedure body, found {:?}", item)
+    };
+
+    let (name, call) = try_get_insert_directive_constant(units[2].tree.item_ref()).unwrap();
+
+    assert_eq!(name, &inserted_name);
+    assert!(matches!(call.item_ref(), AbstractSyntaxNodeItem::ProcedureCall { name, .. } if name == "generate"));
+}
@@ -106,4 +106,212 @@ fn parse_variable_expression_parses_correctly() {
             )
         )
     )
-}
\ No newline at end of file
+}
+#[test]
+fn parse_subtraction_chain_associates_to_the_left() {
+    let units = run_parse_file_return_only_units("main :: () {
+    x := 1 - 2 - 3;
+}");
+
+    assert_eq!(units.len(), 2);
+    assert_eq!(
+        units[0].tree,
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        variable_declaration_item(
+                            string("x"),
+                            node(
+                                position(22, 2, 10),
+                                binary_expression_item(
+                                    node(
+                                        position(28, 2, 16),
+                                        operator_item(subtract_operator())
+                                    ),
+                                    node(
+                                        position(22, 2, 10),
+                                        binary_expression_item(
+                                            node(
+                                                position(24, 2, 12),
+                                                operator_item(subtract_operator())
+                                            ),
+                                            node(
+                                                position(22, 2, 10),
+                                                literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("1")))),
+                                            ),
+                                            node(
+                                                position(26, 2, 14),
+                                                literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("2")))),
+                                            ),
+                                            unresolved_resolvable_type()
+                                        )
+                                    ),
+                                    node(
+                                        position(30, 2, 18),
+                                        literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("3")))),
+                                    ),
+                                    unresolved_resolvable_type()
+                                )
+                            ),
+                            unresolved_resolvable_type()
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_multiplication_binds_tighter_than_addition() {
+    let units = run_parse_file_return_only_units("main :: () {
+    x := 1 * 2 + 3;
+}");
+
+    assert_eq!(units.len(), 2);
+    assert_eq!(
+        units[0].tree,
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        variable_declaration_item(
+                            string("x"),
+                            node(
+                                position(22, 2, 10),
+                                binary_expression_item(
+                                    node(
+                                        position(28, 2, 16),
+                                        operator_item(add_operator())
+                                    ),
+                                    node(
+                                        position(22, 2, 10),
+                                        binary_expression_item(
+                                            node(
+                                                position(24, 2, 12),
+                                                operator_item(multiply_operator())
+                                            ),
+                                            node(
+                                                position(22, 2, 10),
+                                                literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("1")))),
+                                            ),
+                                            node(
+                                                position(26, 2, 14),
+                                                literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("2")))),
+                                            ),
+                                            unresolved_resolvable_type()
+                                        )
+                                    ),
+                                    node(
+                                        position(30, 2, 18),
+                                        literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("3")))),
+                                    ),
+                                    unresolved_resolvable_type()
+                                )
+                            ),
+                            unresolved_resolvable_type()
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_index_binds_tighter_than_the_operator_after_it() {
+    let units = run_parse_file_return_only_units("main :: () {
+    x := p[i + 1] * 2;
+}");
+
+    assert_eq!(units.len(), 2);
+    assert_eq!(
+        units[0].tree,
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        variable_declaration_item(
+                            string("x"),
+                            node(
+                                position(22, 2, 10),
+                                binary_expression_item(
+                                    node(
+                                        position(31, 2, 19),
+                                        operator_item(multiply_operator())
+                                    ),
+                                    node(
+                                        position(22, 2, 10),
+                                        index_expr_item(
+                                            node(
+                                                position(22, 2, 10),
+                                                unknown_scope_identifier_item(string("p"))
+                                            ),
+                                            node(
+                                                position(24, 2, 12),
+                                                binary_expression_item(
+                                                    node(
+                                                        position(26, 2, 14),
+                                                        operator_item(add_operator())
+                                                    ),
+                                                    node(
+                                                        position(24, 2, 12),
+                                                        unknown_scope_identifier_item(string("i"))
+                                                    ),
+                                                    node(
+                                                        position(28, 2, 16),
+                                                        literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("1")))),
+                                                    ),
+                                                    unresolved_resolvable_type()
+                                                )
+                                            ),
+                                            unresolved_resolvable_type()
+                                        )
+                                    ),
+                                    node(
+                                        position(33, 2, 21),
+                                        literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("2")))),
+                                    ),
+                                    unresolved_resolvable_type()
+                                )
+                            ),
+                            unresolved_resolvable_type()
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
+
+#[test]
+fn parse_unclosed_index_is_an_error() {
+    let errors = compile_source_and_get_errors("main :: () {
+    x := p[1;
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            parser_error(expected_close_bracket_error()),
+            position(25, 2, 13)
+        )
+    );
+}
@@ -0,0 +1,71 @@
+use rust_hephaestus::*;
+
+#[test]
+fn parse_initialised_global_variable_parses_correctly() {
+    let units = run_parse_file_return_only_units("counter: s64 = 5;");
+       
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            global_variable_item(
+                string("counter"),
+                node(
+                    position(15, 1, 16),
+                    literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("5")))),
+                ),
+                resolved_resolvable_type(create_shareable(signed_int_64_runtime_type()))
+            )
+        )
+    );
+}
+
+#[test]
+fn parse_zero_initialised_global_variable_parses_correctly() {
+    let units = run_parse_file_return_only_units("buffer: u8;");
+       
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            global_variable_item(
+                string("buffer"),
+                node(position(10, 1, 11), null_item()),
+                resolved_resolvable_type(create_shareable(unsigned_int_8_runtime_type()))
+            )
+        )
+    );
+}
+
+#[test]
+fn parse_assignment_to_existing_identifier_parses_correctly() {
+    let units = run_parse_file_return_only_units("proc :: () {
+    counter = 5;
+}");
+
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("proc"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        assignment_item(
+                            node(position(17, 2, 5), unknown_scope_identifier_item(string("counter"))),
+                            node(
+                                position(27, 2, 15),
+                                literal_item(unresolved_resolvable_literal(unresolved_int_literal(string("5")))),
+                            ),
+                            unresolved_resolvable_type()
+                        )
+                    )
+                )
+            )
+        )
+    );
+}
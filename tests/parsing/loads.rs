@@ -0,0 +1,102 @@
+use rust_hephaestus::*;
+
+fn sorted(mut file_names: Vec<String>) -> Vec<String> {
+    file_names.sort();
+    file_names
+}
+
+#[test]
+fn load_resolves_file_relative_to_loading_file() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"lib/first.hep\";");
+    add_mock_file(&mut reader, "lib/first.hep", "#load \"second.hep\";
+#load \"./nested/../third.hep\";");
+    add_mock_file(&mut reader, "lib/second.hep", "");
+    add_mock_file(&mut reader, "lib/third.hep", "#load \"../fourth.hep\";");
+    add_mock_file(&mut reader, "fourth.hep", "");
+
+    let file_names = compile_file_and_get_parsed_file_names("test.hep", reader);
+
+    assert_eq!(
+        sorted(file_names),
+        vec!(string("fourth.hep"), string("lib/first.hep"), string("lib/second.hep"), string("lib/third.hep"), string("test.hep"))
+    );
+}
+
+#[test]
+fn file_loaded_by_several_files_is_parsed_once() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"first.hep\";
+#load \"second.hep\";
+main :: () {
+    x := common();
+}");
+    add_mock_file(&mut reader, "first.hep", "#load \"common.hep\";");
+    add_mock_file(&mut reader, "second.hep", "#load \"./common.hep\";");
+    add_mock_file(&mut reader, "common.hep", "common :: () -> int {
+    return 1;
+}");
+
+    let file_names = compile_file_and_get_parsed_file_names("test.hep", reader);
+
+    assert_eq!(file_names.iter().filter(|file_name| *file_name == "common.hep").count(), 1);
+}
+
+#[test]
+fn load_cycle_is_reported_with_chain_of_files() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"lib/first.hep\";");
+    add_mock_file(&mut reader, "lib/first.hep", "#load \"second.hep\";");
+    add_mock_file(&mut reader, "lib/second.hep", "main :: () {
+}
+#load \"../test.hep\";");
+
+    let errors = compile_file_and_get_errors("test.hep", reader);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].filename, "lib/second.hep");
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            load_cycle_error(vec!(string("test.hep"), string("lib/first.hep"), string("lib/second.hep"), string("test.hep"))),
+            position(15, 3, 1)
+        )
+    );
+}
+
+#[test]
+fn file_loading_itself_is_reported_as_cycle() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"./test.hep\";");
+
+    let errors = compile_file_and_get_errors("test.hep", reader);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(load_cycle_error(vec!(string("test.hep"), string("test.hep"))), position(0, 1, 1))
+    );
+}
+
+#[test]
+fn file_named_like_preload_is_compiled_from_its_own_source() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "preload.hep", "main :: () {
+    x := 1;
+}");
+
+    let irs = compile_file_and_get_intemediate_representation("preload.hep", reader);
+
+    assert!(irs.iter().any(|ir| ir.filename == "preload.hep" && ir.top_level_symbol == "main"));
+}
+
+#[test]
+fn load_of_file_named_like_preload_reads_that_file() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"preload.hep\";");
+    add_mock_file(&mut reader, "preload.hep", "");
+
+    let file_names = compile_file_and_get_parsed_file_names("test.hep", reader);
+
+    assert_eq!(sorted(file_names), vec!(string("preload.hep"), string("test.hep")));
+}
@@ -4,6 +4,8 @@ mod procedures;
 mod expressions;
 mod assignments;
 mod structs;
+mod globals;
+mod loads;
 
 use rust_hephaestus::*;
 
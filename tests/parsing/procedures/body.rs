@@ -112,4 +112,61 @@ fn parse_procedure_body_parses_correctly() {
             )
         )
     );
-}
\ No newline at end of file
+}
+#[test]
+fn parse_procedure_body_with_if_else_parses_correctly() {
+    let units = run_parse_file_return_only_units("main :: () {
+    if x {
+        return;
+    } else {
+        return;
+    }
+}");
+
+    assert_eq!(units.len(), 2);
+    assert_eq!(
+        units[0].tree,
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        if_item(
+                            node(
+                                position(20, 2, 8),
+                                unknown_scope_identifier_item(string("x"))
+                            ),
+                            vec!(
+                                node(position(32, 3, 9), return_item(vec!()))
+                            ),
+                            vec!(
+                                node(position(61, 5, 9), return_item(vec!()))
+                            )
+                        )
+                    )
+                )
+            )
+        )
+    );
+}
+
+#[test]
+fn parse_procedure_body_with_unclosed_while_reports_error() {
+    let errors = compile_source_and_get_errors("main :: () {
+    while x {
+        return;
+");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            parser_error(expected_close_brace_error()),
+            SourceFilePosition::default()
+        )
+    );
+}
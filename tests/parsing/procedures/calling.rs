@@ -23,6 +23,29 @@ fn parse_procedure_call_parses_correctly() {
     );
 }
 
+#[test]
+fn parse_qualified_procedure_call_parses_correctly() {
+    let units = run_parse_file_return_only_units("#run Basic.SomeProcedure()");
+       
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            run_directive_item(
+                node(
+                    position(5, 1, 6),
+                    procedure_call_item(
+                        string("Basic.SomeProcedure"),
+                        vec!(),
+                        unresolved_resolvable_type()
+                    )
+                )
+            )
+        )
+    );
+}
+
 #[test]
 fn parse_procedure_call_with_arg_parses_correctly() {
     let units= run_parse_file_return_only_units("#run SomeProcedure(a, b)");
@@ -125,7 +125,7 @@ fn parse_procedure_header_with_args_and_return_type_parses_correctly() {
                     ),
                     node(
                         position(41, 1, 42),
-                        member_declaration_item(string("z"), resolved_resolvable_type(create_shareable(string_runtime_type()))),
+                        member_declaration_item(string("z"), unresolved_named_resolvable_type(string("string"))),
                     )
                 ),
                 vec!(
@@ -162,7 +162,7 @@ fn parse_procedure_header_with_pointer_args_and_return_type_parses_correctly() {
                     ),
                     node(
                         position(38, 1, 39),
-                        member_declaration_item(string("z"), resolved_resolvable_type(create_shareable(string_pointer_runtime_type()))),
+                        member_declaration_item(string("z"), unresolved_pointer_resolvable_type(unresolved_named_resolvable_type(string("string")))),
                     )
                 ),
                 vec!(
@@ -248,3 +248,91 @@ fn parse_procedure_header_with_other_built_in_args_types_parses_correctly() {
         )
     );
 }
+
+#[test]
+fn parse_expand_procedure_header_parses_correctly() {
+    let units = run_parse_file_return_only_units("Set :: (value: int) #expand {
+    `x = value;
+}");
+
+    assert_eq!(units.len(), 1);
+    assert_eq!(
+        units[0].tree, 
+        node(
+            position(0, 1, 1),
+            procedure_header_item(
+                string("Set"),
+                vec!(
+                    node(
+                        position(8, 1, 9),
+                        member_declaration_item(string("value"), resolved_resolvable_type(create_shareable(signed_int_64_runtime_type()))),
+                    )
+                ),
+                vec!(),
+                expand_procedure_body_reference(
+                    node(
+                        position(28, 1, 29),
+                        procedure_body_item(
+                            string("Set"),
+                            vec!(
+                                node(
+                                    position(8, 1, 9),
+                                    member_declaration_item(string("value"), resolved_resolvable_type(create_shareable(signed_int_64_runtime_type()))),
+                                )
+                            ),
+                            vec!(),
+                            vec!(
+                                node(
+                                    position(34, 2, 5),
+                                    assignment_item(
+                                        node(
+                                            position(34, 2, 5),
+                                            identifier_item(string("`x"), unknown_scope()),
+                                        ),
+                                        node(
+                                            position(39, 2, 10),
+                                            identifier_item(string("value"), unknown_scope()),
+                                        ),
+                                        unresolved_resolvable_type()
+                                    )
+                                )
+                            )
+                        )
+                    )
+                )
+            )
+        )
+    );
+}
+
+#[test]
+fn parse_procedure_header_with_variadic_arg_parses_correctly() {
+    let units = run_parse_file_return_only_units("Print :: (format: string, ..args: Any) {
+}");
+
+    assert_eq!(units.len(), 2);
+    assert_eq!(
+        units[1].tree, 
+        node(
+            position(0, 1, 1),
+            procedure_header_item(
+                string("Print"),
+                vec!(
+                    node(
+                        position(10, 1, 11),
+                        member_declaration_item(string("format"), unresolved_named_resolvable_type(string("string"))),
+                    ),
+                    node(
+                        position(28, 1, 29),
+                        member_declaration_item(
+                            string("args"),
+                            unresolved_array_view_resolvable_type(unresolved_named_resolvable_type(string("Any")))
+                        ),
+                    )
+                ),
+                vec!(),
+                local_procedure_body_reference(units[0].id),
+            )
+        )
+    );
+}
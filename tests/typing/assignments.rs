@@ -320,4 +320,92 @@ fn typing_struct_member_access_with_cast_assignment_types_sucessfully() {
             )
         )
     )
-}
\ No newline at end of file
+}
+#[test]
+fn typing_known_type_assignment_implicitly_widens_smaller_int() {
+    let units_and_types = compile_source_and_get_units_and_types("main :: () {
+    x: u8 = 1;
+    y: u32 = x;
+}");
+
+    assert_eq!(units_and_types.len(), 2);
+    let (proc_body_unit, proc_body_types) = get_first_typed_procedure_body_unit_named(&units_and_types, "main");
+
+    assert_eq!(proc_body_types.len(), 0);
+    assert_eq!(
+        proc_body_unit.tree, 
+        node(
+            position(11, 1, 12),
+            procedure_body_item(
+                string("main"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(17, 2, 5),
+                        variable_declaration_item(            
+                            string("x"),                     
+                            node(
+                                position(25, 2, 13),
+                                literal_item(resolved_resolvable_literal(resolved_unsigned_int_8_literal(1))),
+                            ),
+                            resolved_resolvable_type(create_shareable(unsigned_int_8_runtime_type()))
+                        )
+                    ),
+                    node(
+                        position(32, 3, 5),
+                        variable_declaration_item(            
+                            string("y"),                     
+                            node(
+                                position(41, 3, 14),
+                                cast_item(
+                                    resolved_resolvable_type(create_shareable(unsigned_int_32_runtime_type())),
+                                    node(
+                                        position(41, 3, 14),
+                                        identifier_item(string("x"), local_scope())
+                                    )
+                                )
+                            ),
+                            resolved_resolvable_type(create_shareable(unsigned_int_32_runtime_type()))
+                        )
+                    )
+                )
+            )
+        )
+    )
+}
+
+#[test]
+fn typing_known_type_assignment_with_lossy_implicit_conversion_reports_error() {
+    let errors = compile_source_and_get_errors("main :: () {
+    x: s64 = 1;
+    y: u8 = x;
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(lossy_implicit_conversion_error(string("s64"), string("u8"))),
+            position(41, 3, 13)
+        )
+    );
+}
+
+#[test]
+fn typing_known_type_assignment_from_unsigned_to_larger_signed_int_is_implicit() {
+    let errors = compile_source_and_get_errors("main :: () {
+    x: u32 = 1;
+    y: s64 = x;
+    z: s32 = x;
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(lossy_implicit_conversion_error(string("u32"), string("s32"))),
+            position(58, 4, 14)
+        )
+    );
+}
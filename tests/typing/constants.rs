@@ -50,4 +50,17 @@ fn typing_known_type_const_types_int_literal_assignment() {
             )
         )
     )
-}
\ No newline at end of file
+}
+#[test]
+fn typing_finds_constant_declared_in_loaded_file() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#load \"constants.hep\";
+main :: () {
+    x := ANSWER;
+}");
+    add_mock_file(&mut reader, "constants.hep", "ANSWER :: 42;");
+
+    let errors = compile_file_and_get_errors("test.hep", reader);
+
+    assert_eq!(errors.len(), 0);
+}
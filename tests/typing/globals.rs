@@ -0,0 +1,108 @@
+use rust_hephaestus::*;
+
+#[test]
+fn typing_global_variable_types_literal_and_registers_definition() {
+    let units_and_types = compile_source_and_get_units_and_types("counter: s64 = 5;");
+
+    assert_eq!(units_and_types.len(), 1);
+    let (unit, types) = &units_and_types[0];
+
+    assert_eq!(types.len(), 1);
+    assert_eq!(types[0].item, global_variable_definition_type_item(create_shareable(signed_int_64_runtime_type())));
+    assert_eq!(
+        unit.tree, 
+        node(
+            position(0, 1, 1),
+            global_variable_item(
+                string("counter"),
+                node(
+                    position(15, 1, 16),
+                    literal_item(resolved_resolvable_literal(resolved_signed_int_64_literal(5))),
+                ),
+                resolved_resolvable_type(create_shareable(signed_int_64_runtime_type()))
+            )
+        )
+    );
+}
+
+#[test]
+fn typing_procedure_body_reads_and_writes_global_variable() {
+    let units_and_types = compile_source_and_get_units_and_types("counter: u32 = 5;
+
+bump :: () {
+    counter = 6;
+    x := counter;
+}");
+
+    let (proc_body_unit, _) = get_first_typed_procedure_body_unit_named(&units_and_types, "bump");
+
+    assert_eq!(
+        proc_body_unit.tree, 
+        node(
+            position(30, 3, 12),
+            procedure_body_item(
+                string("bump"),
+                vec!(),
+                vec!(),
+                vec!(
+                    node(
+                        position(36, 4, 5),
+                        assignment_item(
+                            node(position(36, 4, 5), identifier_item(string("counter"), global_variable_scope())),
+                            node(
+                                position(46, 4, 15),
+                                literal_item(resolved_resolvable_literal(resolved_unsigned_int_32_literal(6))),
+                            ),
+                            resolved_resolvable_type(create_shareable(unsigned_int_32_runtime_type()))
+                        )
+                    ),
+                    node(
+                        position(53, 5, 5),
+                        variable_declaration_item(
+                            string("x"),
+                            node(position(58, 5, 10), identifier_item(string("counter"), global_variable_scope())),
+                            resolved_resolvable_type(create_shareable(unsigned_int_32_runtime_type()))
+                        )
+                    )
+                )
+            )
+        )
+    );
+}
+
+#[test]
+fn typing_assignment_to_constant_reports_error() {
+    let errors = compile_source_and_get_errors("LIMIT :: 5;
+
+main :: () {
+    LIMIT = 6;
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(assignment_to_constant_error(string("LIMIT"))),
+            position(30, 4, 5)
+        )
+    );
+}
+
+#[test]
+fn typing_assignment_to_global_variable_checks_value_type() {
+    let errors = compile_source_and_get_errors("counter: u8 = 1;
+
+main :: () {
+    x: s64 = 1;
+    counter = x;
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(lossy_implicit_conversion_error(string("s64"), string("u8"))),
+            position(61, 5, 15)
+        )
+    );
+}
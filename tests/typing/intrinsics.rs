@@ -0,0 +1,131 @@
+use rust_hephaestus::*;
+
+#[test]
+fn typing_size_of_struct_declared_later_folds_to_literal() {
+    let units_and_types = compile_source_and_get_units_and_types("HEADER_SIZE :: size_of(Header);
+
+Header :: struct {
+    tag: u8;
+    length: u32;
+    offset: u64;
+}");
+
+    let (unit, _) = units_and_types
+        .iter()
+        .find(|(unit, _)| matches!(unit.tree.item_ref(), AbstractSyntaxNodeItem::Constant { .. }))
+        .unwrap();
+
+    assert_eq!(
+        unit.tree,
+        node(
+            position(0, 1, 1),
+            constant_item(
+                string("HEADER_SIZE"),
+                node(
+                    position(15, 1, 16),
+                    literal_item(resolved_resolvable_literal(resolved_signed_int_64_literal(16))),
+                ),
+                resolved_resolvable_type(create_shareable(signed_int_64_runtime_type()))
+            )
+        )
+    )
+}
+
+#[test]
+fn typing_intrinsics_lay_out_nested_structs() {
+    let errors = compile_source_and_get_errors("Inner :: struct {
+    a: u8;
+    b: u16;
+}
+
+Outer :: struct {
+    flag: bool;
+    inner: Inner;
+    count: int;
+    name: string;
+}
+
+#assert size_of(Inner) == 4;
+#assert align_of(Inner) == 2;
+#assert size_of(Outer) == 32;
+#assert align_of(Outer) == 8;
+#assert offset_of(Outer, inner) == 2;
+#assert offset_of(Outer, count) == 8;
+#assert offset_of(Outer, name) == 16;
+#assert size_of(*u8) == 8;
+#assert size_of(u32) == 4;
+#assert size_of(type_of(1)) == 8;");
+
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn typing_size_of_mismatch_reports_assertion_with_evaluated_size() {
+    let errors = compile_source_and_get_errors("Header :: struct {
+    tag: u8;
+    offset: u64;
+    length: u32;
+}
+
+#assert size_of(Header) == 16 \"header layout changed\";");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            interpretation_error(assertion_failed_error(string("header layout changed"), vec!(24, 16))),
+            position(69, 7, 1)
+        )
+    );
+}
+
+#[test]
+fn typing_offset_of_unknown_member_reports_error() {
+    let errors = compile_source_and_get_errors("Header :: struct {
+    tag: u8;
+}
+
+#assert offset_of(Header, length) == 0;");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            type_inference_error(member_not_found_error(string("length"))),
+            position(61, 5, 27)
+        )
+    );
+}
+
+#[test]
+fn typing_array_sized_by_intrinsic_lays_out_in_place() {
+    let errors = compile_source_and_get_errors("Header :: struct {
+    tag: u8;
+    offset: u64;
+}
+
+Packet :: struct {
+    header: Header;
+    payload: [size_of(Header) * 2 + 1] u8;
+}
+
+#assert size_of(Packet) == 56;");
+
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn typing_array_with_length_that_is_not_constant_reports_error() {
+    let errors = compile_source_and_get_errors("main :: (count: s64) {
+    buffer: [count] u8;
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            type_inference_error(array_length_is_not_constant_error()),
+            position(27, 2, 5)
+        )
+    );
+}
@@ -1,4 +1,9 @@
 mod procedures;
 mod expressions;
 mod constants;
-mod assignments;
\ No newline at end of file
+mod assignments;
+mod visibility;
+mod modules;
+mod intrinsics;
+mod globals;
+mod preload;
@@ -0,0 +1,175 @@
+use rust_hephaestus::*;
+
+fn add_basic_module_to_test_file_system(main_source: &str) -> MockFileReader {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", main_source);
+    add_mock_file(&mut reader, "modules/Basic/module.hep", "#load \"helpers.hep\";
+answer :: () -> int {
+    x := helper();
+    return x;
+}");
+    add_mock_file(&mut reader, "modules/Basic/helpers.hep", "helper :: () -> int {
+    return 42;
+}");
+    reader
+}
+
+fn test_module_paths() -> Vec<String> {
+    vec!(string("modules"))
+}
+
+#[test]
+fn typing_finds_procedure_in_imported_module() {
+    let reader = add_basic_module_to_test_file_system("#import \"Basic\";
+main :: () {
+    x := answer();
+}");
+
+    let errors = compile_file_with_module_paths_and_get_errors("test.hep", test_module_paths(), reader);
+
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn typing_finds_qualified_procedure_in_module_imported_as_constant() {
+    let reader = add_basic_module_to_test_file_system("Basic :: #import \"Basic\";
+main :: () {
+    x := Basic.answer();
+}");
+
+    let errors = compile_file_with_module_paths_and_get_errors("test.hep", test_module_paths(), reader);
+
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn typing_does_not_find_unqualified_procedure_in_module_imported_as_constant() {
+    let reader = add_basic_module_to_test_file_system("Basic :: #import \"Basic\";
+main :: () {
+    x := answer();
+}");
+
+    let errors = compile_file_with_module_paths_and_get_errors("test.hep", test_module_paths(), reader);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].filename, "test.hep");
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            type_inference_error(type_not_visible_error(string("modules/Basic/module.hep"), position(21, 2, 1))),
+            position(48, 3, 10)
+        )
+    );
+}
+
+#[test]
+fn typing_does_not_find_program_procedure_from_module() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#import \"Basic\";
+helper :: () -> int {
+    return 1;
+}");
+    add_mock_file(&mut reader, "modules/Basic/module.hep", "answer :: () -> int {
+    return helper();
+}");
+
+    let errors = compile_file_with_module_paths_and_get_errors("test.hep", test_module_paths(), reader);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].filename, "modules/Basic/module.hep");
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            type_inference_error(type_not_visible_error(string("test.hep"), position(17, 2, 1))),
+            position(33, 2, 12)
+        )
+    );
+}
+
+#[test]
+fn module_imported_by_several_files_is_parsed_once() {
+    let mut reader = add_basic_module_to_test_file_system("#load \"other.hep\";
+#import \"Basic\";");
+    add_mock_file(&mut reader, "other.hep", "#import \"Basic\";");
+
+    let file_names = compile_file_with_module_paths_and_get_parsed_file_names("test.hep", test_module_paths(), reader);
+
+    assert_eq!(file_names.iter().filter(|file_name| *file_name == "modules/Basic/module.hep").count(), 1);
+}
+
+#[test]
+fn module_is_found_in_first_module_path_containing_it() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#import \"Basic\";");
+    add_mock_file(&mut reader, "second/Basic/module.hep", "");
+    add_mock_file(&mut reader, "third/Basic/module.hep", "");
+
+    let file_names = compile_file_with_module_paths_and_get_parsed_file_names(
+        "test.hep",
+        vec!(string("first"), string("second"), string("third")),
+        reader
+    );
+
+    assert_eq!(file_names, vec!(string("test.hep"), string("second/Basic/module.hep")));
+}
+
+#[test]
+fn module_is_found_in_bundled_modules_when_not_in_module_paths() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "#import \"Basic\";");
+    add_mock_file(&mut reader, &module_file_path(&get_bundled_modules_path(), "Basic"), "");
+
+    let file_names = compile_file_with_module_paths_and_get_parsed_file_names("test.hep", test_module_paths(), reader);
+
+    assert_eq!(file_names, vec!(string("test.hep"), module_file_path(&get_bundled_modules_path(), "Basic")));
+}
+
+#[test]
+fn importing_missing_module_reports_error() {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", "main :: () {
+}
+#import \"Missing\";");
+
+    let errors = compile_file_with_module_paths_and_get_errors("test.hep", test_module_paths(), reader);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].filename, "test.hep");
+    assert_eq!(errors[0].items[0], compilation_error(module_not_found_error(string("Missing")), position(15, 3, 1)));
+}
+
+#[test]
+fn run_of_qualified_procedure_call_bakes_return_value_into_data_section() {
+    let reader = add_basic_module_to_test_file_system("Basic :: #import \"Basic\";
+#run Basic.answer()");
+
+    let irs = compile_file_with_module_paths_and_get_executed_intemediate_representation("test.hep", test_module_paths(), reader);
+
+    assert_eq!(irs.len(), 1);
+    assert_eq!(irs[0].data.items, vec!(quad_word_data_item(42)));
+}
+
+#[test]
+fn bundled_modules_path_can_be_configured() {
+    let path = find_bundled_modules_path(Some(string("/opt/hephaestus/./modules")), None);
+
+    assert_eq!(path, "/opt/hephaestus/modules");
+}
+
+#[test]
+fn bundled_modules_path_is_beside_executable_when_installed() {
+    let install_directory = std::env::temp_dir().join(format!("hephaestus-install-{}", std::process::id()));
+    std::fs::create_dir_all(install_directory.join("modules")).unwrap();
+
+    let path = find_bundled_modules_path(None, Some(install_directory.join("rust-hephaestus")));
+
+    std::fs::remove_dir_all(&install_directory).unwrap();
+    assert_eq!(path, normalise_file_name(&install_directory.join("modules").to_string_lossy()));
+}
+
+#[test]
+fn bundled_modules_path_falls_back_to_source_tree() {
+    let path = find_bundled_modules_path(None, Some(std::env::temp_dir().join("no-modules-here").join("rust-hephaestus")));
+
+    assert_eq!(path, normalise_file_name(concat!(env!("CARGO_MANIFEST_DIR"), "/modules")));
+}
@@ -0,0 +1,107 @@
+use rust_hephaestus::*;
+
+#[test]
+fn preload_declares_string_layout() {
+    let result = string_runtime_type();
+
+    assert_eq!(result.name, "string");
+    assert_eq!(
+        result.item,
+        string_type_item(vec!(
+            runtime_type_member(string("count"), create_shareable(signed_int_64_runtime_type())),
+            runtime_type_member(string("data"), create_shareable(unsigned_int_8_pointer_runtime_type()))
+        ))
+    );
+    assert_eq!(result.size, resolved_type_size(16));
+}
+
+#[test]
+fn preload_declares_context_with_allocator() {
+    let result = context_runtime_type();
+
+    assert_eq!(result.name, "Context");
+    assert_eq!(
+        result.item,
+        struct_type_item(vec!(
+            runtime_type_member(string("allocator"), create_shareable(allocator_runtime_type())),
+            runtime_type_member(string("thread_index"), create_shareable(unsigned_int_32_runtime_type()))
+        ))
+    );
+    assert_eq!(result.size, resolved_type_size(24));
+}
+
+#[test]
+fn preload_declares_source_code_location() {
+    let result = source_code_location_runtime_type();
+
+    assert_eq!(result.name, "Source_Code_Location");
+    assert_eq!(
+        result.item,
+        struct_type_item(vec!(
+            runtime_type_member(string("fully_pathed_filename"), create_shareable(string_runtime_type())),
+            runtime_type_member(string("line_number"), create_shareable(signed_int_64_runtime_type())),
+            runtime_type_member(string("character_number"), create_shareable(signed_int_64_runtime_type()))
+        ))
+    );
+    assert_eq!(result.size, resolved_type_size(32));
+}
+
+#[test]
+fn preload_is_read_from_bundled_modules() {
+    let result = read_preload_runtime_types(PRELOAD_SOURCE);
+
+    assert_eq!(result.len(), 7);
+    assert_eq!(result[&type_info_built_in_type()], type_info_runtime_type());
+    assert_eq!(result[&any_built_in_type()], any_runtime_type());
+}
+
+#[test]
+fn typing_procedure_with_preload_type_args_has_no_errors() {
+    let errors = compile_source_and_get_errors("SomeProcedure :: (context: *Context, location: *Source_Code_Location) {
+}");
+
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn typing_procedure_with_preload_type_args_finds_core_types_compiled_from_preload() {
+    let units_and_types = compile_source_and_get_units_and_types("SomeProcedure :: (value: *Any, text: string, ..args: Any) {
+}");
+
+    let (_, types) = units_and_types
+        .iter()
+        .find(|(_, types)| !types.is_empty())
+        .unwrap();
+
+    assert_eq!(types[0].item, procedure_definition_type_item(
+        vec!(
+            create_shareable(any_pointer_runtime_type()),
+            create_shareable(string_runtime_type()),
+            create_shareable(array_view_runtime_type(any_runtime_type()).unwrap())
+        ),
+        vec!()
+    ));
+}
+
+#[test]
+fn preload_core_types_are_read_alongside_other_declarations() {
+    let result = read_preload_runtime_types("string :: struct {
+    count: int;
+    data: *u8;
+}
+
+Point :: struct {
+    x: s64;
+}
+
+PRELOAD_LIMIT :: 5;
+
+Any :: struct {
+    type: *void;
+    value_pointer: *void;
+}");
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[&string_built_in_type()], string_runtime_type());
+    assert_eq!(result[&any_built_in_type()].size, resolved_type_size(16));
+}
@@ -185,3 +185,20 @@ fn typing_procedure_body_with_pointer_args_from_header_get_typed_correctly() {
     );
 }
 
+
+#[test]
+fn typing_procedure_body_with_non_bool_condition_reports_error() {
+    let errors = compile_source_and_get_errors("main :: (count: int) {
+    while count {
+    }
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0],
+        compilation_error(
+            type_inference_error(condition_is_not_bool_error()),
+            position(33, 2, 11)
+        )
+    );
+}
@@ -257,4 +257,58 @@ SomeExternalProcedure :: (arg: int) {
     );
 
     assert_eq!(unit.tree, result);
-}
\ No newline at end of file
+}
+#[test]
+fn typing_procedure_call_with_any_arg_boxes_literal() {
+    let units_and_types = compile_source_and_get_units_and_types("SomeProcedure :: () {
+    print(42);
+}
+
+print :: (value: Any) {
+}");
+    let (_unit, print_types) = get_first_typed_procedure_header_unit_named_with_arg_count(
+        &units_and_types, "print", 
+        1
+    );
+    let print_type = print_types[0].clone();
+
+    let (unit, types) = get_first_typed_procedure_body_unit_named(&units_and_types, "SomeProcedure");
+    assert_eq!(types.len(), 0);
+    
+    let result = node(
+        position(20, 1, 21),
+        procedure_body_item(
+            string("SomeProcedure"),
+            vec!(),
+            vec!(),
+            vec!(                       
+                node(                    
+                    position(26, 2, 5),
+                    procedure_call_item(
+                        string("print"),
+                        vec!(
+                            node(
+                                position(32, 2, 11),                            
+                                arg_item( 
+                                    node(
+                                        position(32, 2, 11),
+                                        boxed_item(
+                                            resolved_resolvable_type(create_shareable(signed_int_64_runtime_type())),
+                                            node(
+                                                position(32, 2, 11),
+                                                literal_item(resolved_resolvable_literal(resolved_signed_int_64_literal(42)))
+                                            )
+                                        )
+                                    ),
+                                    resolved_resolvable_type(create_shareable(any_runtime_type())) 
+                                )
+                            ),
+                        ),
+                        resolved_resolvable_type(print_type)
+                    )
+                )
+            )
+        )
+    );
+    assert_eq!(unit.tree, result);
+}
@@ -0,0 +1,41 @@
+use rust_hephaestus::*;
+
+#[test]
+fn typing_expansion_with_unknown_caller_local_reports_error_at_macro_and_call_site() {
+    let errors = compile_source_and_get_errors("set :: (value: int) #expand {
+    `x = value;
+}
+
+main :: () {
+    set(42);
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            expansion_error(
+                string("test.hep"),
+                compilation_error(type_inference_error(caller_local_not_found_error(string("x"))), position(34, 2, 5))
+            ),
+            position(66, 6, 5)
+        )
+    );
+}
+
+#[test]
+fn typing_expansion_used_as_expression_reports_error() {
+    let errors = compile_source_and_get_errors("set :: (value: int) #expand {
+    `x = value;
+}
+
+main :: () {
+    x := set(42);
+}");
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(type_inference_error(expansion_not_a_statement_error(string("set"))), position(71, 6, 10))
+    );
+}
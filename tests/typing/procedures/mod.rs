@@ -1,3 +1,3 @@
 pub mod header;
 pub mod calling;
-pub mod body;
\ No newline at end of file
+pub mod body;pub mod expansion;
@@ -0,0 +1,46 @@
+use rust_hephaestus::*;
+
+fn add_scoped_helper_files_to_test_file_system(main_source: &str) -> MockFileReader {
+    let mut reader = create_mock_file_reader();
+    add_mock_file(&mut reader, "test.hep", main_source);
+    add_mock_file(&mut reader, "helpers.hep", "#scope_file
+Helper :: () {
+}
+#scope_export
+Exported :: () {
+    Helper();
+}");
+    reader
+}
+
+#[test]
+fn typing_finds_exported_procedure_in_loaded_file() {
+    let reader = add_scoped_helper_files_to_test_file_system("#load \"helpers.hep\";
+main :: () {
+    Exported();
+}");
+    
+    let errors = compile_file_and_get_errors("test.hep", reader);
+    
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn typing_does_not_find_file_scoped_procedure_in_loaded_file() {
+    let reader = add_scoped_helper_files_to_test_file_system("#load \"helpers.hep\";
+main :: () {
+    Helper();
+}");
+    
+    let errors = compile_file_and_get_errors("test.hep", reader);
+    
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].filename, "test.hep");
+    assert_eq!(
+        errors[0].items[0], 
+        compilation_error(
+            type_inference_error(type_not_visible_error(string("helpers.hep"), position(12, 2, 1))),
+            position(38, 3, 5)
+        )
+    );
+}